@@ -0,0 +1,35 @@
+; Contracts
+(contract_declaration
+  name: (identifier) @contract.name) @contract
+
+; Interfaces
+(interface_declaration
+  name: (identifier) @interface.name) @interface
+
+; Libraries
+(library_declaration
+  name: (identifier) @library.name) @library
+
+; Functions
+(function_definition
+  name: (identifier) @function.name) @function
+
+; Modifiers
+(modifier_definition
+  name: (identifier) @modifier.name) @modifier
+
+; Events
+(event_definition
+  name: (identifier) @event.name) @event
+
+; Structs
+(struct_declaration
+  name: (identifier) @struct.name) @struct
+
+; Enums
+(enum_declaration
+  name: (identifier) @enum.name) @enum
+
+; State variables
+(state_variable_declaration
+  name: (identifier) @state_variable.name) @state_variable
@@ -6,6 +6,7 @@
 
 use anyhow::Result;
 use cairo_lang_parser::utils::SimpleParserDatabase;
+use cairo_lang_syntax::node::helpers::QueryAttrs;
 use cairo_lang_syntax::node::kind::SyntaxKind;
 use cairo_lang_syntax::node::{ast, SyntaxNode, Terminal, TypedSyntaxNode};
 
@@ -18,6 +19,21 @@ pub struct CairoSymbols {
     pub impls: Vec<ImplSymbol>,
     pub modules: Vec<ModuleSymbol>,
     pub imports: Vec<ImportSymbol>,
+    /// `#[starknet::contract]` modules
+    pub contracts: Vec<ContractSymbol>,
+    /// Members of `#[storage]` structs
+    pub storage_vars: Vec<StorageVarSymbol>,
+    /// `#[event]` items and structs deriving `starknet::Event`
+    pub events: Vec<EventSymbol>,
+}
+
+/// How a contract function is exposed in the ABI
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntryPoint {
+    /// Takes `ref self` and may write storage
+    External,
+    /// Takes `self: @ContractState`, read-only
+    View,
 }
 
 #[derive(Debug)]
@@ -28,6 +44,8 @@ pub struct FunctionSymbol {
     pub is_public: bool,
     pub parameters: Vec<String>,
     pub return_type: Option<String>,
+    /// Set for functions in `#[abi(...)]` impls or marked `#[external(v0)]`
+    pub entry_point: Option<EntryPoint>,
 }
 
 #[derive(Debug)]
@@ -63,6 +81,27 @@ pub struct ModuleSymbol {
     pub is_public: bool,
 }
 
+#[derive(Debug)]
+pub struct ContractSymbol {
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
+#[derive(Debug)]
+pub struct StorageVarSymbol {
+    pub name: String,
+    pub type_name: String,
+    pub line: usize,
+}
+
+#[derive(Debug)]
+pub struct EventSymbol {
+    pub name: String,
+    pub start_line: usize,
+    pub end_line: usize,
+}
+
 #[derive(Debug)]
 pub struct ImportSymbol {
     pub path: String,
@@ -95,20 +134,25 @@ impl CairoParser {
 
         // Extract symbols from the AST
         let mut symbols = CairoSymbols::default();
-        self.extract_symbols_from_node(&syntax_node, content, &mut symbols)?;
+        self.extract_symbols_from_node(&syntax_node, content, &mut symbols, false)?;
 
         Ok(symbols)
     }
 
     /// Recursively extract symbols from a syntax node
+    ///
+    /// `in_abi_impl` is true below an `#[abi(...)]` impl, whose functions are
+    /// contract entry points.
     fn extract_symbols_from_node(
         &self,
         node: &SyntaxNode,
         content: &str,
         symbols: &mut CairoSymbols,
+        in_abi_impl: bool,
     ) -> Result<()> {
         use SyntaxKind::*;
 
+        let mut child_in_abi_impl = in_abi_impl;
         match node.kind(&self.db) {
             FunctionWithBody => {
                 let func = ast::FunctionWithBody::from_syntax_node(&self.db, *node);
@@ -144,6 +188,29 @@ impl CairoParser {
                     ),
                 };
 
+                let entry_point = if in_abi_impl || func.has_attr(&self.db, "external") {
+                    // Storage access is by ref for externals, by snapshot for views
+                    let takes_ref = func
+                        .declaration(&self.db)
+                        .signature(&self.db)
+                        .parameters(&self.db)
+                        .elements(&self.db)
+                        .next()
+                        .is_some_and(|p| {
+                            p.modifiers(&self.db)
+                                .as_syntax_node()
+                                .get_text(&self.db)
+                                .contains("ref")
+                        });
+                    Some(if takes_ref {
+                        EntryPoint::External
+                    } else {
+                        EntryPoint::View
+                    })
+                } else {
+                    None
+                };
+
                 symbols.functions.push(FunctionSymbol {
                     name,
                     start_line,
@@ -151,6 +218,7 @@ impl CairoParser {
                     is_public: self.is_public(node),
                     parameters: params,
                     return_type,
+                    entry_point,
                 });
             }
             ItemStruct => {
@@ -168,6 +236,30 @@ impl CairoParser {
                     .map(|member| member.name(&self.db).text(&self.db).to_string(&self.db))
                     .collect();
 
+                if struct_item.has_attr(&self.db, "storage") {
+                    for member in struct_item.members(&self.db).elements(&self.db) {
+                        let (line, _) = self.get_line_range(&member.as_syntax_node(), content);
+                        symbols.storage_vars.push(StorageVarSymbol {
+                            name: member.name(&self.db).text(&self.db).to_string(&self.db),
+                            type_name: member
+                                .type_clause(&self.db)
+                                .ty(&self.db)
+                                .as_syntax_node()
+                                .get_text(&self.db)
+                                .trim()
+                                .to_string(),
+                            line,
+                        });
+                    }
+                }
+                if self.is_event(&struct_item) {
+                    symbols.events.push(EventSymbol {
+                        name: name.clone(),
+                        start_line,
+                        end_line,
+                    });
+                }
+
                 symbols.structs.push(StructSymbol {
                     name,
                     start_line,
@@ -176,6 +268,17 @@ impl CairoParser {
                     fields,
                 });
             }
+            ItemEnum => {
+                let enum_item = ast::ItemEnum::from_syntax_node(&self.db, *node);
+                if self.is_event(&enum_item) {
+                    let (start_line, end_line) = self.get_line_range(node, content);
+                    symbols.events.push(EventSymbol {
+                        name: enum_item.name(&self.db).text(&self.db).to_string(&self.db),
+                        start_line,
+                        end_line,
+                    });
+                }
+            }
             ItemTrait => {
                 let trait_item = ast::ItemTrait::from_syntax_node(&self.db, *node);
                 let name = trait_item.name(&self.db).text(&self.db).to_string(&self.db);
@@ -212,12 +315,21 @@ impl CairoParser {
                     start_line,
                     end_line,
                 });
+                child_in_abi_impl = in_abi_impl || impl_item.has_attr(&self.db, "abi");
             }
             ItemModule => {
                 let module = ast::ItemModule::from_syntax_node(&self.db, *node);
                 let name = module.name(&self.db).text(&self.db).to_string(&self.db);
                 let (start_line, end_line) = self.get_line_range(node, content);
 
+                if module.has_attr(&self.db, "starknet::contract") {
+                    symbols.contracts.push(ContractSymbol {
+                        name: name.clone(),
+                        start_line,
+                        end_line,
+                    });
+                }
+
                 symbols.modules.push(ModuleSymbol {
                     name,
                     start_line,
@@ -241,7 +353,7 @@ impl CairoParser {
 
         // Recursively process children
         for child in node.get_children(&self.db).iter() {
-            self.extract_symbols_from_node(child, content, symbols)?;
+            self.extract_symbols_from_node(child, content, symbols, child_in_abi_impl)?;
         }
 
         Ok(())
@@ -266,6 +378,16 @@ impl CairoParser {
         }
     }
 
+    /// Whether an item is a contract event: `#[event]` or deriving `starknet::Event`
+    fn is_event<'a>(&'a self, item: &impl QueryAttrs<'a>) -> bool {
+        item.has_attr(&self.db, "event")
+            || item.query_attr(&self.db, "derive").any(|attr| {
+                attr.as_syntax_node()
+                    .get_text(&self.db)
+                    .contains("starknet::Event")
+            })
+    }
+
     /// Check if a node has public visibility
     fn is_public(&self, node: &SyntaxNode) -> bool {
         // Check for 'pub' modifier in the node's text
@@ -325,4 +447,73 @@ trait Display {
         assert_eq!(symbols.traits.len(), 1);
         assert_eq!(symbols.traits[0].name, "Display");
     }
+
+    #[test]
+    fn test_parse_starknet_contract() {
+        let code = r#"
+#[starknet::contract]
+mod counter {
+    #[storage]
+    struct Storage {
+        count: u128,
+        owner: ContractAddress,
+    }
+
+    #[event]
+    #[derive(Drop, starknet::Event)]
+    enum Event {
+        Incremented: Incremented,
+    }
+
+    #[derive(Drop, starknet::Event)]
+    struct Incremented {
+        by: u128,
+    }
+
+    #[abi(embed_v0)]
+    impl CounterImpl of super::ICounter<ContractState> {
+        fn increment(ref self: ContractState, by: u128) {
+            self.count.write(self.count.read() + by);
+        }
+
+        fn get(self: @ContractState) -> u128 {
+            self.count.read()
+        }
+    }
+
+    fn helper(x: u128) -> u128 {
+        x
+    }
+}
+"#;
+
+        let symbols = parse_cairo(code, "counter.cairo").unwrap();
+
+        assert_eq!(symbols.contracts.len(), 1);
+        assert_eq!(symbols.contracts[0].name, "counter");
+
+        let storage: Vec<_> = symbols
+            .storage_vars
+            .iter()
+            .map(|v| (v.name.as_str(), v.type_name.as_str()))
+            .collect();
+        assert_eq!(
+            storage,
+            vec![("count", "u128"), ("owner", "ContractAddress")]
+        );
+
+        let events: Vec<_> = symbols.events.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(events, vec!["Event", "Incremented"]);
+
+        let entry_point = |name: &str| {
+            symbols
+                .functions
+                .iter()
+                .find(|f| f.name == name)
+                .and_then(|f| f.entry_point)
+        };
+        assert_eq!(entry_point("increment"), Some(EntryPoint::External));
+        assert_eq!(entry_point("get"), Some(EntryPoint::View));
+        assert_eq!(entry_point("helper"), None);
+    }
 }
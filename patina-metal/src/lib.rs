@@ -174,6 +174,11 @@ impl Analyzer {
             .get(&(file.metal, query_type))
             .ok_or_else(|| anyhow::anyhow!("No {:?} query for {:?}", query_type, file.metal))?;
 
+        Ok(Self::run_custom_query(file, query))
+    }
+
+    /// Run a caller-supplied query (see `QueryLoader::create_query`) on a parsed file
+    pub fn run_custom_query(file: &ParsedFile, query: &Query) -> Vec<QueryMatch> {
         let mut cursor = QueryCursor::new();
         let mut matches = cursor.matches(query, file.tree.root_node(), file.source.as_bytes());
 
@@ -196,7 +201,7 @@ impl Analyzer {
             }
         }
 
-        Ok(results)
+        results
     }
 
     // Helper methods
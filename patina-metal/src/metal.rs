@@ -56,6 +56,20 @@ impl Metal {
         }
     }
 
+    /// Detect metal from a language name (`rust`, `typescript`, `c++`), or an extension
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "rust" => Some(Metal::Rust),
+            "golang" => Some(Metal::Go),
+            "solidity" => Some(Metal::Solidity),
+            "python" => Some(Metal::Python),
+            "javascript" => Some(Metal::JavaScript),
+            "typescript" => Some(Metal::TypeScript),
+            "c++" => Some(Metal::Cpp),
+            other => Self::from_extension(other),
+        }
+    }
+
     /// Get the tree-sitter language for this metal
     pub fn tree_sitter_language(&self) -> Option<TSLanguage> {
         match self {
@@ -132,6 +146,7 @@ impl Metal {
                 "library_declaration" => "impl",
                 "modifier_definition" => "modifier",
                 "event_definition" => "event",
+                "struct_declaration" => "struct",
                 "if_statement" => "if",
                 "for_statement" => "for",
                 "while_statement" => "while",
@@ -39,6 +39,12 @@ impl QueryLoader {
             include_str!("../queries/go/patterns.scm").to_string(),
         );
 
+        // Solidity queries
+        queries.insert(
+            (Metal::Solidity, QueryType::Symbols),
+            include_str!("../queries/solidity/symbols.scm").to_string(),
+        );
+
         queries
     }
 
@@ -1,4 +1,4 @@
-use patina_metal::{Analyzer, Metal, QueryType};
+use patina_metal::{Analyzer, Metal, QueryType, SymbolKind};
 
 #[test]
 fn test_rust_symbol_query() {
@@ -121,6 +121,73 @@ type Handler interface {
     }
 }
 
+#[test]
+fn test_solidity_symbol_query() {
+    let mut analyzer = Analyzer::new().expect("Failed to create analyzer");
+
+    if !analyzer.has_parser(Metal::Solidity) {
+        println!("Solidity parser not available, skipping");
+        return;
+    }
+
+    let source = r#"
+pragma solidity ^0.8.0;
+
+contract Token {
+    struct Balance {
+        uint256 amount;
+    }
+
+    mapping(address => uint256) public balances;
+
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    modifier onlyOwner() {
+        _;
+    }
+
+    function transfer(address to, uint256 value) public onlyOwner {
+        emit Transfer(msg.sender, to, value);
+    }
+}
+"#;
+
+    let parsed = analyzer
+        .parse(source, Metal::Solidity)
+        .expect("Failed to parse");
+    let matches = analyzer
+        .run_query(&parsed, QueryType::Symbols)
+        .expect("Failed to run query");
+
+    let named = |capture: &str| -> Vec<&str> {
+        matches
+            .iter()
+            .filter(|m| m.capture_name == capture)
+            .map(|m| m.text.as_str())
+            .collect()
+    };
+    assert_eq!(named("contract.name"), vec!["Token"]);
+    assert_eq!(named("event.name"), vec!["Transfer"]);
+    assert_eq!(named("modifier.name"), vec!["onlyOwner"]);
+    assert_eq!(named("function.name"), vec!["transfer"]);
+    assert_eq!(named("struct.name"), vec!["Balance"]);
+    assert_eq!(named("state_variable.name"), vec!["balances"]);
+
+    // The generic walker normalizes the same nodes to symbol kinds
+    let symbols = analyzer.extract_symbols(&parsed);
+    let kind_of = |name: &str| {
+        symbols
+            .iter()
+            .find(|s| s.name == name)
+            .map(|s| s.kind.clone())
+    };
+    assert_eq!(kind_of("Token"), Some(SymbolKind::Contract));
+    assert_eq!(kind_of("Transfer"), Some(SymbolKind::Event));
+    assert_eq!(kind_of("onlyOwner"), Some(SymbolKind::Modifier));
+    assert_eq!(kind_of("transfer"), Some(SymbolKind::Function));
+    assert_eq!(kind_of("Balance"), Some(SymbolKind::Struct));
+}
+
 #[test]
 fn test_rust_complexity_query() {
     let mut analyzer = Analyzer::new().expect("Failed to create analyzer");
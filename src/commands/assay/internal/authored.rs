@@ -0,0 +1,147 @@
+//! Contributor archaeology
+//!
+//! "Do X": List the files (and the functions in them) a contributor has modified.
+//!
+//! Authorship comes from `commits` + `commit_files`, the views materialized
+//! from `git.commit` events (reference repos only have the views). Names match
+//! case-insensitively on substring, so "alice" finds "Alice Smith".
+
+use anyhow::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::super::AssayOptions;
+use super::truncate;
+use crate::commands::exit;
+
+/// One file a contributor touched
+#[derive(Debug, Serialize)]
+pub struct AuthoredFile {
+    pub file: String,
+    /// Commits by the contributor that touched this file
+    pub commits: i64,
+    /// Date of their latest commit to it
+    pub last_changed: String,
+    /// Functions currently defined in the file
+    pub functions: Vec<String>,
+}
+
+/// List files touched by `options.pattern`
+pub fn execute_authored(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let files = query_authored(conn, options)?;
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&files)?);
+        return exit::ensure_found(files.len());
+    }
+
+    let author = options.pattern.as_deref().unwrap_or_default();
+    println!("Files modified by '{}'\n", author);
+    println!(
+        "{:<45} {:>7} {:<12} {:>9}",
+        "File", "Commits", "Last", "Functions"
+    );
+    println!("{}", "-".repeat(76));
+    for f in &files {
+        let date = f.last_changed.split('T').next().unwrap_or(&f.last_changed);
+        println!(
+            "{:<45} {:>7} {:<12} {:>9}",
+            truncate(&f.file, 45),
+            f.commits,
+            date,
+            f.functions.len()
+        );
+    }
+    println!("\nFound {} files", files.len());
+
+    exit::ensure_found(files.len())
+}
+
+/// Files touched by the author in `options.pattern`, most recently changed first
+pub fn query_authored(conn: &Connection, options: &AssayOptions) -> Result<Vec<AuthoredFile>> {
+    let author = options
+        .pattern
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("authored requires --by <name>"))?;
+    let limit = if options.limit > 0 { options.limit } else { 50 };
+
+    let mut stmt = conn.prepare(
+        "SELECT cf.file_path, COUNT(DISTINCT c.sha), MAX(c.timestamp)
+         FROM commit_files cf
+         JOIN commits c ON cf.sha = c.sha
+         WHERE c.author_name LIKE '%' || ?1 || '%'
+         GROUP BY cf.file_path
+         ORDER BY MAX(c.timestamp) DESC, cf.file_path
+         LIMIT ?2",
+    )?;
+    let mut files = stmt
+        .query_map(rusqlite::params![author, limit as i64], |row| {
+            Ok(AuthoredFile {
+                file: row.get(0)?,
+                commits: row.get(1)?,
+                last_changed: row.get(2)?,
+                functions: Vec::new(),
+            })
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    // Code facts store paths with or without "./"; git never does
+    let has_functions = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'function_facts'")?
+        .exists([])?;
+    if has_functions {
+        let mut stmt = conn.prepare(
+            "SELECT name FROM function_facts WHERE file IN (?1, './' || ?1) ORDER BY name",
+        )?;
+        for f in &mut files {
+            f.functions = stmt
+                .query_map([&f.file], |row| row.get(0))?
+                .collect::<rusqlite::Result<Vec<String>>>()?;
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::QueryType;
+
+    #[test]
+    fn test_author_filter_returns_only_their_files() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, message TEXT, author_name TEXT,
+                                   author_email TEXT, timestamp TEXT, branch TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT, change_type TEXT,
+                                        lines_added INTEGER, lines_removed INTEGER);
+             CREATE TABLE function_facts (file TEXT, name TEXT);
+             INSERT INTO commits (sha, author_name, timestamp) VALUES
+                ('a1', 'Alice Smith', '2026-01-02T10:00:00+00:00'),
+                ('b1', 'Bob', '2026-01-03T10:00:00+00:00'),
+                ('a2', 'Alice Smith', '2026-01-04T10:00:00+00:00');
+             INSERT INTO commit_files (sha, file_path) VALUES
+                ('a1', 'src/engine.rs'), ('a1', 'src/shared.rs'),
+                ('b1', 'src/shared.rs'), ('b1', 'src/bob_only.rs'),
+                ('a2', 'src/engine.rs');
+             INSERT INTO function_facts VALUES
+                ('./src/engine.rs', 'tick'), ('./src/engine.rs', 'spawn'),
+                ('./src/bob_only.rs', 'helper');",
+        )?;
+
+        let options = AssayOptions {
+            query_type: QueryType::Authored,
+            pattern: Some("alice".to_string()),
+            ..Default::default()
+        };
+        let files = query_authored(&conn, &options)?;
+
+        let touched: Vec<(&str, i64)> =
+            files.iter().map(|f| (f.file.as_str(), f.commits)).collect();
+        assert_eq!(touched, vec![("src/engine.rs", 2), ("src/shared.rs", 1)]);
+        assert_eq!(files[0].functions, vec!["spawn", "tick"]);
+        assert!(files[0].last_changed.starts_with("2026-01-04"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,189 @@
+//! Clone detection
+//!
+//! "Do X": Group functions whose bodies share a structural fingerprint
+
+use anyhow::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+
+use super::super::AssayOptions;
+use super::truncate;
+use crate::commands::exit;
+
+/// Where one copy lives
+#[derive(Debug, Serialize)]
+pub struct CloneLocation {
+    pub file: String,
+    pub name: String,
+    pub line_count: i64,
+}
+
+/// Functions sharing one fingerprint
+#[derive(Debug, Serialize)]
+pub struct CloneCluster {
+    pub fingerprint: String,
+    pub functions: Vec<CloneLocation>,
+}
+
+impl CloneCluster {
+    /// Lines that would go away if every copy but one were removed
+    fn duplicated_lines(&self) -> i64 {
+        self.functions
+            .iter()
+            .skip(1)
+            .map(|f| f.line_count)
+            .sum::<i64>()
+    }
+}
+
+/// Report clone clusters
+pub fn execute_clones(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let clusters = query_clones(conn, options)?;
+    print_clones(&clusters, options.min_lines, options.json)
+}
+
+/// Run the clones query without printing, largest duplication first
+pub fn query_clones(conn: &Connection, options: &AssayOptions) -> Result<Vec<CloneCluster>> {
+    let has_fingerprints = conn
+        .prepare("SELECT 1 FROM pragma_table_info('function_facts') WHERE name = 'fingerprint'")?
+        .exists([])?;
+    if !has_fingerprints {
+        anyhow::bail!("No function fingerprints yet. Run `patina scrape code` first.");
+    }
+
+    let limit = if options.limit > 0 { options.limit } else { 50 };
+
+    let mut stmt = conn.prepare(
+        "SELECT fingerprint, file, name, line_count
+         FROM function_facts
+         WHERE fingerprint IS NOT NULL AND line_count >= ?1
+           AND fingerprint IN (
+               SELECT fingerprint FROM function_facts
+               WHERE fingerprint IS NOT NULL AND line_count >= ?1
+               GROUP BY fingerprint HAVING COUNT(*) > 1
+           )
+         ORDER BY fingerprint, file, name",
+    )?;
+    let rows = stmt.query_map([options.min_lines as i64], |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            CloneLocation {
+                file: row.get(1)?,
+                name: row.get(2)?,
+                line_count: row.get(3)?,
+            },
+        ))
+    })?;
+
+    let mut clusters: Vec<CloneCluster> = Vec::new();
+    for row in rows {
+        let (fingerprint, location) = row?;
+        match clusters.last_mut() {
+            Some(cluster) if cluster.fingerprint == fingerprint => cluster.functions.push(location),
+            _ => clusters.push(CloneCluster {
+                fingerprint,
+                functions: vec![location],
+            }),
+        }
+    }
+
+    clusters.sort_by(|a, b| {
+        b.duplicated_lines()
+            .cmp(&a.duplicated_lines())
+            .then_with(|| a.fingerprint.cmp(&b.fingerprint))
+    });
+    clusters.truncate(limit);
+    Ok(clusters)
+}
+
+/// Print clone clusters as a list or JSON
+fn print_clones(clusters: &[CloneCluster], min_lines: usize, json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(&clusters)?);
+        return exit::ensure_found(clusters.len());
+    }
+
+    println!("Clone clusters (functions of {}+ lines)\n", min_lines);
+    for (i, cluster) in clusters.iter().enumerate() {
+        println!(
+            "{}. {} copies, {} duplicated lines [{}]",
+            i + 1,
+            cluster.functions.len(),
+            cluster.duplicated_lines(),
+            cluster.fingerprint
+        );
+        for f in &cluster.functions {
+            println!(
+                "   {:<40} {:<30} {:>4} lines",
+                truncate(&f.file, 40),
+                truncate(&f.name, 30),
+                f.line_count
+            );
+        }
+    }
+    println!("\nFound {} clone clusters", clusters.len());
+
+    exit::ensure_found(clusters.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::QueryType;
+    use crate::commands::scrape::code::extract_v2::{extract_code_metadata_v2, FileLimits};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_identical_functions_in_different_files_cluster() -> Result<()> {
+        let temp = TempDir::new()?;
+        let tree = temp.path().join("tree");
+        std::fs::create_dir_all(tree.join("src"))?;
+
+        // Same shape, different names, literals and comments
+        std::fs::write(
+            tree.join("src/orders.rs"),
+            "fn total_orders(items: &[u32]) -> u32 {\n    let mut sum = 0;\n    for item in items {\n        if *item > 10 {\n            sum += item;\n        }\n    }\n    sum\n}\n",
+        )?;
+        std::fs::write(
+            tree.join("src/invoices.rs"),
+            "fn invoice_sum(lines: &[u32]) -> u32 {\n    let mut acc = 0;\n    // skip small lines\n    for line in lines {\n        if *line > 99 {\n            acc += line;\n        }\n    }\n    acc\n}\n\nfn render(name: &str) -> String {\n    let title = name.trim();\n    let upper = title.to_uppercase();\n    format!(\"# {}\", upper)\n}\n",
+        )?;
+
+        let db_path = temp.path().join("patina.db");
+        crate::commands::scrape::database::initialize(&db_path)?;
+        extract_code_metadata_v2(
+            db_path.to_str().unwrap(),
+            &tree,
+            true,
+            FileLimits::default(),
+        )?;
+        let conn = Connection::open(&db_path)?;
+
+        let options = AssayOptions {
+            query_type: QueryType::Clones,
+            min_lines: 3,
+            ..Default::default()
+        };
+        let clusters = query_clones(&conn, &options)?;
+
+        assert_eq!(clusters.len(), 1);
+        let names: Vec<&str> = clusters[0]
+            .functions
+            .iter()
+            .map(|f| f.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["invoice_sum", "total_orders"]);
+        assert!(clusters
+            .iter()
+            .all(|c| c.functions.iter().all(|f| f.name != "render")));
+
+        // Raising the bar above their span drops the cluster
+        let strict = AssayOptions {
+            min_lines: 20,
+            ..options
+        };
+        assert!(query_clones(&conn, &strict)?.is_empty());
+
+        Ok(())
+    }
+}
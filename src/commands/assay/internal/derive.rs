@@ -1,13 +1,16 @@
 //! Structural signal computation
 //!
 //! "Do X": Compute structural signals from code facts
+//!
+//! Git-derived signals (activity, contributors) cover all history unless
+//! `--since` bounds them to a recent window.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use rusqlite::Connection;
 use serde::Serialize;
 
 use super::super::AssayOptions;
-use super::truncate;
+use super::{module_path, truncate};
 
 /// Module signal data
 #[derive(Debug, Serialize)]
@@ -43,7 +46,7 @@ pub struct DeriveSummary {
 }
 
 /// Check if a file is an entry point (main.rs, index.ts, __init__.py, mod.rs, etc.)
-fn is_entry_point(path: &str) -> bool {
+pub(crate) fn is_entry_point(path: &str) -> bool {
     let filename = path.rsplit('/').next().unwrap_or(path);
     matches!(
         filename,
@@ -65,7 +68,7 @@ fn is_entry_point(path: &str) -> bool {
 }
 
 /// Check if a file is a test file
-fn is_test_file(path: &str) -> bool {
+pub(crate) fn is_test_file(path: &str) -> bool {
     let path_lower = path.to_lowercase();
     // Check path components
     path_lower.contains("/test/")
@@ -92,8 +95,64 @@ fn compute_directory_depth(path: &str) -> i64 {
     path.trim_start_matches("./").matches('/').count() as i64
 }
 
-/// Compute structural signals for all modules
+/// Earliest commit date (`YYYY-MM-DD`) a `--since` value admits
+///
+/// Accepts a date or a day count like `30d`.
+fn since_cutoff(since: &str) -> Result<String> {
+    let since = since.trim();
+    if let Some(days) = since.strip_suffix('d') {
+        let days: i64 = days
+            .parse()
+            .with_context(|| format!("Invalid --since day count: '{}'", since))?;
+        let cutoff = chrono::Utc::now() - chrono::Duration::days(days);
+        return Ok(cutoff.format("%Y-%m-%d").to_string());
+    }
+    let date = chrono::NaiveDate::parse_from_str(since, "%Y-%m-%d")
+        .with_context(|| format!("Invalid --since '{}' (use YYYY-MM-DD or Nd)", since))?;
+    Ok(date.format("%Y-%m-%d").to_string())
+}
+
+/// Compute structural signals for all modules, printing them
 pub fn execute_derive(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let result = query_derive(conn, options)?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        match options.since.as_deref().map(since_cutoff).transpose()? {
+            Some(cutoff) => println!("Structural Signals Derived (commits since {})\n", cutoff),
+            None => println!("Structural Signals Derived\n"),
+        }
+        println!(
+            "Summary: {} modules, {} used, {} dormant\n",
+            result.summary.total_modules,
+            result.summary.used_modules,
+            result.summary.dormant_modules
+        );
+        println!(
+            "{:<45} {:>6} {:>8} {:>10} {:>8}",
+            "Path", "Used", "Imports", "Activity", "Central"
+        );
+        println!("{}", "-".repeat(82));
+        for s in &result.signals {
+            println!(
+                "{:<45} {:>6} {:>8} {:>10} {:>8.2}",
+                truncate(&s.path, 45),
+                if s.is_used { "Y" } else { "" },
+                s.importer_count,
+                s.activity_level,
+                s.centrality_score
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute and store structural signals (rebuilds `module_signals`)
+pub fn query_derive(conn: &Connection, options: &AssayOptions) -> Result<DeriveResult> {
+    let since = options.since.as_deref().map(since_cutoff).transpose()?;
+
     // Ensure module_signals table exists with Phase 1.5 columns
     // Drop and recreate to handle schema migration
     conn.execute("DROP TABLE IF EXISTS module_signals", [])?;
@@ -138,14 +197,7 @@ pub fn execute_derive(conn: &Connection, options: &AssayOptions) -> Result<()> {
 
     for (path, file_size) in &modules_with_sizes {
         // Convert file path to module path pattern for import matching
-        // ./src/adapters/claude/mod.rs -> adapters::claude
-        // ./src/adapters/templates.rs -> adapters::templates
-        let module_path = path
-            .trim_start_matches("./")
-            .trim_start_matches("src/")
-            .trim_end_matches(".rs")
-            .trim_end_matches("/mod")
-            .replace('/', "::");
+        let module_path = module_path(path);
 
         // Compute importer_count: how many files import this module
         let importer_count: i64 = conn
@@ -171,10 +223,12 @@ pub fn execute_derive(conn: &Connection, options: &AssayOptions) -> Result<()> {
             .unwrap_or(0.0);
 
         // Compute activity from git commits in eventlog (now includes commit_count)
-        let (activity_level, last_commit_days, commit_count) = compute_activity(conn, path);
+        let (activity_level, last_commit_days, commit_count) =
+            compute_activity(conn, path, since.as_deref());
 
         // Get top contributors from git events (now includes contributor_count)
-        let (top_contributors, contributor_count) = compute_contributors(conn, path);
+        let (top_contributors, contributor_count) =
+            compute_contributors(conn, path, since.as_deref());
 
         // Phase 1.5: Additional signals
         let test_file = is_test_file(path);
@@ -239,48 +293,25 @@ pub fn execute_derive(conn: &Connection, options: &AssayOptions) -> Result<()> {
         .filter(|s| s.activity_level == "dormant")
         .count();
 
-    let result = DeriveResult {
+    Ok(DeriveResult {
         signals,
         summary: DeriveSummary {
             total_modules,
             used_modules,
             dormant_modules,
         },
-    };
-
-    if options.json {
-        println!("{}", serde_json::to_string_pretty(&result)?);
-    } else {
-        println!("Structural Signals Derived\n");
-        println!(
-            "Summary: {} modules, {} used, {} dormant\n",
-            result.summary.total_modules,
-            result.summary.used_modules,
-            result.summary.dormant_modules
-        );
-        println!(
-            "{:<45} {:>6} {:>8} {:>10} {:>8}",
-            "Path", "Used", "Imports", "Activity", "Central"
-        );
-        println!("{}", "-".repeat(82));
-        for s in &result.signals {
-            println!(
-                "{:<45} {:>6} {:>8} {:>10} {:>8.2}",
-                truncate(&s.path, 45),
-                if s.is_used { "Y" } else { "" },
-                s.importer_count,
-                s.activity_level,
-                s.centrality_score
-            );
-        }
-    }
-
-    Ok(())
+    })
 }
 
 /// Compute activity level from git commits
 /// Returns (activity_level, last_commit_days, commit_count)
-fn compute_activity(conn: &Connection, path: &str) -> (String, Option<i64>, i64) {
+///
+/// Only commits on or after `since` (a `YYYY-MM-DD` date) count.
+fn compute_activity(
+    conn: &Connection,
+    path: &str,
+    since: Option<&str>,
+) -> (String, Option<i64>, i64) {
     // Normalize path: strip ./ prefix to match git file paths
     let normalized_path = path.trim_start_matches("./");
 
@@ -293,9 +324,9 @@ fn compute_activity(conn: &Connection, path: &str) -> (String, Option<i64>, i64)
             MAX(c.timestamp) as last_commit
         FROM commit_files cf
         JOIN commits c ON cf.sha = c.sha
-        WHERE cf.file_path = ?
+        WHERE cf.file_path = ?1 AND (?2 IS NULL OR c.timestamp >= ?2)
         "#,
-        [normalized_path],
+        rusqlite::params![normalized_path, since],
         |row| {
             Ok((
                 row.get(0)?,
@@ -356,8 +387,53 @@ pub struct MomentsSummary {
     pub rewrite: i64,
 }
 
-/// Derive moments from git commits
+/// Derive-moments result
+#[derive(Debug, Serialize)]
+pub struct MomentsResult {
+    pub moments: Vec<Moment>,
+    pub summary: MomentsSummary,
+}
+
+/// Derive moments from git commits, printing them
 pub fn execute_derive_moments(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let result = query_derive_moments(conn)?;
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&result)?);
+    } else {
+        let (moments, summary) = (&result.moments, &result.summary);
+        println!("Moments Derived (Temporal Signals)\n");
+        println!(
+            "Summary: {} commits → {} genesis, {} big_bang, {} major, {} breaking, {} migration, {} rewrite\n",
+            summary.total_commits,
+            summary.genesis,
+            summary.big_bang,
+            summary.major,
+            summary.breaking,
+            summary.migration,
+            summary.rewrite,
+        );
+
+        if !moments.is_empty() {
+            println!("{:<12} {:<10} {:>6} Message", "Type", "SHA", "Files");
+            println!("{}", "-".repeat(80));
+            for m in moments {
+                println!(
+                    "{:<12} {:<10} {:>6} {}",
+                    m.moment_type,
+                    &m.sha[..10.min(m.sha.len())],
+                    m.file_count,
+                    truncate(&m.message, 45),
+                );
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Classify commits into moments and store them (rebuilds `moments`)
+pub fn query_derive_moments(conn: &Connection) -> Result<MomentsResult> {
     // Ensure moments table exists
     conn.execute(
         "CREATE TABLE IF NOT EXISTS moments (
@@ -466,46 +542,14 @@ pub fn execute_derive_moments(conn: &Connection, options: &AssayOptions) -> Resu
         rewrite: count_type("rewrite"),
     };
 
-    if options.json {
-        let result = serde_json::json!({
-            "moments": moments,
-            "summary": summary,
-        });
-        println!("{}", serde_json::to_string_pretty(&result)?);
-    } else {
-        println!("Moments Derived (Temporal Signals)\n");
-        println!(
-            "Summary: {} commits → {} genesis, {} big_bang, {} major, {} breaking, {} migration, {} rewrite\n",
-            summary.total_commits,
-            summary.genesis,
-            summary.big_bang,
-            summary.major,
-            summary.breaking,
-            summary.migration,
-            summary.rewrite,
-        );
-
-        if !moments.is_empty() {
-            println!("{:<12} {:<10} {:>6} Message", "Type", "SHA", "Files");
-            println!("{}", "-".repeat(80));
-            for m in &moments {
-                println!(
-                    "{:<12} {:<10} {:>6} {}",
-                    m.moment_type,
-                    &m.sha[..10.min(m.sha.len())],
-                    m.file_count,
-                    truncate(&m.message, 45),
-                );
-            }
-        }
-    }
-
-    Ok(())
+    Ok(MomentsResult { moments, summary })
 }
 
 /// Get top contributors for a file
 /// Returns (top_contributors, contributor_count)
-fn compute_contributors(conn: &Connection, path: &str) -> (Vec<String>, i64) {
+///
+/// Only commits on or after `since` (a `YYYY-MM-DD` date) count.
+fn compute_contributors(conn: &Connection, path: &str, since: Option<&str>) -> (Vec<String>, i64) {
     // Normalize path: strip ./ prefix to match git file paths
     let normalized_path = path.trim_start_matches("./");
 
@@ -519,9 +563,9 @@ fn compute_contributors(conn: &Connection, path: &str) -> (Vec<String>, i64) {
             SELECT COUNT(DISTINCT c.author_name)
             FROM commit_files cf
             JOIN commits c ON cf.sha = c.sha
-            WHERE cf.file_path = ?
+            WHERE cf.file_path = ?1 AND (?2 IS NULL OR c.timestamp >= ?2)
             "#,
-            [normalized_path],
+            rusqlite::params![normalized_path, since],
             |row| row.get(0),
         )
         .unwrap_or(0);
@@ -532,7 +576,7 @@ fn compute_contributors(conn: &Connection, path: &str) -> (Vec<String>, i64) {
         SELECT c.author_name as author, COUNT(DISTINCT cf.sha) as commits
         FROM commit_files cf
         JOIN commits c ON cf.sha = c.sha
-        WHERE cf.file_path = ?
+        WHERE cf.file_path = ?1 AND (?2 IS NULL OR c.timestamp >= ?2)
         GROUP BY author
         ORDER BY commits DESC
         LIMIT 3
@@ -543,10 +587,70 @@ fn compute_contributors(conn: &Connection, path: &str) -> (Vec<String>, i64) {
     };
 
     let top_contributors = stmt
-        .query_map([normalized_path], |row| row.get(0))
+        .query_map(rusqlite::params![normalized_path, since], |row| row.get(0))
         .ok()
         .map(|rows| rows.filter_map(|r| r.ok()).collect())
         .unwrap_or_default();
 
     (top_contributors, contributor_count)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Commit `sha` by `author`, `days_ago` days before now, touching `file`
+    fn seed_commit(conn: &Connection, sha: &str, author: &str, days_ago: i64, file: &str) {
+        let timestamp = (chrono::Utc::now() - chrono::Duration::days(days_ago)).to_rfc3339();
+        conn.execute(
+            "INSERT INTO commits (sha, author_name, timestamp) VALUES (?1, ?2, ?3)",
+            rusqlite::params![sha, author, timestamp],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO commit_files (sha, file_path) VALUES (?1, ?2)",
+            [sha, file],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_since_narrows_activity_window() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, author_name TEXT, timestamp TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);",
+        )?;
+        seed_commit(&conn, "old1", "Alice", 60, "src/engine.rs");
+        seed_commit(&conn, "old2", "Alice", 50, "src/engine.rs");
+        seed_commit(&conn, "new1", "Bob", 3, "src/engine.rs");
+        seed_commit(&conn, "old3", "Alice", 20, "src/legacy.rs");
+
+        let all_time = compute_activity(&conn, "./src/engine.rs", None);
+        assert_eq!((all_time.0.as_str(), all_time.2), ("high", 3));
+        let recent = since_cutoff("10d")?;
+        let windowed = compute_activity(&conn, "./src/engine.rs", Some(&recent));
+        assert_eq!((windowed.0.as_str(), windowed.2), ("high", 1));
+
+        assert_eq!(compute_activity(&conn, "src/legacy.rs", None).0, "medium");
+        let legacy = compute_activity(&conn, "src/legacy.rs", Some(&recent));
+        assert_eq!(
+            (legacy.0.as_str(), legacy.1, legacy.2),
+            ("dormant", None, 0)
+        );
+
+        assert_eq!(
+            compute_contributors(&conn, "src/engine.rs", None),
+            (vec!["Alice".to_string(), "Bob".to_string()], 2)
+        );
+        assert_eq!(
+            compute_contributors(&conn, "src/engine.rs", Some(&recent)),
+            (vec!["Bob".to_string()], 1)
+        );
+
+        assert_eq!(since_cutoff("2026-01-15")?, "2026-01-15");
+        assert!(since_cutoff("last week").is_err());
+
+        Ok(())
+    }
+}
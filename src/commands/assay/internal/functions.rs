@@ -4,13 +4,18 @@
 
 use anyhow::Result;
 use rusqlite::Connection;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::super::AssayOptions;
 use super::truncate;
+use crate::commands::exit::{self, Exit};
+
+/// Why callers/callees can't answer at all
+const CALL_GRAPH_NOT_BUILT: &str = "Call graph not built — this language may not support call \
+extraction, or run `patina scrape code`";
 
 /// Function info
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FunctionInfo {
     pub name: String,
     pub file: String,
@@ -18,10 +23,13 @@ pub struct FunctionInfo {
     pub is_async: bool,
     pub parameters: String,
     pub return_type: Option<String>,
+    /// Edit distance to the pattern (`--fuzzy` only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distance: Option<usize>,
 }
 
 /// Caller/callee info
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CallInfo {
     pub caller: String,
     pub callee: String,
@@ -31,42 +39,45 @@ pub struct CallInfo {
 
 /// Query functions
 pub fn execute_functions(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let functions = query_functions(conn, options)?;
+    print_functions(options.pattern.as_deref(), &functions, options.json)
+}
+
+/// Run the functions query without printing
+pub fn query_functions(conn: &Connection, options: &AssayOptions) -> Result<Vec<FunctionInfo>> {
     let limit = if options.limit > 0 {
         options.limit
     } else {
         100
     };
 
-    let (sql, params): (&str, Vec<String>) = if let Some(pattern) = &options.pattern {
-        (
-            r#"
-            SELECT name, file, is_public, is_async, parameters, return_type
-            FROM function_facts
-            WHERE name LIKE ? OR file LIKE ?
-            ORDER BY file, name
-            LIMIT ?
-            "#,
-            vec![
-                format!("%{}%", pattern),
-                format!("%{}%", pattern),
-                limit.to_string(),
-            ],
-        )
-    } else {
-        (
-            r#"
-            SELECT name, file, is_public, is_async, parameters, return_type
-            FROM function_facts
-            ORDER BY file, name
-            LIMIT ?
-            "#,
-            vec![limit.to_string()],
-        )
-    };
+    if let (true, Some(pattern)) = (options.fuzzy, &options.pattern) {
+        return query_functions_fuzzy(conn, pattern, &modifier_conditions(options), limit);
+    }
+
+    let mut conditions = modifier_conditions(options);
+    let mut params: Vec<String> = Vec::new();
+    if let Some(pattern) = &options.pattern {
+        conditions.push("(name LIKE ? OR file LIKE ?)");
+        params.push(format!("%{}%", pattern));
+        params.push(format!("%{}%", pattern));
+    }
+    params.push(limit.to_string());
 
-    let mut stmt = conn.prepare(sql)?;
-    let functions: Vec<FunctionInfo> = if options.pattern.is_some() {
-        stmt.query_map([&params[0], &params[1], &params[2]], |row| {
+    let sql = format!(
+        r#"
+        SELECT name, file, is_public, is_async, parameters, return_type
+        FROM function_facts
+        {}
+        ORDER BY file, name
+        LIMIT ?
+        "#,
+        where_clause(&conditions)
+    );
+
+    let mut stmt = conn.prepare(&sql)?;
+    let functions: Vec<FunctionInfo> = stmt
+        .query_map(rusqlite::params_from_iter(&params), |row| {
             Ok(FunctionInfo {
                 name: row.get(0)?,
                 file: row.get(1)?,
@@ -74,14 +85,67 @@ pub fn execute_functions(conn: &Connection, options: &AssayOptions) -> Result<()
                 is_async: row.get(3)?,
                 parameters: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
                 return_type: row.get(5)?,
+                distance: None,
             })
         })?
         .filter_map(|r| r.ok())
-        .collect()
+        .collect();
+
+    Ok(functions)
+}
+
+/// SQL conditions for the `--unsafe` / `--const` filters
+fn modifier_conditions(options: &AssayOptions) -> Vec<&'static str> {
+    let mut conditions = Vec::new();
+    if options.unsafe_only {
+        conditions.push("is_unsafe = 1");
+    }
+    if options.const_only {
+        conditions.push("is_const = 1");
+    }
+    conditions
+}
+
+fn where_clause(conditions: &[&str]) -> String {
+    if conditions.is_empty() {
+        String::new()
     } else {
-        stmt.query_map([&params[0]], |row| {
+        format!("WHERE {}", conditions.join(" AND "))
+    }
+}
+
+/// Rank functions by edit distance to `pattern`, closest first
+///
+/// A LIKE prefilter on the pattern's leading and trailing bigrams keeps the
+/// candidate set broad enough to survive typos in the middle of a name.
+fn query_functions_fuzzy(
+    conn: &Connection,
+    pattern: &str,
+    modifiers: &[&str],
+    limit: usize,
+) -> Result<Vec<FunctionInfo>> {
+    let needle = pattern.to_lowercase();
+    let chars: Vec<char> = needle.chars().collect();
+    let head: String = chars.iter().take(2).collect();
+    let tail: String = chars.iter().skip(chars.len().saturating_sub(2)).collect();
+    let max_distance = (chars.len() / 3).max(1);
+
+    let mut conditions = vec!["(name LIKE ? OR name LIKE ?)"];
+    conditions.extend_from_slice(modifiers);
+    let mut stmt = conn.prepare(&format!(
+        r#"
+        SELECT name, file, is_public, is_async, parameters, return_type
+        FROM function_facts
+        {}
+        "#,
+        where_clause(&conditions)
+    ))?;
+    let mut functions: Vec<FunctionInfo> = stmt
+        .query_map([format!("%{}%", head), format!("%{}%", tail)], |row| {
+            let name: String = row.get(0)?;
             Ok(FunctionInfo {
-                name: row.get(0)?,
+                distance: Some(edit_distance(&needle, &name.to_lowercase())),
+                name,
                 file: row.get(1)?,
                 is_public: row.get(2)?,
                 is_async: row.get(3)?,
@@ -90,112 +154,250 @@ pub fn execute_functions(conn: &Connection, options: &AssayOptions) -> Result<()
             })
         })?
         .filter_map(|r| r.ok())
-        .collect()
-    };
+        .filter(|f| f.distance.is_some_and(|d| d <= max_distance))
+        .collect();
 
-    if options.json {
+    functions.sort_by(|a, b| {
+        a.distance
+            .cmp(&b.distance)
+            .then_with(|| a.file.cmp(&b.file))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    functions.truncate(limit);
+    Ok(functions)
+}
+
+/// Optimal string alignment distance: Levenshtein plus adjacent transpositions
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for j in 0..=b.len() {
+        rows[0][j] = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = best;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+/// Print functions as a table or JSON
+pub fn print_functions(
+    pattern: Option<&str>,
+    functions: &[FunctionInfo],
+    json: bool,
+) -> Result<()> {
+    if json {
         println!("{}", serde_json::to_string_pretty(&functions)?);
     } else {
         println!(
             "Functions{}\n",
-            options
-                .pattern
-                .as_ref()
+            pattern
                 .map(|p| format!(" matching '{}'", p))
                 .unwrap_or_default()
         );
-        println!("{:<30} {:<40} {:>5} {:>5}", "Name", "File", "Pub", "Async");
-        println!("{}", "-".repeat(84));
-        for f in &functions {
-            println!(
+        let fuzzy = functions.iter().any(|f| f.distance.is_some());
+        print!("{:<30} {:<40} {:>5} {:>5}", "Name", "File", "Pub", "Async");
+        println!(
+            "{}",
+            if fuzzy {
+                format!(" {:>5}", "Dist")
+            } else {
+                String::new()
+            }
+        );
+        println!("{}", "-".repeat(if fuzzy { 90 } else { 84 }));
+        for f in functions {
+            print!(
                 "{:<30} {:<40} {:>5} {:>5}",
                 truncate(&f.name, 30),
                 truncate(&f.file, 40),
                 if f.is_public { "Y" } else { "" },
                 if f.is_async { "Y" } else { "" }
             );
+            match f.distance {
+                Some(d) => println!(" {:>5}", d),
+                None => println!(),
+            }
         }
         println!("\nFound {} functions", functions.len());
     }
 
-    Ok(())
+    exit::ensure_found(functions.len())
 }
 
-/// Query callers of a function
-pub fn execute_callers(conn: &Connection, options: &AssayOptions) -> Result<()> {
-    let pattern = options
+/// Query methods defined on a type
+pub fn execute_methods(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let methods = query_methods(conn, options)?;
+    let type_name = options.pattern.as_deref().unwrap_or_default();
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&methods)?);
+        return exit::ensure_found(methods.len());
+    }
+
+    println!("Methods of '{}'\n", type_name);
+    println!("{:<5} {:<60} {:<30}", "Pub", "Signature", "File");
+    println!("{}", "-".repeat(97));
+    for m in &methods {
+        let signature = match &m.return_type {
+            Some(ret) => format!("{}({}) -> {}", m.name, m.parameters, ret),
+            None => format!("{}({})", m.name, m.parameters),
+        };
+        println!(
+            "{:<5} {:<60} {:<30}",
+            if m.is_public { "Y" } else { "" },
+            truncate(&signature, 60),
+            truncate(&m.file, 30)
+        );
+    }
+    println!("\nFound {} methods", methods.len());
+
+    exit::ensure_found(methods.len())
+}
+
+/// Run the methods query without printing: functions whose parent is the type
+pub fn query_methods(conn: &Connection, options: &AssayOptions) -> Result<Vec<FunctionInfo>> {
+    let type_name = options
         .pattern
         .as_ref()
-        .ok_or_else(|| anyhow::anyhow!("--callers requires a function name"))?;
+        .ok_or_else(|| anyhow::anyhow!("methods requires a type name"))?;
     let limit = if options.limit > 0 {
         options.limit
     } else {
         100
     };
 
-    let sql = r#"
-        SELECT caller, callee, file, call_type
-        FROM call_graph
-        WHERE callee LIKE ?
-        ORDER BY file, caller
+    let mut stmt = conn.prepare(
+        r#"
+        SELECT name, file, is_public, is_async, parameters, return_type
+        FROM function_facts
+        WHERE parent = ?
+        ORDER BY file, name
         LIMIT ?
-    "#;
-
-    let mut stmt = conn.prepare(sql)?;
-    let callers: Vec<CallInfo> = stmt
-        .query_map([format!("%{}%", pattern), limit.to_string()], |row| {
-            Ok(CallInfo {
-                caller: row.get(0)?,
-                callee: row.get(1)?,
-                file: row.get(2)?,
-                call_type: row.get(3)?,
+        "#,
+    )?;
+    let methods: Vec<FunctionInfo> = stmt
+        .query_map([type_name.clone(), limit.to_string()], |row| {
+            Ok(FunctionInfo {
+                name: row.get(0)?,
+                file: row.get(1)?,
+                is_public: row.get(2)?,
+                is_async: row.get(3)?,
+                parameters: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
+                return_type: row.get(5)?,
+                distance: None,
             })
         })?
         .filter_map(|r| r.ok())
         .collect();
 
-    if options.json {
-        println!("{}", serde_json::to_string_pretty(&callers)?);
-    } else {
-        println!("Callers of '{}'\n", pattern);
-        println!("{:<30} {:<30} {:<20}", "Caller", "Callee", "File");
-        println!("{}", "-".repeat(82));
-        for c in &callers {
-            println!(
-                "{:<30} {:<30} {:<20}",
-                truncate(&c.caller, 30),
-                truncate(&c.callee, 30),
-                truncate(&c.file, 20)
-            );
-        }
-        println!("\nFound {} call sites", callers.len());
-    }
+    Ok(methods)
+}
 
-    Ok(())
+/// Query callers of a function
+pub fn execute_callers(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let edges = require_call_graph(conn)?;
+    let callers = query_callers(conn, options)?;
+    let pattern = options.pattern.as_deref().unwrap_or_default();
+    let printed = print_calls(&format!("Callers of '{}'", pattern), &callers, options.json);
+    if callers.is_empty() && !options.json {
+        println!("{}", no_match_hint(pattern, edges));
+    }
+    printed
 }
 
 /// Query callees of a function
 pub fn execute_callees(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let edges = require_call_graph(conn)?;
+    let callees = query_callees(conn, options)?;
+    let pattern = options.pattern.as_deref().unwrap_or_default();
+    let printed = print_calls(&format!("Callees of '{}'", pattern), &callees, options.json);
+    if callees.is_empty() && !options.json {
+        println!("{}", no_match_hint(pattern, edges));
+    }
+    printed
+}
+
+/// Edge count of `call_graph`, or `Exit::MissingIndex` when it is absent or empty
+///
+/// An unpopulated graph would otherwise look like "no callers" for every name.
+fn require_call_graph(conn: &Connection) -> Result<i64> {
+    let exists = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'call_graph'")?
+        .exists([])?;
+    let edges: i64 = if exists {
+        conn.query_row("SELECT COUNT(*) FROM call_graph", [], |row| row.get(0))?
+    } else {
+        0
+    };
+    if edges == 0 {
+        return Err(anyhow::Error::new(Exit::MissingIndex).context(CALL_GRAPH_NOT_BUILT));
+    }
+    Ok(edges)
+}
+
+/// Shown when a populated call graph has nothing for `pattern`
+fn no_match_hint(pattern: &str, edges: i64) -> String {
+    format!(
+        "No call sites involve '{}' ({} call edges indexed) — check the name with \
+         `patina assay functions {} --fuzzy`",
+        pattern, edges, pattern
+    )
+}
+
+/// Run the callers query without printing
+pub fn query_callers(conn: &Connection, options: &AssayOptions) -> Result<Vec<CallInfo>> {
+    let pattern = options
+        .pattern
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("--callers requires a function name"))?;
+    query_calls(conn, "callee", "caller", pattern, options.limit)
+}
+
+/// Run the callees query without printing
+pub fn query_callees(conn: &Connection, options: &AssayOptions) -> Result<Vec<CallInfo>> {
     let pattern = options
         .pattern
         .as_ref()
         .ok_or_else(|| anyhow::anyhow!("--callees requires a function name"))?;
-    let limit = if options.limit > 0 {
-        options.limit
-    } else {
-        100
-    };
+    query_calls(conn, "caller", "callee", pattern, options.limit)
+}
+
+/// Call graph edges whose `match_column` is like `pattern`, ordered by file then `order_column`
+fn query_calls(
+    conn: &Connection,
+    match_column: &str,
+    order_column: &str,
+    pattern: &str,
+    limit: usize,
+) -> Result<Vec<CallInfo>> {
+    let limit = if limit > 0 { limit } else { 100 };
 
-    let sql = r#"
+    let sql = format!(
+        r#"
         SELECT caller, callee, file, call_type
         FROM call_graph
-        WHERE caller LIKE ?
-        ORDER BY file, callee
+        WHERE {} LIKE ?
+        ORDER BY file, {}
         LIMIT ?
-    "#;
+    "#,
+        match_column, order_column
+    );
 
-    let mut stmt = conn.prepare(sql)?;
-    let callees: Vec<CallInfo> = stmt
+    let mut stmt = conn.prepare(&sql)?;
+    let calls: Vec<CallInfo> = stmt
         .query_map([format!("%{}%", pattern), limit.to_string()], |row| {
             Ok(CallInfo {
                 caller: row.get(0)?,
@@ -207,13 +409,18 @@ pub fn execute_callees(conn: &Connection, options: &AssayOptions) -> Result<()>
         .filter_map(|r| r.ok())
         .collect();
 
-    if options.json {
-        println!("{}", serde_json::to_string_pretty(&callees)?);
+    Ok(calls)
+}
+
+/// Print call sites as a table or JSON
+fn print_calls(title: &str, calls: &[CallInfo], json: bool) -> Result<()> {
+    if json {
+        println!("{}", serde_json::to_string_pretty(&calls)?);
     } else {
-        println!("Callees of '{}'\n", pattern);
+        println!("{}\n", title);
         println!("{:<30} {:<30} {:<20}", "Caller", "Callee", "File");
         println!("{}", "-".repeat(82));
-        for c in &callees {
+        for c in calls {
             println!(
                 "{:<30} {:<30} {:<20}",
                 truncate(&c.caller, 30),
@@ -221,8 +428,155 @@ pub fn execute_callees(conn: &Connection, options: &AssayOptions) -> Result<()>
                 truncate(&c.file, 20)
             );
         }
-        println!("\nFound {} call sites", callees.len());
+        println!("\nFound {} call sites", calls.len());
+    }
+
+    exit::ensure_found(calls.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::QueryType;
+
+    #[test]
+    fn test_fuzzy_functions_tolerate_typos() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (name TEXT, file TEXT, is_public INTEGER, is_async INTEGER, parameters TEXT, return_type TEXT);
+             INSERT INTO function_facts VALUES ('initialize', 'src/eventlog.rs', 1, 0, 'db_path: &Path', 'Result<Connection>');
+             INSERT INTO function_facts VALUES ('finalize', 'src/commands/scrape/progress.rs', 0, 0, '', NULL);
+             INSERT INTO function_facts VALUES ('insert_event', 'src/eventlog.rs', 1, 0, '', NULL);
+             INSERT INTO function_facts VALUES ('render', 'src/main.rs', 0, 0, '', NULL);",
+        )?;
+
+        let mut options = AssayOptions {
+            query_type: QueryType::Functions,
+            pattern: Some("initalize".to_string()),
+            limit: 10,
+            ..Default::default()
+        };
+        assert!(query_functions(&conn, &options)?.is_empty());
+
+        options.fuzzy = true;
+        let functions = query_functions(&conn, &options)?;
+        assert_eq!(functions[0].name, "initialize");
+        assert_eq!(functions[0].distance, Some(1));
+        assert!(functions.iter().all(|f| f.name != "render"));
+
+        assert_eq!(edit_distance("intialize", "initialize"), 1);
+        assert_eq!(edit_distance("niitialize", "initialize"), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsafe_and_const_filters() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (name TEXT, file TEXT, is_public INTEGER, is_async INTEGER, parameters TEXT, return_type TEXT, is_unsafe INTEGER, is_const INTEGER);
+             INSERT INTO function_facts VALUES ('raw_read', 'src/io.rs', 1, 0, 'ptr: *const u8', 'u8', 1, 0);
+             INSERT INTO function_facts VALUES ('kib', 'src/units.rs', 1, 0, 'n: usize', 'usize', 0, 1);
+             INSERT INTO function_facts VALUES ('zeroed', 'src/io.rs', 1, 0, '', 'Self', 1, 1);
+             INSERT INTO function_facts VALUES ('render', 'src/main.rs', 0, 0, '', NULL, 0, 0);",
+        )?;
+        let names = |options: &AssayOptions| -> Result<Vec<String>> {
+            Ok(query_functions(&conn, options)?
+                .into_iter()
+                .map(|f| f.name)
+                .collect())
+        };
+
+        let mut options = AssayOptions {
+            query_type: QueryType::Functions,
+            unsafe_only: true,
+            ..Default::default()
+        };
+        assert_eq!(names(&options)?, vec!["raw_read", "zeroed"]);
+
+        options.unsafe_only = false;
+        options.const_only = true;
+        assert_eq!(names(&options)?, vec!["zeroed", "kib"]);
+
+        options.unsafe_only = true;
+        options.pattern = Some("io".to_string());
+        assert_eq!(names(&options)?, vec!["zeroed"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_methods_only_returns_named_type() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (name TEXT, file TEXT, is_public INTEGER, is_async INTEGER, parameters TEXT, return_type TEXT, parent TEXT);
+             INSERT INTO function_facts VALUES ('new', 'src/foo.rs', 1, 0, 'size: usize', 'Self', 'Foo');
+             INSERT INTO function_facts VALUES ('grow', 'src/foo.rs', 0, 0, '&mut self', NULL, 'Foo');
+             INSERT INTO function_facts VALUES ('open', 'src/bar.rs', 1, 0, 'path: &Path', 'Result<Self>', 'Bar');
+             INSERT INTO function_facts VALUES ('helper', 'src/foo.rs', 0, 0, '', NULL, NULL);",
+        )?;
+
+        let options = AssayOptions {
+            query_type: QueryType::Methods,
+            pattern: Some("Foo".to_string()),
+            ..Default::default()
+        };
+        let names: Vec<String> = query_methods(&conn, &options)?
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        assert_eq!(names, vec!["grow", "new"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_call_graph_explains_why() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        let options = AssayOptions {
+            query_type: QueryType::Callers,
+            pattern: Some("spawn".to_string()),
+            ..Default::default()
+        };
+
+        // Absent and empty tables both mean extraction never produced edges
+        for setup in [
+            "",
+            "CREATE TABLE call_graph (caller TEXT, callee TEXT, file TEXT, call_type TEXT);",
+        ] {
+            conn.execute_batch(setup)?;
+            let err = execute_callers(&conn, &options).unwrap_err();
+            assert_eq!(err.to_string(), CALL_GRAPH_NOT_BUILT);
+            assert_eq!(exit::exit_code(&err), Exit::MissingIndex.code());
+        }
+
+        Ok(())
     }
 
-    Ok(())
+    #[test]
+    fn test_zero_matches_in_populated_call_graph() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE call_graph (caller TEXT, callee TEXT, file TEXT, call_type TEXT);
+             INSERT INTO call_graph VALUES ('main', 'spawn', 'src/main.rs', 'direct');
+             INSERT INTO call_graph VALUES ('spawn', 'tick', 'src/engine.rs', 'direct');",
+        )?;
+        let options = AssayOptions {
+            query_type: QueryType::Callers,
+            pattern: Some("despawn".to_string()),
+            json: true,
+            ..Default::default()
+        };
+
+        assert_eq!(require_call_graph(&conn)?, 2);
+        let err = execute_callers(&conn, &options).unwrap_err();
+        assert_eq!(exit::exit_code(&err), Exit::NoResults.code());
+        assert_eq!(
+            no_match_hint("despawn", 2),
+            "No call sites involve 'despawn' (2 call edges indexed) — check the name with \
+             `patina assay functions despawn --fuzzy`"
+        );
+
+        Ok(())
+    }
 }
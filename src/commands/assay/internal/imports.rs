@@ -1,23 +1,41 @@
 //! Import relationship queries
 //!
 //! "Do X": Query import relationships between files
+//!
+//! `--resolve` maps `crate::` imports to the indexed file defining the longest
+//! matching module path (the same file-to-module mapping `derive` uses).
 
 use anyhow::Result;
 use rusqlite::Connection;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::super::AssayOptions;
-use super::truncate;
+use super::{module_path, truncate};
+use crate::commands::exit;
 
 /// Import info
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ImportInfo {
     pub path: String,
     pub kind: String,
+    /// Indexed file the import names (`--resolve`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<String>,
 }
 
 /// Query what a module imports
 pub fn execute_imports(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let imports = query_imports(conn, options)?;
+    print_imports(
+        options.pattern.as_deref().unwrap_or_default(),
+        &imports,
+        options.json,
+        options.resolve,
+    )
+}
+
+/// Run the imports query without printing
+pub fn query_imports(conn: &Connection, options: &AssayOptions) -> Result<Vec<ImportInfo>> {
     let pattern = options
         .pattern
         .as_ref()
@@ -37,33 +55,141 @@ pub fn execute_imports(conn: &Connection, options: &AssayOptions) -> Result<()>
     "#;
 
     let mut stmt = conn.prepare(sql)?;
-    let imports: Vec<ImportInfo> = stmt
+    let mut imports: Vec<ImportInfo> = stmt
         .query_map([format!("%{}%", pattern), limit.to_string()], |row| {
             Ok(ImportInfo {
                 path: row.get(0)?,
                 kind: row.get(1)?,
+                resolved: None,
             })
         })?
         .filter_map(|r| r.ok())
         .collect();
 
-    if options.json {
+    if options.resolve {
+        resolve_imports(conn, &mut imports)?;
+    }
+
+    Ok(imports)
+}
+
+/// Fill in `resolved` from the files the last scrape indexed
+fn resolve_imports(conn: &Connection, imports: &mut [ImportInfo]) -> Result<()> {
+    let modules = indexed_modules(conn)?;
+    for import in imports {
+        import.resolved = resolve_import(&import.path, &modules);
+    }
+    Ok(())
+}
+
+/// (module path, file) for every indexed Rust file
+pub fn indexed_modules(conn: &Connection) -> Result<Vec<(String, String)>> {
+    let mut stmt = conn.prepare("SELECT path FROM index_state WHERE path LIKE '%.rs'")?;
+    let modules = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .filter_map(|r| r.ok())
+        .map(|path| (module_path(&path), path))
+        .collect();
+    Ok(modules)
+}
+
+/// File whose module is the longest prefix of a `crate::` import path
+pub fn resolve_import(import_path: &str, modules: &[(String, String)]) -> Option<String> {
+    // `crate::a::{b, c}` names items of `a`
+    let path = import_path.split('{').next()?.trim_end_matches("::");
+    let path = path.strip_prefix("crate::")?;
+    modules
+        .iter()
+        .filter(|(module, _)| {
+            path == module
+                || path
+                    .strip_prefix(module.as_str())
+                    .is_some_and(|rest| rest.starts_with("::"))
+        })
+        .max_by_key(|(module, _)| module.len())
+        .map(|(_, file)| file.clone())
+}
+
+/// How an import `--resolve` couldn't map is shown
+fn unresolved_label(import_path: &str) -> &'static str {
+    let relative = ["crate::", "self::", "super::", "."]
+        .iter()
+        .any(|prefix| import_path.starts_with(prefix));
+    if relative {
+        "(unresolved)"
+    } else {
+        "(external)"
+    }
+}
+
+/// Print imports as a table or JSON (with a resolved-file column for `--resolve`)
+pub fn print_imports(
+    pattern: &str,
+    imports: &[ImportInfo],
+    json: bool,
+    resolve: bool,
+) -> Result<()> {
+    if json {
         println!("{}", serde_json::to_string_pretty(&imports)?);
+    } else if resolve {
+        println!("Imports matching '{}'\n", pattern);
+        println!("{:<50} {:<40}", "Import Path", "Resolved");
+        println!("{}", "-".repeat(91));
+        for i in imports {
+            let resolved = i
+                .resolved
+                .as_deref()
+                .unwrap_or_else(|| unresolved_label(&i.path));
+            println!(
+                "{:<50} {:<40}",
+                truncate(&i.path, 50),
+                truncate(resolved, 40)
+            );
+        }
+        println!("\nFound {} imports", imports.len());
     } else {
         println!("Imports matching '{}'\n", pattern);
         println!("{:<60} {:>10}", "Import Path", "Kind");
         println!("{}", "-".repeat(72));
-        for i in &imports {
+        for i in imports {
             println!("{:<60} {:>10}", truncate(&i.path, 60), i.kind);
         }
         println!("\nFound {} imports", imports.len());
     }
 
-    Ok(())
+    exit::ensure_found(imports.len())
+}
+
+/// A file importing the queried module
+#[derive(Debug, Serialize)]
+pub struct ImporterInfo {
+    pub file: String,
+    /// Names it imports from the module
+    pub names: String,
 }
 
 /// Query what modules import a given module
 pub fn execute_importers(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let importers = query_importers(conn, options)?;
+    let pattern = options.pattern.as_deref().unwrap_or_default();
+
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&importers)?);
+    } else {
+        println!("Modules importing '{}'\n", pattern);
+        println!("{:<50} Imported Names", "File");
+        println!("{}", "-".repeat(80));
+        for i in &importers {
+            println!("{:<50} {}", truncate(&i.file, 50), truncate(&i.names, 30));
+        }
+        println!("\nFound {} importers", importers.len());
+    }
+
+    exit::ensure_found(importers.len())
+}
+
+/// Files importing the module named by `options.pattern`, without printing
+pub fn query_importers(conn: &Connection, options: &AssayOptions) -> Result<Vec<ImporterInfo>> {
     let pattern = options
         .pattern
         .as_ref()
@@ -83,31 +209,70 @@ pub fn execute_importers(conn: &Connection, options: &AssayOptions) -> Result<()
     "#;
 
     let mut stmt = conn.prepare(sql)?;
-    let importers: Vec<(String, String)> = stmt
+    let importers = stmt
         .query_map([format!("%{}%", pattern), limit.to_string()], |row| {
-            Ok((
-                row.get(0)?,
-                row.get::<_, Option<String>>(1)?.unwrap_or_default(),
-            ))
+            Ok(ImporterInfo {
+                file: row.get(0)?,
+                names: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+            })
         })?
         .filter_map(|r| r.ok())
         .collect();
+    Ok(importers)
+}
 
-    if options.json {
-        let result: Vec<_> = importers
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::QueryType;
+
+    #[test]
+    fn test_resolve_maps_crate_imports_to_files() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE index_state (path TEXT PRIMARY KEY, mtime BIGINT, size BIGINT);
+             CREATE TABLE import_facts (file TEXT, import_path TEXT, imported_names TEXT,
+                                        import_kind TEXT, line_number INTEGER);
+             INSERT INTO index_state VALUES
+                ('./src/commands/mod.rs', 0, 0),
+                ('./src/commands/scry/mod.rs', 0, 0),
+                ('./src/main.rs', 0, 0);
+             INSERT INTO import_facts VALUES
+                ('./src/main.rs', 'crate::commands::scry::ScryOptions', 'ScryOptions', 'use', 3),
+                ('./src/main.rs', 'crate::commands::{exit, output}', 'exit,output', 'use', 4),
+                ('./src/main.rs', 'anyhow::Result', 'Result', 'use', 1),
+                ('./src/main.rs', 'crate::gone::Thing', 'Thing', 'use', 5);",
+        )?;
+        let options = AssayOptions {
+            query_type: QueryType::Imports,
+            pattern: Some("main.rs".to_string()),
+            resolve: true,
+            ..Default::default()
+        };
+
+        let imports = query_imports(&conn, &options)?;
+        let resolved: Vec<(&str, Option<&str>)> = imports
             .iter()
-            .map(|(file, names)| serde_json::json!({"file": file, "names": names}))
+            .map(|i| (i.path.as_str(), i.resolved.as_deref()))
             .collect();
-        println!("{}", serde_json::to_string_pretty(&result)?);
-    } else {
-        println!("Modules importing '{}'\n", pattern);
-        println!("{:<50} Imported Names", "File");
-        println!("{}", "-".repeat(80));
-        for (file, names) in &importers {
-            println!("{:<50} {}", truncate(file, 50), truncate(names, 30));
-        }
-        println!("\nFound {} importers", importers.len());
-    }
+        assert_eq!(
+            resolved,
+            vec![
+                ("anyhow::Result", None),
+                (
+                    "crate::commands::scry::ScryOptions",
+                    Some("./src/commands/scry/mod.rs")
+                ),
+                (
+                    "crate::commands::{exit, output}",
+                    Some("./src/commands/mod.rs")
+                ),
+                ("crate::gone::Thing", None),
+            ]
+        );
+        assert_eq!(unresolved_label("anyhow::Result"), "(external)");
+        assert_eq!(unresolved_label("crate::gone::Thing"), "(unresolved)");
 
-    Ok(())
+        Ok(())
+    }
 }
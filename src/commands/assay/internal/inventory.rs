@@ -4,13 +4,13 @@
 
 use anyhow::Result;
 use rusqlite::Connection;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 use super::super::AssayOptions;
 use super::truncate;
 
 /// Module stats from inventory query
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ModuleStats {
     pub path: String,
     pub lines: i64,
@@ -20,13 +20,13 @@ pub struct ModuleStats {
 }
 
 /// Inventory result
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InventoryResult {
     pub modules: Vec<ModuleStats>,
     pub summary: InventorySummary,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InventorySummary {
     pub total_files: usize,
     pub total_lines: i64,
@@ -95,6 +95,12 @@ pub fn execute_inventory(
     options: &AssayOptions,
     _repo_name: Option<&str>,
 ) -> Result<()> {
+    let result = query_inventory(conn, options)?;
+    print_inventory(&result, options.json)
+}
+
+/// Run the inventory query without printing
+pub fn query_inventory(conn: &Connection, options: &AssayOptions) -> Result<InventoryResult> {
     let pattern = options.pattern.as_deref().unwrap_or("%");
     let limit = if options.limit > 0 {
         options.limit
@@ -135,16 +141,19 @@ pub fn execute_inventory(
     let total_lines: i64 = modules.iter().map(|m| m.lines).sum();
     let total_functions: i64 = modules.iter().map(|m| m.functions).sum();
 
-    let result = InventoryResult {
+    Ok(InventoryResult {
         modules,
         summary: InventorySummary {
             total_files,
             total_lines,
             total_functions,
         },
-    };
+    })
+}
 
-    if options.json {
+/// Print inventory results as a table or JSON
+pub fn print_inventory(result: &InventoryResult, json: bool) -> Result<()> {
+    if json {
         println!("{}", serde_json::to_string_pretty(&result)?);
     } else {
         println!("Codebase Inventory\n");
@@ -2,14 +2,34 @@
 //!
 //! Follows dependable-rust pattern: private modules with curated re-exports.
 
+mod authored;
+mod clones;
 mod derive;
 mod functions;
 mod imports;
 mod inventory;
+mod query;
+mod summary;
 mod util;
 
-pub(super) use derive::{execute_derive, execute_derive_moments};
-pub(super) use functions::{execute_callees, execute_callers, execute_functions};
-pub(super) use imports::{execute_importers, execute_imports};
-pub(super) use inventory::{collect_inventory_json, execute_inventory};
-pub(super) use util::truncate;
+pub(super) use authored::{execute_authored, query_authored};
+pub(super) use clones::{execute_clones, query_clones};
+pub(super) use derive::{
+    execute_derive, execute_derive_moments, query_derive, query_derive_moments,
+};
+pub(crate) use derive::{is_entry_point, is_test_file};
+pub(crate) use functions::edit_distance;
+pub(super) use functions::{
+    execute_callees, execute_callers, execute_functions, execute_methods, print_functions,
+    query_callees, query_callers, query_functions, query_methods, FunctionInfo,
+};
+pub(super) use imports::{
+    execute_importers, execute_imports, print_imports, query_importers, query_imports, ImportInfo,
+};
+use imports::{indexed_modules, resolve_import};
+pub(super) use inventory::{
+    collect_inventory_json, execute_inventory, print_inventory, query_inventory, InventoryResult,
+};
+pub(super) use query::{execute_query, query_captures};
+pub(super) use summary::{execute_summary, query_summary};
+pub(super) use util::{module_path, truncate};
@@ -0,0 +1,172 @@
+//! Ad-hoc tree-sitter queries over indexed files
+//!
+//! "Do X": Run a user-supplied s-expression query and list its captures.
+//!
+//! Files come from `index_state` (what the last scrape indexed), filtered to
+//! the requested language, and are re-read from disk so captures reflect the
+//! working tree.
+
+use anyhow::{Context, Result};
+use patina_metal::queries::QueryLoader;
+use patina_metal::{Analyzer, Metal, ParsedFile};
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tree_sitter::Parser;
+
+use super::super::AssayOptions;
+use super::truncate;
+use crate::commands::exit;
+
+/// One capture from a query match
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureInfo {
+    pub file: String,
+    pub capture: String,
+    pub text: String,
+    /// 1-based line the capture starts on
+    pub line: usize,
+}
+
+/// Run the query over sources under `root` and print captures
+pub fn execute_query(conn: &Connection, root: &Path, options: &AssayOptions) -> Result<()> {
+    let captures = query_captures(conn, root, options)?;
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&captures)?);
+        return exit::ensure_found(captures.len());
+    }
+
+    println!(
+        "{:<40} {:>6} {:<16} {:<30}",
+        "File", "Line", "Capture", "Text"
+    );
+    println!("{}", "-".repeat(95));
+    for c in &captures {
+        let text = c.text.lines().next().unwrap_or_default();
+        println!(
+            "{:<40} {:>6} {:<16} {:<30}",
+            truncate(&c.file, 40),
+            c.line,
+            truncate(&c.capture, 16),
+            truncate(text, 30)
+        );
+    }
+    println!("\nFound {} captures", captures.len());
+
+    exit::ensure_found(captures.len())
+}
+
+/// Captures of `options.pattern` over indexed `options.lang` files under `root`
+pub fn query_captures(
+    conn: &Connection,
+    root: &Path,
+    options: &AssayOptions,
+) -> Result<Vec<CaptureInfo>> {
+    let lang = options
+        .lang
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("query requires --lang"))?;
+    let metal =
+        Metal::from_name(lang).ok_or_else(|| anyhow::anyhow!("Unsupported language '{}'", lang))?;
+    let pattern = options
+        .pattern
+        .as_deref()
+        .ok_or_else(|| anyhow::anyhow!("query requires --pattern"))?;
+    let limit = if options.limit > 0 {
+        options.limit
+    } else {
+        100
+    };
+
+    let query = QueryLoader::create_query(metal, pattern)
+        .map_err(|e| anyhow::anyhow!("Invalid {} query: {}", lang, e))?;
+    let language = metal
+        .tree_sitter_language()
+        .ok_or_else(|| anyhow::anyhow!("No parser available for {}", lang))?;
+    let mut parser = Parser::new();
+    parser
+        .set_language(&language)
+        .context("Failed to set parser language")?;
+
+    let mut stmt = conn.prepare("SELECT path FROM index_state ORDER BY path")?;
+    let paths: Vec<String> = stmt
+        .query_map([], |row| row.get(0))?
+        .filter_map(|r| r.ok())
+        .filter(|path: &String| Metal::from_path(Path::new(path)) == Some(metal))
+        .collect();
+
+    let mut captures = Vec::new();
+    for path in paths {
+        // Deleted since the last scrape
+        let Ok(source) = std::fs::read_to_string(root.join(&path)) else {
+            continue;
+        };
+        let Some(tree) = parser.parse(&source, None) else {
+            continue;
+        };
+        let file = ParsedFile {
+            tree,
+            metal,
+            source,
+        };
+        for m in Analyzer::run_custom_query(&file, &query) {
+            captures.push(CaptureInfo {
+                file: path.clone(),
+                capture: m.capture_name,
+                text: m.text,
+                line: m.start_line + 1,
+            });
+            if captures.len() >= limit {
+                return Ok(captures);
+            }
+        }
+    }
+
+    Ok(captures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::QueryType;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_capture_query_over_indexed_rust_file() -> Result<()> {
+        let temp = TempDir::new()?;
+        std::fs::create_dir_all(temp.path().join("src"))?;
+        std::fs::write(
+            temp.path().join("src/lib.rs"),
+            "pub fn spawn() {}\n\nstruct World;\n\nfn tick(world: &World) {}\n",
+        )?;
+        std::fs::write(temp.path().join("src/app.py"), "def spawn():\n    pass\n")?;
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE index_state (path TEXT PRIMARY KEY, mtime BIGINT, size BIGINT);
+             INSERT INTO index_state VALUES ('src/lib.rs', 0, 0), ('src/app.py', 0, 0);",
+        )?;
+
+        let mut options = AssayOptions {
+            query_type: QueryType::Query,
+            lang: Some("rust".to_string()),
+            pattern: Some("(function_item name: (identifier) @n)".to_string()),
+            ..Default::default()
+        };
+        let captures = query_captures(&conn, temp.path(), &options)?;
+        let found: Vec<(&str, &str, usize)> = captures
+            .iter()
+            .map(|c| (c.file.as_str(), c.text.as_str(), c.line))
+            .collect();
+        assert_eq!(
+            found,
+            vec![("src/lib.rs", "spawn", 1), ("src/lib.rs", "tick", 5)]
+        );
+        assert!(captures.iter().all(|c| c.capture == "n"));
+
+        options.pattern = Some("(function_item name: (identifer) @n)".to_string());
+        let err = query_captures(&conn, temp.path(), &options).unwrap_err();
+        assert!(err.to_string().starts_with("Invalid rust query"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+//! One-screen codebase overview
+//!
+//! "Do X": Summarize inventory, hotspots, dormancy, import cycles and fact coverage.
+//!
+//! Totals come from the inventory query. Hotspots rank files by call graph
+//! degree (the edge count `derive` scales into `centrality_score`). Dormancy
+//! is read from `module_signals`, so it needs a prior `assay derive`. Cycles
+//! are strongly connected groups of files in the resolved `crate::` import graph.
+
+use anyhow::Result;
+use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::super::{AssayOptions, QueryType};
+use super::{indexed_modules, query_inventory, resolve_import, truncate};
+use crate::commands::exit;
+
+/// Hotspots listed in the summary
+const TOP_HOTSPOTS: usize = 5;
+
+/// A file ranked by call graph degree
+#[derive(Debug, Serialize)]
+pub struct Hotspot {
+    pub path: String,
+    /// Call graph edges in the file
+    pub degree: i64,
+}
+
+/// Summary result
+#[derive(Debug, Serialize)]
+pub struct AssaySummary {
+    pub modules: usize,
+    pub lines: i64,
+    pub functions: i64,
+    pub hotspots: Vec<Hotspot>,
+    /// Modules `derive` marked dormant (None until it has run)
+    pub dormant_modules: Option<usize>,
+    pub import_cycles: usize,
+    /// Share of modules with import facts
+    pub import_coverage: f64,
+    /// Share of modules with call graph edges
+    pub call_graph_coverage: f64,
+}
+
+/// Print the summary
+pub fn execute_summary(conn: &Connection, options: &AssayOptions) -> Result<()> {
+    let summary = query_summary(conn)?;
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&summary)?);
+        return exit::ensure_found(summary.modules);
+    }
+
+    println!("Codebase Summary\n");
+    println!(
+        "  Modules:   {}   Lines: {}   Functions: {}",
+        summary.modules, summary.lines, summary.functions
+    );
+    match summary.dormant_modules {
+        Some(dormant) => println!("  Dormant:   {} modules", dormant),
+        None => println!("  Dormant:   unknown (run 'patina assay derive')"),
+    }
+    println!("  Cycles:    {} import cycles", summary.import_cycles);
+    println!(
+        "  Coverage:  imports {:.0}%, call graph {:.0}%",
+        summary.import_coverage * 100.0,
+        summary.call_graph_coverage * 100.0
+    );
+
+    println!("\nTop hotspots (call graph degree)\n");
+    if summary.hotspots.is_empty() {
+        println!("  (no call graph edges)");
+    }
+    for h in &summary.hotspots {
+        println!("  {:<50} {:>6}", truncate(&h.path, 50), h.degree);
+    }
+
+    exit::ensure_found(summary.modules)
+}
+
+/// Run every part of the summary without printing
+pub fn query_summary(conn: &Connection) -> Result<AssaySummary> {
+    let inventory = query_inventory(
+        conn,
+        &AssayOptions {
+            query_type: QueryType::Inventory,
+            // Every module, not the inventory's display default
+            limit: i64::MAX as usize,
+            ..Default::default()
+        },
+    )?;
+    let modules: BTreeSet<&str> = inventory.modules.iter().map(|m| m.path.as_str()).collect();
+    let share = |count: usize| {
+        if modules.is_empty() {
+            0.0
+        } else {
+            count as f64 / modules.len() as f64
+        }
+    };
+
+    let degrees = file_degrees(conn)?;
+    let mut hotspots: Vec<Hotspot> = degrees
+        .iter()
+        .map(|(path, &degree)| Hotspot {
+            path: path.clone(),
+            degree,
+        })
+        .collect();
+    hotspots.sort_by(|a, b| b.degree.cmp(&a.degree).then_with(|| a.path.cmp(&b.path)));
+    hotspots.truncate(TOP_HOTSPOTS);
+
+    let dormant_modules = if has_table(conn, "module_signals")? {
+        let count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM module_signals WHERE activity_level = 'dormant'",
+            [],
+            |row| row.get(0),
+        )?;
+        Some(count as usize)
+    } else {
+        None
+    };
+
+    let with_imports = inventory.modules.iter().filter(|m| m.imports > 0).count();
+    let with_calls = degrees
+        .keys()
+        .filter(|path| modules.contains(path.as_str()))
+        .count();
+
+    Ok(AssaySummary {
+        modules: inventory.summary.total_files,
+        lines: inventory.summary.total_lines,
+        functions: inventory.summary.total_functions,
+        hotspots,
+        dormant_modules,
+        import_cycles: count_cycles(&import_graph(conn)?),
+        import_coverage: share(with_imports),
+        call_graph_coverage: share(with_calls),
+    })
+}
+
+fn has_table(conn: &Connection, name: &str) -> Result<bool> {
+    Ok(conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1")?
+        .exists([name])?)
+}
+
+/// Call graph edges per file (empty before call extraction has run)
+fn file_degrees(conn: &Connection) -> Result<BTreeMap<String, i64>> {
+    if !has_table(conn, "call_graph")? {
+        return Ok(BTreeMap::new());
+    }
+    let mut stmt = conn.prepare("SELECT file, COUNT(*) FROM call_graph GROUP BY file")?;
+    let degrees = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    Ok(degrees)
+}
+
+/// File -> files it imports, for imports that resolve to an indexed file
+fn import_graph(conn: &Connection) -> Result<BTreeMap<String, BTreeSet<String>>> {
+    let mut graph: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    if !has_table(conn, "import_facts")? {
+        return Ok(graph);
+    }
+    let modules = indexed_modules(conn)?;
+    let mut stmt = conn.prepare("SELECT file, import_path FROM import_facts")?;
+    let imports = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<Vec<(String, String)>>>()?;
+    for (file, import_path) in imports {
+        let Some(target) = resolve_import(&import_path, &modules) else {
+            continue;
+        };
+        let file = file.trim_start_matches("./").to_string();
+        let target = target.trim_start_matches("./").to_string();
+        if file != target {
+            graph.entry(file).or_default().insert(target);
+        }
+    }
+    Ok(graph)
+}
+
+/// Strongly connected components with more than one file (Kosaraju)
+fn count_cycles(graph: &BTreeMap<String, BTreeSet<String>>) -> usize {
+    let nodes: BTreeSet<&str> = graph
+        .iter()
+        .flat_map(|(from, to)| std::iter::once(from.as_str()).chain(to.iter().map(|t| t.as_str())))
+        .collect();
+    let index: BTreeMap<&str, usize> = nodes.iter().enumerate().map(|(i, n)| (*n, i)).collect();
+    let mut forward = vec![Vec::new(); nodes.len()];
+    let mut reverse = vec![Vec::new(); nodes.len()];
+    for (from, targets) in graph {
+        for to in targets {
+            forward[index[from.as_str()]].push(index[to.as_str()]);
+            reverse[index[to.as_str()]].push(index[from.as_str()]);
+        }
+    }
+
+    // Pass 1: finish order over the forward graph
+    let mut visited = vec![false; nodes.len()];
+    let mut order = Vec::with_capacity(nodes.len());
+    for start in 0..nodes.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut stack = vec![(start, 0)];
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            if let Some(&child) = forward[node].get(*next) {
+                *next += 1;
+                if !visited[child] {
+                    visited[child] = true;
+                    stack.push((child, 0));
+                }
+            } else {
+                order.push(node);
+                stack.pop();
+            }
+        }
+    }
+
+    // Pass 2: components of the reverse graph, latest finisher first
+    let mut assigned = vec![false; nodes.len()];
+    let mut cycles = 0;
+    for &start in order.iter().rev() {
+        if assigned[start] {
+            continue;
+        }
+        assigned[start] = true;
+        let mut stack = vec![start];
+        let mut size = 0;
+        while let Some(node) = stack.pop() {
+            size += 1;
+            for &parent in &reverse[node] {
+                if !assigned[parent] {
+                    assigned[parent] = true;
+                    stack.push(parent);
+                }
+            }
+        }
+        if size > 1 {
+            cycles += 1;
+        }
+    }
+    cycles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::assay::internal::execute_derive;
+
+    #[test]
+    fn test_summary_matches_individual_queries() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE index_state (path TEXT PRIMARY KEY, mtime BIGINT, size BIGINT, line_count INTEGER);
+             CREATE TABLE function_facts (file TEXT, name TEXT);
+             CREATE TABLE import_facts (file TEXT, import_path TEXT, imported_names TEXT,
+                                        import_kind TEXT, line_number INTEGER);
+             CREATE TABLE call_graph (caller TEXT, callee TEXT, file TEXT, call_type TEXT);
+             INSERT INTO index_state VALUES
+                ('./src/main.rs', 0, 300, 120), ('./src/engine.rs', 0, 200, 80),
+                ('./src/world.rs', 0, 100, 40), ('./src/unused.rs', 0, 10, 5);
+             INSERT INTO function_facts VALUES
+                ('./src/main.rs', 'main'), ('./src/engine.rs', 'tick'),
+                ('./src/engine.rs', 'spawn'), ('./src/world.rs', 'step');
+             INSERT INTO import_facts (file, import_path) VALUES
+                ('./src/main.rs', 'crate::engine::tick'),
+                ('./src/engine.rs', 'crate::world::step'),
+                ('./src/world.rs', 'crate::engine::spawn'),
+                ('./src/world.rs', 'anyhow::Result');
+             INSERT INTO call_graph VALUES
+                ('main', 'tick', './src/main.rs', 'direct'),
+                ('tick', 'spawn', './src/engine.rs', 'direct'),
+                ('tick', 'step', './src/engine.rs', 'direct'),
+                ('spawn', 'new', './src/engine.rs', 'direct'),
+                ('step', 'spawn', './src/world.rs', 'direct');",
+        )?;
+
+        // Before derive, dormancy is unknown rather than zero
+        assert_eq!(query_summary(&conn)?.dormant_modules, None);
+        execute_derive(&conn, &AssayOptions::default())?;
+
+        let summary = query_summary(&conn)?;
+        let inventory = query_inventory(&conn, &AssayOptions::default())?;
+        assert_eq!(summary.modules, inventory.summary.total_files);
+        assert_eq!(summary.lines, inventory.summary.total_lines);
+        assert_eq!(summary.functions, inventory.summary.total_functions);
+        assert_eq!(
+            (summary.modules, summary.lines, summary.functions),
+            (4, 245, 4)
+        );
+
+        // Hotspot degree is the call graph count behind derive's centrality
+        let centrality: Vec<(String, f64)> = conn
+            .prepare("SELECT path, centrality_score FROM module_signals WHERE centrality_score > 0 ORDER BY centrality_score DESC, path")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        let hotspots: Vec<(String, f64)> = summary
+            .hotspots
+            .iter()
+            .map(|h| (h.path.clone(), h.degree as f64 / 100.0))
+            .collect();
+        assert_eq!(hotspots, centrality);
+        assert_eq!(summary.hotspots[0].path, "./src/engine.rs");
+
+        let dormant: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM module_signals WHERE activity_level = 'dormant'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(summary.dormant_modules, Some(dormant as usize));
+
+        // engine <-> world import each other; main only points into the cycle
+        assert_eq!(summary.import_cycles, 1);
+        assert_eq!(summary.import_coverage, 0.75);
+        assert_eq!(summary.call_graph_coverage, 0.75);
+
+        Ok(())
+    }
+}
@@ -9,6 +9,18 @@ pub fn truncate(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Module path a Rust source file defines
+///
+/// `./src/adapters/claude/mod.rs` -> `adapters::claude`,
+/// `./src/adapters/templates.rs` -> `adapters::templates`
+pub fn module_path(path: &str) -> String {
+    path.trim_start_matches("./")
+        .trim_start_matches("src/")
+        .trim_end_matches(".rs")
+        .trim_end_matches("/mod")
+        .replace('/', "::")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -18,4 +30,13 @@ mod tests {
         assert_eq!(truncate("short", 10), "short");
         assert_eq!(truncate("a very long string", 10), "a very ..."); // 7 chars + "..."
     }
+
+    #[test]
+    fn test_module_path() {
+        assert_eq!(
+            module_path("./src/adapters/claude/mod.rs"),
+            "adapters::claude"
+        );
+        assert_eq!(module_path("src/commands/exit.rs"), "commands::exit");
+    }
 }
@@ -4,18 +4,39 @@
 //! - Module inventory with line counts, function counts
 //! - Import/importer relationships
 //! - Caller/callee relationships from call graph
+//! - Methods defined on a type (impl/trait parent)
+//! - Ad-hoc tree-sitter queries over indexed files
+//! - Files a contributor has modified
+//! - Clone clusters from structural fingerprints
+//! - One-screen summary: totals, hotspots, dormancy, import cycles, coverage
+//!
+//! When `PATINA_MOTHER` is set, inventory/imports/functions queries run on the
+//! mother daemon against its database (like scry does).
+//!
+//! `--output` writes the `--json` result shape to a file instead.
 
 mod internal;
 
+use crate::commands::{exit, output};
 use anyhow::{Context, Result};
 use internal::{
-    collect_inventory_json, execute_callees, execute_callers, execute_derive,
-    execute_derive_moments, execute_functions, execute_importers, execute_imports,
-    execute_inventory,
+    collect_inventory_json, execute_authored, execute_callees, execute_callers, execute_clones,
+    execute_derive, execute_derive_moments, execute_functions, execute_importers, execute_imports,
+    execute_inventory, execute_methods, execute_query, execute_summary, print_functions,
+    print_imports, print_inventory, query_authored, query_callees, query_callers, query_captures,
+    query_clones, query_derive, query_derive_moments, query_functions, query_importers,
+    query_imports, query_inventory, query_methods, query_summary, FunctionInfo, ImportInfo,
+    InventoryResult,
 };
+use patina::mother;
 use rusqlite::Connection;
 
-const DB_PATH: &str = ".patina/local/data/patina.db";
+// Path classifiers shared with scry orient's fallback ranking
+pub(crate) use internal::{is_entry_point, is_test_file};
+// Fuzzy matching shared with scry's "did you mean" file suggestions
+pub(crate) use internal::edit_distance;
+
+pub(crate) const DB_PATH: &str = ".patina/local/data/patina.db";
 
 /// Query type for assay command
 #[derive(Debug, Clone, Copy, Default)]
@@ -25,10 +46,46 @@ pub enum QueryType {
     Imports,
     Importers,
     Functions,
+    Methods,
     Callers,
     Callees,
+    Clones,
+    Query,
+    Authored,
     Derive,
     DeriveMoments,
+    Summary,
+}
+
+impl QueryType {
+    /// Wire name used in mother assay requests
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueryType::Inventory => "inventory",
+            QueryType::Imports => "imports",
+            QueryType::Importers => "importers",
+            QueryType::Functions => "functions",
+            QueryType::Methods => "methods",
+            QueryType::Callers => "callers",
+            QueryType::Callees => "callees",
+            QueryType::Clones => "clones",
+            QueryType::Query => "query",
+            QueryType::Authored => "authored",
+            QueryType::Derive => "derive",
+            QueryType::DeriveMoments => "derive-moments",
+            QueryType::Summary => "summary",
+        }
+    }
+
+    /// Query types the mother daemon can answer, by wire name
+    pub fn remote(name: &str) -> Option<Self> {
+        match name {
+            "inventory" => Some(QueryType::Inventory),
+            "imports" => Some(QueryType::Imports),
+            "functions" => Some(QueryType::Functions),
+            _ => None,
+        }
+    }
 }
 
 /// Options for assay command
@@ -42,10 +99,36 @@ pub struct AssayOptions {
     pub repo: Option<String>,
     /// Query all registered repos
     pub all_repos: bool,
+    /// Rank function matches by edit distance instead of substring
+    pub fuzzy: bool,
+    /// Shortest function (in lines) `clones` considers
+    pub min_lines: usize,
+    /// Only list `unsafe` functions
+    pub unsafe_only: bool,
+    /// Only list `const` functions
+    pub const_only: bool,
+    /// Language a tree-sitter `query` pattern is written for
+    pub lang: Option<String>,
+    /// Map each import to the indexed file it names
+    pub resolve: bool,
+    /// Only count commits since this date (`YYYY-MM-DD`) or `Nd` days ago
+    pub since: Option<String>,
 }
 
 /// Execute assay command
 pub fn execute(options: AssayOptions) -> Result<()> {
+    // Route to mother when configured (supported query types only)
+    if mother::is_configured()
+        && !options.all_repos
+        && !options.fuzzy
+        && !options.unsafe_only
+        && !options.const_only
+        && !options.resolve
+        && QueryType::remote(options.query_type.name()).is_some()
+    {
+        return execute_via_mother(&options);
+    }
+
     // Handle all_repos mode: iterate over all registered repos
     if options.all_repos {
         return execute_all_repos(&options);
@@ -57,23 +140,147 @@ pub fn execute(options: AssayOptions) -> Result<()> {
         None => DB_PATH.to_string(),
     };
 
-    let conn = Connection::open(&db_path)
-        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    let root = source_root(options.repo.as_deref())?;
 
     // Show repo context if specified
     if let Some(ref repo) = options.repo {
         println!("Repository: {}\n", repo);
     }
 
+    execute_local(std::path::Path::new(&db_path), &root, &options)
+}
+
+/// Working tree that source-reading queries parse: the repo's clone, else `.`
+pub fn source_root(repo: Option<&str>) -> Result<std::path::PathBuf> {
+    match repo {
+        Some(name) => crate::commands::repo::get_path(name),
+        None => Ok(std::path::PathBuf::from(".")),
+    }
+}
+
+/// Write the query's JSON result to `path`, printing only a summary line
+pub fn execute_to_file(options: &AssayOptions, path: &std::path::Path) -> Result<()> {
+    if options.all_repos {
+        anyhow::bail!("--output writes one repo's results; drop --all-repos");
+    }
+    let db_path = match &options.repo {
+        Some(name) => crate::commands::repo::get_db_path(name)?,
+        None => DB_PATH.to_string(),
+    };
+    exit::require_db(std::path::Path::new(&db_path))?;
+    let conn = Connection::open(&db_path)
+        .with_context(|| format!("Failed to open database: {}", db_path))?;
+
+    let count = write_results(&conn, options, path)?;
+    println!("{}", output::summary(count, path));
+    exit::ensure_found(count)
+}
+
+/// Serialize the result to `path`; returns how many results it holds
+fn write_results(
+    conn: &Connection,
+    options: &AssayOptions,
+    path: &std::path::Path,
+) -> Result<usize> {
+    let value = query_json(conn, options)?;
+    // Inventory and the derive queries are objects wrapping their list
+    let count = value
+        .as_array()
+        .or_else(|| value["modules"].as_array())
+        .or_else(|| value["signals"].as_array())
+        .or_else(|| value["moments"].as_array())
+        .map_or(1, Vec::len);
+    output::write(path, &serde_json::to_string_pretty(&value)?)?;
+    Ok(count)
+}
+
+/// Run the query against one database (sources under `root`), printing results
+fn execute_local(
+    db_path: &std::path::Path,
+    root: &std::path::Path,
+    options: &AssayOptions,
+) -> Result<()> {
+    exit::require_db(db_path)?;
+    let conn = Connection::open(db_path)
+        .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
+
     match options.query_type {
-        QueryType::Inventory => execute_inventory(&conn, &options, None),
-        QueryType::Imports => execute_imports(&conn, &options),
-        QueryType::Importers => execute_importers(&conn, &options),
-        QueryType::Functions => execute_functions(&conn, &options),
-        QueryType::Callers => execute_callers(&conn, &options),
-        QueryType::Callees => execute_callees(&conn, &options),
-        QueryType::Derive => execute_derive(&conn, &options),
-        QueryType::DeriveMoments => execute_derive_moments(&conn, &options),
+        QueryType::Inventory => execute_inventory(&conn, options, None),
+        QueryType::Imports => execute_imports(&conn, options),
+        QueryType::Importers => execute_importers(&conn, options),
+        QueryType::Functions => execute_functions(&conn, options),
+        QueryType::Methods => execute_methods(&conn, options),
+        QueryType::Callers => execute_callers(&conn, options),
+        QueryType::Callees => execute_callees(&conn, options),
+        QueryType::Clones => execute_clones(&conn, options),
+        QueryType::Query => execute_query(&conn, root, options),
+        QueryType::Authored => execute_authored(&conn, options),
+        QueryType::Derive => execute_derive(&conn, options),
+        QueryType::DeriveMoments => execute_derive_moments(&conn, options),
+        QueryType::Summary => execute_summary(&conn, options),
+    }
+}
+
+/// Run a query and return its result as JSON
+///
+/// Used by the mother daemon and the MCP assay tools; the shape matches what
+/// `--json` prints locally.
+pub fn query_json(conn: &Connection, options: &AssayOptions) -> Result<serde_json::Value> {
+    let value = match options.query_type {
+        QueryType::Inventory => serde_json::to_value(query_inventory(conn, options)?)?,
+        QueryType::Imports => serde_json::to_value(query_imports(conn, options)?)?,
+        QueryType::Functions => serde_json::to_value(query_functions(conn, options)?)?,
+        QueryType::Methods => serde_json::to_value(query_methods(conn, options)?)?,
+        QueryType::Callers => serde_json::to_value(query_callers(conn, options)?)?,
+        QueryType::Callees => serde_json::to_value(query_callees(conn, options)?)?,
+        QueryType::Clones => serde_json::to_value(query_clones(conn, options)?)?,
+        QueryType::Query => {
+            let root = source_root(options.repo.as_deref())?;
+            serde_json::to_value(query_captures(conn, &root, options)?)?
+        }
+        QueryType::Authored => serde_json::to_value(query_authored(conn, options)?)?,
+        QueryType::Summary => serde_json::to_value(query_summary(conn)?)?,
+        QueryType::Importers => serde_json::to_value(query_importers(conn, options)?)?,
+        QueryType::Derive => serde_json::to_value(query_derive(conn, options)?)?,
+        QueryType::DeriveMoments => serde_json::to_value(query_derive_moments(conn)?)?,
+    };
+    Ok(value)
+}
+
+/// Execute assay via mother daemon
+fn execute_via_mother(options: &AssayOptions) -> Result<()> {
+    let request = mother::AssayRequest {
+        query_type: options.query_type.name().to_string(),
+        pattern: options.pattern.clone(),
+        limit: options.limit,
+        repo: options.repo.clone(),
+    };
+    let response = mother::assay(request)?;
+
+    if !options.json {
+        let address = mother::get_address().unwrap_or_else(|| "unknown".to_string());
+        println!("Mother: {}\n", address);
+    }
+
+    match options.query_type {
+        QueryType::Inventory => {
+            let result: InventoryResult = serde_json::from_value(response.result)?;
+            print_inventory(&result, options.json)
+        }
+        QueryType::Imports => {
+            let imports: Vec<ImportInfo> = serde_json::from_value(response.result)?;
+            print_imports(
+                options.pattern.as_deref().unwrap_or_default(),
+                &imports,
+                options.json,
+                false,
+            )
+        }
+        QueryType::Functions => {
+            let functions: Vec<FunctionInfo> = serde_json::from_value(response.result)?;
+            print_functions(options.pattern.as_deref(), &functions, options.json)
+        }
+        other => anyhow::bail!("'{}' queries are not supported via mother", other.name()),
     }
 }
 
@@ -135,3 +342,142 @@ fn execute_all_repos(options: &AssayOptions) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes_for_missing_db_and_no_matches() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let db_path = temp.path().join("patina.db");
+        let options = AssayOptions {
+            query_type: QueryType::Functions,
+            pattern: Some("spawn".to_string()),
+            ..Default::default()
+        };
+
+        let root = std::path::Path::new(".");
+        let err = execute_local(&db_path, root, &options).unwrap_err();
+        assert_eq!(exit::exit_code(&err), 4);
+        assert!(!db_path.exists(), "a missing database must not be created");
+
+        Connection::open(&db_path)?.execute_batch(
+            "CREATE TABLE function_facts (name TEXT, file TEXT, is_public INTEGER,
+                                          is_async INTEGER, parameters TEXT, return_type TEXT);
+             INSERT INTO function_facts VALUES ('tick', 'src/engine.rs', 1, 0, '', NULL);",
+        )?;
+        let err = execute_local(&db_path, root, &options).unwrap_err();
+        assert_eq!(exit::exit_code(&err), 3);
+
+        let found = AssayOptions {
+            pattern: Some("tick".to_string()),
+            ..options
+        };
+        execute_local(&db_path, root, &found)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_file_holds_json_results() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (name TEXT, file TEXT, is_public INTEGER,
+                                          is_async INTEGER, parameters TEXT, return_type TEXT);
+             INSERT INTO function_facts VALUES ('tick', 'src/engine.rs', 1, 0, '', NULL),
+                                               ('tally', 'src/stats.rs', 0, 0, '', NULL);",
+        )?;
+        let options = AssayOptions {
+            query_type: QueryType::Functions,
+            pattern: Some("t".to_string()),
+            ..Default::default()
+        };
+
+        // Parent directories are created on demand
+        let path = temp.path().join("dumps/assay/functions.json");
+        let count = write_results(&conn, &options, &path)?;
+
+        let written: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+        assert_eq!(written, query_json(&conn, &options)?);
+        assert_eq!(count, 2);
+        assert_eq!(
+            output::summary(count, &path),
+            format!("Wrote 2 results to {}", path.display())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_output_file_covers_importers_and_derive() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE index_state (path TEXT PRIMARY KEY, mtime BIGINT, size BIGINT, line_count INTEGER);
+             CREATE TABLE import_facts (file TEXT, import_path TEXT, imported_names TEXT,
+                                        import_kind TEXT, line_number INTEGER);
+             CREATE TABLE commits (sha TEXT, message TEXT, timestamp TEXT, author_name TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             INSERT INTO index_state VALUES ('./src/main.rs', 0, 300, 120), ('./src/engine.rs', 0, 200, 80);
+             INSERT INTO import_facts (file, import_path, imported_names) VALUES
+                ('./src/main.rs', 'crate::engine', 'tick');
+             INSERT INTO commits VALUES
+                ('aaaa', 'initial import', '2024-01-01T00:00:00Z', 'ana'),
+                ('bbbb', 'rewrite the engine', '2024-02-01T00:00:00Z', 'ana');
+             INSERT INTO commit_files VALUES ('aaaa', 'src/main.rs'), ('bbbb', 'src/engine.rs');",
+        )?;
+        let write =
+            |query_type: QueryType, pattern: Option<&str>| -> Result<(usize, serde_json::Value)> {
+                let options = AssayOptions {
+                    query_type,
+                    pattern: pattern.map(String::from),
+                    ..Default::default()
+                };
+                let path = temp.path().join(format!("{}.json", query_type.name()));
+                let count = write_results(&conn, &options, &path)?;
+                Ok((
+                    count,
+                    serde_json::from_str(&std::fs::read_to_string(&path)?)?,
+                ))
+            };
+
+        let (count, importers) = write(QueryType::Importers, Some("engine"))?;
+        assert_eq!(count, 1);
+        assert_eq!(
+            importers,
+            serde_json::json!([{"file": "./src/main.rs", "names": "tick"}])
+        );
+
+        let (count, derived) = write(QueryType::Derive, None)?;
+        assert_eq!(count, 2);
+        assert_eq!(derived["summary"]["total_modules"], 2);
+        let engine = derived["signals"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["path"] == "./src/engine.rs")
+            .unwrap();
+        assert_eq!(engine["importer_count"], 1);
+        assert_eq!(engine["commit_count"], 1);
+
+        let (count, moments) = write(QueryType::DeriveMoments, None)?;
+        assert_eq!(count, 2);
+        let kinds: Vec<(&str, &str)> = moments["moments"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|m| {
+                (
+                    m["sha"].as_str().unwrap(),
+                    m["moment_type"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(kinds, vec![("aaaa", "genesis"), ("bbbb", "rewrite")]);
+        assert_eq!(moments["summary"]["total_commits"], 2);
+
+        Ok(())
+    }
+}
@@ -4,13 +4,29 @@
 //! real metrics instead of fabricated confidence scores.
 //!
 //! E4.6a: --grounding flag computes semantic grounding from usearch embeddings.
+//! --explain-grounding traces one belief: neighbor scores, cutoffs, and reach hops.
+//!
+//! `belief diff` compares metric snapshots taken at the end of each scrape.
+//! `belief graph` exports the supports/attacks/evidence network as DOT or JSON.
+//! `belief contradictions` flags same-topic beliefs whose stances oppose.
+//! `belief ground` refreshes semantic grounding after an `oxidize`, without
+//! re-scraping belief files.
 
 use anyhow::{Context, Result};
 use clap::Subcommand;
+use patina::embeddings::id_offsets::{belief_key, classify_key, KeyKind};
 use rusqlite::Connection;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
-use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+use usearch::Index;
+
+use crate::commands::oxidize::index_meta;
 
+use super::scrape::beliefs::{
+    build_belief_graph, compute_belief_grounding, explain_grounding, ground_with_index,
+    semantic_index_path, BeliefGraph, GroundingParams, GroundingTrace, BELIEFS_DIR,
+};
 use super::scrape::database;
 use super::scry::internal::enrichment::{enrich_results, SearchResults};
 
@@ -29,14 +45,74 @@ pub enum BeliefCommands {
         /// Show semantic grounding — nearest code/commits/sessions for each belief (E4.6a)
         #[arg(long)]
         grounding: bool,
+
+        /// Output as JSON (per-belief metrics, warnings, and summary)
+        #[arg(long, conflicts_with = "grounding")]
+        json: bool,
+
+        /// Flag beliefs not revised in this many days as stale
+        #[arg(long, default_value = "365")]
+        stale_days: i64,
+
+        /// Recompute grounding with this minimum neighbor similarity (default from config: 0.85)
+        #[arg(long)]
+        min_grounding_score: Option<f32>,
+
+        /// Recompute grounding examining this many neighbors per belief (default from config: 20)
+        #[arg(long)]
+        grounding_limit: Option<usize>,
+
+        /// Trace one belief's grounding: neighbor scores, threshold cutoffs, and reach hops
+        #[arg(long, value_name = "BELIEF_ID", conflicts_with_all = ["grounding", "json"])]
+        explain_grounding: Option<String>,
+    },
+
+    /// Recompute semantic grounding against the current index (no belief re-parse)
+    Ground {
+        /// Minimum neighbor similarity (default from config: 0.85)
+        #[arg(long)]
+        min_score: Option<f32>,
+
+        /// Neighbors examined per belief (default from config: 20)
+        #[arg(long)]
+        limit: Option<usize>,
+    },
+
+    /// Compare belief metrics between the last two scrapes
+    Diff,
+
+    /// Export the belief network (supports/attacks/evidence edges)
+    Graph {
+        /// Output format: "dot" (default) or "json"
+        #[arg(long, default_value = "dot")]
+        format: String,
+    },
+
+    /// Flag active beliefs on the same topic that take opposing stances
+    Contradictions {
+        /// Minimum embedding similarity for two beliefs to count as the same topic
+        #[arg(long, default_value = "0.8")]
+        min_similarity: f32,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
+/// Days since last revision before a belief is flagged `stale`
+const DEFAULT_STALE_DAYS: i64 = 365;
+
 pub fn execute(command: Option<BeliefCommands>) -> Result<()> {
     let cmd = command.unwrap_or(BeliefCommands::Audit {
         sort: "use".to_string(),
         warnings_only: false,
         grounding: false,
+        json: false,
+        stale_days: DEFAULT_STALE_DAYS,
+        min_grounding_score: None,
+        grounding_limit: None,
+        explain_grounding: None,
     });
 
     match cmd {
@@ -44,10 +120,49 @@ pub fn execute(command: Option<BeliefCommands>) -> Result<()> {
             sort,
             warnings_only,
             grounding,
-        } => run_audit(&sort, warnings_only, grounding),
+            json,
+            stale_days,
+            min_grounding_score,
+            grounding_limit,
+            explain_grounding,
+        } => {
+            let regrounding =
+                (min_grounding_score.is_some() || grounding_limit.is_some()).then(|| {
+                    let defaults = GroundingParams::from_config(Path::new("."));
+                    GroundingParams {
+                        min_score: min_grounding_score.unwrap_or(defaults.min_score),
+                        limit: grounding_limit.unwrap_or(defaults.limit),
+                    }
+                });
+            if let Some(belief_id) = explain_grounding {
+                return run_explain_grounding(&belief_id, regrounding);
+            }
+            run_audit(
+                &sort,
+                warnings_only,
+                grounding,
+                json,
+                stale_days,
+                regrounding,
+            )
+        }
+        BeliefCommands::Ground { min_score, limit } => {
+            let defaults = GroundingParams::from_config(Path::new("."));
+            run_ground(GroundingParams {
+                min_score: min_score.unwrap_or(defaults.min_score),
+                limit: limit.unwrap_or(defaults.limit),
+            })
+        }
+        BeliefCommands::Diff => run_diff(),
+        BeliefCommands::Graph { format } => run_graph(&format),
+        BeliefCommands::Contradictions {
+            min_similarity,
+            json,
+        } => run_contradictions(min_similarity, json),
     }
 }
 
+#[derive(Serialize)]
 struct BeliefRow {
     id: String,
     entrenchment: String,
@@ -66,9 +181,28 @@ struct BeliefRow {
     grounding_code_count: i32,
     grounding_commit_count: i32,
     grounding_session_count: i32,
+    // Age: days since `revised` (or `extracted` if never revised)
+    revised: Option<String>,
+    extracted: Option<String>,
+    days_since_revision: Option<i64>,
+    stale: bool,
 }
 
 impl BeliefRow {
+    /// Compute age from revised/extracted dates and flag beliefs older than `stale_days`
+    fn apply_staleness(&mut self, today: chrono::NaiveDate, stale_days: i64) {
+        self.days_since_revision = self
+            .revised
+            .as_deref()
+            .or(self.extracted.as_deref())
+            .and_then(parse_belief_date)
+            .map(|date| (today - date).num_days());
+        self.stale = self
+            .days_since_revision
+            .map(|days| days > stale_days)
+            .unwrap_or(false);
+    }
+
     fn total_use(&self) -> i32 {
         self.cited_by_beliefs + self.cited_by_sessions
     }
@@ -121,11 +255,210 @@ impl BeliefRow {
         if self.grounding_total() == 0 && self.grounding_score == 0.0 {
             warnings.push("floating");
         }
+        if self.stale {
+            warnings.push("stale");
+        }
         warnings
     }
 }
 
-fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result<()> {
+/// Parse a frontmatter date (`2026-01-22`, optionally followed by a time)
+fn parse_belief_date(value: &str) -> Option<chrono::NaiveDate> {
+    let date = value.trim().get(..10)?;
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Aggregate counts across all beliefs (the audit's summary block)
+#[derive(Serialize)]
+struct AuditSummary {
+    total_beliefs: usize,
+    total_citations: i32,
+    cited_by_beliefs: i32,
+    cited_by_sessions: i32,
+    evidence_total: i32,
+    evidence_verified: i32,
+    with_warnings: usize,
+    no_evidence: usize,
+    unverified: usize,
+    unused: usize,
+    stale: usize,
+    beliefs_with_queries: usize,
+    verification_total: i32,
+    verification_passed: i32,
+    verification_failed: i32,
+    verification_errored: i32,
+    grounded: usize,
+    floating: usize,
+}
+
+impl AuditSummary {
+    fn from_rows(rows: &[BeliefRow]) -> Self {
+        let grounded = rows.iter().filter(|r| r.grounding_total() > 0).count();
+        Self {
+            total_beliefs: rows.len(),
+            total_citations: rows.iter().map(|r| r.total_use()).sum(),
+            cited_by_beliefs: rows.iter().map(|r| r.cited_by_beliefs).sum(),
+            cited_by_sessions: rows.iter().map(|r| r.cited_by_sessions).sum(),
+            evidence_total: rows.iter().map(|r| r.evidence_count).sum(),
+            evidence_verified: rows.iter().map(|r| r.evidence_verified).sum(),
+            with_warnings: rows
+                .iter()
+                .filter(|r| !r.health_warnings().is_empty())
+                .count(),
+            no_evidence: rows.iter().filter(|r| r.evidence_count == 0).count(),
+            unverified: rows
+                .iter()
+                .filter(|r| r.evidence_verified == 0 && r.evidence_count > 0)
+                .count(),
+            unused: rows.iter().filter(|r| r.total_use() == 0).count(),
+            stale: rows.iter().filter(|r| r.stale).count(),
+            beliefs_with_queries: rows.iter().filter(|r| r.verification_total > 0).count(),
+            verification_total: rows.iter().map(|r| r.verification_total).sum(),
+            verification_passed: rows.iter().map(|r| r.verification_passed).sum(),
+            verification_failed: rows.iter().map(|r| r.verification_failed).sum(),
+            verification_errored: rows.iter().map(|r| r.verification_errored).sum(),
+            grounded,
+            floating: rows.len() - grounded,
+        }
+    }
+}
+
+/// Build the `belief audit --json` document: per-belief rows (with computed
+/// fields the table derives on the fly) plus the summary block
+fn audit_json(rows: &[&BeliefRow], summary: &AuditSummary, sort_by: &str) -> serde_json::Value {
+    let beliefs: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let mut value = serde_json::to_value(row).unwrap_or_default();
+            if let Some(obj) = value.as_object_mut() {
+                obj.insert("total_use".into(), row.total_use().into());
+                obj.insert("grounding_total".into(), row.grounding_total().into());
+                obj.insert(
+                    "health_warnings".into(),
+                    serde_json::json!(row.health_warnings()),
+                );
+            }
+            value
+        })
+        .collect();
+
+    serde_json::json!({
+        "sort": sort_by,
+        "beliefs": beliefs,
+        "summary": summary,
+    })
+}
+
+/// Rewrite grounding columns and code reach from the current semantic index
+fn run_ground(params: GroundingParams) -> Result<()> {
+    let db_path = Path::new(database::PATINA_DB);
+    if !db_path.exists() {
+        anyhow::bail!("No database found. Run `patina scrape` first.");
+    }
+
+    let conn = Connection::open(db_path)?;
+    let index_path = semantic_index_path();
+    let Some(grounded) = ground_with_index(&conn, &index_path, params)? else {
+        anyhow::bail!("No semantic index found. Run `patina oxidize` first.");
+    };
+
+    println!(
+        "✓ Grounding refreshed from {} ({} beliefs grounded)",
+        index_path.display(),
+        grounded
+    );
+    Ok(())
+}
+
+/// Print why one belief reaches the files it does (`audit --explain-grounding`)
+fn run_explain_grounding(belief_id: &str, regrounding: Option<GroundingParams>) -> Result<()> {
+    let db_path = Path::new(database::PATINA_DB);
+    if !db_path.exists() {
+        anyhow::bail!("No database found. Run `patina scrape` first.");
+    }
+    let conn = Connection::open(db_path)?;
+
+    let index_path = semantic_index_path();
+    if !index_path.exists() {
+        anyhow::bail!("No semantic index found. Run `patina oxidize` first.");
+    }
+    // Explicit thresholds re-ground first, so the stored reach matches the trace
+    let params = match regrounding {
+        Some(params) => {
+            ground_with_index(&conn, &index_path, params)?;
+            params
+        }
+        None => GroundingParams::from_config(Path::new(".")),
+    };
+
+    let index =
+        Index::new(&index_meta::load_options(&index_path)).context("Failed to create index")?;
+    index
+        .load(&index_path.to_string_lossy())
+        .context("Failed to load semantic index")?;
+
+    let trace = explain_grounding(&conn, &index, belief_id, params)?;
+    print!("{}", render_grounding_trace(&trace));
+    Ok(())
+}
+
+fn render_grounding_trace(trace: &GroundingTrace) -> String {
+    let mut out = format!(
+        "\n  Grounding trace — {} (min score {:.2}, {} neighbors)\n\n",
+        trace.belief_id, trace.params.min_score, trace.params.limit
+    );
+
+    out.push_str(&format!("  Neighbors ({} kept):\n", trace.kept().count()));
+    if trace.neighbors.is_empty() {
+        out.push_str("    (none)\n");
+    }
+    for n in &trace.neighbors {
+        let status = if n.score >= trace.params.min_score {
+            "kept".to_string()
+        } else {
+            format!("below {:.2}", trace.params.min_score)
+        };
+        out.push_str(&format!(
+            "    {:.3}  {:<9} {:<50} {}\n",
+            n.score,
+            n.kind.as_str(),
+            truncate(&n.label, 50),
+            status
+        ));
+    }
+
+    let filtered = trace.filtered().count();
+    if filtered > 0 {
+        out.push_str(&format!(
+            "\n  {} borderline neighbor(s) filtered by min score {:.2} (--min-grounding-score)\n",
+            filtered, trace.params.min_score
+        ));
+    }
+
+    out.push_str("\n  Reach (belief_code_reach):\n");
+    if trace.reach.is_empty() {
+        out.push_str("    (none — no kept commit or session neighbor touches source code)\n");
+    }
+    for (file, score, hops) in &trace.reach {
+        out.push_str(&format!(
+            "    {:.3}  {:<50} via {}\n",
+            score,
+            truncate(file, 50),
+            hops
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+fn run_audit(
+    sort_by: &str,
+    warnings_only: bool,
+    show_grounding: bool,
+    json: bool,
+    stale_days: i64,
+    regrounding: Option<GroundingParams>,
+) -> Result<()> {
     let db_path = Path::new(database::PATINA_DB);
     if !db_path.exists() {
         anyhow::bail!("No database found. Run `patina scrape` first.");
@@ -133,6 +466,13 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
 
     let conn = Connection::open(db_path)?;
 
+    // Stored counts are rewritten, so later audits see the same threshold
+    if let Some(params) = regrounding {
+        if !compute_belief_grounding(&conn, params)? {
+            anyhow::bail!("No semantic index found. Run `patina oxidize` first.");
+        }
+    }
+
     // Check if metric columns exist
     let has_metrics = conn
         .prepare("SELECT cited_by_beliefs FROM beliefs LIMIT 1")
@@ -163,7 +503,7 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
 
     let sql = format!(
         "SELECT id, entrenchment, cited_by_beliefs, cited_by_sessions, applied_in,
-                evidence_count, evidence_verified, defeated_attacks{}{}
+                evidence_count, evidence_verified, defeated_attacks, revised, extracted{}{}
          FROM beliefs
          ORDER BY {}",
         if has_verification {
@@ -180,9 +520,9 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
     );
 
     let mut stmt = conn.prepare(&sql)?;
-    let rows: Vec<BeliefRow> = stmt
+    let mut rows: Vec<BeliefRow> = stmt
         .query_map([], |row| {
-            let base_idx = 8; // 0-7 are always present
+            let base_idx = 10; // 0-9 are always present
             let v_offset = base_idx;
             let g_offset = if has_verification {
                 v_offset + 4
@@ -239,12 +579,21 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
                 } else {
                     0
                 },
+                revised: row.get(8)?,
+                extracted: row.get(9)?,
+                days_since_revision: None,
+                stale: false,
             })
         })?
         .filter_map(|r| r.ok())
         .collect();
 
-    if rows.is_empty() {
+    let today = chrono::Local::now().date_naive();
+    for row in &mut rows {
+        row.apply_staleness(today, stale_days);
+    }
+
+    if rows.is_empty() && !json {
         println!("No beliefs found. Create beliefs in layer/surface/epistemic/beliefs/");
         return Ok(());
     }
@@ -258,6 +607,16 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
         rows.iter().collect()
     };
 
+    let summary = AuditSummary::from_rows(&rows);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&audit_json(&display_rows, &summary, sort_by))?
+        );
+        return Ok(());
+    }
+
     // Print header
     println!(
         "\n  Belief Audit — {} beliefs (sorted by {})\n",
@@ -273,12 +632,8 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
         "──────", "─────", "─────", "────", "────", "────", "────", "─────", "─────────", "───────"
     );
 
-    let mut warning_count = 0;
     for row in &display_rows {
         let warnings = row.health_warnings();
-        if !warnings.is_empty() {
-            warning_count += 1;
-        }
         let warning_str = if warnings.is_empty() {
             String::new()
         } else {
@@ -286,11 +641,7 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
         };
 
         // Truncate ID for display
-        let display_id = if row.id.len() > 35 {
-            format!("{}…", &row.id[..34])
-        } else {
-            row.id.clone()
-        };
+        let display_id = truncate(&row.id, 35);
 
         println!(
             "  {:<36} {:>5} {:>5} {:>4} {:>4} {:>4} {:>4} {:>5} {:>9} {:>7} {}",
@@ -308,77 +659,75 @@ fn run_audit(sort_by: &str, warnings_only: bool, show_grounding: bool) -> Result
         );
     }
 
-    // Summary
-    let total_use: i32 = rows.iter().map(|r| r.total_use()).sum();
-    let total_evidence: i32 = rows.iter().map(|r| r.evidence_count).sum();
-    let total_verified: i32 = rows.iter().map(|r| r.evidence_verified).sum();
-    let with_no_evidence: usize = rows.iter().filter(|r| r.evidence_count == 0).count();
-    let with_unverified: usize = rows
-        .iter()
-        .filter(|r| r.evidence_verified == 0 && r.evidence_count > 0)
-        .count();
-    let unused: usize = rows.iter().filter(|r| r.total_use() == 0).count();
-
-    // Verification stats
-    let beliefs_with_queries: usize = rows.iter().filter(|r| r.verification_total > 0).count();
-    let total_queries: i32 = rows.iter().map(|r| r.verification_total).sum();
-    let total_passed: i32 = rows.iter().map(|r| r.verification_passed).sum();
-    let total_failed: i32 = rows.iter().map(|r| r.verification_failed).sum();
-    let total_errored: i32 = rows.iter().map(|r| r.verification_errored).sum();
-
-    // Grounding stats
-    let grounded: usize = rows.iter().filter(|r| r.grounding_total() > 0).count();
-    let floating: usize = rows.len() - grounded;
-
     println!("\n  ── Summary ──");
-    println!("  Total beliefs: {}", rows.len());
+    println!("  Total beliefs: {}", summary.total_beliefs);
     println!(
         "  Total citations: {} ({} by beliefs, {} by sessions)",
-        total_use,
-        rows.iter().map(|r| r.cited_by_beliefs).sum::<i32>(),
-        rows.iter().map(|r| r.cited_by_sessions).sum::<i32>()
+        summary.total_citations, summary.cited_by_beliefs, summary.cited_by_sessions
     );
     println!(
         "  Evidence: {} total, {} verified ({:.0}%)",
-        total_evidence,
-        total_verified,
-        if total_evidence > 0 {
-            total_verified as f64 / total_evidence as f64 * 100.0
+        summary.evidence_total,
+        summary.evidence_verified,
+        if summary.evidence_total > 0 {
+            summary.evidence_verified as f64 / summary.evidence_total as f64 * 100.0
         } else {
             0.0
         }
     );
-    if total_queries > 0 {
+    if summary.verification_total > 0 {
         println!(
             "  Verification: {} queries across {} beliefs ({} passed, {} contested, {} errors)",
-            total_queries, beliefs_with_queries, total_passed, total_failed, total_errored
+            summary.verification_total,
+            summary.beliefs_with_queries,
+            summary.verification_passed,
+            summary.verification_failed,
+            summary.verification_errored
         );
     }
-    if grounded > 0 || floating > 0 {
-        println!("  Grounding: {} grounded, {} floating", grounded, floating);
+    if summary.grounded > 0 || summary.floating > 0 {
+        println!(
+            "  Grounding: {} grounded, {} floating",
+            summary.grounded, summary.floating
+        );
     }
-    if warning_count > 0 {
-        println!("\n  Warnings: {}", warning_count);
-        if with_no_evidence > 0 {
-            println!("    {} beliefs with no evidence", with_no_evidence);
+    if summary.with_warnings > 0 {
+        println!("\n  Warnings: {}", summary.with_warnings);
+        if summary.no_evidence > 0 {
+            println!("    {} beliefs with no evidence", summary.no_evidence);
         }
-        if with_unverified > 0 {
-            println!("    {} beliefs with unverified evidence", with_unverified);
+        if summary.unverified > 0 {
+            println!(
+                "    {} beliefs with unverified evidence",
+                summary.unverified
+            );
+        }
+        if summary.unused > 0 {
+            println!("    {} beliefs with no citations", summary.unused);
         }
-        if unused > 0 {
-            println!("    {} beliefs with no citations", unused);
+        if summary.stale > 0 {
+            println!(
+                "    {} beliefs stale (not revised in {}+ days)",
+                summary.stale, stale_days
+            );
         }
-        if floating > 0 {
+        if summary.floating > 0 {
             println!(
                 "    {} beliefs floating (no code/commit/session grounding)",
-                floating
+                summary.floating
             );
         }
-        if total_failed > 0 {
-            println!("    {} beliefs with contested verification", total_failed);
+        if summary.verification_failed > 0 {
+            println!(
+                "    {} beliefs with contested verification",
+                summary.verification_failed
+            );
         }
-        if total_errored > 0 {
-            println!("    {} beliefs with verification errors", total_errored);
+        if summary.verification_errored > 0 {
+            println!(
+                "    {} beliefs with verification errors",
+                summary.verification_errored
+            );
         }
     }
     println!();
@@ -410,22 +759,13 @@ fn run_grounding_report(conn: &Connection, rows: &[BeliefRow]) -> Result<()> {
     }
 
     // Load usearch index
-    let index_options = IndexOptions {
-        dimensions: 256,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
+    let index_options = index_meta::load_options(Path::new(&index_path));
 
     let index = Index::new(&index_options).context("Failed to create index")?;
     index
         .load(&index_path)
         .context("Failed to load semantic index")?;
 
-    const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
-    const CODE_ID_OFFSET: i64 = 1_000_000_000;
-    const PATTERN_ID_OFFSET: i64 = 2_000_000_000;
-    const COMMIT_ID_OFFSET: i64 = 3_000_000_000;
     const GROUNDING_LIMIT: usize = 20; // Search this many neighbors
     const DISPLAY_LIMIT: usize = 3; // Show top 3 per type
 
@@ -446,11 +786,11 @@ fn run_grounding_report(conn: &Connection, rows: &[BeliefRow]) -> Result<()> {
             Err(_) => continue,
         };
 
-        let belief_key = (BELIEF_ID_OFFSET + rowid) as u64;
+        let key = belief_key(rowid) as u64;
 
         // Get belief's vector
-        let mut vector = vec![0.0_f32; 256];
-        if index.get(belief_key, &mut vector).is_err() {
+        let mut vector = vec![0.0_f32; index.dimensions()];
+        if index.get(key, &mut vector).is_err() {
             continue;
         }
 
@@ -488,13 +828,11 @@ fn run_grounding_report(conn: &Connection, rows: &[BeliefRow]) -> Result<()> {
                 continue; // Skip self
             }
 
-            let key = r.id;
-            if (CODE_ID_OFFSET..PATTERN_ID_OFFSET).contains(&key) {
-                code_results.push(r);
-            } else if (COMMIT_ID_OFFSET..BELIEF_ID_OFFSET).contains(&key) {
-                commit_results.push(r);
-            } else if key < CODE_ID_OFFSET {
-                session_results.push(r);
+            match classify_key(r.id) {
+                KeyKind::Code => code_results.push(r),
+                KeyKind::Commit => commit_results.push(r),
+                KeyKind::Session => session_results.push(r),
+                _ => {}
             }
         }
 
@@ -508,11 +846,7 @@ fn run_grounding_report(conn: &Connection, rows: &[BeliefRow]) -> Result<()> {
         }
 
         // Display
-        let display_id = if row.id.len() > 35 {
-            format!("{}…", &row.id[..34])
-        } else {
-            row.id.clone()
-        };
+        let display_id = truncate(&row.id, 35);
 
         println!(
             "  {} ({}c {}m {}s)",
@@ -555,3 +889,702 @@ fn truncate(s: &str, max: usize) -> String {
     let truncated: String = s.chars().take(max - 1).collect();
     format!("{}…", truncated)
 }
+
+/// Metrics captured per belief in `belief_snapshots`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct SnapshotMetrics {
+    citations: i32,
+    evidence_verified: i32,
+    grounding_total: i32,
+    verification_passed: i32,
+}
+
+/// A belief present in both snapshots whose metrics changed
+#[derive(Debug)]
+struct BeliefDelta {
+    id: String,
+    before: SnapshotMetrics,
+    after: SnapshotMetrics,
+}
+
+impl BeliefDelta {
+    fn became_floating(&self) -> bool {
+        self.before.grounding_total > 0 && self.after.grounding_total == 0
+    }
+}
+
+#[derive(Debug, Default)]
+struct BeliefDiff {
+    changed: Vec<BeliefDelta>,
+    added: Vec<String>,
+    removed: Vec<String>,
+}
+
+fn load_snapshot(
+    conn: &Connection,
+    snapshot_at: &str,
+) -> Result<BTreeMap<String, SnapshotMetrics>> {
+    let mut stmt = conn.prepare(
+        "SELECT belief_id, citations, evidence_verified, grounding_total, verification_passed
+         FROM belief_snapshots WHERE snapshot_at = ?1",
+    )?;
+    let rows = stmt
+        .query_map([snapshot_at], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                SnapshotMetrics {
+                    citations: row.get(1)?,
+                    evidence_verified: row.get(2)?,
+                    grounding_total: row.get(3)?,
+                    verification_passed: row.get(4)?,
+                },
+            ))
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(rows)
+}
+
+fn diff_snapshots(
+    previous: &BTreeMap<String, SnapshotMetrics>,
+    current: &BTreeMap<String, SnapshotMetrics>,
+) -> BeliefDiff {
+    let ids: BTreeSet<&String> = previous.keys().chain(current.keys()).collect();
+    let mut diff = BeliefDiff::default();
+
+    for id in ids {
+        match (previous.get(id), current.get(id)) {
+            (Some(before), Some(after)) if before != after => diff.changed.push(BeliefDelta {
+                id: id.clone(),
+                before: *before,
+                after: *after,
+            }),
+            (None, Some(_)) => diff.added.push(id.clone()),
+            (Some(_), None) => diff.removed.push(id.clone()),
+            _ => {}
+        }
+    }
+
+    diff
+}
+
+fn format_delta(before: i32, after: i32) -> String {
+    match after - before {
+        0 => "\u{00b7}".to_string(), // middle dot
+        d => format!("{:+}", d),
+    }
+}
+
+/// Report per-belief metric deltas between the two most recent scrape snapshots
+fn run_diff() -> Result<()> {
+    let db_path = Path::new(database::PATINA_DB);
+    if !db_path.exists() {
+        anyhow::bail!("No database found. Run `patina scrape` first.");
+    }
+
+    let conn = Connection::open(db_path)?;
+
+    let snapshots: Vec<String> = match conn.prepare(
+        "SELECT DISTINCT snapshot_at FROM belief_snapshots ORDER BY snapshot_at DESC LIMIT 2",
+    ) {
+        Ok(mut stmt) => stmt
+            .query_map([], |row| row.get(0))?
+            .filter_map(|r| r.ok())
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+
+    if snapshots.len() < 2 {
+        println!("Need at least two belief snapshots to diff. Run `patina scrape` again.");
+        return Ok(());
+    }
+
+    let current = load_snapshot(&conn, &snapshots[0])?;
+    let previous = load_snapshot(&conn, &snapshots[1])?;
+    let diff = diff_snapshots(&previous, &current);
+
+    println!("\n  Belief Diff — {} → {}\n", snapshots[1], snapshots[0]);
+
+    if diff.changed.is_empty() && diff.added.is_empty() && diff.removed.is_empty() {
+        println!("  No belief metrics changed.\n");
+        return Ok(());
+    }
+
+    if !diff.changed.is_empty() {
+        println!(
+            "  {:<36} {:>5} {:>6} {:>6} {:>6}",
+            "BELIEF", "CITE", "EVID-V", "GROUND", "V-PASS"
+        );
+        for delta in &diff.changed {
+            let display_id = truncate(&delta.id, 35);
+            println!(
+                "  {:<36} {:>5} {:>6} {:>6} {:>6}{}",
+                display_id,
+                format_delta(delta.before.citations, delta.after.citations),
+                format_delta(
+                    delta.before.evidence_verified,
+                    delta.after.evidence_verified
+                ),
+                format_delta(delta.before.grounding_total, delta.after.grounding_total),
+                format_delta(
+                    delta.before.verification_passed,
+                    delta.after.verification_passed
+                ),
+                if delta.became_floating() {
+                    "  (now floating)"
+                } else {
+                    ""
+                },
+            );
+        }
+        println!();
+    }
+
+    if !diff.added.is_empty() {
+        println!("  Added ({}):", diff.added.len());
+        for id in &diff.added {
+            println!("    + {}", id);
+        }
+        println!();
+    }
+
+    if !diff.removed.is_empty() {
+        println!("  Removed ({}):", diff.removed.len());
+        for id in &diff.removed {
+            println!("    - {}", id);
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Export the belief graph parsed from belief files on disk
+fn run_graph(format: &str) -> Result<()> {
+    let beliefs_dir = Path::new(BELIEFS_DIR);
+    if !beliefs_dir.exists() {
+        anyhow::bail!("No beliefs directory found ({})", BELIEFS_DIR);
+    }
+
+    let graph = build_belief_graph(beliefs_dir)?;
+
+    match format {
+        "dot" => print!("{}", render_graph_dot(&graph)),
+        "json" => println!(
+            "{}",
+            serde_json::to_string_pretty(&render_graph_json(&graph))?
+        ),
+        other => anyhow::bail!("Unknown graph format '{}'. Use 'dot' or 'json'.", other),
+    }
+
+    Ok(())
+}
+
+/// Edge targets that have no belief file (e.g. defeated hypotheticals like `[[async-by-default]]`)
+fn unknown_nodes(graph: &BeliefGraph) -> Vec<String> {
+    let mut unknown: Vec<String> = graph
+        .edges
+        .iter()
+        .flat_map(|e| [&e.from, &e.to])
+        .filter(|id| !graph.beliefs.contains(id))
+        .cloned()
+        .collect();
+    unknown.sort();
+    unknown.dedup();
+    unknown
+}
+
+fn render_graph_json(graph: &BeliefGraph) -> serde_json::Value {
+    let mut nodes: Vec<serde_json::Value> = graph
+        .beliefs
+        .iter()
+        .map(|id| serde_json::json!({ "id": id, "known": true }))
+        .collect();
+    nodes.extend(
+        unknown_nodes(graph)
+            .iter()
+            .map(|id| serde_json::json!({ "id": id, "known": false })),
+    );
+
+    serde_json::json!({
+        "nodes": nodes,
+        "edges": graph.edges,
+    })
+}
+
+fn render_graph_dot(graph: &BeliefGraph) -> String {
+    let mut out = String::from("digraph beliefs {\n    rankdir=LR;\n    node [shape=box];\n\n");
+
+    for id in &graph.beliefs {
+        out.push_str(&format!("    {};\n", dot_id(id)));
+    }
+    // Referenced but not on disk — drawn dashed so they read as outside the layer
+    for id in unknown_nodes(graph) {
+        out.push_str(&format!("    {} [style=dashed];\n", dot_id(&id)));
+    }
+    out.push('\n');
+
+    for edge in &graph.edges {
+        let style = match (edge.relation, edge.defeated) {
+            ("attacks", true) => "color=gray, style=dashed, label=\"attacks (defeated)\"",
+            ("attacks", false) => "color=red, label=\"attacks\"",
+            ("supports", _) => "color=darkgreen, label=\"supports\"",
+            _ => "color=blue, style=dotted, label=\"evidence\"",
+        };
+        out.push_str(&format!(
+            "    {} -> {} [{}];\n",
+            dot_id(&edge.from),
+            dot_id(&edge.to),
+            style
+        ));
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+/// Quote a node id for DOT, escaping `\` and `"` so any id stays one token
+fn dot_id(id: &str) -> String {
+    format!("\"{}\"", id.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// An active belief's statement with its semantic-index vector
+struct BeliefVector {
+    id: String,
+    statement: String,
+    vector: Vec<f32>,
+}
+
+/// Two beliefs on the same topic whose stances look opposed
+#[derive(Debug, Serialize)]
+struct Contradiction {
+    a: String,
+    b: String,
+    similarity: f32,
+    statement_a: String,
+    statement_b: String,
+}
+
+/// Words that flip a statement's stance
+const NEGATIONS: &[&str] = &[
+    "not",
+    "no",
+    "never",
+    "nor",
+    "without",
+    "avoid",
+    "avoids",
+    "cannot",
+    "can't",
+    "don't",
+    "dont",
+    "doesn't",
+    "shouldn't",
+    "mustn't",
+    "won't",
+    "isn't",
+    "aren't",
+    "forbid",
+    "disallow",
+];
+
+/// Opposite ends of the same axis; one on each side flips the stance
+const ANTONYMS: &[(&str, &str)] = &[
+    ("sync", "async"),
+    ("synchronous", "asynchronous"),
+    ("mutable", "immutable"),
+    ("explicit", "implicit"),
+    ("static", "dynamic"),
+    ("public", "private"),
+    ("eager", "lazy"),
+    ("enable", "disable"),
+    ("include", "exclude"),
+    ("allow", "deny"),
+    ("more", "fewer"),
+];
+
+fn stance_words(statement: &str) -> BTreeSet<String> {
+    statement
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|w| !w.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Whether two statements take opposite stances: an odd number of flips,
+/// counting a negation mismatch and each antonym pair split between them
+///
+/// "Use sync code" vs "Avoid async code" negates and swaps, so it agrees.
+fn stances_oppose(a: &str, b: &str) -> bool {
+    let (a, b) = (stance_words(a), stance_words(b));
+    let negations = |words: &BTreeSet<String>| {
+        words
+            .iter()
+            .filter(|w| NEGATIONS.contains(&w.as_str()))
+            .count()
+    };
+    let mut flips = usize::from(negations(&a) % 2 != negations(&b) % 2);
+    for (x, y) in ANTONYMS {
+        let has = |words: &BTreeSet<String>, w: &str| words.contains(w);
+        let split = (has(&a, x) && !has(&a, y) && has(&b, y) && !has(&b, x))
+            || (has(&a, y) && !has(&a, x) && has(&b, x) && !has(&b, y));
+        flips += usize::from(split);
+    }
+    flips % 2 == 1
+}
+
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom < f32::EPSILON {
+        0.0
+    } else {
+        dot / denom
+    }
+}
+
+/// Pairs at or above `min_similarity` whose stances oppose, most similar first
+fn find_contradictions(beliefs: &[BeliefVector], min_similarity: f32) -> Vec<Contradiction> {
+    let mut found = Vec::new();
+    for (i, a) in beliefs.iter().enumerate() {
+        for b in &beliefs[i + 1..] {
+            let similarity = cosine(&a.vector, &b.vector);
+            if similarity >= min_similarity && stances_oppose(&a.statement, &b.statement) {
+                found.push(Contradiction {
+                    a: a.id.clone(),
+                    b: b.id.clone(),
+                    similarity,
+                    statement_a: a.statement.clone(),
+                    statement_b: b.statement.clone(),
+                });
+            }
+        }
+    }
+    found.sort_by(|x, y| y.similarity.total_cmp(&x.similarity));
+    found
+}
+
+/// Active beliefs that have a vector in the semantic index
+fn load_belief_vectors(conn: &Connection, index: &Index) -> Result<Vec<BeliefVector>> {
+    let mut stmt = conn.prepare(
+        "SELECT rowid, id, COALESCE(statement, '') FROM beliefs WHERE status = 'active' ORDER BY id",
+    )?;
+    let rows: Vec<(i64, String, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let mut beliefs = Vec::new();
+    for (rowid, id, statement) in rows {
+        let mut vector = vec![0.0_f32; index.dimensions()];
+        if index.get(belief_key(rowid) as u64, &mut vector).is_err()
+            || vector.iter().all(|v| v.abs() < f32::EPSILON)
+        {
+            continue;
+        }
+        beliefs.push(BeliefVector {
+            id,
+            statement,
+            vector,
+        });
+    }
+    Ok(beliefs)
+}
+
+/// List candidate contradictions among active beliefs
+fn run_contradictions(min_similarity: f32, json: bool) -> Result<()> {
+    let db_path = Path::new(database::PATINA_DB);
+    if !db_path.exists() {
+        anyhow::bail!("No database found. Run `patina scrape` first.");
+    }
+    let conn = Connection::open(db_path)?;
+
+    let model = crate::commands::scry::internal::search::get_embedding_model();
+    let index_path = format!(
+        ".patina/local/data/embeddings/{}/projections/semantic.usearch",
+        model
+    );
+    if !Path::new(&index_path).exists() {
+        anyhow::bail!("Semantic index not found. Run `patina oxidize` first.");
+    }
+    let index = Index::new(&index_meta::load_options(Path::new(&index_path)))
+        .context("Failed to create index")?;
+    index
+        .load(&index_path)
+        .context("Failed to load semantic index")?;
+
+    let beliefs = load_belief_vectors(&conn, &index)?;
+    let contradictions = find_contradictions(&beliefs, min_similarity);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&contradictions)?);
+        return Ok(());
+    }
+
+    println!(
+        "Candidate contradictions ({} active beliefs embedded, similarity >= {:.2})\n",
+        beliefs.len(),
+        min_similarity
+    );
+    if contradictions.is_empty() {
+        println!("  None found.");
+        return Ok(());
+    }
+    for c in &contradictions {
+        println!("  {:.3}  {}  \u{27f7}  {}", c.similarity, c.a, c.b);
+        println!("         {}", truncate(&c.statement_a, 70));
+        println!("         {}\n", truncate(&c.statement_b, 70));
+    }
+    println!(
+        "{} candidate(s) for review. Record resolutions under ## Attacks.",
+        contradictions.len()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(id: &str) -> BeliefRow {
+        BeliefRow {
+            id: id.to_string(),
+            entrenchment: "medium".to_string(),
+            cited_by_beliefs: 0,
+            cited_by_sessions: 0,
+            applied_in: 0,
+            evidence_count: 0,
+            evidence_verified: 0,
+            defeated_attacks: 0,
+            verification_total: 0,
+            verification_passed: 0,
+            verification_failed: 0,
+            verification_errored: 0,
+            grounding_score: 0.0,
+            grounding_code_count: 0,
+            grounding_commit_count: 0,
+            grounding_session_count: 0,
+            revised: None,
+            extracted: None,
+            days_since_revision: None,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_stale_warning_uses_revised_date() {
+        let today = chrono::NaiveDate::from_ymd_opt(2026, 10, 14).unwrap();
+
+        let mut old = BeliefRow {
+            entrenchment: "very-high".to_string(),
+            extracted: Some("2023-05-01".to_string()),
+            revised: Some("2024-06-01".to_string()),
+            ..row("old-belief")
+        };
+        old.apply_staleness(today, 365);
+        assert!(old.stale);
+        assert!(old.health_warnings().contains(&"stale"));
+
+        let mut recent = BeliefRow {
+            extracted: Some("2023-05-01".to_string()),
+            revised: Some("2026-09-01".to_string()),
+            ..row("recent-belief")
+        };
+        recent.apply_staleness(today, 365);
+        assert!(!recent.stale);
+        assert!(!recent.health_warnings().contains(&"stale"));
+        assert_eq!(recent.days_since_revision, Some(43));
+
+        // Falls back to extracted when never revised; no dates means never stale
+        let mut unrevised = BeliefRow {
+            extracted: Some("2025-01-01".to_string()),
+            ..row("unrevised")
+        };
+        unrevised.apply_staleness(today, 365);
+        assert!(unrevised.stale);
+
+        let mut undated = row("undated");
+        undated.apply_staleness(today, 365);
+        assert!(!undated.stale);
+
+        let rows = vec![old, recent, unrevised, undated];
+        assert_eq!(AuditSummary::from_rows(&rows).stale, 2);
+    }
+
+    fn metrics(citations: i32, evidence: i32, grounding: i32, passed: i32) -> SnapshotMetrics {
+        SnapshotMetrics {
+            citations,
+            evidence_verified: evidence,
+            grounding_total: grounding,
+            verification_passed: passed,
+        }
+    }
+
+    #[test]
+    fn test_diff_snapshots_reports_deltas_and_membership() {
+        let previous: BTreeMap<String, SnapshotMetrics> = [
+            ("gaining".to_string(), metrics(1, 1, 2, 0)),
+            ("losing".to_string(), metrics(4, 3, 5, 2)),
+            ("steady".to_string(), metrics(2, 2, 2, 2)),
+            ("retired".to_string(), metrics(0, 0, 0, 0)),
+        ]
+        .into_iter()
+        .collect();
+        let current: BTreeMap<String, SnapshotMetrics> = [
+            ("gaining".to_string(), metrics(3, 2, 4, 1)),
+            ("losing".to_string(), metrics(2, 1, 0, 1)),
+            ("steady".to_string(), metrics(2, 2, 2, 2)),
+            ("fresh".to_string(), metrics(0, 1, 0, 0)),
+        ]
+        .into_iter()
+        .collect();
+
+        let diff = diff_snapshots(&previous, &current);
+
+        assert_eq!(diff.added, vec!["fresh"]);
+        assert_eq!(diff.removed, vec!["retired"]);
+        assert_eq!(diff.changed.len(), 2);
+
+        let gaining = diff.changed.iter().find(|d| d.id == "gaining").unwrap();
+        assert_eq!(gaining.after.citations - gaining.before.citations, 2);
+        assert_eq!(
+            gaining.after.evidence_verified - gaining.before.evidence_verified,
+            1
+        );
+        assert!(!gaining.became_floating());
+
+        let losing = diff.changed.iter().find(|d| d.id == "losing").unwrap();
+        assert_eq!(losing.after.citations - losing.before.citations, -2);
+        assert_eq!(
+            losing.after.verification_passed - losing.before.verification_passed,
+            -1
+        );
+        assert!(losing.became_floating());
+
+        assert_eq!(format_delta(1, 3), "+2");
+        assert_eq!(format_delta(4, 2), "-2");
+        assert_eq!(format_delta(2, 2), "\u{00b7}");
+    }
+
+    #[test]
+    fn test_audit_json_includes_warnings_and_summary() {
+        let healthy = BeliefRow {
+            cited_by_beliefs: 2,
+            cited_by_sessions: 3,
+            applied_in: 1,
+            evidence_count: 2,
+            evidence_verified: 2,
+            verification_total: 2,
+            verification_passed: 1,
+            verification_failed: 1,
+            grounding_score: 0.9,
+            grounding_code_count: 1,
+            grounding_commit_count: 2,
+            ..row("healthy")
+        };
+        let floating = row("floating");
+        let rows = vec![healthy, floating];
+
+        let summary = AuditSummary::from_rows(&rows);
+        let display: Vec<&BeliefRow> = rows.iter().collect();
+        let value = audit_json(&display, &summary, "use");
+
+        let beliefs = value["beliefs"].as_array().unwrap();
+        assert_eq!(beliefs.len(), 2);
+        assert_eq!(beliefs[0]["id"], "healthy");
+        assert_eq!(beliefs[0]["total_use"], 5);
+        assert_eq!(beliefs[0]["grounding_total"], 3);
+        assert_eq!(
+            beliefs[0]["health_warnings"],
+            serde_json::json!(["verify-contested"])
+        );
+        assert_eq!(
+            beliefs[1]["health_warnings"],
+            serde_json::json!(["no-evidence", "unused", "no-applications", "floating"])
+        );
+
+        let summary = &value["summary"];
+        assert_eq!(summary["total_beliefs"], 2);
+        assert_eq!(summary["grounded"], 1);
+        assert_eq!(summary["floating"], 1);
+        assert_eq!(summary["verification_total"], 2);
+        assert_eq!(summary["verification_passed"], 1);
+        assert_eq!(summary["verification_failed"], 1);
+        assert_eq!(summary["with_warnings"], 2);
+    }
+
+    #[test]
+    fn test_opposite_phrasing_on_same_topic_flagged() {
+        let belief = |id: &str, statement: &str, vector: [f32; 3]| BeliefVector {
+            id: id.to_string(),
+            statement: statement.to_string(),
+            vector: vector.to_vec(),
+        };
+        let beliefs = vec![
+            belief(
+                "sync-cli",
+                "Always use synchronous code in the CLI",
+                [1.0, 0.1, 0.0],
+            ),
+            belief(
+                "no-sync-cli",
+                "Never use synchronous code in the CLI",
+                [0.95, 0.15, 0.0],
+            ),
+            belief(
+                "sync-tools",
+                "Avoid asynchronous code in the CLI",
+                [0.9, 0.2, 0.05],
+            ),
+            belief(
+                "small-commits",
+                "Do not batch unrelated changes",
+                [0.0, 0.0, 1.0],
+            ),
+        ];
+
+        let found = find_contradictions(&beliefs, 0.8);
+        let pairs: Vec<(&str, &str)> = found.iter().map(|c| (c.a.as_str(), c.b.as_str())).collect();
+        assert!(pairs.contains(&("sync-cli", "no-sync-cli")));
+        // Negating the antonym agrees with the original
+        assert!(!pairs.contains(&("sync-cli", "sync-tools")));
+        // Far apart in topic: never paired
+        assert!(pairs
+            .iter()
+            .all(|(a, b)| *a != "small-commits" && *b != "small-commits"));
+    }
+
+    #[test]
+    fn test_display_id_truncates_on_char_boundaries() {
+        // Byte 34 falls inside 'é'
+        let id = format!("{}é-keeps-going-past-the-column", "x".repeat(33));
+        let shown = truncate(&id, 35);
+        assert_eq!(shown.chars().count(), 35);
+        assert_eq!(shown, format!("{}é…", "x".repeat(33)));
+        assert_eq!(truncate("short-id", 35), "short-id");
+    }
+
+    #[test]
+    fn test_graph_dot_escapes_ids() {
+        use super::super::scrape::beliefs::BeliefEdge;
+
+        let graph = BeliefGraph {
+            beliefs: vec![r#"say-"hi""#.to_string(), r"C:\beliefs".to_string()],
+            edges: vec![BeliefEdge {
+                from: r#"say-"hi""#.to_string(),
+                to: r#"missing\"ref"#.to_string(),
+                relation: "supports",
+                defeated: false,
+            }],
+        };
+
+        let dot = render_graph_dot(&graph);
+        assert!(dot.contains(r#"    "say-\"hi\"";"#));
+        assert!(dot.contains(r#"    "C:\\beliefs";"#));
+        assert!(dot.contains(r#"    "missing\\\"ref" [style=dashed];"#));
+        assert!(dot.contains(r#"    "say-\"hi\"" -> "missing\\\"ref" [color=darkgreen"#));
+    }
+}
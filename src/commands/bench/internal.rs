@@ -1,9 +1,17 @@
 //! Internal implementation for bench command
 //!
 //! Metrics: MRR, Recall@K, Latency p50/p95
+//!
+//! `--csv` adds one row per query (hits, precision and recall at the result
+//! limit, reciprocal rank) so runs can be diffed across commits.
+//!
+//! Several query sets (`-` reads one from stdin) run as one suite: the summary
+//! aggregates every query and breaks the metrics down per set.
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::io::Read;
 use std::path::Path;
 use std::time::{Duration, Instant};
 
@@ -55,6 +63,34 @@ pub struct QuerySet {
     pub queries: Vec<BenchQuery>,
 }
 
+impl BenchQuery {
+    /// Per-result relevance against this query's ground truth.
+    ///
+    /// Each expected doc (or keyword) is credited at most once, so several
+    /// chunks of the same file don't count as several hits.
+    pub fn relevance(&self, retrieved: &[String]) -> Vec<bool> {
+        let ground_truth = GroundTruth::from_query(self);
+        let mut credited = HashSet::new();
+        retrieved
+            .iter()
+            .map(|doc_id| {
+                ground_truth
+                    .targets()
+                    .iter()
+                    .enumerate()
+                    .find(|(i, t)| !credited.contains(i) && ground_truth.target_matches(t, doc_id))
+                    .map(|(i, _)| credited.insert(i))
+                    .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Number of expected relevant documents (or keywords, for legacy sets)
+    pub fn relevant_count(&self) -> usize {
+        GroundTruth::from_query(self).expected_count()
+    }
+}
+
 impl QuerySet {
     /// Load query set from JSON file
     pub fn load(path: &Path) -> Result<Self> {
@@ -63,10 +99,23 @@ impl QuerySet {
         serde_json::from_str(&content)
             .with_context(|| format!("Failed to parse query set: {}", path.display()))
     }
+
+    /// Load from a path, or from `stdin` when the path is `-`
+    pub fn load_or_stdin(path: &str, mut stdin: impl Read) -> Result<Self> {
+        if path != "-" {
+            return Self::load(Path::new(path));
+        }
+        let mut content = String::new();
+        stdin
+            .read_to_string(&mut content)
+            .context("Failed to read query set from stdin")?;
+        serde_json::from_str(&content).context("Failed to parse query set from stdin")
+    }
 }
 
 /// Results from a single query
 /// Note: query_id and retrieved_docs removed (unused). Add back for --verbose mode.
+#[derive(Clone)]
 struct QueryResult {
     latency: Duration,
     reciprocal_rank: f64,
@@ -90,6 +139,135 @@ struct BenchmarkResults {
     /// Repo recall for cross-project queries (None if not applicable)
     #[serde(skip_serializing_if = "Option::is_none")]
     repo_recall: Option<f64>,
+    /// Per-set breakdown when several query sets ran together
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    query_sets: Vec<BenchmarkResults>,
+}
+
+impl BenchmarkResults {
+    /// Aggregate metrics over `results` (empty sets score zero)
+    fn summarize(name: &str, results: &[QueryResult]) -> Self {
+        let num_queries = results.len();
+        let mean = |metric: fn(&QueryResult) -> f64| {
+            results.iter().map(metric).sum::<f64>() / num_queries.max(1) as f64
+        };
+
+        // Repo recall only over queries that had expected_repos
+        let repo_recalls: Vec<f64> = results.iter().filter_map(|r| r.repo_recall).collect();
+        let repo_recall = if !repo_recalls.is_empty() {
+            Some(repo_recalls.iter().sum::<f64>() / repo_recalls.len() as f64)
+        } else {
+            None
+        };
+
+        let mut latencies: Vec<Duration> = results.iter().map(|r| r.latency).collect();
+        latencies.sort();
+        let latency_mean = latencies.iter().sum::<Duration>() / num_queries.max(1) as u32;
+
+        Self {
+            query_set: name.to_string(),
+            num_queries,
+            mrr: mean(|r| r.reciprocal_rank),
+            recall_at_5: mean(|r| r.recall_at_5),
+            recall_at_10: mean(|r| r.recall_at_10),
+            latency_p50_ms: percentile(&latencies, 50.0).as_secs_f64() * 1000.0,
+            latency_p95_ms: percentile(&latencies, 95.0).as_secs_f64() * 1000.0,
+            latency_mean_ms: latency_mean.as_secs_f64() * 1000.0,
+            repo_recall,
+            query_sets: Vec::new(),
+        }
+    }
+
+    /// Aggregate every query across sets, keeping a per-set breakdown if there are several
+    fn aggregate(sets: &[(&str, Vec<QueryResult>)]) -> Self {
+        let names: Vec<&str> = sets.iter().map(|(name, _)| *name).collect();
+        let all: Vec<QueryResult> = sets
+            .iter()
+            .flat_map(|(_, results)| results.iter().cloned())
+            .collect();
+        let mut total = Self::summarize(&names.join(" + "), &all);
+        if sets.len() > 1 {
+            total.query_sets = sets
+                .iter()
+                .map(|(name, results)| Self::summarize(name, results))
+                .collect();
+        }
+        total
+    }
+}
+
+impl QueryResult {
+    /// Score `retrieved` against `query`'s ground truth
+    fn new(query: &BenchQuery, retrieved: &[String], latency: Duration) -> Self {
+        let ground_truth = GroundTruth::from_query(query);
+        Self {
+            latency,
+            reciprocal_rank: reciprocal_rank(retrieved, &ground_truth),
+            recall_at_5: recall_at_k(retrieved, &ground_truth, 5),
+            recall_at_10: recall_at_k(retrieved, &ground_truth, 10),
+            repo_recall: repo_recall(retrieved, &query.expected_repos),
+        }
+    }
+}
+
+/// Per-query metrics at the result limit (one `--csv` row)
+#[derive(Debug)]
+struct QueryRow {
+    id: String,
+    query: String,
+    expected: usize,
+    hits: usize,
+    precision: f64,
+    recall: f64,
+    reciprocal_rank: f64,
+}
+
+impl QueryRow {
+    /// Score `retrieved` against `query`'s ground truth, counting the top `k`
+    fn new(query: &BenchQuery, retrieved: &[String], k: usize) -> Self {
+        let ground_truth = GroundTruth::from_query(query);
+        let top_k = &retrieved[..retrieved.len().min(k)];
+        let hits = query.relevance(top_k).iter().filter(|&&hit| hit).count();
+        Self {
+            id: query.id.clone(),
+            query: query.query.clone(),
+            expected: ground_truth.expected_count(),
+            hits,
+            precision: if k > 0 { hits as f64 / k as f64 } else { 0.0 },
+            recall: recall_at_k(retrieved, &ground_truth, k),
+            reciprocal_rank: reciprocal_rank(retrieved, &ground_truth),
+        }
+    }
+}
+
+/// Quote a CSV field if it contains a separator, quote or newline
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Write per-query rows as CSV, with `k` (the result limit) in the metric headers
+fn write_csv(path: &Path, rows: &[QueryRow], k: usize) -> Result<()> {
+    let mut out = format!(
+        "id,query,expected,hits@{k},precision@{k},recall@{k},mrr\n",
+        k = k
+    );
+    for row in rows {
+        out.push_str(&format!(
+            "{},{},{},{},{:.4},{:.4},{:.4}\n",
+            csv_field(&row.id),
+            csv_field(&row.query),
+            row.expected,
+            row.hits,
+            row.precision,
+            row.recall,
+            row.reciprocal_rank
+        ));
+    }
+    std::fs::write(path, out).with_context(|| format!("Failed to write CSV: {}", path.display()))
 }
 
 /// Ground truth for a benchmark query
@@ -112,18 +290,29 @@ impl<'a> GroundTruth<'a> {
     /// Check if a retrieved doc_id matches ground truth
     /// Prefers doc matching; falls back to keyword matching if no docs specified
     fn matches(&self, doc_id: &str) -> bool {
-        if !self.docs.is_empty() {
+        self.targets()
+            .iter()
+            .any(|t| self.target_matches(t, doc_id))
+    }
+
+    /// Ground truth entries in use: docs if given, else legacy keywords
+    fn targets(&self) -> &[String] {
+        if self.is_strong() {
+            self.docs
+        } else {
+            self.keywords
+        }
+    }
+
+    /// Check a retrieved doc_id against a single ground truth entry
+    fn target_matches(&self, target: &str, doc_id: &str) -> bool {
+        if self.is_strong() {
             // Strong matching: doc_id must contain one of the relevant doc paths
             // e.g., doc_id="src/retrieval/fusion.rs:42" matches "src/retrieval/fusion.rs"
-            self.docs
-                .iter()
-                .any(|d| doc_id.contains(d) || d.contains(doc_id))
+            doc_id.contains(target) || target.contains(doc_id)
         } else {
             // Legacy keyword matching (weak)
-            let doc_lower = doc_id.to_lowercase();
-            self.keywords
-                .iter()
-                .any(|k| doc_lower.contains(&k.to_lowercase()))
+            doc_id.to_lowercase().contains(&target.to_lowercase())
         }
     }
 
@@ -319,21 +508,25 @@ pub fn build_retrieval_config(
     }
 }
 
-/// Run the benchmark and report results
+/// Run the benchmark over one or more query sets and report results
 pub fn run_benchmark(
-    query_set: &QuerySet,
+    query_sets: &[QuerySet],
     limit: usize,
     json_output: bool,
     verbose: bool,
+    csv: Option<&Path>,
     config: RetrievalConfig,
     repo: Option<String>,
 ) -> Result<()> {
+    let total_queries: usize = query_sets.iter().map(|s| s.queries.len()).sum();
     println!("🔬 Patina Retrieval Benchmark");
-    println!(
-        "   Query set: {} ({} queries)",
-        query_set.name,
-        query_set.queries.len()
-    );
+    for query_set in query_sets {
+        println!(
+            "   Query set: {} ({} queries)",
+            query_set.name,
+            query_set.queries.len()
+        );
+    }
     println!("   Limit: {} results per query", limit);
     println!(
         "   Config: rrf_k={}, fetch_multiplier={}",
@@ -362,130 +555,133 @@ pub fn run_benchmark(
         ..Default::default()
     };
 
-    // Run each query
-    let mut results: Vec<QueryResult> = Vec::new();
-
-    for (i, bench_query) in query_set.queries.iter().enumerate() {
-        print!(
-            "   [{}/{}] {} ... ",
-            i + 1,
-            query_set.queries.len(),
-            bench_query.id
-        );
-
-        let start = Instant::now();
-        let fused_results = if repo.is_some() {
-            engine.query_with_options(&bench_query.query, limit, &query_options)?
-        } else {
-            engine.query(&bench_query.query, limit)?
-        };
-        let latency = start.elapsed();
-
-        let retrieved_docs: Vec<String> = fused_results.iter().map(|r| r.doc_id.clone()).collect();
+    // Run each query, grouped by the set it came from
+    let mut sets: Vec<(&str, Vec<QueryResult>)> = Vec::new();
+    let mut rows: Vec<QueryRow> = Vec::new();
+    let mut done = 0;
+
+    for query_set in query_sets {
+        let mut results: Vec<QueryResult> = Vec::new();
+        for bench_query in &query_set.queries {
+            done += 1;
+            print!("   [{}/{}] {} ... ", done, total_queries, bench_query.id);
+
+            let start = Instant::now();
+            let fused_results = if repo.is_some() {
+                engine.query_with_options(&bench_query.query, limit, &query_options)?
+            } else {
+                engine.query(&bench_query.query, limit)?
+            };
+            let latency = start.elapsed();
+
+            let retrieved_docs: Vec<String> =
+                fused_results.iter().map(|r| r.doc_id.clone()).collect();
+            let result = QueryResult::new(bench_query, &retrieved_docs, latency);
+            let (rr, r5, r10) = (
+                result.reciprocal_rank,
+                result.recall_at_5,
+                result.recall_at_10,
+            );
 
-        let ground_truth = GroundTruth::from_query(bench_query);
-        let rr = reciprocal_rank(&retrieved_docs, &ground_truth);
-        let r5 = recall_at_k(&retrieved_docs, &ground_truth, 5);
-        let r10 = recall_at_k(&retrieved_docs, &ground_truth, 10);
-        let rrepo = repo_recall(&retrieved_docs, &bench_query.expected_repos);
+            // Show repo recall if this is a cross-project query
+            if let Some(repo_r) = result.repo_recall {
+                println!(
+                    "{:.0}ms (RR={:.2}, R@5={:.0}%, R@10={:.0}%, Repo={:.0}%)",
+                    latency.as_millis(),
+                    rr,
+                    r5 * 100.0,
+                    r10 * 100.0,
+                    repo_r * 100.0
+                );
+            } else {
+                println!(
+                    "{:.0}ms (RR={:.2}, R@5={:.0}%, R@10={:.0}%)",
+                    latency.as_millis(),
+                    rr,
+                    r5 * 100.0,
+                    r10 * 100.0
+                );
+            }
 
-        // Show repo recall if this is a cross-project query
-        if let Some(repo_r) = rrepo {
-            println!(
-                "{:.0}ms (RR={:.2}, R@5={:.0}%, R@10={:.0}%, Repo={:.0}%)",
-                latency.as_millis(),
-                rr,
-                r5 * 100.0,
-                r10 * 100.0,
-                repo_r * 100.0
-            );
-        } else {
-            println!(
-                "{:.0}ms (RR={:.2}, R@5={:.0}%, R@10={:.0}%)",
-                latency.as_millis(),
-                rr,
-                r5 * 100.0,
-                r10 * 100.0
-            );
-        }
+            // Verbose: show detailed analysis for failures or all queries
+            if verbose {
+                let ground_truth = GroundTruth::from_query(bench_query);
+                print_verbose_analysis(bench_query, &retrieved_docs, &ground_truth, rr);
+            }
+            if csv.is_some() {
+                rows.push(QueryRow::new(bench_query, &retrieved_docs, limit));
+            }
 
-        // Verbose: show detailed analysis for failures or all queries
-        if verbose {
-            print_verbose_analysis(bench_query, &retrieved_docs, &ground_truth, rr);
+            results.push(result);
         }
-
-        results.push(QueryResult {
-            latency,
-            reciprocal_rank: rr,
-            recall_at_5: r5,
-            recall_at_10: r10,
-            repo_recall: rrepo,
-        });
+        sets.push((query_set.name.as_str(), results));
     }
 
-    // Calculate aggregate metrics
-    let num_queries = results.len();
-    let mrr = results.iter().map(|r| r.reciprocal_rank).sum::<f64>() / num_queries as f64;
-    let recall_5 = results.iter().map(|r| r.recall_at_5).sum::<f64>() / num_queries as f64;
-    let recall_10 = results.iter().map(|r| r.recall_at_10).sum::<f64>() / num_queries as f64;
-
-    // Calculate repo recall if any queries had expected_repos
-    let repo_recalls: Vec<f64> = results.iter().filter_map(|r| r.repo_recall).collect();
-    let avg_repo_recall = if !repo_recalls.is_empty() {
-        Some(repo_recalls.iter().sum::<f64>() / repo_recalls.len() as f64)
-    } else {
-        None
-    };
+    if let Some(path) = csv {
+        write_csv(path, &rows, limit)?;
+        println!("   Per-query CSV: {}", path.display());
+    }
 
-    let mut latencies: Vec<Duration> = results.iter().map(|r| r.latency).collect();
-    latencies.sort();
-
-    let latency_mean = latencies.iter().sum::<Duration>() / num_queries as u32;
-    let latency_p50 = percentile(&latencies, 50.0);
-    let latency_p95 = percentile(&latencies, 95.0);
-
-    let benchmark_results = BenchmarkResults {
-        query_set: query_set.name.clone(),
-        num_queries,
-        mrr,
-        recall_at_5: recall_5,
-        recall_at_10: recall_10,
-        latency_p50_ms: latency_p50.as_secs_f64() * 1000.0,
-        latency_p95_ms: latency_p95.as_secs_f64() * 1000.0,
-        latency_mean_ms: latency_mean.as_secs_f64() * 1000.0,
-        repo_recall: avg_repo_recall,
-    };
+    let benchmark_results = BenchmarkResults::aggregate(&sets);
 
     if json_output {
         println!("{}", serde_json::to_string_pretty(&benchmark_results)?);
     } else {
+        let mrr = benchmark_results.mrr;
+        let recall_10 = benchmark_results.recall_at_10;
         println!();
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
-        println!("📊 Results: {}", query_set.name);
+        println!("📊 Results: {}", benchmark_results.query_set);
         println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
         println!();
         println!("   Relevance Metrics:");
         println!("   ├─ MRR:        {:.3}", mrr);
-        println!("   ├─ Recall@5:   {:.1}%", recall_5 * 100.0);
+        println!(
+            "   ├─ Recall@5:   {:.1}%",
+            benchmark_results.recall_at_5 * 100.0
+        );
         println!("   └─ Recall@10:  {:.1}%", recall_10 * 100.0);
 
+        if !benchmark_results.query_sets.is_empty() {
+            println!();
+            println!("   Per Query Set:");
+            let last = benchmark_results.query_sets.len() - 1;
+            for (i, set) in benchmark_results.query_sets.iter().enumerate() {
+                let branch = if i == last { "└─" } else { "├─" };
+                println!(
+                    "   {} {} ({} queries): MRR {:.3}, R@5 {:.1}%, R@10 {:.1}%",
+                    branch,
+                    set.query_set,
+                    set.num_queries,
+                    set.mrr,
+                    set.recall_at_5 * 100.0,
+                    set.recall_at_10 * 100.0
+                );
+            }
+        }
+
         // Show routing metrics if this is a cross-project queryset
-        if let Some(repo_r) = avg_repo_recall {
+        if let Some(repo_r) = benchmark_results.repo_recall {
+            let with_repos = sets
+                .iter()
+                .flat_map(|(_, results)| results)
+                .filter(|r| r.repo_recall.is_some())
+                .count();
             println!();
             println!("   Routing Metrics (cross-project):");
             println!(
                 "   └─ Repo Recall: {:.1}% ({}/{} queries with expected_repos)",
                 repo_r * 100.0,
-                repo_recalls.len(),
-                num_queries
+                with_repos,
+                benchmark_results.num_queries
             );
         }
 
         println!();
         println!("   Latency:");
-        println!("   ├─ p50:  {:.0}ms", latency_p50.as_millis());
-        println!("   ├─ p95:  {:.0}ms", latency_p95.as_millis());
-        println!("   └─ mean: {:.0}ms", latency_mean.as_millis());
+        println!("   ├─ p50:  {:.0}ms", benchmark_results.latency_p50_ms);
+        println!("   ├─ p95:  {:.0}ms", benchmark_results.latency_p95_ms);
+        println!("   └─ mean: {:.0}ms", benchmark_results.latency_mean_ms);
         println!();
 
         // Quality assessment
@@ -662,3 +858,132 @@ fn clean_commit_message(message: &str) -> String {
 
     cleaned
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_csv_has_one_row_per_query() -> Result<()> {
+        let query_set: QuerySet = serde_json::from_str(
+            r#"{
+                "name": "tiny",
+                "queries": [
+                    {"id": "q1", "query": "how does fusion work",
+                     "relevant_docs": ["src/retrieval/fusion.rs", "src/retrieval/engine.rs"]},
+                    {"id": "q2", "query": "parse config, then \"validate\"",
+                     "relevant_docs": ["src/project/mod.rs"]}
+                ]
+            }"#,
+        )?;
+        let retrieved = [
+            vec![
+                "src/main.rs:1".to_string(),
+                "src/retrieval/fusion.rs:42".to_string(),
+                "src/retrieval/fusion.rs:90".to_string(),
+            ],
+            vec!["src/lib.rs:3".to_string()],
+        ];
+        let rows: Vec<QueryRow> = query_set
+            .queries
+            .iter()
+            .zip(&retrieved)
+            .map(|(q, docs)| QueryRow::new(q, docs, 2))
+            .collect();
+
+        let temp = tempfile::TempDir::new()?;
+        let path = temp.path().join("bench.csv");
+        write_csv(&path, &rows, 2)?;
+
+        let csv = std::fs::read_to_string(&path)?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "id,query,expected,hits@2,precision@2,recall@2,mrr",
+                "q1,how does fusion work,2,1,0.5000,0.5000,0.5000",
+                "q2,\"parse config, then \"\"validate\"\"\",1,0,0.0000,0.0000,0.0000",
+            ]
+        );
+
+        Ok(())
+    }
+
+    const TINY: &str = r#"{
+        "name": "tiny",
+        "queries": [
+            {"id": "q1", "query": "fusion", "relevant_docs": ["src/retrieval/fusion.rs"]},
+            {"id": "q2", "query": "config", "relevant_docs": ["src/project/mod.rs"]}
+        ]
+    }"#;
+
+    #[test]
+    fn test_query_set_from_stdin() -> Result<()> {
+        let query_set = QuerySet::load_or_stdin("-", std::io::Cursor::new(TINY))?;
+        assert_eq!(query_set.name, "tiny");
+        let ids: Vec<&str> = query_set.queries.iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, vec!["q1", "q2"]);
+
+        let err = QuerySet::load_or_stdin("-", std::io::Cursor::new("not json")).unwrap_err();
+        assert!(err.to_string().contains("stdin"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiple_query_sets_aggregate_with_breakdown() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let first = temp.path().join("tiny.json");
+        let second = temp.path().join("other.json");
+        std::fs::write(&first, TINY)?;
+        std::fs::write(
+            &second,
+            r#"{"name": "other", "queries": [
+                {"id": "o1", "query": "scry", "relevant_docs": ["src/commands/scry/mod.rs"]}
+            ]}"#,
+        )?;
+        let sets: Vec<QuerySet> = [&first, &second]
+            .iter()
+            .map(|p| QuerySet::load_or_stdin(p.to_str().unwrap(), std::io::empty()))
+            .collect::<Result<_>>()?;
+
+        // q1 hit at rank 2, q2 missed, o1 hit at rank 1
+        let retrieved = |id: &str| -> Vec<String> {
+            let docs: &[&str] = match id {
+                "q1" => &["src/main.rs:1", "src/retrieval/fusion.rs:42"],
+                "o1" => &["src/commands/scry/mod.rs:10"],
+                _ => &["src/lib.rs:3"],
+            };
+            docs.iter().map(|d| d.to_string()).collect()
+        };
+        let scored: Vec<(&str, Vec<QueryResult>)> = sets
+            .iter()
+            .map(|set| {
+                let results = set
+                    .queries
+                    .iter()
+                    .map(|q| QueryResult::new(q, &retrieved(&q.id), Duration::from_millis(5)))
+                    .collect();
+                (set.name.as_str(), results)
+            })
+            .collect();
+
+        let total = BenchmarkResults::aggregate(&scored);
+        assert_eq!(total.query_set, "tiny + other");
+        assert_eq!(total.num_queries, 3);
+        assert!((total.mrr - 0.5).abs() < 1e-9);
+        assert!((total.recall_at_10 - 2.0 / 3.0).abs() < 1e-9);
+
+        let per_set: Vec<(&str, usize, f64)> = total
+            .query_sets
+            .iter()
+            .map(|s| (s.query_set.as_str(), s.num_queries, s.mrr))
+            .collect();
+        assert_eq!(per_set, vec![("tiny", 2, 0.25), ("other", 1, 1.0)]);
+
+        // A single set gets no breakdown
+        assert!(BenchmarkResults::aggregate(&scored[..1])
+            .query_sets
+            .is_empty());
+        Ok(())
+    }
+}
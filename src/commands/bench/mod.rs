@@ -1,9 +1,9 @@
 //! Benchmark command - measure retrieval quality
 //!
 //! Public interface:
-//! - `execute()` - run retrieval benchmarks
+//! - `execute()` - run retrieval benchmarks over one or more query sets
 //! - `generate()` - generate querysets from git commits
-//! - `QuerySet` - benchmark query set format
+//! - `QuerySet` - benchmark query set format (also read by `eval --query-set`)
 //!
 //! Follows dependable-rust: metrics calculation is internal
 
@@ -16,14 +16,16 @@ pub use internal::QuerySet;
 
 /// Options for benchmark execution
 pub struct BenchOptions {
-    /// Path to query set JSON file
-    pub query_set: String,
+    /// Query set JSON files (`-` reads one from stdin)
+    pub query_sets: Vec<String>,
     /// Number of results to retrieve per query (default: 10)
     pub limit: usize,
     /// Output as JSON
     pub json: bool,
     /// Show detailed per-query analysis
     pub verbose: bool,
+    /// Write per-query metrics as CSV to this path
+    pub csv: Option<String>,
     /// Override RRF k value (default: from config or 60)
     pub rrf_k: Option<usize>,
     /// Override fetch multiplier (default: from config or 2)
@@ -36,17 +38,22 @@ pub struct BenchOptions {
 
 /// Execute retrieval benchmark
 pub fn execute(options: BenchOptions) -> Result<()> {
-    let query_set = QuerySet::load(Path::new(&options.query_set))?;
+    let query_sets = options
+        .query_sets
+        .iter()
+        .map(|path| QuerySet::load_or_stdin(path, std::io::stdin()))
+        .collect::<Result<Vec<_>>>()?;
 
     // Build retrieval config from project config with CLI overrides
     let config =
         internal::build_retrieval_config(options.rrf_k, options.fetch_multiplier, options.oracle);
 
     internal::run_benchmark(
-        &query_set,
+        &query_sets,
         options.limit,
         options.json,
         options.verbose,
+        options.csv.as_deref().map(Path::new),
         config,
         options.repo,
     )
@@ -1,15 +1,30 @@
 use anyhow::{Context, Result};
 use patina::environment::Environment;
+use patina::mother;
 use patina::project;
 use patina::session::SessionManager;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::path::Path;
+
+use crate::commands::oxidize::trainer::Projection;
+
+/// Projections scry reads (one `.usearch` each)
+const DIMENSIONS: [&str; 3] = ["semantic", "temporal", "dependency"];
 
 #[derive(Serialize, Deserialize)]
 struct HealthCheck {
     status: String, // "healthy", "warning", "critical"
     environment_changes: EnvironmentChanges,
     project_config: ProjectStatus,
+    /// Present only when PATINA_MOTHER is configured
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    mother: Option<MotherStatus>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    indices: Option<IndexHealth>,
+    /// Present only with --audit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    orphaned_embeddings: Option<Vec<OrphanedEmbeddings>>,
     recommendations: Vec<String>,
 }
 
@@ -28,6 +43,48 @@ struct ToolChange {
     required: bool,
 }
 
+#[derive(Serialize, Deserialize)]
+struct MotherStatus {
+    address: String,
+    reachable: bool,
+    ready: bool,
+    version: Option<String>,
+    model: Option<String>,
+    indices: Vec<String>,
+    error: Option<String>,
+}
+
+/// Embedding indices for the configured model
+#[derive(Serialize, Deserialize)]
+struct IndexHealth {
+    model: String,
+    model_dimensions: Option<usize>,
+    dimensions: Vec<DimensionStatus>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct DimensionStatus {
+    name: String,
+    exists: bool,
+    /// Width stored in the .usearch file itself
+    index_dimensions: Option<usize>,
+    /// Width recorded in the .meta.json sidecar
+    meta_dimensions: Option<usize>,
+    /// Output width of the projection weights
+    projection_dimensions: Option<usize>,
+    /// Embedding width the projection was trained on
+    projection_input: Option<usize>,
+    mismatch: Option<String>,
+}
+
+/// Embeddings directory for a model other than the configured one
+#[derive(Serialize, Deserialize)]
+struct OrphanedEmbeddings {
+    model: String,
+    path: String,
+    bytes: u64,
+}
+
 #[derive(Serialize, Deserialize)]
 struct ProjectStatus {
     llm: String,
@@ -36,7 +93,7 @@ struct ProjectStatus {
     sessions: usize,
 }
 
-pub fn execute(json_output: bool) -> Result<i32> {
+pub fn execute(json_output: bool, audit: bool) -> Result<i32> {
     // Find project root first
     let project_root = SessionManager::find_project_root()
         .context("Not in a Patina project directory. Run 'patina init' first.")?;
@@ -85,6 +142,69 @@ pub fn execute(json_output: bool) -> Result<i32> {
         sessions: session_count,
     };
 
+    // Check the configured mother daemon, if any
+    if mother::is_configured() {
+        let status = check_mother();
+        if !status.reachable {
+            health_check.recommendations.push(format!(
+                "Mother at {} is unreachable: start it with 'patina mother start'",
+                status.address
+            ));
+        } else if !status.ready {
+            health_check.recommendations.push(format!(
+                "Mother at {} has no indices: run 'patina oxidize' on the host",
+                status.address
+            ));
+        }
+        health_check.mother = Some(status);
+    }
+
+    // Check embedding indices against the configured model
+    let model = &config.embeddings.model;
+    let model_dimensions = patina::embeddings::ModelRegistry::load()
+        .ok()
+        .and_then(|r| r.get_model(model).ok().map(|m| m.dimensions));
+    let indices = check_indices(&project_root, model, model_dimensions);
+    let missing: Vec<&str> = indices
+        .dimensions
+        .iter()
+        .filter(|d| !d.exists)
+        .map(|d| d.name.as_str())
+        .collect();
+    if !missing.is_empty() {
+        health_check.recommendations.push(format!(
+            "No {} index for {}: run 'patina oxidize'",
+            missing.join("/"),
+            model
+        ));
+    }
+    for dim in &indices.dimensions {
+        if let Some(ref problem) = dim.mismatch {
+            health_check.recommendations.push(format!(
+                "{} index: {} - run 'patina oxidize --force'",
+                dim.name, problem
+            ));
+            if health_check.status == "healthy" {
+                health_check.status = "warning".to_string();
+            }
+        }
+    }
+    health_check.indices = Some(indices);
+
+    // Audit: report (never delete) embeddings built for other models
+    if audit {
+        let orphaned = find_orphaned_embeddings(&project_root, model);
+        for orphan in &orphaned {
+            health_check.recommendations.push(format!(
+                "Embeddings for unused model '{}' take {}: remove with 'rm -rf {}'",
+                orphan.model,
+                format_size(orphan.bytes),
+                orphan.path
+            ));
+        }
+        health_check.orphaned_embeddings = Some(orphaned);
+    }
+
     // Display results
     if json_output {
         println!("{}", serde_json::to_string_pretty(&health_check)?);
@@ -175,10 +295,133 @@ fn analyze_environment(current: &Environment, stored_tools: &[String]) -> Result
             layer_patterns: 0,
             sessions: 0,
         },
+        mother: None,
+        indices: None,
+        orphaned_embeddings: None,
         recommendations,
     })
 }
 
+/// Embedding directories that don't belong to the configured model
+fn find_orphaned_embeddings(project_root: &Path, model: &str) -> Vec<OrphanedEmbeddings> {
+    let embeddings_dir = project_root.join(".patina/local/data/embeddings");
+    let Ok(entries) = fs::read_dir(&embeddings_dir) else {
+        return Vec::new();
+    };
+
+    let mut orphaned: Vec<OrphanedEmbeddings> = entries
+        .filter_map(Result::ok)
+        .filter(|e| e.path().is_dir() && e.file_name() != model)
+        .map(|e| OrphanedEmbeddings {
+            model: e.file_name().to_string_lossy().to_string(),
+            path: e.path().to_string_lossy().to_string(),
+            bytes: patina::paths::dir_size(&e.path()).unwrap_or(0),
+        })
+        .collect();
+    orphaned.sort_by(|a, b| a.model.cmp(&b.model));
+    orphaned
+}
+
+fn format_size(bytes: u64) -> String {
+    const MB: u64 = 1024 * 1024;
+    if bytes >= MB {
+        format!("{:.1} MB", bytes as f64 / MB as f64)
+    } else {
+        format!("{} KB", bytes / 1024)
+    }
+}
+
+/// Inspect each dimension's index and projection for the configured model
+fn check_indices(project_root: &Path, model: &str, model_dimensions: Option<usize>) -> IndexHealth {
+    use crate::commands::oxidize::index_meta;
+
+    let projections = project_root
+        .join(".patina/local/data/embeddings")
+        .join(model)
+        .join("projections");
+
+    let dimensions = DIMENSIONS
+        .iter()
+        .map(|name| {
+            let index_path = projections.join(format!("{}.usearch", name));
+            let weights_path = projections.join(format!("{}.safetensors", name));
+
+            let exists = index_path.exists();
+            let index_dimensions = exists.then(|| stored_dimensions(&index_path)).flatten();
+            let meta_dimensions = index_meta::read(&index_path).map(|m| m.dimensions);
+            let projection_dimensions = Projection::read_output_dim(&weights_path);
+            let projection_input = Projection::read_input_dim(&weights_path);
+
+            let mismatch = match (index_dimensions, meta_dimensions, projection_dimensions) {
+                (Some(index), _, Some(projection)) if index != projection => Some(format!(
+                    "index is {}-dim but projection outputs {}",
+                    index, projection
+                )),
+                (Some(index), Some(meta), _) if index != meta => Some(format!(
+                    "index is {}-dim but metadata records {}",
+                    index, meta
+                )),
+                _ => match (projection_input, model_dimensions) {
+                    (Some(input), Some(embedding)) if input != embedding => Some(format!(
+                        "projection expects {}-dim embeddings but {} produces {}",
+                        input, model, embedding
+                    )),
+                    _ => None,
+                },
+            };
+
+            DimensionStatus {
+                name: name.to_string(),
+                exists,
+                index_dimensions,
+                meta_dimensions,
+                projection_dimensions,
+                projection_input,
+                mismatch,
+            }
+        })
+        .collect();
+
+    IndexHealth {
+        model: model.to_string(),
+        model_dimensions,
+        dimensions,
+    }
+}
+
+/// Width stored in a .usearch file (the header wins over the options it is opened with)
+fn stored_dimensions(index_path: &Path) -> Option<usize> {
+    use crate::commands::oxidize::index_meta;
+
+    let index = usearch::Index::new(&index_meta::load_options(index_path)).ok()?;
+    index.view(index_path.to_str()?).ok()?;
+    Some(index.dimensions())
+}
+
+fn check_mother() -> MotherStatus {
+    let address = mother::get_address().unwrap_or_default();
+    match mother::health() {
+        Ok(health) => MotherStatus {
+            address,
+            reachable: true,
+            ready: health.is_ready(),
+            version: Some(health.version),
+            model: Some(health.model).filter(|m| !m.is_empty()),
+            indices: health.indices,
+            error: None,
+        },
+        Err(e) => MotherStatus {
+            address,
+            reachable: false,
+            ready: false,
+            version: None,
+            model: None,
+            indices: Vec::new(),
+            error: Some(e.to_string()),
+        },
+    }
+}
+
 fn is_tool_required(tool: &str) -> bool {
     // Core tools required for Patina projects
     // Docker is optional (detected but not required)
@@ -267,6 +510,59 @@ fn display_health_check(
     );
     println!("  ✓ Sessions: {} recorded", health.project_config.sessions);
 
+    if let Some(ref m) = health.mother {
+        println!("\nMother ({}):", m.address);
+        if !m.reachable {
+            println!(
+                "  ⚠ Unreachable: {}",
+                m.error.as_deref().unwrap_or("unknown error")
+            );
+        } else {
+            let marker = if m.ready { "✓" } else { "⚠" };
+            println!(
+                "  {marker} Version {} · model {}",
+                m.version.as_deref().unwrap_or("unknown"),
+                m.model.as_deref().unwrap_or("unknown")
+            );
+            if m.indices.is_empty() {
+                println!("  ⚠ Indices: none (not ready for queries)");
+            } else {
+                println!("  {marker} Indices: {}", m.indices.join(", "));
+            }
+        }
+    }
+
+    if let Some(ref indices) = health.indices {
+        println!("\nIndices ({}):", indices.model);
+        for dim in &indices.dimensions {
+            if let Some(ref problem) = dim.mismatch {
+                println!("  ⚠ {}: {}", dim.name, problem);
+            } else if !dim.exists {
+                println!("  ⚠ {}: missing", dim.name);
+            } else {
+                let width = dim
+                    .index_dimensions
+                    .map(|d| format!("{}-dim", d))
+                    .unwrap_or_else(|| "unreadable".to_string());
+                println!("  ✓ {}: {}", dim.name, width);
+            }
+        }
+    }
+
+    if let Some(ref orphaned) = health.orphaned_embeddings {
+        println!("\nAudit:");
+        if orphaned.is_empty() {
+            println!("  ✓ No embeddings from other models");
+        }
+        for orphan in orphaned {
+            println!(
+                "  ⚠ Unused embeddings: {} ({})",
+                orphan.model,
+                format_size(orphan.bytes)
+            );
+        }
+    }
+
     if !health.recommendations.is_empty() {
         println!("\nRecommendations:");
         for (i, rec) in health.recommendations.iter().enumerate() {
@@ -276,3 +572,98 @@ fn display_health_check(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::oxidize::index_meta::{self, IndexMeta, Quantization};
+    use tempfile::TempDir;
+
+    /// Build `<name>.usearch` at `index_dim` and `<name>.safetensors` projecting 8 → `proj_dim`
+    fn write_fixture(projections: &Path, name: &str, index_dim: usize, proj_dim: usize) {
+        fs::create_dir_all(projections).unwrap();
+        let index_path = projections.join(format!("{}.usearch", name));
+        let meta = IndexMeta {
+            quantization: Quantization::F32,
+            dimensions: index_dim,
+        };
+        let index = usearch::Index::new(&meta.index_options()).unwrap();
+        index.reserve(1).unwrap();
+        index.add(1, &vec![1.0_f32; index_dim]).unwrap();
+        index.save(index_path.to_str().unwrap()).unwrap();
+        index_meta::write(&index_path, &meta).unwrap();
+
+        Projection::new(8, 16, proj_dim)
+            .save_safetensors(&projections.join(format!("{}.safetensors", name)))
+            .unwrap();
+    }
+
+    #[test]
+    fn test_index_dimension_mismatch_is_flagged() {
+        let temp = TempDir::new().unwrap();
+        let projections = temp
+            .path()
+            .join(".patina/local/data/embeddings/e5-base-v2/projections");
+        write_fixture(&projections, "semantic", 32, 32);
+        // Projection retrained at 64 but the index still holds 32-dim vectors
+        write_fixture(&projections, "temporal", 32, 64);
+
+        let health = check_indices(temp.path(), "e5-base-v2", Some(8));
+        let by_name = |n: &str| health.dimensions.iter().find(|d| d.name == n).unwrap();
+
+        let semantic = by_name("semantic");
+        assert!(semantic.exists);
+        assert_eq!(semantic.index_dimensions, Some(32));
+        assert!(semantic.mismatch.is_none());
+
+        let temporal = by_name("temporal");
+        assert_eq!(temporal.index_dimensions, Some(32));
+        assert_eq!(temporal.projection_dimensions, Some(64));
+        assert_eq!(
+            temporal.mismatch.as_deref(),
+            Some("index is 32-dim but projection outputs 64")
+        );
+
+        let dependency = by_name("dependency");
+        assert!(!dependency.exists);
+        assert!(dependency.mismatch.is_none());
+
+        // Embedding model changed under a trained projection
+        let health = check_indices(temp.path(), "e5-base-v2", Some(384));
+        assert_eq!(
+            health.dimensions[0].mismatch.as_deref(),
+            Some("projection expects 8-dim embeddings but e5-base-v2 produces 384")
+        );
+    }
+
+    #[test]
+    fn test_audit_flags_embeddings_from_other_model() {
+        let temp = TempDir::new().unwrap();
+        let embeddings = temp.path().join(".patina/local/data/embeddings");
+        fs::create_dir_all(embeddings.join("model-a/projections")).unwrap();
+        fs::create_dir_all(embeddings.join("model-b/projections")).unwrap();
+        fs::write(
+            embeddings.join("model-a/projections/semantic.usearch"),
+            [0u8; 10],
+        )
+        .unwrap();
+        fs::write(
+            embeddings.join("model-b/projections/semantic.usearch"),
+            [0u8; 3000],
+        )
+        .unwrap();
+        fs::write(
+            embeddings.join("model-b/projections/temporal.usearch"),
+            [0u8; 96],
+        )
+        .unwrap();
+
+        let orphaned = find_orphaned_embeddings(temp.path(), "model-a");
+
+        assert_eq!(orphaned.len(), 1);
+        assert_eq!(orphaned[0].model, "model-b");
+        assert_eq!(orphaned[0].bytes, 3096);
+        // Reported, not removed
+        assert!(embeddings.join("model-b").exists());
+    }
+}
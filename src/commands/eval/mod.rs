@@ -1,7 +1,8 @@
 //! Evaluation framework for validating retrieval quality
 //!
 //! Tests the unified QueryEngine pipeline + per-oracle ablation.
-//! Ground truth: function_facts (semantic), co_changes (temporal), beliefs (knowledge).
+//! Ground truth: function_facts (semantic), co_changes (temporal), beliefs (knowledge),
+//! or a curated bench query set via `--query-set`.
 //!
 //! Key questions:
 //! - "Does the unified pipeline improve over individual oracles?"
@@ -9,23 +10,51 @@
 
 use anyhow::Result;
 use rusqlite::Connection;
+use serde::Serialize;
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 
-use crate::retrieval::{FusedResult, QueryEngine, RetrievalConfig};
+use crate::commands::bench::QuerySet;
+use crate::retrieval::{QueryEngine, RetrievalConfig};
+
+/// Human-readable eval output, dropped under `--json` so stdout stays parseable
+#[derive(Debug, Clone, Copy)]
+struct Output {
+    json: bool,
+}
+
+impl Output {
+    fn line(self, text: impl std::fmt::Display) {
+        if !self.json {
+            println!("{}", text);
+        }
+    }
+}
 
 /// Evaluation results for one engine + test combination
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct EvalResults {
     pub engine: String,
+    /// Evaluated dimension: "semantic" or "temporal" ("all" for the unified
+    /// pipeline on a query set)
+    pub dimension: String,
     pub test_name: String,
     pub num_queries: usize,
     pub precision_at_5: f32,
     pub precision_at_10: f32,
+    /// Fraction of all relevant docs found in the top 10
+    pub recall_at_10: f32,
+    /// Harmonic mean of precision@10 and recall@10
+    pub f1_at_10: f32,
+    /// Mean reciprocal rank of the first relevant result
+    pub mrr: f32,
+    /// NDCG@10 with binary gains
+    pub ndcg_at_10: f32,
     pub random_baseline: f32,
 }
 
 /// Belief self-retrieval results (MRR-based)
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BeliefSelfResults {
     pub engine: String,
     pub num_queries: usize,
@@ -34,7 +63,7 @@ pub struct BeliefSelfResults {
 }
 
 /// Belief-code co-retrieval results (split metrics per reviewer feedback)
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct BeliefCoResults {
     pub engine: String,
     pub num_queries: usize,
@@ -47,9 +76,18 @@ pub struct BeliefCoResults {
 }
 
 /// Run evaluation
-pub fn execute(dimension: Option<String>) -> Result<()> {
-    println!("📊 Evaluation Framework\n");
-    println!("Testing retrieval quality: unified pipeline + per-oracle ablation\n");
+///
+/// With `query_set`, ground truth comes from that bench-format file instead of
+/// being derived from the knowledge DB.
+pub fn execute(dimension: Option<String>, json: bool, query_set: Option<String>) -> Result<()> {
+    let out = Output { json };
+
+    if let Some(path) = query_set {
+        return execute_query_set(Path::new(&path), dimension, out);
+    }
+
+    out.line("📊 Evaluation Framework\n");
+    out.line("Testing retrieval quality: unified pipeline + per-oracle ablation\n");
 
     let db_path = ".patina/local/data/patina.db";
     let conn = Connection::open(db_path)?;
@@ -79,42 +117,42 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
 
     // Semantic tests: --dimension narrows which tests run, not which engines
     if dimension.is_none() || dimension.as_deref() == Some("semantic") {
-        println!("━━━ Unified Pipeline (code → same-file) ━━━\n");
-        let results = eval_semantic_co_retrieval(&conn, &unified, "unified")?;
-        print_results(&results);
+        out.line("━━━ Unified Pipeline (code → same-file) ━━━\n");
+        let results = eval_semantic_co_retrieval(&conn, &unified, "unified", out)?;
+        print_results(&results, out);
         all_results.push(results);
 
-        println!("\n━━━ Ablation: no-belief (code → same-file) ━━━\n");
-        let results = eval_semantic_co_retrieval(&conn, &no_belief, "no-belief")?;
-        print_results(&results);
+        out.line("\n━━━ Ablation: no-belief (code → same-file) ━━━\n");
+        let results = eval_semantic_co_retrieval(&conn, &no_belief, "no-belief", out)?;
+        print_results(&results, out);
         all_results.push(results);
 
-        println!("\n━━━ Ablation: semantic-only (code → same-file) ━━━\n");
-        let results = eval_semantic_co_retrieval(&conn, &semantic_only, "semantic-only")?;
-        print_results(&results);
+        out.line("\n━━━ Ablation: semantic-only (code → same-file) ━━━\n");
+        let results = eval_semantic_co_retrieval(&conn, &semantic_only, "semantic-only", out)?;
+        print_results(&results, out);
         all_results.push(results);
     }
 
     // Temporal tests
     if dimension.is_none() || dimension.as_deref() == Some("temporal") {
         // Score distribution (unified only, no ground truth)
-        println!("\n━━━ Unified Pipeline (text → score distribution) ━━━\n");
-        eval_temporal_text(&conn, &unified)?;
+        out.line("\n━━━ Unified Pipeline (text → score distribution) ━━━\n");
+        eval_temporal_text(&conn, &unified, out)?;
 
         // File co-change (unified + temporal-only)
-        println!("\n━━━ Unified Pipeline (file → co-change) ━━━\n");
-        let results = eval_temporal_file(&conn, &unified, "unified")?;
-        print_results(&results);
+        out.line("\n━━━ Unified Pipeline (file → co-change) ━━━\n");
+        let results = eval_temporal_file(&conn, &unified, "unified", out)?;
+        print_results(&results, out);
         all_results.push(results);
 
-        println!("\n━━━ Ablation: no-belief (file → co-change) ━━━\n");
-        let results = eval_temporal_file(&conn, &no_belief, "no-belief")?;
-        print_results(&results);
+        out.line("\n━━━ Ablation: no-belief (file → co-change) ━━━\n");
+        let results = eval_temporal_file(&conn, &no_belief, "no-belief", out)?;
+        print_results(&results, out);
         all_results.push(results);
 
-        println!("\n━━━ Ablation: temporal-only (file → co-change) ━━━\n");
-        let results = eval_temporal_file(&conn, &temporal_only, "temporal-only")?;
-        print_results(&results);
+        out.line("\n━━━ Ablation: temporal-only (file → co-change) ━━━\n");
+        let results = eval_temporal_file(&conn, &temporal_only, "temporal-only", out)?;
+        print_results(&results, out);
         all_results.push(results);
     }
 
@@ -123,80 +161,84 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
     let mut co_results = Vec::new();
 
     if dimension.is_none() || dimension.as_deref() == Some("belief") {
-        println!("\n━━━ Unified Pipeline (belief self-retrieval) ━━━\n");
-        let results = eval_belief_self_retrieval(&conn, &unified, "unified")?;
-        print_belief_self_results(&results);
+        out.line("\n━━━ Unified Pipeline (belief self-retrieval) ━━━\n");
+        let results = eval_belief_self_retrieval(&conn, &unified, "unified", out)?;
+        print_belief_self_results(&results, out);
         self_results.push(results);
 
-        println!("\n━━━ Ablation: no-belief (belief self-retrieval) ━━━\n");
-        let results = eval_belief_self_retrieval(&conn, &no_belief, "no-belief")?;
-        print_belief_self_results(&results);
+        out.line("\n━━━ Ablation: no-belief (belief self-retrieval) ━━━\n");
+        let results = eval_belief_self_retrieval(&conn, &no_belief, "no-belief", out)?;
+        print_belief_self_results(&results, out);
         self_results.push(results);
 
-        println!("\n━━━ Unified Pipeline (belief→code co-retrieval) ━━━\n");
-        let results = eval_belief_code_co_retrieval(&conn, &unified, "unified")?;
-        print_belief_co_results(&results);
+        out.line("\n━━━ Unified Pipeline (belief→code co-retrieval) ━━━\n");
+        let results = eval_belief_code_co_retrieval(&conn, &unified, "unified", out)?;
+        print_belief_co_results(&results, out);
         co_results.push(results);
 
-        println!("\n━━━ Ablation: no-belief (belief→code co-retrieval) ━━━\n");
-        let results = eval_belief_code_co_retrieval(&conn, &no_belief, "no-belief")?;
-        print_belief_co_results(&results);
+        out.line("\n━━━ Ablation: no-belief (belief→code co-retrieval) ━━━\n");
+        let results = eval_belief_code_co_retrieval(&conn, &no_belief, "no-belief", out)?;
+        print_belief_co_results(&results, out);
         co_results.push(results);
     }
 
     // Summary table: structural tests
-    println!("\n━━━ Summary ━━━\n");
-    println!(
-        "{:<35} {:>12} {:>12} {:>12}",
-        "Pipeline", "P@5", "P@10", "vs Random"
-    );
-    println!("{}", "─".repeat(75));
+    out.line("\n━━━ Summary ━━━\n");
+    out.line(format_args!(
+        "{:<35} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8} {:>10}",
+        "Pipeline", "P@5", "P@10", "R@10", "F1@10", "MRR", "NDCG@10", "vs Random"
+    ));
+    out.line(format_args!("{}", "─".repeat(105)));
     for r in &all_results {
         let vs_random = if r.random_baseline > 0.0 {
             r.precision_at_10 / r.random_baseline
         } else {
             0.0
         };
-        println!(
-            "{:<35} {:>11.1}% {:>11.1}% {:>11.1}x",
+        out.line(format_args!(
+            "{:<35} {:>7.1}% {:>7.1}% {:>7.1}% {:>8.3} {:>8.3} {:>8.3} {:>9.1}x",
             format!("{} ({})", r.engine, r.test_name),
             r.precision_at_5 * 100.0,
             r.precision_at_10 * 100.0,
+            r.recall_at_10 * 100.0,
+            r.f1_at_10,
+            r.mrr,
+            r.ndcg_at_10,
             vs_random
-        );
+        ));
     }
 
     // Summary table: belief tests
     if !self_results.is_empty() {
-        println!(
+        out.line(format_args!(
             "\n{:<35} {:>12} {:>12}",
             "Pipeline (self-retrieval)", "MRR", "Hit Rate"
-        );
-        println!("{}", "─".repeat(63));
+        ));
+        out.line(format_args!("{}", "─".repeat(63)));
         for r in &self_results {
-            println!(
+            out.line(format_args!(
                 "{:<35} {:>12.3} {:>11.1}%",
                 r.engine,
                 r.mrr,
                 r.hit_rate * 100.0,
-            );
+            ));
         }
     }
 
     if !co_results.is_empty() {
-        println!(
+        out.line(format_args!(
             "\n{:<35} {:>10} {:>10} {:>10}",
             "Pipeline (co-retrieval)", "B.Pres", "ReachR", "Co-Retr"
-        );
-        println!("{}", "─".repeat(69));
+        ));
+        out.line(format_args!("{}", "─".repeat(69)));
         for r in &co_results {
-            println!(
+            out.line(format_args!(
                 "{:<35} {:>9.1}% {:>9.1}% {:>9.1}%",
                 r.engine,
                 r.belief_present_rate * 100.0,
                 r.reach_recall * 100.0,
                 r.co_retrieval_rate * 100.0,
-            );
+            ));
         }
     }
 
@@ -204,12 +246,12 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
     const STRUCTURAL_BUDGET_PP: f32 = 5.0; // max acceptable regression in percentage points
     let mut d1_pass = true;
 
-    println!("\n━━━ D1 Belief Delta (unified vs no-belief) ━━━\n");
-    println!(
+    out.line("\n━━━ D1 Belief Delta (unified vs no-belief) ━━━\n");
+    out.line(format_args!(
         "{:<25} {:>12} {:>12} {:>8} {:>8}",
         "Test", "Unified", "No-Belief", "Delta", "Verdict"
-    );
-    println!("{}", "─".repeat(69));
+    ));
+    out.line(format_args!("{}", "─".repeat(69)));
 
     // Self-retrieval delta (MRR)
     if let (Some(u), Some(nb)) = (
@@ -221,10 +263,10 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
         if delta < 0.0 {
             d1_pass = false;
         }
-        println!(
+        out.line(format_args!(
             "{:<25} {:>8.3}MRR {:>8.3}MRR {:>+7.3} {:>8}",
             "self-retrieval", u.mrr, nb.mrr, delta, verdict
-        );
+        ));
     }
 
     // Co-retrieval delta (co_retrieval_rate)
@@ -237,14 +279,14 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
         if delta < 0.0 {
             d1_pass = false;
         }
-        println!(
+        out.line(format_args!(
             "{:<25} {:>9.1}%   {:>9.1}%   {:>+6.1}% {:>8}",
             "belief→code",
             u.co_retrieval_rate * 100.0,
             nb.co_retrieval_rate * 100.0,
             delta * 100.0,
             verdict
-        );
+        ));
     }
 
     // Structural test deltas (P@10, budget-enforced)
@@ -265,7 +307,7 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
                 d1_pass = false;
                 "FAIL"
             };
-            println!(
+            out.line(format_args!(
                 "{:<25} {:>11.1}% {:>11.1}% {:>+6.1}pp {:>5} (budget: {}pp)",
                 test,
                 u.precision_at_10 * 100.0,
@@ -273,22 +315,174 @@ pub fn execute(dimension: Option<String>) -> Result<()> {
                 delta_pp,
                 verdict,
                 STRUCTURAL_BUDGET_PP,
-            );
+            ));
         }
     }
 
-    println!(
+    out.line(format_args!(
         "\n{}",
         if d1_pass {
             "D1 VERDICT: PASS — knowledge gains positive, structural regression within budget"
         } else {
             "D1 VERDICT: FAIL — see failing tests above"
         }
-    );
+    ));
+
+    if json {
+        let report = eval_json(&all_results, &self_results, &co_results, d1_pass);
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
 
     Ok(())
 }
 
+/// Evaluate a curated query set: unified pipeline plus per-dimension oracles
+fn execute_query_set(path: &Path, dimension: Option<String>, out: Output) -> Result<()> {
+    let query_set = QuerySet::load(path)?;
+
+    out.line("📊 Evaluation Framework\n");
+    out.line(format_args!(
+        "Query set: {} ({} queries)\n",
+        query_set.name,
+        query_set.queries.len()
+    ));
+
+    let mut runs = vec![("unified", "all", QueryEngine::new())];
+    for oracle in ["semantic", "temporal"] {
+        if dimension.is_none() || dimension.as_deref() == Some(oracle) {
+            let engine = QueryEngine::with_config(RetrievalConfig {
+                oracle_filter: Some(vec![oracle.to_string()]),
+                ..Default::default()
+            });
+            runs.push((
+                if oracle == "semantic" {
+                    "semantic-only"
+                } else {
+                    "temporal-only"
+                },
+                oracle,
+                engine,
+            ));
+        }
+    }
+
+    let mut all_results = Vec::new();
+    for (engine_name, dim, engine) in &runs {
+        out.line(format_args!("━━━ {} ({}) ━━━", engine_name, query_set.name));
+        let results = eval_query_set(&query_set, engine_name, dim, |q| {
+            Ok(engine.query(q, 10)?.into_iter().map(|r| r.doc_id).collect())
+        })?;
+        print_results(&results, out);
+        all_results.push(results);
+        out.line("");
+    }
+
+    out.line(format_args!(
+        "{:<35} {:>8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+        "Pipeline", "P@5", "P@10", "R@10", "F1@10", "MRR", "NDCG@10"
+    ));
+    out.line(format_args!("{}", "─".repeat(94)));
+    for r in &all_results {
+        out.line(format_args!(
+            "{:<35} {:>7.1}% {:>7.1}% {:>7.1}% {:>8.3} {:>8.3} {:>8.3}",
+            format!("{} ({})", r.engine, r.dimension),
+            r.precision_at_5 * 100.0,
+            r.precision_at_10 * 100.0,
+            r.recall_at_10 * 100.0,
+            r.f1_at_10,
+            r.mrr,
+            r.ndcg_at_10,
+        ));
+    }
+
+    if out.json {
+        let report = serde_json::json!({
+            "query_set": query_set.name,
+            "results": all_results,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    }
+
+    Ok(())
+}
+
+/// Score one engine against a query set's listed expected docs
+///
+/// `retrieve` returns ranked doc_ids for a query. Queries without any
+/// ground truth are skipped rather than counted as vacuous hits.
+fn eval_query_set(
+    query_set: &QuerySet,
+    engine_name: &str,
+    dimension: &str,
+    mut retrieve: impl FnMut(&str) -> Result<Vec<String>>,
+) -> Result<EvalResults> {
+    let mut total_precision_5 = 0.0;
+    let mut total_precision_10 = 0.0;
+    let mut total_recall_10 = 0.0;
+    let mut total_f1_10 = 0.0;
+    let mut total_rr = 0.0;
+    let mut total_ndcg = 0.0;
+    let mut num_queries = 0;
+
+    for query in &query_set.queries {
+        let num_relevant = query.relevant_count();
+        if num_relevant == 0 {
+            continue;
+        }
+
+        let retrieved = retrieve(&query.query)?;
+        let relevance = query.relevance(&retrieved);
+
+        let p10 = precision_at_k(&relevance, 10);
+        let r10 = recall_at_k(&relevance, num_relevant, 10);
+        total_precision_5 += precision_at_k(&relevance, 5);
+        total_precision_10 += p10;
+        total_recall_10 += r10;
+        total_f1_10 += f1_score(p10, r10);
+        total_rr += reciprocal_rank(&relevance);
+        total_ndcg += ndcg_at_k(&relevance, num_relevant, 10);
+        num_queries += 1;
+    }
+
+    let mean = |total: f32| {
+        if num_queries > 0 {
+            total / num_queries as f32
+        } else {
+            0.0
+        }
+    };
+
+    Ok(EvalResults {
+        engine: engine_name.to_string(),
+        dimension: dimension.to_string(),
+        test_name: format!("query-set:{}", query_set.name),
+        num_queries,
+        precision_at_5: mean(total_precision_5),
+        precision_at_10: mean(total_precision_10),
+        recall_at_10: mean(total_recall_10),
+        f1_at_10: mean(total_f1_10),
+        mrr: mean(total_rr),
+        ndcg_at_10: mean(total_ndcg),
+        // Curated sets carry no corpus-size information
+        random_baseline: 0.0,
+    })
+}
+
+/// Machine-readable eval report for CI regression tracking
+fn eval_json(
+    results: &[EvalResults],
+    self_results: &[BeliefSelfResults],
+    co_results: &[BeliefCoResults],
+    d1_pass: bool,
+) -> serde_json::Value {
+    serde_json::json!({
+        "results": results,
+        "belief_self_retrieval": self_results,
+        "belief_co_retrieval": co_results,
+        "d1_pass": d1_pass,
+    })
+}
+
 // ============================================================================
 // Semantic evaluation: function_facts co-retrieval
 // ============================================================================
@@ -303,6 +497,7 @@ fn eval_semantic_co_retrieval(
     conn: &Connection,
     engine: &QueryEngine,
     engine_name: &str,
+    out: Output,
 ) -> Result<EvalResults> {
     // Load function_facts grouped by file
     let mut files: HashMap<String, Vec<(String, String)>> = HashMap::new();
@@ -347,25 +542,34 @@ fn eval_semantic_co_retrieval(
     // Files with 3+ functions have enough for query + expected results
     let valid_files: Vec<_> = files.iter().filter(|(_, funcs)| funcs.len() >= 3).collect();
 
-    println!(
+    out.line(format_args!(
         "Found {} files with 3+ functions ({} total functions)",
         valid_files.len(),
         files.values().map(|v| v.len()).sum::<usize>()
-    );
+    ));
 
     if valid_files.is_empty() {
         return Ok(EvalResults {
             engine: engine_name.to_string(),
+            dimension: "semantic".to_string(),
             test_name: "code→same-file".to_string(),
             num_queries: 0,
             precision_at_5: 0.0,
             precision_at_10: 0.0,
+            recall_at_10: 0.0,
+            f1_at_10: 0.0,
+            mrr: 0.0,
+            ndcg_at_10: 0.0,
             random_baseline: 0.0,
         });
     }
 
     let mut total_precision_5 = 0.0;
     let mut total_precision_10 = 0.0;
+    let mut total_recall_10 = 0.0;
+    let mut total_f1_10 = 0.0;
+    let mut total_rr = 0.0;
+    let mut total_ndcg = 0.0;
     let mut num_queries = 0;
 
     // Sample up to 20 files
@@ -382,35 +586,48 @@ fn eval_semantic_co_retrieval(
         let (file_path, functions) = valid_files[idx];
 
         // Use first function's description as query
-        let query = &functions[0].1;
+        let (query_name, query) = &functions[0];
         let expected_file = normalize_path(file_path);
         let expected_count = functions.len() - 1; // exclude query function itself
+        let query_suffix = format!("::{}", query_name);
 
         if let Ok(results) = engine.query(query, 10) {
-            let hits_5 = count_file_hits(&results, &expected_file, 5);
-            let hits_10 = count_file_hits(&results, &expected_file, 10);
+            // Other functions from the same file are relevant; the query's own doc is not
+            let relevance: Vec<bool> = results
+                .iter()
+                .map(|r| {
+                    extract_file_from_doc_id(&r.doc_id) == expected_file
+                        && !r.doc_id.ends_with(&query_suffix)
+                })
+                .collect();
 
-            let p5 = hits_5 as f32 / 5.0_f32.min(expected_count as f32);
-            let p10 = hits_10 as f32 / 10.0_f32.min(expected_count as f32);
+            let p5 = precision_at_k(&relevance, 5);
+            let p10 = precision_at_k(&relevance, 10);
+            let r10 = recall_at_k(&relevance, expected_count, 10);
 
             total_precision_5 += p5;
             total_precision_10 += p10;
+            total_recall_10 += r10;
+            total_f1_10 += f1_score(p10, r10);
+            total_rr += reciprocal_rank(&relevance);
+            total_ndcg += ndcg_at_k(&relevance, expected_count, 10);
             num_queries += 1;
 
             if num_queries <= 3 {
-                println!(
-                    "  {} ({} funcs): P@5={:.0}%, P@10={:.0}%",
+                out.line(format_args!(
+                    "  {} ({} funcs): P@5={:.0}%, P@10={:.0}%, R@10={:.0}%",
                     file_path,
                     functions.len(),
                     p5 * 100.0,
-                    p10 * 100.0
-                );
+                    p10 * 100.0,
+                    r10 * 100.0
+                ));
             }
         }
     }
 
     if num_queries > 3 {
-        println!("  ... and {} more queries", num_queries - 3);
+        out.line(format_args!("  ... and {} more queries", num_queries - 3));
     }
 
     // Random baseline: chance of hitting same-file function
@@ -420,6 +637,7 @@ fn eval_semantic_co_retrieval(
 
     Ok(EvalResults {
         engine: engine_name.to_string(),
+        dimension: "semantic".to_string(),
         test_name: "code→same-file".to_string(),
         num_queries,
         precision_at_5: if num_queries > 0 {
@@ -432,6 +650,26 @@ fn eval_semantic_co_retrieval(
         } else {
             0.0
         },
+        recall_at_10: if num_queries > 0 {
+            total_recall_10 / num_queries as f32
+        } else {
+            0.0
+        },
+        f1_at_10: if num_queries > 0 {
+            total_f1_10 / num_queries as f32
+        } else {
+            0.0
+        },
+        mrr: if num_queries > 0 {
+            total_rr / num_queries as f32
+        } else {
+            0.0
+        },
+        ndcg_at_10: if num_queries > 0 {
+            total_ndcg / num_queries as f32
+        } else {
+            0.0
+        },
         random_baseline,
     })
 }
@@ -444,7 +682,7 @@ fn eval_semantic_co_retrieval(
 ///
 /// Measures whether the unified pipeline returns meaningful score distributions
 /// for text queries. No precision — just diagnostic.
-fn eval_temporal_text(conn: &Connection, engine: &QueryEngine) -> Result<()> {
+fn eval_temporal_text(conn: &Connection, engine: &QueryEngine, out: Output) -> Result<()> {
     let mut stmt = conn.prepare(
         "SELECT json_extract(data, '$.content') as content
          FROM eventlog
@@ -459,10 +697,10 @@ fn eval_temporal_text(conn: &Connection, engine: &QueryEngine) -> Result<()> {
         queries.push(row.get(0)?);
     }
 
-    println!(
+    out.line(format_args!(
         "Testing {} text queries (score distribution)",
         queries.len()
-    );
+    ));
 
     let mut avg_top_score = 0.0;
     let mut avg_score_variance = 0.0;
@@ -489,12 +727,12 @@ fn eval_temporal_text(conn: &Connection, engine: &QueryEngine) -> Result<()> {
         avg_score_variance /= num_queries as f32;
     }
 
-    println!("  Avg top fused score: {:.4}", avg_top_score);
-    println!(
+    out.line(format_args!("  Avg top fused score: {:.4}", avg_top_score));
+    out.line(format_args!(
         "  Avg score variance: {:.6} (low = results are random-ish)",
         avg_score_variance
-    );
-    println!("  Queries evaluated: {}", num_queries);
+    ));
+    out.line(format_args!("  Queries evaluated: {}", num_queries));
 
     Ok(())
 }
@@ -508,6 +746,7 @@ fn eval_temporal_file(
     conn: &Connection,
     engine: &QueryEngine,
     engine_name: &str,
+    out: Output,
 ) -> Result<EvalResults> {
     // Get files with known co-changes
     let mut stmt = conn.prepare(
@@ -537,24 +776,33 @@ fn eval_temporal_file(
         .take(20)
         .collect();
 
-    println!(
+    out.line(format_args!(
         "Testing {} files with known co-change partners",
         test_files.len()
-    );
+    ));
 
     if test_files.is_empty() {
         return Ok(EvalResults {
             engine: engine_name.to_string(),
+            dimension: "temporal".to_string(),
             test_name: "file→co-change".to_string(),
             num_queries: 0,
             precision_at_5: 0.0,
             precision_at_10: 0.0,
+            recall_at_10: 0.0,
+            f1_at_10: 0.0,
+            mrr: 0.0,
+            ndcg_at_10: 0.0,
             random_baseline: 0.0,
         });
     }
 
     let mut total_precision_5 = 0.0;
     let mut total_precision_10 = 0.0;
+    let mut total_recall_10 = 0.0;
+    let mut total_f1_10 = 0.0;
+    let mut total_rr = 0.0;
+    let mut total_ndcg = 0.0;
     let mut num_queries = 0;
 
     for (file_path, expected_partners) in &test_files {
@@ -573,38 +821,37 @@ fn eval_temporal_file(
                 .map(|p| normalize_path(p))
                 .collect();
 
-            let hits_5 = retrieved_files
-                .iter()
-                .take(5)
-                .filter(|f| normalized_partners.contains(f.as_str()))
-                .count();
-            let hits_10 = retrieved_files
+            let relevance: Vec<bool> = retrieved_files
                 .iter()
-                .take(10)
-                .filter(|f| normalized_partners.contains(f.as_str()))
-                .count();
+                .map(|f| normalized_partners.contains(f.as_str()))
+                .collect();
+            let hits_10 = relevance.iter().take(10).filter(|&&r| r).count();
 
-            let max_possible = normalized_partners.len().min(10);
-            let p5 = hits_5 as f32 / 5.0_f32.min(max_possible as f32);
-            let p10 = hits_10 as f32 / max_possible as f32;
+            let p5 = precision_at_k(&relevance, 5);
+            let p10 = precision_at_k(&relevance, 10);
+            let r10 = recall_at_k(&relevance, normalized_partners.len(), 10);
 
             total_precision_5 += p5;
             total_precision_10 += p10;
+            total_recall_10 += r10;
+            total_f1_10 += f1_score(p10, r10);
+            total_rr += reciprocal_rank(&relevance);
+            total_ndcg += ndcg_at_k(&relevance, normalized_partners.len(), 10);
             num_queries += 1;
 
             if num_queries <= 3 {
-                println!(
+                out.line(format_args!(
                     "  {}: found {}/{} partners in top 10",
                     file_path,
                     hits_10,
                     expected_partners.len().min(10)
-                );
+                ));
             }
         }
     }
 
     if num_queries > 3 {
-        println!("  ... and {} more queries", num_queries - 3);
+        out.line(format_args!("  ... and {} more queries", num_queries - 3));
     }
 
     // Random baseline
@@ -615,6 +862,7 @@ fn eval_temporal_file(
 
     Ok(EvalResults {
         engine: engine_name.to_string(),
+        dimension: "temporal".to_string(),
         test_name: "file→co-change".to_string(),
         num_queries,
         precision_at_5: if num_queries > 0 {
@@ -627,6 +875,26 @@ fn eval_temporal_file(
         } else {
             0.0
         },
+        recall_at_10: if num_queries > 0 {
+            total_recall_10 / num_queries as f32
+        } else {
+            0.0
+        },
+        f1_at_10: if num_queries > 0 {
+            total_f1_10 / num_queries as f32
+        } else {
+            0.0
+        },
+        mrr: if num_queries > 0 {
+            total_rr / num_queries as f32
+        } else {
+            0.0
+        },
+        ndcg_at_10: if num_queries > 0 {
+            total_ndcg / num_queries as f32
+        } else {
+            0.0
+        },
         random_baseline,
     })
 }
@@ -643,6 +911,7 @@ fn eval_belief_self_retrieval(
     conn: &Connection,
     engine: &QueryEngine,
     engine_name: &str,
+    out: Output,
 ) -> Result<BeliefSelfResults> {
     let mut stmt = conn.prepare("SELECT id, statement FROM beliefs ORDER BY id")?;
     let mut beliefs: Vec<(String, String)> = Vec::new();
@@ -653,7 +922,10 @@ fn eval_belief_self_retrieval(
         beliefs.push((id, statement));
     }
 
-    println!("Testing {} beliefs (self-retrieval)", beliefs.len());
+    out.line(format_args!(
+        "Testing {} beliefs (self-retrieval)",
+        beliefs.len()
+    ));
 
     if beliefs.is_empty() {
         return Ok(BeliefSelfResults {
@@ -689,13 +961,13 @@ fn eval_belief_self_retrieval(
                 let rank_str = rank
                     .map(|r| format!("@{}", r))
                     .unwrap_or("miss".to_string());
-                println!("  {} — {}", id, rank_str);
+                out.line(format_args!("  {} — {}", id, rank_str));
             }
         }
     }
 
     if num_queries > 5 {
-        println!("  ... and {} more beliefs", num_queries - 5);
+        out.line(format_args!("  ... and {} more beliefs", num_queries - 5));
     }
 
     let mrr = if num_queries > 0 {
@@ -727,6 +999,7 @@ fn eval_belief_code_co_retrieval(
     conn: &Connection,
     engine: &QueryEngine,
     engine_name: &str,
+    out: Output,
 ) -> Result<BeliefCoResults> {
     // Load beliefs that have code reach entries
     let mut stmt = conn.prepare(
@@ -748,10 +1021,10 @@ fn eval_belief_code_co_retrieval(
         beliefs_with_reach.push((id, statement, files));
     }
 
-    println!(
+    out.line(format_args!(
         "Testing {} beliefs with code reach",
         beliefs_with_reach.len()
-    );
+    ));
 
     if beliefs_with_reach.is_empty() {
         return Ok(BeliefCoResults {
@@ -806,19 +1079,19 @@ fn eval_belief_code_co_retrieval(
 
             if num_queries <= 5 {
                 let bp = if belief_present { "✓" } else { "✗" };
-                println!(
+                out.line(format_args!(
                     "  {} — belief:{} reach:{}/{} files",
                     id,
                     bp,
                     reach_hits,
                     reached_files.len()
-                );
+                ));
             }
         }
     }
 
     if num_queries > 5 {
-        println!("  ... and {} more beliefs", num_queries - 5);
+        out.line(format_args!("  ... and {} more beliefs", num_queries - 5));
     }
 
     let belief_present_rate = if num_queries > 0 {
@@ -846,26 +1119,32 @@ fn eval_belief_code_co_retrieval(
     })
 }
 
-fn print_belief_self_results(results: &BeliefSelfResults) {
-    println!("\nResults ({} beliefs):", results.num_queries);
-    println!("  MRR:       {:.3}", results.mrr);
-    println!("  Hit rate:  {:.1}%", results.hit_rate * 100.0);
+fn print_belief_self_results(results: &BeliefSelfResults, out: Output) {
+    out.line(format_args!("\nResults ({} beliefs):", results.num_queries));
+    out.line(format_args!("  MRR:       {:.3}", results.mrr));
+    out.line(format_args!(
+        "  Hit rate:  {:.1}%",
+        results.hit_rate * 100.0
+    ));
 }
 
-fn print_belief_co_results(results: &BeliefCoResults) {
-    println!(
+fn print_belief_co_results(results: &BeliefCoResults, out: Output) {
+    out.line(format_args!(
         "\nResults ({} beliefs with code reach):",
         results.num_queries
-    );
-    println!(
+    ));
+    out.line(format_args!(
         "  Belief present: {:.1}%",
         results.belief_present_rate * 100.0
-    );
-    println!("  Reach recall:   {:.1}%", results.reach_recall * 100.0);
-    println!(
+    ));
+    out.line(format_args!(
+        "  Reach recall:   {:.1}%",
+        results.reach_recall * 100.0
+    ));
+    out.line(format_args!(
         "  Co-retrieval:   {:.1}% (belief + ≥1 code)",
         results.co_retrieval_rate * 100.0
-    );
+    ));
 }
 
 // ============================================================================
@@ -892,13 +1171,58 @@ fn normalize_path(path: &str) -> String {
     path.strip_prefix("./").unwrap_or(path).to_string()
 }
 
-/// Count results in top-K whose doc_id resolves to the expected file
-fn count_file_hits(results: &[FusedResult], expected_file: &str, k: usize) -> usize {
-    results
+/// Hits in the top k divided by k
+fn precision_at_k(relevance: &[bool], k: usize) -> f32 {
+    if k == 0 {
+        return 0.0;
+    }
+    relevance.iter().take(k).filter(|&&r| r).count() as f32 / k as f32
+}
+
+/// Hits in the top k divided by all relevant docs (0.0 if nothing is relevant)
+fn recall_at_k(relevance: &[bool], num_relevant: usize, k: usize) -> f32 {
+    if num_relevant == 0 {
+        return 0.0;
+    }
+    relevance.iter().take(k).filter(|&&r| r).count() as f32 / num_relevant as f32
+}
+
+/// Harmonic mean of precision and recall
+fn f1_score(precision: f32, recall: f32) -> f32 {
+    if precision + recall > 0.0 {
+        2.0 * precision * recall / (precision + recall)
+    } else {
+        0.0
+    }
+}
+
+/// Reciprocal rank of the first relevant result (0.0 if none)
+fn reciprocal_rank(relevance: &[bool]) -> f32 {
+    relevance
+        .iter()
+        .position(|&r| r)
+        .map(|i| 1.0 / (i + 1) as f32)
+        .unwrap_or(0.0)
+}
+
+/// NDCG@k with binary gains; the ideal ranking puts min(num_relevant, k) hits first
+fn ndcg_at_k(relevance: &[bool], num_relevant: usize, k: usize) -> f32 {
+    let discount = |i: usize| 1.0 / ((i + 2) as f32).log2();
+
+    let dcg: f32 = relevance
         .iter()
         .take(k)
-        .filter(|r| extract_file_from_doc_id(&r.doc_id) == expected_file)
-        .count()
+        .enumerate()
+        .filter(|(_, &r)| r)
+        .map(|(i, _)| discount(i))
+        .sum();
+    let idcg: f32 = (0..num_relevant.min(k)).map(discount).sum();
+
+    if idcg > 0.0 {
+        dcg / idcg
+    } else {
+        0.0
+    }
 }
 
 fn get_file_type(path: &str) -> &'static str {
@@ -913,16 +1237,32 @@ fn get_file_type(path: &str) -> &'static str {
     }
 }
 
-fn print_results(results: &EvalResults) {
-    println!("\nResults ({} queries):", results.num_queries);
-    println!("  Precision@5:  {:.1}%", results.precision_at_5 * 100.0);
-    println!("  Precision@10: {:.1}%", results.precision_at_10 * 100.0);
-    println!("  Random baseline: {:.2}%", results.random_baseline * 100.0);
+fn print_results(results: &EvalResults, out: Output) {
+    out.line(format_args!("\nResults ({} queries):", results.num_queries));
+    out.line(format_args!(
+        "  Precision@5:  {:.1}%",
+        results.precision_at_5 * 100.0
+    ));
+    out.line(format_args!(
+        "  Precision@10: {:.1}%",
+        results.precision_at_10 * 100.0
+    ));
+    out.line(format_args!(
+        "  Recall@10:    {:.1}%",
+        results.recall_at_10 * 100.0
+    ));
+    out.line(format_args!("  F1@10:        {:.3}", results.f1_at_10));
+    out.line(format_args!("  MRR:          {:.3}", results.mrr));
+    out.line(format_args!("  NDCG@10:      {:.3}", results.ndcg_at_10));
+    out.line(format_args!(
+        "  Random baseline: {:.2}%",
+        results.random_baseline * 100.0
+    ));
     if results.random_baseline > 0.0 && results.precision_at_10 > 0.0 {
-        println!(
+        out.line(format_args!(
             "  Improvement: {:.1}x over random",
             results.precision_at_10 / results.random_baseline
-        );
+        ));
     }
 }
 
@@ -1087,3 +1427,148 @@ pub fn execute_feedback() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eval_result(dimension: &str, test_name: &str) -> EvalResults {
+        EvalResults {
+            engine: "unified".to_string(),
+            dimension: dimension.to_string(),
+            test_name: test_name.to_string(),
+            num_queries: 20,
+            precision_at_5: 0.4,
+            precision_at_10: 0.3,
+            recall_at_10: 0.6,
+            f1_at_10: 0.4,
+            mrr: 0.5,
+            ndcg_at_10: 0.45,
+            random_baseline: 0.01,
+        }
+    }
+
+    #[test]
+    fn test_eval_json_one_object_per_dimension() {
+        let results = vec![
+            eval_result("semantic", "code→same-file"),
+            eval_result("temporal", "file→co-change"),
+        ];
+        let json = eval_json(&results, &[], &[], true);
+
+        let entries = json["results"].as_array().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0]["dimension"], "semantic");
+        assert_eq!(entries[1]["dimension"], "temporal");
+        for entry in entries {
+            for field in [
+                "precision_at_5",
+                "precision_at_10",
+                "recall_at_10",
+                "f1_at_10",
+                "mrr",
+                "ndcg_at_10",
+                "random_baseline",
+            ] {
+                assert!(entry[field].is_f64(), "{} should be numeric", field);
+            }
+        }
+        assert_eq!(json["d1_pass"], true);
+    }
+
+    #[test]
+    fn test_precision_recall_separate_when_relevant_exceeds_k() {
+        // 25 relevant docs, all 10 retrieved are hits
+        let relevance = vec![true; 10];
+        assert_eq!(precision_at_k(&relevance, 10), 1.0);
+        assert_eq!(precision_at_k(&relevance, 5), 1.0);
+        assert!((recall_at_k(&relevance, 25, 10) - 0.4).abs() < 1e-6);
+        assert!((f1_score(1.0, 0.4) - 0.8 / 1.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_precision_recall_partial_hits() {
+        // 3 relevant, 2 found at ranks 2 and 7
+        let mut relevance = vec![false; 10];
+        relevance[1] = true;
+        relevance[6] = true;
+        assert!((precision_at_k(&relevance, 10) - 0.2).abs() < 1e-6);
+        assert!((precision_at_k(&relevance, 5) - 0.2).abs() < 1e-6);
+        assert!((recall_at_k(&relevance, 3, 10) - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(recall_at_k(&relevance, 0, 10), 0.0);
+        assert_eq!(f1_score(0.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn test_query_set_metrics_match_hand_computed() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let path = temp.path().join("curated.json");
+        std::fs::write(
+            &path,
+            r#"{
+                "name": "curated",
+                "queries": [
+                    {"id": "q1", "query": "fusion", "relevant_docs": ["src/retrieval/fusion.rs", "src/retrieval/engine.rs"]},
+                    {"id": "q2", "query": "oxidize", "relevant_docs": ["src/commands/oxidize/mod.rs"]},
+                    {"id": "q3", "query": "no truth"}
+                ]
+            }"#,
+        )?;
+        let query_set = QuerySet::load(&path)?;
+
+        let results = eval_query_set(&query_set, "unified", "all", |q| {
+            Ok(match q {
+                // Hits at ranks 2 and 3; second fusion chunk is not a new hit
+                "fusion" => vec![
+                    "src/main.rs".to_string(),
+                    "src/retrieval/fusion.rs:10".to_string(),
+                    "src/retrieval/engine.rs:3".to_string(),
+                    "src/retrieval/fusion.rs:90".to_string(),
+                ],
+                // No hits
+                "oxidize" => vec!["src/lib.rs".to_string()],
+                _ => panic!("query without ground truth should be skipped"),
+            })
+        })?;
+
+        assert_eq!(results.num_queries, 2);
+        assert_eq!(results.test_name, "query-set:curated");
+        // q1: P@5 = 2/5, P@10 = 2/10, R@10 = 1, RR = 1/2; q2: all zero
+        assert!((results.precision_at_5 - 0.2).abs() < 1e-6);
+        assert!((results.precision_at_10 - 0.1).abs() < 1e-6);
+        assert!((results.recall_at_10 - 0.5).abs() < 1e-6);
+        assert!((results.mrr - 0.25).abs() < 1e-6);
+        let f1_q1 = 2.0 * 0.2 * 1.0 / 1.2;
+        assert!((results.f1_at_10 - f1_q1 / 2.0).abs() < 1e-6);
+        let ndcg_q1 = (1.0 / 3f32.log2() + 0.5) / (1.0 + 1.0 / 3f32.log2());
+        assert!((results.ndcg_at_10 - ndcg_q1 / 2.0).abs() < 1e-6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reciprocal_rank() {
+        assert_eq!(reciprocal_rank(&[true, false, true]), 1.0);
+        assert_eq!(reciprocal_rank(&[false, false, false, true]), 0.25);
+        assert_eq!(reciprocal_rank(&[false, false]), 0.0);
+        assert_eq!(reciprocal_rank(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_ndcg_at_k() {
+        // Perfect ranking
+        assert!((ndcg_at_k(&[true, true, false], 2, 10) - 1.0).abs() < 1e-6);
+
+        // Single relevant doc at rank 2: (1/log2(3)) / 1
+        let expected = 1.0 / 3.0_f32.log2();
+        assert!((ndcg_at_k(&[false, true], 1, 10) - expected).abs() < 1e-6);
+
+        // Hits at ranks 1 and 3 of 2 relevant: (1 + 1/log2(4)) / (1 + 1/log2(3))
+        let expected = (1.0 + 0.5) / (1.0 + 1.0 / 3.0_f32.log2());
+        assert!((ndcg_at_k(&[true, false, true], 2, 10) - expected).abs() < 1e-6);
+
+        // Hits beyond k don't count; nothing relevant scores zero
+        assert_eq!(ndcg_at_k(&[false, true], 1, 1), 0.0);
+        assert_eq!(ndcg_at_k(&[false, false], 0, 10), 0.0);
+    }
+}
@@ -0,0 +1,114 @@
+//! Process exit codes
+//!
+//! Commands return `anyhow::Result`, which on its own only tells a script
+//! "worked" or "didn't". A failure class rides inside the error instead - an
+//! [`Exit`], or a typed error that maps to one like `ScryError` - and `main`
+//! turns it into the process status listed in [`HELP`].
+
+use anyhow::Result;
+use std::fmt;
+use std::path::Path;
+
+use super::scry::internal::error::ScryError;
+
+/// Generic failure (anything without a more specific class)
+pub const FAILURE: i32 = 1;
+
+/// Exit codes, for `--help`
+pub const HELP: &str = "\
+Exit codes:
+  0  success
+  1  error
+  2  invalid arguments
+  3  no results
+  4  index or database missing (run `patina scrape` / `patina oxidize`)
+  5  authentication failure";
+
+/// Failure classes scripts can tell apart
+///
+/// Codes start at 3: clap already exits with 2 on a usage error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exit {
+    /// The command ran but matched nothing
+    NoResults,
+    /// The database or an index it needs hasn't been built
+    MissingIndex,
+    /// A forge or service rejected (or lacked) credentials
+    Auth,
+}
+
+impl Exit {
+    pub fn code(self) -> i32 {
+        match self {
+            Exit::NoResults => 3,
+            Exit::MissingIndex => 4,
+            Exit::Auth => 5,
+        }
+    }
+}
+
+impl fmt::Display for Exit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Exit::NoResults => write!(f, "no results"),
+            Exit::MissingIndex => write!(f, "index or database missing"),
+            Exit::Auth => write!(f, "authentication failed"),
+        }
+    }
+}
+
+impl std::error::Error for Exit {}
+
+/// Exit code for any error: the first classified cause in its chain, else 1
+pub fn exit_code(error: &anyhow::Error) -> i32 {
+    error
+        .chain()
+        .find_map(|cause| {
+            cause
+                .downcast_ref::<Exit>()
+                .map(|exit| exit.code())
+                .or_else(|| cause.downcast_ref::<ScryError>().map(ScryError::exit_code))
+        })
+        .unwrap_or(FAILURE)
+}
+
+/// Fail with `Exit::MissingIndex` unless the database at `path` exists
+///
+/// `Connection::open` would silently create an empty one.
+pub fn require_db(path: &Path) -> Result<()> {
+    if path.exists() {
+        return Ok(());
+    }
+    Err(anyhow::Error::new(Exit::MissingIndex).context(format!(
+        "Database not found: {}. Run 'patina scrape' first.",
+        path.display()
+    )))
+}
+
+/// `Exit::NoResults` when `count` is zero (the command already said so)
+pub fn ensure_found(count: usize) -> Result<()> {
+    if count == 0 {
+        return Err(Exit::NoResults.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classified_cause_survives_context() {
+        let err = anyhow::Error::new(Exit::Auth).context("gh not logged in");
+        assert_eq!(exit_code(&err), 5);
+        assert_eq!(exit_code(&anyhow::anyhow!("boom")), FAILURE);
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let err = require_db(&temp.path().join("patina.db")).unwrap_err();
+        assert_eq!(exit_code(&err), 4);
+        assert!(err.to_string().starts_with("Database not found"));
+
+        assert_eq!(exit_code(&ensure_found(0).unwrap_err()), 3);
+        assert!(ensure_found(3).is_ok());
+    }
+}
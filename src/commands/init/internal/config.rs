@@ -73,8 +73,22 @@ pub fn create_project_config(
         embeddings: EmbeddingsSection {
             model: "e5-base-v2".to_string(),
         },
-        search: SearchSection::default(),
+        // Preserve project stop words on re-init
+        search: SearchSection {
+            stop_words: existing_config
+                .as_ref()
+                .map(|c| c.search.stop_words.clone())
+                .unwrap_or_default(),
+            ..SearchSection::default()
+        },
         retrieval: RetrievalSection::default(),
+        // Preserve tuned scrape limits on re-init
+        scrape: existing_config
+            .as_ref()
+            .map(|c| c.scrape.clone())
+            .unwrap_or_default(),
+        // Preserve team scry defaults on re-init
+        scry: existing_config.as_ref().and_then(|c| c.scry.clone()),
         // Always refresh environment detection
         environment: Some(EnvironmentSection {
             os: environment.os.clone(),
@@ -117,6 +131,7 @@ projections:
     layers: [768, 1024, 256]
     epochs: 10
     batch_size: 32
+    half_life_days: 180  # older co-changes count for less
 
   # Dependency projection - functions that call each other are related
   dependency:
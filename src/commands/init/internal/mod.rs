@@ -407,6 +407,7 @@ build/
 
 # Patina local state (derived, not committed)
 .patina/local/
+.patina/cache/
 ENVIRONMENT.toml
 
 # Temporary files
@@ -582,6 +583,10 @@ fn ensure_gitignore_entries(gitignore_path: &Path) -> Result<()> {
             ".patina/local/",
             "Patina local state (derived, not committed)",
         ),
+        (
+            ".patina/cache/",
+            "Patina fetch cache (derived, not committed)",
+        ),
         ("*.db", "Database files"),
         ("*.key", "Private keys"),
         ("*.pem", "Certificates"),
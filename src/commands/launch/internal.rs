@@ -187,7 +187,10 @@ fn resolve_project_path(path_opt: Option<&str>) -> Result<PathBuf> {
     Ok(canonical)
 }
 
-/// Check if mother is running via UDS health endpoint
+/// Check if mother is running via UDS
+///
+/// Uses `/version` for liveness; `/health` reports readiness and answers 503
+/// when the mother is up but has no indices yet.
 pub fn check_mother_health() -> bool {
     let sock_path = paths::serve::socket_path();
     let mut stream = match std::os::unix::net::UnixStream::connect(&sock_path) {
@@ -196,7 +199,7 @@ pub fn check_mother_health() -> bool {
     };
     let _ = stream.set_read_timeout(Some(Duration::from_secs(2)));
 
-    let request = "GET /health HTTP/1.1\r\nHost: localhost\r\n\r\n";
+    let request = "GET /version HTTP/1.1\r\nHost: localhost\r\n\r\n";
     if stream.write_all(request.as_bytes()).is_err() {
         return false;
     }
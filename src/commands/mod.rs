@@ -5,10 +5,12 @@ pub mod bench;
 pub mod context;
 pub mod doctor;
 pub mod eval;
+pub mod exit;
 pub mod init;
 pub mod launch;
 pub mod model;
 pub mod mother;
+pub mod output;
 pub mod oxidize;
 pub mod persona;
 pub mod rebuild;
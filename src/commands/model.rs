@@ -122,7 +122,7 @@ fn remove(name: &str) -> Result<()> {
     }
 
     // Get size for display
-    let size = dir_size(&model_dir)?;
+    let size = patina::paths::dir_size(&model_dir)?;
     let size_mb = size / (1024 * 1024);
 
     println!("Remove '{}' from cache? ({} MB)", name, size_mb);
@@ -194,18 +194,3 @@ fn status() -> Result<()> {
 
     Ok(())
 }
-
-/// Calculate directory size
-fn dir_size(path: &std::path::Path) -> Result<u64> {
-    let mut size = 0;
-    if path.is_dir() {
-        for entry in std::fs::read_dir(path)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_file() {
-                size += std::fs::metadata(&path)?.len();
-            }
-        }
-    }
-    Ok(size)
-}
@@ -4,6 +4,7 @@
 //! - Container queries to Mac mother
 //! - Hot model caching (E5 embeddings)
 //! - Cross-project knowledge access
+//! - Per-endpoint request metrics (`GET /metrics`)
 //!
 //! Design: Blocking HTTP microserver (no async/tokio)
 //!
@@ -16,11 +17,15 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{Read, Write};
 use std::net::{Shutdown, TcpListener};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
+use super::metrics::Metrics;
 use super::microserver;
-use crate::retrieval::{QueryEngine, QueryOptions};
+use crate::commands::assay::{self, AssayOptions, QueryType};
+use crate::commands::scry::internal::error::http_status;
+use crate::retrieval::{QueryEngine, QueryOptions, RetrievalConfig};
 
 /// Maximum request body size (1 MB)
 const MAX_BODY_SIZE: usize = 1_048_576;
@@ -28,6 +33,12 @@ const MAX_BODY_SIZE: usize = 1_048_576;
 /// Maximum results per query
 const MAX_LIMIT: usize = 1000;
 
+/// Maximum queries per batch request
+const MAX_BATCH: usize = 100;
+
+/// Dimension indices reported by /health
+const DIMENSIONS: [&str; 3] = ["semantic", "temporal", "dependency"];
+
 // === Transport-free request/response types ===
 
 /// HTTP request independent of transport
@@ -87,6 +98,7 @@ pub struct ServerState {
     version: String,
     token: String,
     secrets_cache: Mutex<Option<SecretsCacheEntry>>,
+    metrics: Metrics,
 }
 
 impl ServerState {
@@ -96,6 +108,7 @@ impl ServerState {
             version: env!("CARGO_PKG_VERSION").to_string(),
             token,
             secrets_cache: Mutex::new(None),
+            metrics: Metrics::default(),
         }
     }
 
@@ -109,9 +122,14 @@ impl ServerState {
 /// Health check response
 #[derive(Serialize)]
 struct HealthResponse {
+    /// "ok" when at least one index is on disk, "no_indices" otherwise
     status: String,
     version: String,
     uptime_secs: u64,
+    /// Embedding model the daemon's project is configured for
+    model: String,
+    /// Dimension indices present for that model
+    indices: Vec<String>,
 }
 
 /// Scry API request
@@ -131,6 +149,12 @@ fn default_limit() -> usize {
     10
 }
 
+/// Batch scry API request — many queries, one round trip
+#[derive(Deserialize)]
+struct ScryBatchRequest {
+    queries: Vec<ScryRequest>,
+}
+
 /// Scry API response
 #[derive(Serialize)]
 struct ScryResponse {
@@ -138,6 +162,23 @@ struct ScryResponse {
     count: usize,
 }
 
+/// Assay API request
+#[derive(Deserialize)]
+struct AssayRequest {
+    query_type: String,
+    pattern: Option<String>,
+    #[serde(default)]
+    limit: usize,
+    repo: Option<String>,
+}
+
+/// Assay API response
+#[derive(Serialize)]
+struct AssayResponse {
+    query_type: String,
+    result: serde_json::Value,
+}
+
 /// Single result in JSON format
 #[derive(Serialize)]
 struct ScryResultJson {
@@ -180,28 +221,80 @@ fn json_error(status: u16, message: &str) -> HttpResponse {
 
 // === Transport-free handlers ===
 
-/// Route request to handler
+/// Route request to handler, recording its latency under "METHOD /path"
 fn route_request(request: &HttpRequest, state: &ServerState, require_auth: bool) -> HttpResponse {
+    let started = Instant::now();
     let response = match (request.method.as_str(), request.path.as_str()) {
-        ("GET", "/health") => handle_health(state),
+        ("GET", "/metrics") => return with_security_headers(handle_metrics(state)),
+        ("GET", "/health") => handle_health(state, Path::new(".")),
         ("GET", "/version") => handle_version(state),
-        ("POST", "/api/scry") => handle_scry(request, state, require_auth),
+        ("POST", "/api/scry") => handle_scry(request, state, require_auth, |query| {
+            run_scry(&QueryEngine::new(), query)
+        }),
+        ("POST", "/api/scry/batch") => {
+            handle_project_scry_batch(request, state, require_auth, Path::new("."))
+        }
+        ("POST", "/api/assay") => {
+            handle_assay(request, state, require_auth, Path::new(assay::DB_PATH))
+        }
         ("GET", "/secrets/cache") => handle_secrets_get(request, state, require_auth),
         ("POST", "/secrets/cache") => handle_secrets_cache(request, state, require_auth),
         ("POST", "/secrets/lock") => handle_secrets_lock(request, state, require_auth),
-        _ => json_error(404, "Not found"),
+        // Unknown paths are not metered, so callers can't grow the endpoint map
+        _ => return with_security_headers(json_error(404, "Not found")),
     };
+    let endpoint = format!("{} {}", request.method, request.path);
+    state
+        .metrics
+        .record(&endpoint, started.elapsed(), response.status);
     with_security_headers(response)
 }
 
-/// Handle GET /health
-fn handle_health(state: &ServerState) -> HttpResponse {
+/// Handle GET /metrics
+fn handle_metrics(state: &ServerState) -> HttpResponse {
     HttpResponse::json(
         200,
+        &serde_json::json!({
+            "uptime_secs": state.uptime_secs(),
+            "endpoints": state.metrics.summary(),
+        }),
+    )
+}
+
+/// Handle GET /health
+///
+/// Doubles as a readiness probe: 503 until some dimension index exists, so
+/// containers can tell "daemon up" apart from "daemon can answer queries".
+fn handle_health(state: &ServerState, project_root: &Path) -> HttpResponse {
+    let model = patina::project::load(project_root)
+        .ok()
+        .map(|c| c.embeddings.model)
+        .unwrap_or_else(|| "e5-base-v2".to_string());
+
+    let projections = project_root
+        .join(".patina/local/data/embeddings")
+        .join(&model)
+        .join("projections");
+    let indices: Vec<String> = DIMENSIONS
+        .iter()
+        .filter(|dim| projections.join(format!("{}.usearch", dim)).exists())
+        .map(|dim| dim.to_string())
+        .collect();
+
+    let (status, label) = if indices.is_empty() {
+        (503, "no_indices")
+    } else {
+        (200, "ok")
+    };
+
+    HttpResponse::json(
+        status,
         &HealthResponse {
-            status: "ok".to_string(),
+            status: label.to_string(),
             version: state.version.clone(),
             uptime_secs: state.uptime_secs(),
+            model,
+            indices,
         },
     )
 }
@@ -218,7 +311,14 @@ fn handle_version(state: &ServerState) -> HttpResponse {
 }
 
 /// Handle POST /api/scry
-fn handle_scry(request: &HttpRequest, state: &ServerState, require_auth: bool) -> HttpResponse {
+///
+/// `run` executes the query; a `ScryError` inside its error picks the status.
+fn handle_scry(
+    request: &HttpRequest,
+    state: &ServerState,
+    require_auth: bool,
+    run: impl FnOnce(ScryRequest) -> Result<ScryResponse>,
+) -> HttpResponse {
     if require_auth && !check_auth(request, &state.token) {
         return json_error(401, "Unauthorized");
     }
@@ -227,42 +327,172 @@ fn handle_scry(request: &HttpRequest, state: &ServerState, require_auth: bool) -
         return json_error(400, "Missing request body");
     }
 
-    let mut body: ScryRequest = match serde_json::from_slice(&request.body) {
+    let body: ScryRequest = match serde_json::from_slice(&request.body) {
         Ok(req) => req,
         Err(e) => return json_error(400, &format!("Invalid JSON: {}", e)),
     };
 
+    match run(body) {
+        Ok(response) => HttpResponse::json(200, &response),
+        Err(e) => json_error(http_status(&e), &format!("Scry failed: {}", e)),
+    }
+}
+
+/// Handle POST /api/scry/batch
+///
+/// `run` executes a single query; responses are aligned with request order.
+fn handle_scry_batch(
+    request: &HttpRequest,
+    state: &ServerState,
+    require_auth: bool,
+    mut run: impl FnMut(ScryRequest) -> Result<ScryResponse>,
+) -> HttpResponse {
+    if require_auth && !check_auth(request, &state.token) {
+        return json_error(401, "Unauthorized");
+    }
+
+    if request.body.is_empty() {
+        return json_error(400, "Missing request body");
+    }
+
+    let body: ScryBatchRequest = match serde_json::from_slice(&request.body) {
+        Ok(req) => req,
+        Err(e) => return json_error(400, &format!("Invalid JSON: {}", e)),
+    };
+
+    if body.queries.len() > MAX_BATCH {
+        return json_error(400, &format!("Batch too large (max {} queries)", MAX_BATCH));
+    }
+
+    let mut responses = Vec::with_capacity(body.queries.len());
+    for (i, query) in body.queries.into_iter().enumerate() {
+        match run(query) {
+            Ok(response) => responses.push(response),
+            Err(e) => {
+                return json_error(
+                    http_status(&e),
+                    &format!("Scry failed for query {}: {}", i, e),
+                )
+            }
+        }
+    }
+
+    HttpResponse::json(200, &responses)
+}
+
+/// Handle POST /api/scry/batch with the real engine over `project_root`
+fn handle_project_scry_batch(
+    request: &HttpRequest,
+    state: &ServerState,
+    require_auth: bool,
+    project_root: &Path,
+) -> HttpResponse {
+    // One engine for the whole batch: oracles and embedder are reused
+    let engine = QueryEngine::in_project(project_root, RetrievalConfig::default());
+    handle_scry_batch(request, state, require_auth, |query| {
+        run_scry(&engine, query)
+    })
+}
+
+/// Run one scry query against an engine
+fn run_scry(engine: &QueryEngine, mut body: ScryRequest) -> Result<ScryResponse> {
     body.limit = body.limit.min(MAX_LIMIT);
 
-    let engine = QueryEngine::new();
     let query_opts = QueryOptions {
         repo: body.repo,
         all_repos: body.all_repos,
         include_issues: body.include_issues,
+        ..Default::default()
     };
 
-    match engine.query_with_options(&body.query, body.limit, &query_opts) {
-        Ok(results) => {
-            let json_results: Vec<ScryResultJson> = results
-                .into_iter()
-                .map(|r| ScryResultJson {
-                    id: 0,
-                    content: r.content,
-                    score: r.fused_score,
-                    event_type: r.sources.join("+"),
-                    source_id: r.doc_id,
-                    timestamp: r.metadata.timestamp.unwrap_or_default(),
-                })
-                .collect();
-
-            let response = ScryResponse {
-                count: json_results.len(),
-                results: json_results,
-            };
-
-            HttpResponse::json(200, &response)
-        }
-        Err(e) => json_error(500, &format!("Scry failed: {}", e)),
+    let results: Vec<ScryResultJson> = engine
+        .query_with_options(&body.query, body.limit, &query_opts)?
+        .into_iter()
+        .map(|r| ScryResultJson {
+            id: 0,
+            content: r.content,
+            score: r.fused_score,
+            event_type: r.sources.join("+"),
+            source_id: r.doc_id,
+            timestamp: r.metadata.timestamp.unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(ScryResponse {
+        count: results.len(),
+        results,
+    })
+}
+
+/// Handle POST /api/assay
+///
+/// Runs the local assay queries against `default_db` (or a registered repo's DB).
+fn handle_assay(
+    request: &HttpRequest,
+    state: &ServerState,
+    require_auth: bool,
+    default_db: &Path,
+) -> HttpResponse {
+    if require_auth && !check_auth(request, &state.token) {
+        return json_error(401, "Unauthorized");
+    }
+
+    if request.body.is_empty() {
+        return json_error(400, "Missing request body");
+    }
+
+    let body: AssayRequest = match serde_json::from_slice(&request.body) {
+        Ok(req) => req,
+        Err(e) => return json_error(400, &format!("Invalid JSON: {}", e)),
+    };
+
+    let Some(query_type) = QueryType::remote(&body.query_type) else {
+        return json_error(
+            400,
+            &format!("Unsupported assay query: {}", body.query_type),
+        );
+    };
+
+    let db_path = match &body.repo {
+        Some(name) => match crate::commands::repo::get_db_path(name) {
+            Ok(path) => std::path::PathBuf::from(path),
+            Err(e) => return json_error(404, &format!("{}", e)),
+        },
+        None => default_db.to_path_buf(),
+    };
+    // Connection::open would create an empty DB; report the real problem instead
+    if !db_path.exists() {
+        return json_error(404, "Database not found");
+    }
+
+    let options = AssayOptions {
+        query_type,
+        pattern: body.pattern,
+        limit: body.limit.min(MAX_LIMIT),
+        json: true,
+        repo: body.repo,
+        all_repos: false,
+        fuzzy: false,
+        min_lines: 0,
+        unsafe_only: false,
+        const_only: false,
+        lang: None,
+        resolve: false,
+        since: None,
+    };
+
+    let result = rusqlite::Connection::open(&db_path)
+        .map_err(anyhow::Error::from)
+        .and_then(|conn| assay::query_json(&conn, &options));
+    match result {
+        Ok(result) => HttpResponse::json(
+            200,
+            &AssayResponse {
+                query_type: body.query_type,
+                result,
+            },
+        ),
+        Err(e) => json_error(500, &format!("Assay failed: {}", e)),
     }
 }
 
@@ -534,3 +764,304 @@ extern "C" fn sigint_handler(_: libc::c_int) {
     super::cleanup_socket();
     std::process::exit(0);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(path: &str, body: &str) -> HttpRequest {
+        HttpRequest {
+            method: "POST".to_string(),
+            path: path.to_string(),
+            headers: vec![],
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    /// Fake query runner: up to two results (capped by limit) echoing the query text
+    fn echo(query: ScryRequest) -> Result<ScryResponse> {
+        let results: Vec<ScryResultJson> = (0..query.limit.min(2))
+            .map(|i| ScryResultJson {
+                id: i as i64,
+                content: format!("{} #{}", query.query, i),
+                score: 1.0,
+                event_type: "semantic".to_string(),
+                source_id: format!("src/{}.rs", query.query),
+                timestamp: String::new(),
+            })
+            .collect();
+        Ok(ScryResponse {
+            count: results.len(),
+            results,
+        })
+    }
+
+    #[test]
+    fn test_scry_batch_returns_aligned_results() {
+        let state = ServerState::new(String::new());
+        let request = post(
+            "/api/scry/batch",
+            r#"{"queries": [{"query": "alpha", "limit": 1}, {"query": "beta"}]}"#,
+        );
+
+        let response = handle_scry_batch(&request, &state, false, echo);
+        assert_eq!(response.status, 200);
+
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        let responses = body.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["count"], 1);
+        assert_eq!(responses[0]["results"][0]["source_id"], "src/alpha.rs");
+        assert_eq!(responses[1]["count"], 2);
+        assert_eq!(responses[1]["results"][0]["source_id"], "src/beta.rs");
+    }
+
+    #[test]
+    fn test_scry_batch_queries_project_database() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let data_dir = temp.path().join(".patina/local/data");
+        std::fs::create_dir_all(&data_dir).unwrap();
+        let conn = rusqlite::Connection::open(data_dir.join("patina.db")).unwrap();
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE code_fts USING fts5(
+                symbol_name, file_path, content, event_type,
+                tokenize='porter unicode61'
+            );
+            INSERT INTO code_fts VALUES (
+                'rrf_fuse', 'src/retrieval/fusion.rs::rrf_fuse',
+                'Reciprocal rank fusion of oracle results', 'code.function'
+            ), (
+                'handle_scry_batch', 'src/commands/mother/daemon.rs::handle_scry_batch',
+                'Run every query in a batch request', 'code.function'
+            );",
+        )
+        .unwrap();
+        drop(conn);
+
+        let state = ServerState::new(String::new());
+        let request = post(
+            "/api/scry/batch",
+            r#"{"queries": [{"query": "rrf_fuse"}, {"query": "handle_scry_batch"}]}"#,
+        );
+        let response = handle_project_scry_batch(&request, &state, false, temp.path());
+        assert_eq!(response.status, 200);
+
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        let responses = body.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        let source_ids = |i: usize| -> Vec<String> {
+            responses[i]["results"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|r| r["source_id"].as_str().unwrap().to_string())
+                .collect()
+        };
+        assert!(source_ids(0).contains(&"src/retrieval/fusion.rs::rrf_fuse".to_string()));
+        assert!(
+            source_ids(1).contains(&"src/commands/mother/daemon.rs::handle_scry_batch".to_string())
+        );
+    }
+
+    fn with_auth(mut request: HttpRequest, value: &str) -> HttpRequest {
+        request
+            .headers
+            .push(("authorization".to_string(), value.to_string()));
+        request
+    }
+
+    #[test]
+    fn test_bearer_token_auth() {
+        let state = ServerState::new("secret".to_string());
+        let body = r#"{"queries": [{"query": "alpha"}]}"#;
+
+        // Accepted (header name is case-insensitive)
+        let request = with_auth(post("/api/scry/batch", body), "Bearer secret");
+        assert_eq!(handle_scry_batch(&request, &state, true, echo).status, 200);
+
+        // Missing
+        let request = post("/api/scry/batch", body);
+        assert_eq!(handle_scry_batch(&request, &state, true, echo).status, 401);
+
+        // Wrong token, or right token without the Bearer scheme
+        for value in ["Bearer nope", "secret"] {
+            let request = with_auth(post("/api/scry/batch", body), value);
+            assert_eq!(handle_scry_batch(&request, &state, true, echo).status, 401);
+        }
+
+        // UDS transport skips auth: file permissions are the gate
+        let request = post("/api/scry/batch", body);
+        assert_eq!(handle_scry_batch(&request, &state, false, echo).status, 200);
+    }
+
+    #[test]
+    fn test_routed_endpoints_require_auth_over_tcp() {
+        let state = ServerState::new("secret".to_string());
+        for path in ["/api/scry", "/api/assay", "/secrets/cache", "/secrets/lock"] {
+            let response = route_request(&post(path, "{}"), &state, true);
+            assert_eq!(response.status, 401, "{} should require auth", path);
+        }
+
+        // Health stays open for liveness probes
+        let request = HttpRequest {
+            method: "GET".to_string(),
+            path: "/health".to_string(),
+            headers: vec![],
+            body: vec![],
+        };
+        assert_ne!(route_request(&request, &state, true).status, 401);
+    }
+
+    #[test]
+    fn test_assay_inventory_returns_modules() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("patina.db");
+        let conn = rusqlite::Connection::open(&db_path).unwrap();
+        conn.execute_batch(
+            "CREATE TABLE index_state (path TEXT, line_count INTEGER, size INTEGER);
+             CREATE TABLE function_facts (file TEXT, name TEXT);
+             CREATE TABLE import_facts (file TEXT, import_path TEXT);
+             INSERT INTO index_state VALUES ('src/main.rs', 120, 4000), ('src/lib.rs', 30, 900);
+             INSERT INTO function_facts VALUES ('src/main.rs', 'main'), ('src/main.rs', 'run');",
+        )
+        .unwrap();
+        drop(conn);
+
+        let state = ServerState::new(String::new());
+        let request = post("/api/assay", r#"{"query_type": "inventory"}"#);
+        let response = handle_assay(&request, &state, false, &db_path);
+        assert_eq!(response.status, 200);
+
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(body["query_type"], "inventory");
+        let modules = body["result"]["modules"].as_array().unwrap();
+        assert_eq!(modules.len(), 2);
+        assert_eq!(modules[0]["path"], "src/main.rs");
+        assert_eq!(modules[0]["functions"], 2);
+        assert_eq!(body["result"]["summary"]["total_lines"], 150);
+
+        // Unsupported types and missing databases are reported, not guessed at
+        let request = post("/api/assay", r#"{"query_type": "callers"}"#);
+        assert_eq!(handle_assay(&request, &state, false, &db_path).status, 400);
+        let missing = temp.path().join("missing.db");
+        let request = post("/api/assay", r#"{"query_type": "inventory"}"#);
+        assert_eq!(handle_assay(&request, &state, false, &missing).status, 404);
+        assert!(!missing.exists());
+    }
+
+    #[test]
+    fn test_health_reports_model_and_indices() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let state = ServerState::new(String::new());
+
+        // No indices yet: reachable but not ready
+        let response = handle_health(&state, temp.path());
+        assert_eq!(response.status, 503);
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(body["status"], "no_indices");
+        assert_eq!(body["model"], "e5-base-v2");
+        assert_eq!(body["indices"].as_array().unwrap().len(), 0);
+        assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+
+        let projections = temp
+            .path()
+            .join(".patina/local/data/embeddings/e5-base-v2/projections");
+        std::fs::create_dir_all(&projections).unwrap();
+        std::fs::write(projections.join("temporal.usearch"), b"").unwrap();
+
+        let response = handle_health(&state, temp.path());
+        assert_eq!(response.status, 200);
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        assert_eq!(body["status"], "ok");
+        assert_eq!(body["indices"], serde_json::json!(["temporal"]));
+        assert!(body["uptime_secs"].is_u64());
+    }
+
+    #[test]
+    fn test_metrics_report_requests_and_latency() {
+        let state = ServerState::new(String::new());
+        for _ in 0..3 {
+            assert_eq!(
+                route_request(&post("/api/scry", ""), &state, false).status,
+                400
+            );
+        }
+        let version = HttpRequest {
+            method: "GET".to_string(),
+            ..post("/version", "")
+        };
+        route_request(&version, &state, false);
+        route_request(&post("/nope", ""), &state, false);
+
+        let metrics = HttpRequest {
+            method: "GET".to_string(),
+            ..post("/metrics", "")
+        };
+        let response = route_request(&metrics, &state, false);
+        assert_eq!(response.status, 200);
+        let body: serde_json::Value = serde_json::from_slice(&response.body).unwrap();
+        let endpoints = body["endpoints"].as_object().unwrap();
+        assert_eq!(
+            endpoints.keys().collect::<Vec<_>>(),
+            vec!["GET /version", "POST /api/scry"]
+        );
+
+        let scry = &endpoints["POST /api/scry"];
+        assert_eq!(scry["requests"], 3);
+        assert_eq!(scry["errors"], 3);
+        assert_eq!(scry["error_rate"], 1.0);
+        assert_eq!(scry["latency"]["samples"], 3);
+        assert!(scry["latency"]["p95_ms"].as_f64().unwrap() >= 0.0);
+        assert_eq!(endpoints["GET /version"]["errors"], 0);
+    }
+
+    #[test]
+    fn test_scry_batch_rejects_bad_requests() {
+        let state = ServerState::new("secret".to_string());
+
+        let request = post("/api/scry/batch", r#"{"queries": []}"#);
+        assert_eq!(handle_scry_batch(&request, &state, true, echo).status, 401);
+
+        let request = post("/api/scry/batch", "not json");
+        assert_eq!(handle_scry_batch(&request, &state, false, echo).status, 400);
+
+        let queries = vec![r#"{"query": "q"}"#; MAX_BATCH + 1].join(",");
+        let request = post(
+            "/api/scry/batch",
+            &format!(r#"{{"queries": [{}]}}"#, queries),
+        );
+        assert_eq!(handle_scry_batch(&request, &state, false, echo).status, 400);
+    }
+
+    #[test]
+    fn test_scry_status_follows_typed_error() {
+        use crate::commands::scry::internal::error::ScryError;
+
+        let state = ServerState::new(String::new());
+        let request = post("/api/scry", r#"{"query": "alpha"}"#);
+        let status = |err: fn() -> anyhow::Error| {
+            let response = handle_scry(&request, &state, false, |_| Err(err()));
+            (response.status, String::from_utf8(response.body).unwrap())
+        };
+
+        let (code, body) = status(|| {
+            ScryError::IndexMissing {
+                dimension: "semantic".to_string(),
+                path: "semantic.usearch".to_string(),
+            }
+            .into()
+        });
+        assert_eq!(code, 503);
+        assert!(body.contains("patina oxidize"));
+        assert_eq!(
+            status(|| ScryError::EmbedFailed(anyhow::anyhow!("no model")).into()).0,
+            500
+        );
+        assert_eq!(
+            status(|| ScryError::UnknownDimension("spatial".to_string()).into()).0,
+            400
+        );
+        assert_eq!(status(|| anyhow::anyhow!("disk on fire")).0, 500);
+        assert_eq!(handle_scry(&request, &state, false, echo).status, 200);
+    }
+}
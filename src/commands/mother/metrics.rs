@@ -0,0 +1,121 @@
+//! In-memory request metrics for the mother daemon
+//!
+//! Per-endpoint request and error counts plus a rolling window of recent
+//! latencies, served as JSON at `GET /metrics`. Nothing is persisted: counts
+//! reset with the daemon, and the window caps memory on a long-lived server.
+
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Latency samples kept per endpoint for percentiles
+const WINDOW: usize = 1024;
+
+/// Counters for one endpoint
+#[derive(Debug, Default)]
+struct EndpointStats {
+    requests: u64,
+    errors: u64,
+    /// Most recent latencies in milliseconds, oldest first
+    latencies_ms: VecDeque<f64>,
+}
+
+/// Request metrics shared by all connection threads
+#[derive(Debug, Default)]
+pub struct Metrics {
+    endpoints: Mutex<BTreeMap<String, EndpointStats>>,
+}
+
+/// Latency summary over the rolling window
+#[derive(Debug, Serialize)]
+pub struct LatencySummary {
+    pub samples: usize,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+/// What `GET /metrics` reports for one endpoint
+#[derive(Debug, Serialize)]
+pub struct EndpointSummary {
+    pub requests: u64,
+    /// Responses with a 4xx/5xx status
+    pub errors: u64,
+    pub error_rate: f64,
+    pub latency: LatencySummary,
+}
+
+impl Metrics {
+    /// Record one handled request (`endpoint` is e.g. "POST /api/scry")
+    pub fn record(&self, endpoint: &str, elapsed: Duration, status: u16) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = endpoints.entry(endpoint.to_string()).or_default();
+        stats.requests += 1;
+        if status >= 400 {
+            stats.errors += 1;
+        }
+        if stats.latencies_ms.len() == WINDOW {
+            stats.latencies_ms.pop_front();
+        }
+        stats.latencies_ms.push_back(elapsed.as_secs_f64() * 1000.0);
+    }
+
+    /// Current summary for every endpoint seen so far
+    pub fn summary(&self) -> BTreeMap<String, EndpointSummary> {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|e| e.into_inner());
+        endpoints
+            .iter()
+            .map(|(name, stats)| {
+                let mut sorted: Vec<f64> = stats.latencies_ms.iter().copied().collect();
+                sorted.sort_by(f64::total_cmp);
+                let summary = EndpointSummary {
+                    requests: stats.requests,
+                    errors: stats.errors,
+                    error_rate: stats.errors as f64 / stats.requests as f64,
+                    latency: LatencySummary {
+                        samples: sorted.len(),
+                        p50_ms: percentile(&sorted, 0.50),
+                        p95_ms: percentile(&sorted, 0.95),
+                        max_ms: sorted.last().copied().unwrap_or(0.0),
+                    },
+                };
+                (name.clone(), summary)
+            })
+            .collect()
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted` (0.0 when empty)
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_keeps_recent_latencies() {
+        let metrics = Metrics::default();
+        for ms in 1..=(WINDOW as u64 + 100) {
+            metrics.record("POST /api/scry", Duration::from_millis(ms), 200);
+        }
+        metrics.record("POST /api/scry", Duration::from_millis(5), 500);
+
+        let summary = metrics.summary();
+        let scry = &summary["POST /api/scry"];
+        assert_eq!(scry.requests, WINDOW as u64 + 101);
+        assert_eq!(scry.errors, 1);
+        assert_eq!(scry.latency.samples, WINDOW);
+        assert_eq!(scry.latency.max_ms, (WINDOW + 100) as f64);
+
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 0.5), 2.0);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 0.95), 4.0);
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+}
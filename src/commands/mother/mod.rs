@@ -39,6 +39,7 @@
 
 pub(crate) mod daemon;
 pub(crate) mod graph;
+pub(crate) mod metrics;
 pub(crate) mod microserver;
 
 use anyhow::{bail, Context, Result};
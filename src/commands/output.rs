@@ -0,0 +1,26 @@
+//! `--output` files for query commands
+//!
+//! Result dumps go to the file; stdout keeps a one-line summary so the
+//! decorative headers never end up in the capture.
+
+use anyhow::{Context, Result};
+use std::path::Path;
+
+/// Write `contents` to `path`, creating parent directories as needed
+pub fn write(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(path, contents).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// The stdout line shown instead of the results
+pub fn summary(count: usize, path: &Path) -> String {
+    format!(
+        "Wrote {} result{} to {}",
+        count,
+        if count == 1 { "" } else { "s" },
+        path.display()
+    )
+}
@@ -0,0 +1,238 @@
+//! Incremental index updates for oxidize
+//!
+//! "Do X": Re-embed only rows whose content changed since the last oxidize.
+//!
+//! `embedding_state` records a content hash per (projection, key). On an
+//! incremental run the existing index is updated in place: changed keys are
+//! removed and re-added, vanished keys are removed, unchanged keys are left
+//! alone. Projection weights are reused, since retraining would move every
+//! vector anyway — `--force` retrains and rebuilds from scratch.
+
+use anyhow::{Context, Result};
+use rusqlite::{params, Connection};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use usearch::Index;
+
+/// Counts from an incremental update
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct UpdateStats {
+    pub added: usize,
+    pub updated: usize,
+    pub removed: usize,
+    pub unchanged: usize,
+}
+
+/// Create the embedding_state table if missing
+pub fn ensure_state_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS embedding_state (
+            projection TEXT NOT NULL,
+            key INTEGER NOT NULL,
+            content_hash TEXT NOT NULL,
+            PRIMARY KEY (projection, key)
+        )",
+    )?;
+    Ok(())
+}
+
+fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Whether any state was recorded for a projection (i.e. a full build ran)
+pub fn has_state(conn: &Connection, projection: &str) -> Result<bool> {
+    ensure_state_table(conn)?;
+    let count: i64 = conn.query_row(
+        "SELECT COUNT(*) FROM embedding_state WHERE projection = ?",
+        [projection],
+        |row| row.get(0),
+    )?;
+    Ok(count > 0)
+}
+
+/// Replace a projection's state after a full rebuild
+pub fn record_state(conn: &Connection, projection: &str, events: &[(i64, String)]) -> Result<()> {
+    ensure_state_table(conn)?;
+    let tx = conn.unchecked_transaction()?;
+    tx.execute(
+        "DELETE FROM embedding_state WHERE projection = ?",
+        [projection],
+    )?;
+    {
+        let mut stmt = tx.prepare(
+            "INSERT OR REPLACE INTO embedding_state (projection, key, content_hash) VALUES (?, ?, ?)",
+        )?;
+        for (key, content) in events {
+            stmt.execute(params![projection, key, content_hash(content)])?;
+        }
+    }
+    tx.commit()?;
+    Ok(())
+}
+
+/// Bring an existing index in line with `events`, embedding only changed rows
+pub fn update_index(
+    conn: &Connection,
+    projection: &str,
+    index: &Index,
+    events: &[(i64, String)],
+    mut embed: impl FnMut(&str) -> Result<Vec<f32>>,
+) -> Result<UpdateStats> {
+    ensure_state_table(conn)?;
+
+    let known: HashMap<i64, String> = {
+        let mut stmt =
+            conn.prepare("SELECT key, content_hash FROM embedding_state WHERE projection = ?")?;
+        let rows = stmt.query_map([projection], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<rusqlite::Result<_>>()?
+    };
+
+    let mut stats = UpdateStats::default();
+    let mut changed = Vec::new();
+    for (key, content) in events {
+        let hash = content_hash(content);
+        match known.get(key) {
+            Some(old) if *old == hash && index.contains(*key as u64) => stats.unchanged += 1,
+            Some(_) => {
+                stats.updated += 1;
+                changed.push((*key, content.as_str(), hash));
+            }
+            None => {
+                stats.added += 1;
+                changed.push((*key, content.as_str(), hash));
+            }
+        }
+    }
+
+    let current: HashSet<i64> = events.iter().map(|(key, _)| *key).collect();
+    let vanished: Vec<i64> = known
+        .keys()
+        .filter(|key| !current.contains(key))
+        .copied()
+        .collect();
+
+    index
+        .reserve(index.size() + changed.len())
+        .context("Failed to reserve index capacity")?;
+
+    let tx = conn.unchecked_transaction()?;
+    for (key, content, hash) in &changed {
+        let vector = embed(content)?;
+        if index.contains(*key as u64) {
+            index
+                .remove(*key as u64)
+                .context("Failed to remove stale vector")?;
+        }
+        index
+            .add(*key as u64, &vector)
+            .context("Failed to add vector to index")?;
+        tx.execute(
+            "INSERT OR REPLACE INTO embedding_state (projection, key, content_hash) VALUES (?, ?, ?)",
+            params![projection, key, hash],
+        )?;
+    }
+    for key in &vanished {
+        if index.contains(*key as u64) {
+            index
+                .remove(*key as u64)
+                .context("Failed to remove vanished vector")?;
+        }
+        tx.execute(
+            "DELETE FROM embedding_state WHERE projection = ? AND key = ?",
+            params![projection, key],
+        )?;
+        stats.removed += 1;
+    }
+    tx.commit()?;
+
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use usearch::{IndexOptions, MetricKind, ScalarKind};
+
+    fn small_index() -> Index {
+        Index::new(&IndexOptions {
+            dimensions: 4,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    /// Deterministic per-content vector standing in for embed + project
+    fn fake_embed(content: &str) -> Vec<f32> {
+        let hash = Sha256::digest(content.as_bytes());
+        hash[..4].iter().map(|b| *b as f32 + 1.0).collect()
+    }
+
+    fn vector(index: &Index, key: u64) -> Vec<f32> {
+        let mut out = vec![0.0_f32; 4];
+        index.get(key, &mut out).unwrap();
+        out
+    }
+
+    #[test]
+    fn test_only_changed_function_is_reembedded() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        let index = small_index();
+
+        let mut events = vec![
+            (1, "fn parse(input: &str) -> Ast".to_string()),
+            (2, "fn render(ast: &Ast) -> String".to_string()),
+            (3, "fn main()".to_string()),
+        ];
+
+        // First run: everything is new
+        let stats = update_index(&conn, "dependency", &index, &events, |c| Ok(fake_embed(c)))?;
+        assert_eq!(stats.added, 3);
+        let before: Vec<Vec<f32>> = (1..=3).map(|k| vector(&index, k)).collect();
+
+        // Change one function body
+        events[1].1 = "fn render(ast: &Ast, width: usize) -> String".to_string();
+        let mut embedded = Vec::new();
+        let stats = update_index(&conn, "dependency", &index, &events, |c| {
+            embedded.push(c.to_string());
+            Ok(fake_embed(c))
+        })?;
+
+        assert_eq!(
+            stats,
+            UpdateStats {
+                added: 0,
+                updated: 1,
+                removed: 0,
+                unchanged: 2,
+            }
+        );
+        assert_eq!(embedded, vec![events[1].1.clone()]);
+        assert_eq!(vector(&index, 1), before[0]);
+        assert_ne!(vector(&index, 2), before[1]);
+        assert_eq!(vector(&index, 3), before[2]);
+        assert_eq!(index.size(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vanished_rows_are_removed() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        let index = small_index();
+        let events = vec![(1, "a".to_string()), (2, "b".to_string())];
+        update_index(&conn, "temporal", &index, &events, |c| Ok(fake_embed(c)))?;
+        assert!(has_state(&conn, "temporal")?);
+
+        let stats = update_index(&conn, "temporal", &index, &events[..1], |c| {
+            Ok(fake_embed(c))
+        })?;
+        assert_eq!(stats.removed, 1);
+        assert!(!index.contains(2));
+        assert!(index.contains(1));
+
+        Ok(())
+    }
+}
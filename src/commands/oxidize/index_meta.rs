@@ -0,0 +1,211 @@
+//! Sidecar metadata for USearch indices built by oxidize
+//!
+//! "Do X": Record how an index was built so readers open it with matching options.
+//!
+//! `semantic.usearch` gets a `semantic.meta.json` next to it holding the
+//! scalar quantization and dimensions. Indices without a sidecar predate
+//! quantization support and are F32; their width comes from the projection
+//! weights beside them, or the historical 256.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+use super::trainer::Projection;
+
+/// Projection width of indices built before dimensions were recorded
+pub const LEGACY_DIMENSIONS: usize = 256;
+
+/// Scalar type vectors are stored as inside the index
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Quantization {
+    #[default]
+    F32,
+    F16,
+    I8,
+}
+
+impl Quantization {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Quantization::F32 => "f32",
+            Quantization::F16 => "f16",
+            Quantization::I8 => "i8",
+        }
+    }
+
+    pub fn scalar_kind(&self) -> ScalarKind {
+        match self {
+            Quantization::F32 => ScalarKind::F32,
+            Quantization::F16 => ScalarKind::F16,
+            Quantization::I8 => ScalarKind::I8,
+        }
+    }
+}
+
+/// Contents of the sidecar file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexMeta {
+    pub quantization: Quantization,
+    pub dimensions: usize,
+}
+
+impl IndexMeta {
+    /// Cosine index options matching this metadata
+    pub fn index_options(&self) -> IndexOptions {
+        IndexOptions {
+            dimensions: self.dimensions,
+            metric: MetricKind::Cos,
+            quantization: self.quantization.scalar_kind(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Sidecar path for an index: `semantic.usearch` → `semantic.meta.json`
+pub fn meta_path(index_path: &Path) -> PathBuf {
+    index_path.with_extension("meta.json")
+}
+
+/// Write the sidecar for an index
+pub fn write(index_path: &Path, meta: &IndexMeta) -> Result<()> {
+    let path = meta_path(index_path);
+    let json = serde_json::to_string_pretty(meta)?;
+    std::fs::write(&path, json)
+        .with_context(|| format!("Failed to write index metadata: {}", path.display()))
+}
+
+/// Read the sidecar for an index, if present and parseable
+pub fn read(index_path: &Path) -> Option<IndexMeta> {
+    let content = std::fs::read_to_string(meta_path(index_path)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Options to open an index with: from its sidecar, else F32 at the width of
+/// the sibling projection weights (`semantic.safetensors`)
+pub fn load_options(index_path: &Path) -> IndexOptions {
+    read(index_path)
+        .unwrap_or_else(|| IndexMeta {
+            quantization: Quantization::F32,
+            dimensions: Projection::read_output_dim(&index_path.with_extension("safetensors"))
+                .unwrap_or(LEGACY_DIMENSIONS),
+        })
+        .index_options()
+}
+
+/// Fail clearly when a query vector doesn't match the index width
+///
+/// USearch reads `dimensions` floats from whatever it is handed, so a
+/// mismatched projection would otherwise search with garbage.
+pub fn ensure_dimensions(index: &Index, vector: &[f32]) -> Result<()> {
+    if vector.len() != index.dimensions() {
+        anyhow::bail!(
+            "Query vector has {} dimensions but index has {}. Run 'patina oxidize --force' to rebuild.",
+            vector.len(),
+            index.dimensions()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use usearch::Index;
+
+    #[test]
+    fn test_f16_index_loadable_and_searchable_via_sidecar() -> Result<()> {
+        let temp = TempDir::new()?;
+        let index_path = temp.path().join("semantic.usearch");
+        let path_str = index_path.to_str().unwrap();
+
+        let meta = IndexMeta {
+            quantization: Quantization::F16,
+            dimensions: 4,
+        };
+        let index = Index::new(&meta.index_options())?;
+        index.reserve(3)?;
+        index.add(1, &[1.0_f32, 0.0, 0.0, 0.0])?;
+        index.add(2, &[0.0_f32, 1.0, 0.0, 0.0])?;
+        index.add(3, &[0.0_f32, 0.0, 1.0, 0.0])?;
+        index.save(path_str)?;
+        write(&index_path, &meta)?;
+
+        // Readers (scry, oracles) pick the quantization up from the sidecar
+        let options = load_options(&index_path);
+        assert_eq!(options.quantization, ScalarKind::F16);
+        assert_eq!(options.dimensions, 4);
+
+        let loaded = Index::new(&options)?;
+        loaded.load(path_str)?;
+        assert_eq!(loaded.size(), 3);
+        let matches = loaded.search(&[0.0_f32, 0.9, 0.1, 0.0], 1)?;
+        assert_eq!(matches.keys, vec![2]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_missing_sidecar_defaults_to_f32() {
+        let temp = TempDir::new().unwrap();
+        let options = load_options(&temp.path().join("temporal.usearch"));
+        assert_eq!(options.quantization, ScalarKind::F32);
+        assert_eq!(options.dimensions, LEGACY_DIMENSIONS);
+        assert_eq!(
+            meta_path(Path::new("x/temporal.usearch")),
+            PathBuf::from("x/temporal.meta.json")
+        );
+    }
+
+    #[test]
+    fn test_dim_128_projection_index_loads_and_queries() -> Result<()> {
+        let temp = TempDir::new()?;
+        let weights_path = temp.path().join("semantic.safetensors");
+        let index_path = temp.path().join("semantic.usearch");
+        let path_str = index_path.to_str().unwrap();
+
+        // Train a 128-wide projection on toy embeddings
+        let mut projection = Projection::new(16, 32, 128);
+        let anchors = vec![vec![1.0; 16], vec![0.0; 16]];
+        let positives = vec![vec![0.9; 16], vec![0.1; 16]];
+        let negatives = vec![vec![0.0; 16], vec![1.0; 16]];
+        projection.train(&anchors, &positives, &negatives, 3, 0.01)?;
+        projection.save_safetensors(&weights_path)?;
+
+        let inputs: Vec<Vec<f32>> = (0..4)
+            .map(|i| (0..16).map(|j| ((i * 16 + j) % 7) as f32).collect())
+            .collect();
+        let meta = IndexMeta {
+            quantization: Quantization::F32,
+            dimensions: projection.output_dim(),
+        };
+        let index = Index::new(&meta.index_options())?;
+        index.reserve(inputs.len())?;
+        for (key, input) in inputs.iter().enumerate() {
+            index.add(key as u64, &projection.forward(input))?;
+        }
+        index.save(path_str)?;
+
+        // Weights alone are enough to recover the width (pre-sidecar layout)
+        assert_eq!(load_options(&index_path).dimensions, 128);
+        write(&index_path, &meta)?;
+        let options = load_options(&index_path);
+        assert_eq!(options.dimensions, 128);
+
+        // Load the way scry does and query with a projected vector
+        let loaded = Index::new(&options)?;
+        loaded.load(path_str)?;
+        let query = Projection::load_safetensors(&weights_path)?.forward(&inputs[2]);
+        ensure_dimensions(&loaded, &query)?;
+        let matches = loaded.search(&query, 1)?;
+        assert_eq!(matches.keys, vec![2]);
+
+        // A 256-wide query is rejected instead of misread
+        assert!(ensure_dimensions(&loaded, &[0.0; 256]).is_err());
+
+        Ok(())
+    }
+}
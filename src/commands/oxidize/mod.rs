@@ -4,6 +4,8 @@
 
 pub mod commits;
 pub mod dependency;
+pub mod incremental;
+pub mod index_meta;
 pub mod pairs;
 pub mod recipe;
 pub mod temporal;
@@ -12,13 +14,42 @@ pub mod trainer;
 use anyhow::{Context, Result};
 use commits::{generate_commit_pairs, has_commits, has_sessions};
 use dependency::generate_dependency_pairs;
+use index_meta::{IndexMeta, Quantization};
 use pairs::{generate_same_session_pairs, TrainingPair};
 use recipe::{OxidizeRecipe, ProjectionConfig};
 use temporal::generate_temporal_pairs;
 use trainer::Projection;
 
-/// Run oxidize command
+use patina::embeddings::id_offsets::{
+    self, belief_key, classify_key, code_key, commit_key, forge_key, pattern_key, KeyKind,
+};
+
+/// Rows embedded per embedder call when building an index
+const EMBED_BATCH_SIZE: usize = 64;
+
+/// Options for oxidize
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OxidizeOptions {
+    /// Scalar type for index vectors
+    pub quant: Quantization,
+    /// Retrain projections and rebuild indices instead of updating in place
+    pub force: bool,
+    /// Download the embedding model if it isn't cached
+    pub auto_pull: bool,
+}
+
+/// Run oxidize command with default options (F32, incremental)
 pub fn oxidize() -> Result<()> {
+    oxidize_with(OxidizeOptions::default())
+}
+
+/// Run oxidize command
+///
+/// Incremental by default: when a projection's weights, index, and
+/// embedding_state already exist with matching settings, only rows whose
+/// content changed are re-embedded. `force` retrains everything.
+pub fn oxidize_with(options: OxidizeOptions) -> Result<()> {
+    let quant = options.quant;
     println!("🧪 Oxidize - Build embeddings and projections");
 
     // Load recipe
@@ -27,6 +58,7 @@ pub fn oxidize() -> Result<()> {
     let model_name = recipe.get_model_name()?;
     println!("✅ Recipe loaded: {}", model_name);
     println!("   Projections: {}", recipe.projections.len());
+    println!("   Quantization: {}", quant.as_str());
 
     for (name, config) in &recipe.projections {
         println!(
@@ -44,11 +76,35 @@ pub fn oxidize() -> Result<()> {
     std::fs::create_dir_all(&output_dir)?;
 
     // Create embedder once, reuse for all projections
-    use patina::embeddings::create_embedder;
+    use patina::embeddings::{create_embedder, ensure_model_available};
+    ensure_model_available(options.auto_pull)?;
     let mut embedder = create_embedder()?;
 
     // Train each projection
     for (name, config) in &recipe.projections {
+        let weights_path = format!("{}/{}.safetensors", output_dir, name);
+        let index_path = format!("{}/{}.usearch", output_dir, name);
+        let meta = IndexMeta {
+            quantization: quant,
+            dimensions: config.output_dim(),
+        };
+
+        if !options.force
+            && can_update_incrementally(name, db_path, &weights_path, &index_path, &meta)?
+        {
+            println!("\n{}", "=".repeat(60));
+            println!(
+                "♻️  Updating {} index incrementally (--force to retrain)",
+                name
+            );
+            println!("{}", "=".repeat(60));
+
+            let projection = Projection::load_safetensors(std::path::Path::new(&weights_path))?;
+            update_projection_index(name, db_path, &mut embedder, &projection, &index_path)?;
+            println!("\n✅ {} projection up to date!", name);
+            continue;
+        }
+
         println!("\n{}", "=".repeat(60));
         println!("📊 Training {} projection...", name);
         println!("{}", "=".repeat(60));
@@ -57,7 +113,6 @@ pub fn oxidize() -> Result<()> {
 
         // Save trained weights
         println!("\n💾 Saving projection weights...");
-        let weights_path = format!("{}/{}.safetensors", output_dir, name);
         projection.save_safetensors(std::path::Path::new(&weights_path))?;
         println!("   Saved to: {}", weights_path);
 
@@ -70,6 +125,7 @@ pub fn oxidize() -> Result<()> {
             &projection,
             config.output_dim(),
             &output_dir,
+            quant,
         )?;
 
         println!("\n✅ {} projection complete!", name);
@@ -86,7 +142,7 @@ pub fn oxidize() -> Result<()> {
 ///
 /// Looks up repo path from registry, changes to that directory,
 /// ensures recipe exists, and runs oxidize.
-pub fn oxidize_for_repo(repo_name: &str) -> Result<()> {
+pub fn oxidize_for_repo(repo_name: &str, options: OxidizeOptions) -> Result<()> {
     use std::os::unix::fs::symlink;
 
     // Look up repo path
@@ -147,7 +203,7 @@ projections:
     }
 
     // Run oxidize
-    let result = oxidize();
+    let result = oxidize_with(options);
 
     // Clean up symlink
     if repo_resources.is_symlink() {
@@ -193,7 +249,10 @@ fn train_projection(
         }
         "temporal" => {
             println!("   Strategy: files that co-change are related");
-            generate_temporal_pairs(db_path, num_pairs)?
+            let half_life = config
+                .half_life_days
+                .unwrap_or(temporal::DEFAULT_HALF_LIFE_DAYS);
+            generate_temporal_pairs(db_path, num_pairs, half_life)?
         }
         "dependency" => {
             println!("   Strategy: functions that call each other are related");
@@ -211,15 +270,12 @@ fn train_projection(
 
     // Generate embeddings
     println!("\n🔮 Generating embeddings...");
-    let mut anchors = Vec::new();
-    let mut positives = Vec::new();
-    let mut negatives = Vec::new();
-
-    for pair in &pairs {
-        anchors.push(embedder.embed_passage(&pair.anchor)?);
-        positives.push(embedder.embed_passage(&pair.positive)?);
-        negatives.push(embedder.embed_passage(&pair.negative)?);
-    }
+    let anchor_texts: Vec<String> = pairs.iter().map(|p| p.anchor.clone()).collect();
+    let positive_texts: Vec<String> = pairs.iter().map(|p| p.positive.clone()).collect();
+    let negative_texts: Vec<String> = pairs.iter().map(|p| p.negative.clone()).collect();
+    let anchors = embedder.embed_passage_batch(&anchor_texts)?;
+    let positives = embedder.embed_passage_batch(&positive_texts)?;
+    let negatives = embedder.embed_passage_batch(&negative_texts)?;
 
     println!("   Embedded {} triplets", anchors.len());
 
@@ -256,23 +312,19 @@ fn build_projection_index(
     projection: &Projection,
     output_dim: usize,
     output_dir: &str,
+    quant: Quantization,
 ) -> Result<()> {
     use rusqlite::Connection;
-    use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+    use usearch::Index;
 
     // Open database
     let conn = Connection::open(db_path)
         .with_context(|| format!("Failed to open database: {}", db_path))?;
 
     // Get content to index based on projection type
-    let events: Vec<(i64, String)> = match projection_name {
-        "semantic" => query_session_events(&conn)?,
-        "temporal" => query_file_events(&conn)?,
-        "dependency" => dependency::query_function_events(&conn)?,
-        _ => {
-            println!("   ⚠️  No index builder for {} - skipping", projection_name);
-            return Ok(());
-        }
+    let Some(events) = query_projection_events(&conn, projection_name)? else {
+        println!("   ⚠️  No index builder for {} - skipping", projection_name);
+        return Ok(());
     };
 
     println!("   Found {} items to index", events.len());
@@ -283,28 +335,29 @@ fn build_projection_index(
     }
 
     // Create USearch index
-    let options = IndexOptions {
+    let meta = IndexMeta {
+        quantization: quant,
         dimensions: output_dim,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
     };
 
-    let index = Index::new(&options).context("Failed to create USearch index")?;
+    let index = Index::new(&meta.index_options()).context("Failed to create USearch index")?;
     index
         .reserve(events.len())
         .context("Failed to reserve index capacity")?;
 
     // Embed, project, and add to index
     println!("   Embedding and projecting vectors...");
-    for (id, content) in &events {
-        let embedding = embedder
-            .embed_passage(content)
-            .context("Failed to generate embedding")?;
-        let projected = projection.forward(&embedding);
-        index
-            .add(*id as u64, &projected)
-            .context("Failed to add vector to index")?;
+    for chunk in events.chunks(EMBED_BATCH_SIZE) {
+        let contents: Vec<String> = chunk.iter().map(|(_, content)| content.clone()).collect();
+        let embeddings = embedder
+            .embed_passage_batch(&contents)
+            .context("Failed to generate embeddings")?;
+        for ((id, _), embedding) in chunk.iter().zip(&embeddings) {
+            let projected = projection.forward(embedding);
+            index
+                .add(*id as u64, &projected)
+                .context("Failed to add vector to index")?;
+        }
     }
 
     // Save index
@@ -312,6 +365,8 @@ fn build_projection_index(
     index
         .save(&index_path)
         .context("Failed to save USearch index")?;
+    index_meta::write(std::path::Path::new(&index_path), &meta)?;
+    incremental::record_state(&conn, projection_name, &events)?;
 
     println!("   ✅ Index built: {} vectors", events.len());
     println!("   Saved to: {}", index_path);
@@ -319,8 +374,120 @@ fn build_projection_index(
     Ok(())
 }
 
+/// Content to index for a projection (None if the projection has no index builder)
+fn query_projection_events(
+    conn: &rusqlite::Connection,
+    projection_name: &str,
+) -> Result<Option<Vec<(i64, String)>>> {
+    Ok(match projection_name {
+        "semantic" => Some(query_session_events(conn)?),
+        "temporal" => Some(query_file_events(conn)?),
+        "dependency" => Some(dependency::query_function_events(conn)?),
+        _ => None,
+    })
+}
+
+/// An index can be updated in place if a prior full build left weights, a
+/// matching index, and embedding_state behind
+fn can_update_incrementally(
+    projection_name: &str,
+    db_path: &str,
+    weights_path: &str,
+    index_path: &str,
+    meta: &IndexMeta,
+) -> Result<bool> {
+    use std::path::Path;
+
+    if !Path::new(weights_path).exists() || !Path::new(index_path).exists() {
+        return Ok(false);
+    }
+    let matches = index_meta::read(Path::new(index_path)).is_some_and(|stored| {
+        stored.quantization == meta.quantization && stored.dimensions == meta.dimensions
+    });
+    if !matches {
+        return Ok(false);
+    }
+
+    let conn = rusqlite::Connection::open(db_path)
+        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    incremental::has_state(&conn, projection_name)
+}
+
+/// Re-embed changed rows into an existing index and save it
+fn update_projection_index(
+    projection_name: &str,
+    db_path: &str,
+    embedder: &mut Box<dyn patina::embeddings::EmbeddingEngine>,
+    projection: &Projection,
+    index_path: &str,
+) -> Result<()> {
+    use usearch::Index;
+
+    let conn = rusqlite::Connection::open(db_path)
+        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    let Some(events) = query_projection_events(&conn, projection_name)? else {
+        return Ok(());
+    };
+
+    let options = index_meta::load_options(std::path::Path::new(index_path));
+    let index = Index::new(&options).context("Failed to create USearch index")?;
+    index
+        .load(index_path)
+        .with_context(|| format!("Failed to load index: {}", index_path))?;
+    if projection.output_dim() != index.dimensions() {
+        anyhow::bail!(
+            "{} projection outputs {} dimensions but index has {}. Run 'patina oxidize --force'.",
+            projection_name,
+            projection.output_dim(),
+            index.dimensions()
+        );
+    }
+
+    let stats = incremental::update_index(&conn, projection_name, &index, &events, |content| {
+        let embedding = embedder
+            .embed_passage(content)
+            .context("Failed to generate embedding")?;
+        Ok(projection.forward(&embedding))
+    })?;
+
+    index
+        .save(index_path)
+        .context("Failed to save USearch index")?;
+
+    println!(
+        "   {} added, {} updated, {} removed, {} unchanged",
+        stats.added, stats.updated, stats.removed, stats.unchanged
+    );
+    println!("   Saved to: {}", index_path);
+
+    Ok(())
+}
+
 /// Query session events for semantic index
 fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)>> {
+    let events = semantic_events(conn)?;
+
+    let count = |kind: KeyKind| {
+        events
+            .iter()
+            .filter(|(key, _)| classify_key(*key) == kind)
+            .count()
+    };
+    println!(
+        "   Indexed {} session events + {} code facts + {} patterns + {} commits + {} beliefs + {} forge",
+        count(KeyKind::Session),
+        count(KeyKind::Code),
+        count(KeyKind::Pattern),
+        count(KeyKind::Commit),
+        count(KeyKind::Belief),
+        count(KeyKind::Forge)
+    );
+
+    Ok(events)
+}
+
+/// Every (key, text) pair the semantic index is built from, keyed per [`id_offsets`]
+pub fn semantic_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)>> {
     let mut events = Vec::new();
 
     // 1. Session events from eventlog
@@ -340,10 +507,7 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
         events.push((seq, content));
     }
 
-    let session_count = events.len();
-
     // 2. Code facts from function_facts (use offset to avoid ID collision)
-    const CODE_ID_OFFSET: i64 = 1_000_000_000;
     let mut stmt = conn.prepare(
         "SELECT rowid, file, name, parameters, return_type, is_public, is_async
          FROM function_facts
@@ -379,14 +543,11 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
             }
         }
 
-        events.push((CODE_ID_OFFSET + rowid, desc));
+        events.push((code_key(rowid), desc));
     }
 
-    let code_count = events.len() - session_count;
-
     // 3. Layer patterns from patterns + pattern_fts tables (use offset to avoid ID collision)
     // Note: patterns table may not exist in ref repos - skip gracefully
-    const PATTERN_ID_OFFSET: i64 = 2_000_000_000;
     let has_patterns: bool = conn
         .query_row(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='patterns'",
@@ -431,14 +592,11 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
             }
             desc.push_str(&format!(". File: {}", file_path));
 
-            events.push((PATTERN_ID_OFFSET + rowid, desc));
+            events.push((pattern_key(rowid), desc));
         }
     }
 
-    let pattern_count = events.len() - session_count - code_count;
-
     // 4. Git commits (the "why" behind code changes)
-    const COMMIT_ID_OFFSET: i64 = 3_000_000_000;
     let mut stmt = conn.prepare(
         "SELECT rowid, sha, message FROM commits
          WHERE message IS NOT NULL AND length(message) > 30
@@ -453,13 +611,10 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
 
         // Use the full commit message for semantic search
         let desc = format!("Commit {}: {}", &sha[..7.min(sha.len())], message);
-        events.push((COMMIT_ID_OFFSET + rowid, desc));
+        events.push((commit_key(rowid), desc));
     }
 
-    let commit_count = events.len() - session_count - code_count - pattern_count;
-
     // 5. Epistemic beliefs (project decisions with confidence)
-    const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
     let has_beliefs: bool = conn
         .query_row(
             "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='beliefs'",
@@ -500,15 +655,13 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
                 confidence, entrenchment
             ));
 
-            events.push((BELIEF_ID_OFFSET + rowid, desc));
+            events.push((belief_key(rowid), desc));
         }
     }
 
-    let belief_count = events.len() - session_count - code_count - pattern_count - commit_count;
-
     // 6. Forge events (issues + PRs) — embed title+body for semantic search
-    // Query eventlog directly (forge_issues/forge_prs views have broken event_seq)
-    const FORGE_ID_OFFSET: i64 = 5_000_000_000;
+    // Query eventlog directly (forge_issues/forge_prs views have broken event_seq);
+    // only the latest event per issue/PR, so re-synced items embed once
     {
         let mut stmt = conn.prepare(
             "SELECT seq, event_type, source_id,
@@ -516,7 +669,12 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
                     json_extract(data, '$.body') as body
              FROM eventlog
              WHERE event_type IN ('forge.issue', 'forge.pr')
-               AND title IS NOT NULL",
+               AND title IS NOT NULL
+               AND seq IN (
+                   SELECT MAX(seq) FROM eventlog
+                   WHERE event_type IN ('forge.issue', 'forge.pr')
+                   GROUP BY event_type, source_id
+               )",
         )?;
 
         let mut rows = stmt.query([])?;
@@ -537,21 +695,24 @@ fn query_session_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)
                 _ => format!("{} #{}: {}", kind, number, title),
             };
 
-            events.push((FORGE_ID_OFFSET + seq, desc));
+            events.push((forge_key(seq), desc));
         }
     }
 
-    let forge_count =
-        events.len() - session_count - code_count - pattern_count - commit_count - belief_count;
-
-    println!(
-        "   Indexed {} session events + {} code facts + {} patterns + {} commits + {} beliefs + {} forge",
-        session_count, code_count, pattern_count, commit_count, belief_count, forge_count
-    );
-
     Ok(events)
 }
 
+/// Per-kind share of semantic source rows that have a key in `index`
+pub fn semantic_coverage(
+    conn: &rusqlite::Connection,
+    index: &usearch::Index,
+) -> Result<Vec<id_offsets::KindCoverage>> {
+    let keys = semantic_events(conn)?.into_iter().map(|(key, _)| key);
+    Ok(id_offsets::coverage_by_kind(keys, |key| {
+        index.contains(key as u64)
+    }))
+}
+
 /// Query file events for temporal index
 fn query_file_events(conn: &rusqlite::Connection) -> Result<Vec<(i64, String)>> {
     // Get unique files from co_changes with their index
@@ -29,6 +29,9 @@ pub struct ProjectionConfig {
     pub epochs: usize,
     /// Batch size for training
     pub batch_size: usize,
+    /// Temporal only: days after which a co-change counts half as much
+    #[serde(default)]
+    pub half_life_days: Option<f64>,
 }
 
 impl OxidizeRecipe {
@@ -147,6 +150,10 @@ impl ProjectionConfig {
             anyhow::bail!("Projection '{}': batch_size must be > 0", name);
         }
 
+        if self.half_life_days.is_some_and(|days| days <= 0.0) {
+            anyhow::bail!("Projection '{}': half_life_days must be > 0", name);
+        }
+
         Ok(())
     }
 
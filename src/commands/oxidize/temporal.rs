@@ -2,28 +2,129 @@
 //!
 //! Generate (anchor, positive, negative) triplets from co_changes for contrastive learning.
 //! Files that change together in commits are considered temporally related.
+//!
+//! Each co-change is weighted by an exponential decay on its commit date, so a
+//! pair that coupled heavily years ago but not since is sampled less often than
+//! one that is coupled now.
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, FixedOffset};
 use rusqlite::Connection;
 use std::collections::{HashMap, HashSet};
 
 use super::pairs::TrainingPair;
+use crate::commands::scrape::git::{current_paths, MAX_FILES_PER_COMMIT};
 
 /// Minimum co-change count to consider files as related
 const MIN_COCHANGE_COUNT: i64 = 2;
 
+/// Default half-life (days) for co-change decay when the recipe doesn't set one
+pub const DEFAULT_HALF_LIFE_DAYS: f64 = 180.0;
+
+/// Decayed co-change strength per (file_a, file_b) pair, file_a < file_b
+///
+/// Every commit touching both files adds `0.5^(age / half_life)`, where age
+/// is measured back from the newest commit (not the wall clock, so a stale
+/// clone still favours its own recent history). Commits with more than
+/// `MAX_FILES_PER_COMMIT` files are skipped, and paths are coalesced through
+/// `current_paths`, as in `co_changes`. Returns an empty map when commit
+/// history isn't available.
+pub fn decayed_cochange_weights(
+    conn: &Connection,
+    half_life_days: f64,
+) -> Result<HashMap<(String, String), f64>> {
+    let mut weights = HashMap::new();
+
+    let has_history: bool = conn.query_row(
+        "SELECT COUNT(*) = 2 FROM sqlite_master
+         WHERE type = 'table' AND name IN ('commits', 'commit_files')",
+        [],
+        |row| row.get(0),
+    )?;
+    if !has_history {
+        return Ok(weights);
+    }
+
+    // Databases scraped before rename tracking have no file_renames table
+    let has_renames: bool = conn
+        .prepare("SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'file_renames'")?
+        .exists([])?;
+    let renamed = if has_renames {
+        current_paths(conn)?
+    } else {
+        HashMap::new()
+    };
+
+    // Files per commit, with the commit date
+    let mut commits: Vec<(DateTime<FixedOffset>, Vec<String>)> = Vec::new();
+    let mut stmt = conn.prepare(
+        "SELECT cf.sha, cf.file_path, c.timestamp
+         FROM commit_files cf JOIN commits c ON c.sha = cf.sha
+         ORDER BY cf.sha, cf.file_path",
+    )?;
+    let mut rows = stmt.query([])?;
+    let mut current_sha: Option<String> = None;
+    while let Some(row) = rows.next()? {
+        let sha: String = row.get(0)?;
+        let file_path: String = row.get(1)?;
+        if current_sha.as_ref() != Some(&sha) {
+            let timestamp: String = row.get(2)?;
+            let Ok(date) = DateTime::parse_from_rfc3339(&timestamp) else {
+                current_sha = None;
+                continue;
+            };
+            commits.push((date, Vec::new()));
+            current_sha = Some(sha);
+        }
+        if let Some((_, files)) = commits.last_mut() {
+            files.push(renamed.get(&file_path).cloned().unwrap_or(file_path));
+        }
+    }
+
+    let Some(newest) = commits.iter().map(|(date, _)| *date).max() else {
+        return Ok(weights);
+    };
+
+    for (date, files) in &mut commits {
+        // Renaming can reorder paths or fold two into one
+        files.sort();
+        files.dedup();
+        if files.len() < 2 || files.len() > MAX_FILES_PER_COMMIT {
+            continue;
+        }
+        let age_days = (newest - *date).num_seconds() as f64 / 86_400.0;
+        let weight = 0.5_f64.powf(age_days / half_life_days);
+        // Sorted above, so files[i] < files[j]
+        for i in 0..files.len() {
+            for j in (i + 1)..files.len() {
+                *weights
+                    .entry((files[i].clone(), files[j].clone()))
+                    .or_insert(0.0) += weight;
+            }
+        }
+    }
+
+    Ok(weights)
+}
+
 /// Generate training pairs where files that co-change are similar
 ///
 /// Strategy:
 /// - Anchor: random file from co_changes
-/// - Positive: file that frequently changes with anchor
+/// - Positive: file that changes with anchor, picked by decayed co-change weight
 /// - Negative: file that rarely/never changes with anchor
-pub fn generate_temporal_pairs(db_path: &str, num_pairs: usize) -> Result<Vec<TrainingPair>> {
+pub fn generate_temporal_pairs(
+    db_path: &str,
+    num_pairs: usize,
+    half_life_days: f64,
+) -> Result<Vec<TrainingPair>> {
     let conn = Connection::open(db_path)
         .with_context(|| format!("Failed to open database: {}", db_path))?;
 
-    // Load co-change relationships (file_a -> set of related files)
-    let mut cochanges: HashMap<String, HashSet<String>> = HashMap::new();
+    let decayed = decayed_cochange_weights(&conn, half_life_days)?;
+
+    // Load co-change relationships (file -> related files with weight)
+    let mut cochanges: HashMap<String, Vec<(String, f64)>> = HashMap::new();
     let mut all_files: HashSet<String> = HashSet::new();
 
     let mut stmt = conn.prepare(
@@ -37,6 +138,17 @@ pub fn generate_temporal_pairs(db_path: &str, num_pairs: usize) -> Result<Vec<Tr
     while let Some(row) = rows.next()? {
         let file_a: String = row.get(0)?;
         let file_b: String = row.get(1)?;
+        let count: i64 = row.get(2)?;
+
+        // Raw count when there's no commit history to decay
+        let weight = if decayed.is_empty() {
+            count as f64
+        } else {
+            decayed
+                .get(&(file_a.clone(), file_b.clone()))
+                .copied()
+                .unwrap_or(0.0)
+        };
 
         // Track all files
         all_files.insert(file_a.clone());
@@ -46,11 +158,11 @@ pub fn generate_temporal_pairs(db_path: &str, num_pairs: usize) -> Result<Vec<Tr
         cochanges
             .entry(file_a.clone())
             .or_default()
-            .insert(file_b.clone());
+            .push((file_b.clone(), weight));
         cochanges
             .entry(file_b.clone())
             .or_default()
-            .insert(file_a.clone());
+            .push((file_a.clone(), weight));
     }
 
     // Filter to files with at least one co-change partner
@@ -81,17 +193,14 @@ pub fn generate_temporal_pairs(db_path: &str, num_pairs: usize) -> Result<Vec<Tr
         let anchor_idx = rng.usize(..files_with_cochanges.len());
         let (anchor_file, anchor_partners) = files_with_cochanges[anchor_idx];
 
-        // Pick positive from co-change partners
-        let partners_vec: Vec<_> = anchor_partners.iter().collect();
-        let positive_idx = rng.usize(..partners_vec.len());
-        let positive_file = partners_vec[positive_idx];
+        // Pick positive from co-change partners, favouring recent coupling
+        let positive_file = pick_weighted(&mut rng, anchor_partners);
 
         // Pick negative from files that don't co-change with anchor
+        let is_partner = |file: &str| anchor_partners.iter().any(|(p, _)| p == file);
         let mut negative_file = all_files_vec[rng.usize(..all_files_vec.len())];
         let mut attempts = 0;
-        while (anchor_partners.contains(negative_file) || *negative_file == *anchor_file)
-            && attempts < 100
-        {
+        while (is_partner(negative_file) || *negative_file == *anchor_file) && attempts < 100 {
             negative_file = all_files_vec[rng.usize(..all_files_vec.len())];
             attempts += 1;
         }
@@ -111,6 +220,24 @@ pub fn generate_temporal_pairs(db_path: &str, num_pairs: usize) -> Result<Vec<Tr
     Ok(pairs)
 }
 
+/// Pick a partner with probability proportional to its weight
+///
+/// Falls back to a uniform pick when every weight has decayed to nothing.
+fn pick_weighted<'a>(rng: &mut fastrand::Rng, partners: &'a [(String, f64)]) -> &'a String {
+    let total: f64 = partners.iter().map(|(_, w)| w).sum();
+    if total <= 0.0 {
+        return &partners[rng.usize(..partners.len())].0;
+    }
+    let mut target = rng.f64() * total;
+    for (file, weight) in partners {
+        if target < *weight {
+            return file;
+        }
+        target -= weight;
+    }
+    &partners[partners.len() - 1].0
+}
+
 /// Convert file path to text suitable for embedding
 ///
 /// Creates a description that E5 can meaningfully embed:
@@ -173,7 +300,9 @@ mod tests {
     #[test]
     fn test_generate_temporal_pairs() {
         let temp_db = create_test_db();
-        let pairs = generate_temporal_pairs(temp_db.path().to_str().unwrap(), 10).unwrap();
+        let pairs =
+            generate_temporal_pairs(temp_db.path().to_str().unwrap(), 10, DEFAULT_HALF_LIFE_DAYS)
+                .unwrap();
 
         assert_eq!(pairs.len(), 10);
 
@@ -218,7 +347,75 @@ mod tests {
         )
         .unwrap();
 
-        let result = generate_temporal_pairs(temp_file.path().to_str().unwrap(), 5);
+        let result = generate_temporal_pairs(
+            temp_file.path().to_str().unwrap(),
+            5,
+            DEFAULT_HALF_LIFE_DAYS,
+        );
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_recent_cochange_outweighs_stale_with_equal_counts() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, timestamp TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             INSERT INTO commits VALUES
+                ('old1', '2023-01-10T12:00:00+00:00'),
+                ('old2', '2023-02-10T12:00:00+00:00'),
+                ('new1', '2025-05-01T12:00:00+00:00'),
+                ('new2', '2025-06-01T12:00:00+00:00');
+             INSERT INTO commit_files VALUES
+                ('old1', 'src/legacy.rs'), ('old1', 'src/old_api.rs'),
+                ('old2', 'src/legacy.rs'), ('old2', 'src/old_api.rs'),
+                ('new1', 'src/engine.rs'), ('new1', 'src/scheduler.rs'),
+                ('new2', 'src/engine.rs'), ('new2', 'src/scheduler.rs');",
+        )
+        .unwrap();
+
+        let weights = decayed_cochange_weights(&conn, 180.0).unwrap();
+        let stale = weights[&("src/legacy.rs".to_string(), "src/old_api.rs".to_string())];
+        let recent = weights[&("src/engine.rs".to_string(), "src/scheduler.rs".to_string())];
+
+        // Both pairs co-changed twice; only recency differs
+        assert!(recent > stale, "recent {} vs stale {}", recent, stale);
+        // The newest commit counts fully, one a month older about 0.89
+        assert!((recent - (1.0 + 0.5_f64.powf(31.0 / 180.0))).abs() < 1e-9);
+        assert!(stale < 0.1);
+    }
+
+    #[test]
+    fn test_renamed_file_keeps_decayed_weight() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, timestamp TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             CREATE TABLE file_renames (sha TEXT, old_path TEXT, new_path TEXT, timestamp TEXT);
+             INSERT INTO commits VALUES
+                ('c1', '2025-05-01T12:00:00+00:00'),
+                ('c2', '2025-05-20T12:00:00+00:00'),
+                ('c3', '2025-06-01T12:00:00+00:00');
+             INSERT INTO commit_files VALUES
+                ('c1', 'src/engine.rs'), ('c1', 'src/scheduler.rs'),
+                ('c2', 'src/engine.rs'), ('c2', 'src/scheduler.rs'),
+                ('c3', 'src/core/engine.rs'), ('c3', 'src/scheduler.rs');
+             INSERT INTO file_renames VALUES
+                ('c3', 'src/engine.rs', 'src/core/engine.rs', '2025-06-01T12:00:00+00:00');",
+        )
+        .unwrap();
+
+        let weights = decayed_cochange_weights(&conn, 180.0).unwrap();
+        let pair = (
+            "src/core/engine.rs".to_string(),
+            "src/scheduler.rs".to_string(),
+        );
+
+        // All three commits count toward the current name
+        let expected = 1.0 + 0.5_f64.powf(12.0 / 180.0) + 0.5_f64.powf(31.0 / 180.0);
+        assert!((weights[&pair] - expected).abs() < 1e-9);
+        assert!(
+            !weights.contains_key(&("src/engine.rs".to_string(), "src/scheduler.rs".to_string()))
+        );
+    }
 }
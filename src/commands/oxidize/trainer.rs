@@ -8,6 +8,9 @@ use safetensors::SafeTensors;
 use std::collections::HashMap;
 use std::path::Path;
 
+/// Largest safetensors header read for metadata; real headers are a few KiB
+const MAX_HEADER_BYTES: u64 = 8 << 20;
+
 /// Cache of intermediate values from forward pass (for backprop)
 struct ForwardCache {
     /// Pre-activation at hidden layer
@@ -42,6 +45,11 @@ pub struct Projection {
 }
 
 impl Projection {
+    /// Dimension of projected vectors
+    pub fn output_dim(&self) -> usize {
+        self.b2.len()
+    }
+
     /// Create new projection with random weights
     pub fn new(input_dim: usize, hidden_dim: usize, output_dim: usize) -> Self {
         let mut rng = Rng::new();
@@ -282,6 +290,35 @@ impl Projection {
         Ok(())
     }
 
+    /// Output dimension recorded in a safetensors file's metadata, reading only the header
+    pub fn read_output_dim(path: &Path) -> Option<usize> {
+        Self::read_metadata_dim(path, "output_dim")
+    }
+
+    /// Input (embedding) dimension recorded in a safetensors file's metadata
+    pub fn read_input_dim(path: &Path) -> Option<usize> {
+        Self::read_metadata_dim(path, "input_dim")
+    }
+
+    fn read_metadata_dim(path: &Path, key: &str) -> Option<usize> {
+        use std::io::Read;
+
+        let mut file = std::fs::File::open(path).ok()?;
+        let file_len = file.metadata().ok()?.len();
+        let mut len = [0u8; 8];
+        file.read_exact(&mut len).ok()?;
+        // The length prefix is untrusted: never allocate past the file or the cap
+        let len = u64::from_le_bytes(len);
+        if len > MAX_HEADER_BYTES || len > file_len.saturating_sub(8) {
+            return None;
+        }
+        let mut header = vec![0u8; usize::try_from(len).ok()?];
+        file.read_exact(&mut header).ok()?;
+
+        let header: serde_json::Value = serde_json::from_slice(&header).ok()?;
+        header["__metadata__"][key].as_str()?.parse().ok()
+    }
+
     /// Load projection weights from safetensors format
     pub fn load_safetensors(path: &Path) -> Result<Self> {
         use std::fs;
@@ -451,6 +488,23 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_metadata_dim_ignores_bogus_header_length() -> Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("projection.safetensors");
+        Projection::new(10, 20, 5).save_safetensors(&path)?;
+        assert_eq!(Projection::read_output_dim(&path), Some(5));
+        assert_eq!(Projection::read_input_dim(&path), Some(10));
+
+        // A corrupt length prefix claiming terabytes of header
+        let mut bytes = (1u64 << 40).to_le_bytes().to_vec();
+        bytes.extend_from_slice(br#"{"__metadata__":{"output_dim":"5"}}"#);
+        std::fs::write(&path, bytes)?;
+        assert_eq!(Projection::read_output_dim(&path), None);
+
+        Ok(())
+    }
+
     #[test]
     fn test_forward_pass_after_load() -> Result<()> {
         use tempfile::tempdir;
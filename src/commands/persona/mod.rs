@@ -3,14 +3,20 @@
 //! Captures and queries user preferences, style, and knowledge that spans projects.
 //!
 //! Storage layout (via paths module):
-//! - Events (source): ~/.patina/personas/default/events/
-//! - Cache (derived): ~/.patina/cache/personas/default/
+//! - Events (source): ~/.patina/personas/<name>/events/
+//! - Cache (derived): ~/.patina/cache/personas/<name>/
 //!
-//! Entry points:
+//! `<name>` is the active persona: `--persona`, else `PATINA_PERSONA`, else
+//! `default`. Personas are separate knowledge bases (e.g. `work` vs `oss`);
+//! scry's persona results come from the active one.
+//!
+//! Entry points (each takes the persona name first, see `resolve`):
 //! - note(content, domains) - capture knowledge
-//! - materialize() - build searchable index from events
+//! - materialize() - build searchable index from events (incremental; `--force` rebuilds)
 //! - query(text, limit, min_score) - semantic search
 //! - list(limit, domains) - show recent entries
+//! - stats() - domain counts and active/superseded split
+//! - export(path) / import(path) - portable JSONL bundle for backup and transfer
 
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
@@ -18,10 +24,11 @@ use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::io::Write;
+use std::path::Path;
 use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
 use uuid::Uuid;
 
-use patina::embeddings::create_embedder;
+use patina::embeddings::{create_embedder, ensure_model_available, EmbeddingEngine};
 use patina::paths::persona as persona_paths;
 
 /// Captured knowledge event (private - implementation detail)
@@ -38,8 +45,27 @@ struct PersonaEvent {
     working_project: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     supersedes: Option<String>,
+    /// Event removed by a `knowledge_deleted` tombstone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    deletes: Option<String>,
+    /// After this instant the note stays in the log but is hidden from search and list
+    #[serde(
+        default,
+        with = "chrono::serde::ts_seconds_option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl PersonaEvent {
+    fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|t| t <= now)
+    }
 }
 
+const EVENT_CAPTURED: &str = "knowledge_captured";
+const EVENT_DELETED: &str = "knowledge_deleted";
+
 /// Query result (public - returned to callers)
 #[derive(Debug)]
 pub struct PersonaResult {
@@ -50,20 +76,62 @@ pub struct PersonaResult {
     pub timestamp: String,
 }
 
+/// Parse a TTL like `30m`, `12h`, `7d` or `2w`
+pub fn parse_ttl(ttl: &str) -> Result<chrono::Duration> {
+    let ttl = ttl.trim();
+    let split = ttl
+        .find(|c: char| !c.is_ascii_digit())
+        .with_context(|| format!("TTL needs a unit (m, h, d, w): '{}'", ttl))?;
+    let (amount, unit) = ttl.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("Invalid TTL amount: '{}'", ttl))?;
+
+    match unit {
+        "m" => Ok(chrono::Duration::minutes(amount)),
+        "h" => Ok(chrono::Duration::hours(amount)),
+        "d" => Ok(chrono::Duration::days(amount)),
+        "w" => Ok(chrono::Duration::weeks(amount)),
+        _ => anyhow::bail!("Unknown TTL unit '{}' (use m, h, d, w)", unit),
+    }
+}
+
+/// Persona a command works on: `--persona`, else `PATINA_PERSONA`, else `default`
+pub fn resolve(flag: Option<&str>) -> Result<String> {
+    match flag {
+        Some(name) => Ok(persona_paths::check_name(name)?.to_string()),
+        None => persona_paths::active(),
+    }
+}
+
+/// Persona to mix into search results, or None (with a warning) when
+/// `PATINA_PERSONA` is invalid
+///
+/// Search carries on without persona results rather than failing outright.
+pub fn active_for_search() -> Option<String> {
+    match persona_paths::active() {
+        Ok(name) => Some(name),
+        Err(e) => {
+            eprintln!("patina: {}; persona results disabled", e);
+            None
+        }
+    }
+}
+
 /// Capture knowledge directly
 pub fn note(
+    persona: &str,
     content: &str,
     domains: Option<Vec<String>>,
     supersedes: Option<String>,
+    ttl: Option<chrono::Duration>,
 ) -> Result<String> {
-    let events_dir = persona_paths::events_dir();
-    fs::create_dir_all(&events_dir).context("Failed to create events directory")?;
-
     let event_id = format!("evt_{}", Uuid::new_v4().simple());
+    let timestamp = Utc::now();
     let event = PersonaEvent {
         id: event_id.clone(),
-        event_type: "knowledge_captured".to_string(),
-        timestamp: Utc::now(),
+        event_type: EVENT_CAPTURED.to_string(),
+        timestamp,
         source: "direct".to_string(),
         content: content.to_string(),
         domains: domains.unwrap_or_default(),
@@ -71,12 +139,21 @@ pub fn note(
             .ok()
             .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string())),
         supersedes,
+        deletes: None,
+        expires_at: ttl.map(|d| timestamp + d),
     };
 
-    // Append to daily events file
+    append_event(&persona_paths::events_dir_for(persona), &event)?;
+    Ok(event_id)
+}
+
+/// Append an event to its daily events file
+fn append_event(events_dir: &Path, event: &PersonaEvent) -> Result<()> {
+    fs::create_dir_all(events_dir).context("Failed to create events directory")?;
+
     let date = event.timestamp.format("%Y%m%d").to_string();
     let events_file = events_dir.join(format!("{}.jsonl", date));
-    let line = serde_json::to_string(&event)?;
+    let line = serde_json::to_string(event)?;
 
     let mut file = fs::OpenOptions::new()
         .create(true)
@@ -84,20 +161,148 @@ pub fn note(
         .open(&events_file)?;
     writeln!(file, "{}", line)?;
 
-    Ok(event_id)
+    Ok(())
+}
+
+/// Entry removed (or previewed for removal) by `delete`
+#[derive(Debug)]
+pub struct DeletedEntry {
+    pub id: String,
+    pub content: String,
+}
+
+/// Remove knowledge by event id or case-insensitive content match.
+///
+/// The log stays append-only: each removal is a `knowledge_deleted` tombstone
+/// that `materialize` and `list` honor. With `dry_run`, nothing is written.
+pub fn delete(
+    persona: &str,
+    event_id: Option<&str>,
+    matching: Option<&str>,
+    dry_run: bool,
+) -> Result<Vec<DeletedEntry>> {
+    delete_in(
+        &persona_paths::events_dir_for(persona),
+        event_id,
+        matching,
+        dry_run,
+    )
+}
+
+fn delete_in(
+    events_dir: &Path,
+    event_id: Option<&str>,
+    matching: Option<&str>,
+    dry_run: bool,
+) -> Result<Vec<DeletedEntry>> {
+    let events = read_events(events_dir)?;
+    let deleted = deleted_ids(&events);
+    let needle = matching.map(|m| m.to_lowercase());
+
+    let targets: Vec<DeletedEntry> = events
+        .into_iter()
+        .filter(|e| e.event_type == EVENT_CAPTURED && !deleted.contains(&e.id))
+        .filter(|e| {
+            event_id.is_some_and(|id| e.id == id)
+                || needle
+                    .as_ref()
+                    .is_some_and(|n| e.content.to_lowercase().contains(n))
+        })
+        .map(|e| DeletedEntry {
+            id: e.id,
+            content: e.content,
+        })
+        .collect();
+
+    if let Some(id) = event_id {
+        if targets.is_empty() {
+            anyhow::bail!("No active persona entry with id: {}", id);
+        }
+    }
+
+    if !dry_run {
+        for target in &targets {
+            append_event(
+                events_dir,
+                &PersonaEvent {
+                    id: format!("evt_{}", Uuid::new_v4().simple()),
+                    event_type: EVENT_DELETED.to_string(),
+                    timestamp: Utc::now(),
+                    source: "direct".to_string(),
+                    content: String::new(),
+                    domains: Vec::new(),
+                    working_project: None,
+                    supersedes: None,
+                    deletes: Some(target.id.clone()),
+                    expires_at: None,
+                },
+            )?;
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Ids removed by tombstone events
+fn deleted_ids(events: &[PersonaEvent]) -> std::collections::HashSet<String> {
+    events
+        .iter()
+        .filter(|e| e.event_type == EVENT_DELETED)
+        .filter_map(|e| e.deletes.clone())
+        .collect()
+}
+
+/// Cosine similarity above which two notes are treated as rewordings of each other
+pub const DEFAULT_DEDUP_THRESHOLD: f32 = 0.97;
+
+/// Notes embedded per embedder call during materialize
+const EMBED_BATCH_SIZE: usize = 32;
+
+/// Vector index options (768-dim E5-base-v2)
+fn index_options() -> IndexOptions {
+    IndexOptions {
+        dimensions: 768,
+        metric: MetricKind::Cos,
+        quantization: ScalarKind::F32,
+        ..Default::default()
+    }
 }
 
 /// Build searchable index from events
-pub fn materialize() -> Result<()> {
-    let events_dir = persona_paths::events_dir();
-    let cache_dir = persona_paths::cache_dir();
-    fs::create_dir_all(&cache_dir)?;
+pub fn materialize(persona: &str) -> Result<()> {
+    materialize_with_threshold(persona, DEFAULT_DEDUP_THRESHOLD, false)
+}
 
-    let db_path = cache_dir.join("persona.db");
-    let index_path = cache_dir.join("persona.usearch");
+/// Build searchable index from events, deduplicating notes at the given similarity
+///
+/// With `force`, everything materialized so far is dropped and every event is
+/// re-embedded; otherwise only events after the last processed one are.
+pub fn materialize_with_threshold(persona: &str, dedup_threshold: f32, force: bool) -> Result<()> {
+    println!("Loading embedding model...");
+    let mut embedder = create_embedder()?;
+    let cache_dir = persona_paths::cache_dir_for(persona);
 
-    // Open database and create schema
-    let conn = Connection::open(&db_path)?;
+    if force {
+        println!("Dropping materialized knowledge for a full rebuild...");
+        reset_materialized(&cache_dir)?;
+    }
+
+    println!("Processing events...");
+    let processed = materialize_into(
+        &persona_paths::events_dir_for(persona),
+        &cache_dir,
+        embedder.as_mut(),
+        dedup_threshold,
+        Utc::now(),
+    )?;
+
+    println!("Materialized {} events", processed);
+    Ok(())
+}
+
+/// Open the materialized knowledge database, creating the schema if needed
+fn open_knowledge_db(db_path: &Path) -> Result<Connection> {
+    let conn = Connection::open(db_path)?;
     conn.execute(
         "CREATE TABLE IF NOT EXISTS knowledge (
             rowid INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -109,10 +314,18 @@ pub fn materialize() -> Result<()> {
             timestamp TEXT NOT NULL,
             working_project TEXT,
             supersedes TEXT,
-            superseded_by TEXT
+            superseded_by TEXT,
+            expires_at INTEGER
         )",
         [],
     )?;
+    // Databases materialized before TTL support lack the column
+    let has_expiry: bool = conn
+        .prepare("SELECT 1 FROM pragma_table_info('knowledge') WHERE name = 'expires_at'")?
+        .exists([])?;
+    if !has_expiry {
+        conn.execute("ALTER TABLE knowledge ADD COLUMN expires_at INTEGER", [])?;
+    }
     conn.execute(
         "CREATE TABLE IF NOT EXISTS metadata (key TEXT PRIMARY KEY, value TEXT)",
         [],
@@ -122,22 +335,53 @@ pub fn materialize() -> Result<()> {
         [],
     )?;
 
-    // Load embedder
-    println!("Loading embedding model...");
-    let mut embedder = create_embedder()?;
+    Ok(conn)
+}
 
-    // Create vector index (768-dim E5-base-v2)
-    let options = IndexOptions {
-        dimensions: 768,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
-    let index = Index::new(&options)?;
-    index.reserve(1000)?;
+/// Forget everything materialized so the next pass re-embeds every event
+fn reset_knowledge(conn: &Connection, index_path: &Path) -> Result<()> {
+    conn.execute("DELETE FROM knowledge", [])?;
+    conn.execute("DELETE FROM metadata WHERE key = 'last_event_id'", [])?;
+    if index_path.exists() {
+        fs::remove_file(index_path)
+            .with_context(|| format!("Failed to remove {}", index_path.display()))?;
+    }
+    Ok(())
+}
+
+/// Drop the knowledge table, vector index and resume point under `cache_dir`
+fn reset_materialized(cache_dir: &Path) -> Result<()> {
+    let db_path = cache_dir.join("persona.db");
+    if !db_path.exists() {
+        return Ok(());
+    }
+    let conn = open_knowledge_db(&db_path)?;
+    reset_knowledge(&conn, &cache_dir.join("persona.usearch"))
+}
+
+/// Materialize events from `events_dir` into `cache_dir`, returning how many were processed.
+///
+/// After embedding each event, its nearest already-indexed neighbours are checked;
+/// any active entry at or above `dedup_threshold` similarity is marked
+/// `superseded_by` the new event, so queries only surface the latest wording.
+/// Notes already past their expiry at `now` are not indexed. A missing or
+/// unreadable index can't be extended, so it triggers a full rebuild.
+fn materialize_into(
+    events_dir: &Path,
+    cache_dir: &Path,
+    embedder: &mut dyn EmbeddingEngine,
+    dedup_threshold: f32,
+    now: DateTime<Utc>,
+) -> Result<usize> {
+    fs::create_dir_all(cache_dir)?;
+
+    let db_path = cache_dir.join("persona.db");
+    let index_path = cache_dir.join("persona.usearch");
+
+    let conn = open_knowledge_db(&db_path)?;
 
     // Get last processed event ID for incremental
-    let last_id: Option<String> = conn
+    let mut last_id: Option<String> = conn
         .query_row(
             "SELECT value FROM metadata WHERE key = 'last_event_id'",
             [],
@@ -145,69 +389,127 @@ pub fn materialize() -> Result<()> {
         )
         .ok();
 
-    println!("Processing events...");
+    // Incremental runs extend the saved index so dedup sees earlier vectors
+    let mut index = Index::new(&index_options())?;
+    if last_id.is_some() {
+        let loaded = index_path.exists() && index.load(index_path.to_str().unwrap()).is_ok();
+        if !loaded {
+            eprintln!("⚠️  Persona index missing or unreadable, rebuilding from all events");
+            reset_knowledge(&conn, &index_path)?;
+            index = Index::new(&index_options())?;
+            last_id = None;
+        }
+    }
+    index.reserve(index.size() + 1000)?;
+
     let mut processed = 0;
-    let mut found_last = last_id.is_none();
     let mut last_processed_id: Option<String> = None;
 
-    // Read event files
-    if events_dir.exists() {
-        let mut files: Vec<_> = fs::read_dir(&events_dir)?
-            .filter_map(|e| e.ok())
-            .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
+    let events = read_events(events_dir)?;
+    // Tombstones are gathered up front so a deletion applies whichever order it arrives in
+    let deleted = deleted_ids(&events);
+
+    // Resume after the last processed event (nothing new if it's gone)
+    let start = match &last_id {
+        Some(id) => events
+            .iter()
+            .position(|e| &e.id == id)
+            .map_or(events.len(), |i| i + 1),
+        None => 0,
+    };
+    let is_live = |event: &PersonaEvent| {
+        event.event_type != EVENT_DELETED && !deleted.contains(&event.id) && !event.is_expired(now)
+    };
+
+    // Embedding is the slow part, so each chunk's notes go through the embedder
+    // in one call; index and table writes stay sequential and in event order
+    for chunk in events[start..].chunks(EMBED_BATCH_SIZE) {
+        let texts: Vec<String> = chunk
+            .iter()
+            .filter(|e| is_live(e))
+            .map(|e| e.content.clone())
             .collect();
-        files.sort_by_key(|a| a.file_name());
-
-        for entry in files {
-            let content = fs::read_to_string(entry.path())?;
-            for line in content.lines() {
-                if let Ok(event) = serde_json::from_str::<PersonaEvent>(line) {
-                    // Skip until we find last processed
-                    if !found_last {
-                        if Some(&event.id) == last_id.as_ref() {
-                            found_last = true;
-                        }
-                        continue;
+        let mut embeddings = embedder.embed_query_batch(&texts)?.into_iter();
+
+        for event in chunk {
+            if event.event_type == EVENT_DELETED {
+                // Drop an already-materialized target from both index and table
+                if let Some(ref target) = event.deletes {
+                    let rowid: Option<i64> = conn
+                        .query_row(
+                            "SELECT rowid FROM knowledge WHERE id = ?1",
+                            params![target],
+                            |row| row.get(0),
+                        )
+                        .ok();
+                    if let Some(rowid) = rowid {
+                        index.remove(rowid as u64)?;
+                        conn.execute("DELETE FROM knowledge WHERE rowid = ?1", params![rowid])?;
                     }
+                }
+                last_processed_id = Some(event.id.clone());
+                processed += 1;
+                continue;
+            }
+            if !is_live(event) {
+                last_processed_id = Some(event.id.clone());
+                continue;
+            }
+
+            // If this supersedes another event, mark the old one
+            if let Some(ref old_id) = event.supersedes {
+                conn.execute(
+                    "UPDATE knowledge SET superseded_by = ?1 WHERE id = ?2",
+                    params![&event.id, old_id],
+                )?;
+            }
 
-                    // If this supersedes another event, mark the old one
-                    if let Some(ref old_id) = event.supersedes {
+            let embedding = embeddings
+                .next()
+                .context("Embedder returned fewer vectors than notes")?;
+
+            // Near-duplicates of the new note are superseded by it
+            if index.size() > 0 {
+                let neighbours = index.search(&embedding, 5)?;
+                for (rowid, distance) in neighbours.keys.iter().zip(neighbours.distances.iter()) {
+                    if 1.0 - distance >= dedup_threshold {
                         conn.execute(
-                            "UPDATE knowledge SET superseded_by = ?1 WHERE id = ?2",
-                            params![&event.id, old_id],
+                            "UPDATE knowledge SET superseded_by = ?1
+                             WHERE rowid = ?2 AND superseded_by IS NULL AND id != ?1",
+                            params![&event.id, *rowid as i64],
                         )?;
                     }
-
-                    // Embed and store
-                    let embedding = embedder.embed_query(&event.content)?;
-
-                    let rowid: i64 = conn.query_row(
-                        "INSERT OR REPLACE INTO knowledge (id, event_type, content, source, domains, timestamp, working_project, supersedes, superseded_by)
-                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, NULL)
-                         RETURNING rowid",
-                        params![
-                            &event.id,
-                            &event.event_type,
-                            &event.content,
-                            &event.source,
-                            serde_json::to_string(&event.domains)?,
-                            event.timestamp.to_rfc3339(),
-                            &event.working_project,
-                            &event.supersedes,
-                        ],
-                        |row| row.get(0),
-                    )?;
-
-                    index.add(rowid as u64, &embedding)?;
-                    last_processed_id = Some(event.id.clone());
-                    processed += 1;
-
-                    if processed % 10 == 0 {
-                        print!(".");
-                        std::io::stdout().flush().ok();
-                    }
                 }
             }
+
+            // superseded_by is looked up rather than NULL so an imported event
+            // that a newer local event already supersedes stays superseded
+            let rowid: i64 = conn.query_row(
+                "INSERT OR REPLACE INTO knowledge (id, event_type, content, source, domains, timestamp, working_project, supersedes, superseded_by, expires_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, (SELECT id FROM knowledge WHERE supersedes = ?1 LIMIT 1), ?9)
+                 RETURNING rowid",
+                params![
+                    &event.id,
+                    &event.event_type,
+                    &event.content,
+                    &event.source,
+                    serde_json::to_string(&event.domains)?,
+                    event.timestamp.to_rfc3339(),
+                    &event.working_project,
+                    &event.supersedes,
+                    event.expires_at.map(|t| t.timestamp()),
+                ],
+                |row| row.get(0),
+            )?;
+
+            index.add(rowid as u64, &embedding)?;
+            last_processed_id = Some(event.id.clone());
+            processed += 1;
+
+            if processed % 10 == 0 {
+                print!(".");
+                std::io::stdout().flush().ok();
+            }
         }
     }
 
@@ -223,18 +525,45 @@ pub fn materialize() -> Result<()> {
         }
     }
 
-    println!("Materialized {} events", processed);
-    Ok(())
+    Ok(processed)
 }
 
 /// Semantic search of persona knowledge
 pub fn query(
+    persona: &str,
     query_text: &str,
     limit: usize,
     min_score: f32,
     domains: Option<Vec<String>>,
 ) -> Result<Vec<PersonaResult>> {
-    let cache_dir = persona_paths::cache_dir();
+    let cache_dir = persona_paths::cache_dir_for(persona);
+    if !cache_dir.join("persona.usearch").exists() {
+        return Ok(Vec::new());
+    }
+
+    ensure_model_available(false)?;
+    let mut embedder = create_embedder()?;
+    query_in(
+        &cache_dir,
+        embedder.as_mut(),
+        query_text,
+        limit,
+        min_score,
+        domains,
+        Utc::now(),
+    )
+}
+
+/// Semantic search against the index in `cache_dir`, hiding entries expired at `now`
+fn query_in(
+    cache_dir: &Path,
+    embedder: &mut dyn EmbeddingEngine,
+    query_text: &str,
+    limit: usize,
+    min_score: f32,
+    domains: Option<Vec<String>>,
+    now: DateTime<Utc>,
+) -> Result<Vec<PersonaResult>> {
     let db_path = cache_dir.join("persona.db");
     let index_path = cache_dir.join("persona.usearch");
 
@@ -243,17 +572,10 @@ pub fn query(
     }
 
     // Load index
-    let options = IndexOptions {
-        dimensions: 768,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
-    let index = Index::new(&options)?;
+    let index = Index::new(&index_options())?;
     index.load(index_path.to_str().unwrap())?;
 
     // Embed query
-    let mut embedder = create_embedder()?;
     let query_embedding = embedder.embed_query(query_text)?;
 
     // Search more than limit to allow for filtering
@@ -270,11 +592,12 @@ pub fn query(
             continue;
         }
 
-        // Query excludes superseded entries
+        // Query excludes superseded and expired entries
         let result = conn.query_row(
             "SELECT content, source, domains, timestamp FROM knowledge
-             WHERE rowid = ?1 AND superseded_by IS NULL",
-            params![*rowid as i64],
+             WHERE rowid = ?1 AND superseded_by IS NULL
+               AND (expires_at IS NULL OR expires_at > ?2)",
+            params![*rowid as i64, now.timestamp()],
             |row| {
                 let domains_json: String = row.get(2)?;
                 let domains: Vec<String> = serde_json::from_str(&domains_json).unwrap_or_default();
@@ -309,9 +632,9 @@ pub fn query(
 }
 
 /// Check persona oracle status
-pub fn status() -> Result<PersonaStatus> {
-    let events_dir = persona_paths::events_dir();
-    let cache_dir = persona_paths::cache_dir();
+pub fn status(persona: &str) -> Result<PersonaStatus> {
+    let events_dir = persona_paths::events_dir_for(persona);
+    let cache_dir = persona_paths::cache_dir_for(persona);
     let db_path = cache_dir.join("persona.db");
     let index_path = cache_dir.join("persona.usearch");
 
@@ -349,6 +672,71 @@ pub fn status() -> Result<PersonaStatus> {
     })
 }
 
+/// Shape of the materialized knowledge base
+#[derive(Debug, Default, Serialize)]
+pub struct PersonaStats {
+    pub total: usize,
+    pub active: usize,
+    pub superseded: usize,
+    pub earliest: Option<String>,
+    pub latest: Option<String>,
+    /// Active entries per domain, most frequent first
+    pub domains: Vec<DomainCount>,
+}
+
+/// Active entry count for one domain
+#[derive(Debug, Serialize, PartialEq)]
+pub struct DomainCount {
+    pub domain: String,
+    pub count: usize,
+}
+
+/// Summarize the materialized knowledge table
+pub fn stats(persona: &str) -> Result<PersonaStats> {
+    let db_path = persona_paths::cache_dir_for(persona).join("persona.db");
+    if !db_path.exists() {
+        return Ok(PersonaStats::default());
+    }
+    stats_in(&Connection::open(&db_path)?)
+}
+
+fn stats_in(conn: &Connection) -> Result<PersonaStats> {
+    let (total, active, earliest, latest): (i64, i64, Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT COUNT(*), COUNT(*) FILTER (WHERE superseded_by IS NULL), MIN(timestamp), MAX(timestamp)
+             FROM knowledge",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )?;
+
+    let mut counts: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    let mut stmt = conn.prepare("SELECT domains FROM knowledge WHERE superseded_by IS NULL")?;
+    let rows = stmt.query_map([], |row| row.get::<_, Option<String>>(0))?;
+    for domains_json in rows {
+        let domains: Vec<String> = domains_json?
+            .and_then(|j| serde_json::from_str(&j).ok())
+            .unwrap_or_default();
+        for domain in domains {
+            *counts.entry(domain).or_default() += 1;
+        }
+    }
+
+    let mut domains: Vec<DomainCount> = counts
+        .into_iter()
+        .map(|(domain, count)| DomainCount { domain, count })
+        .collect();
+    domains.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.domain.cmp(&b.domain)));
+
+    Ok(PersonaStats {
+        total: total as usize,
+        active: active as usize,
+        superseded: (total - active) as usize,
+        earliest,
+        latest,
+        domains,
+    })
+}
+
 /// Persona oracle status
 #[derive(Debug)]
 pub struct PersonaStatus {
@@ -360,58 +748,161 @@ pub struct PersonaStatus {
 }
 
 /// List recent persona entries from event files
-pub fn list(limit: usize, domains: Option<Vec<String>>) -> Result<Vec<PersonaResult>> {
-    let events_dir = persona_paths::events_dir();
+pub fn list(
+    persona: &str,
+    limit: usize,
+    domains: Option<Vec<String>>,
+) -> Result<Vec<PersonaResult>> {
+    list_in(
+        &persona_paths::events_dir_for(persona),
+        limit,
+        domains,
+        Utc::now(),
+    )
+}
+
+fn list_in(
+    events_dir: &Path,
+    limit: usize,
+    domains: Option<Vec<String>>,
+    now: DateTime<Utc>,
+) -> Result<Vec<PersonaResult>> {
+    let events = read_events(events_dir)?;
+    let deleted = deleted_ids(&events);
+
+    // Newest first, skipping tombstones, the entries they remove, and expired notes
+    let results = events
+        .into_iter()
+        .rev()
+        .filter(|e| e.event_type != EVENT_DELETED && !deleted.contains(&e.id))
+        .filter(|e| !e.is_expired(now))
+        // Filter by domains if specified
+        .filter(|e| {
+            domains
+                .as_ref()
+                .is_none_or(|filter| filter.iter().any(|d| e.domains.contains(d)))
+        })
+        .take(limit)
+        .map(|event| PersonaResult {
+            content: event.content,
+            score: 1.0, // No score for list
+            domains: event.domains,
+            source: event.source,
+            timestamp: event.timestamp.to_rfc3339(),
+        })
+        .collect();
+
+    Ok(results)
+}
 
+/// Read all events from a directory of daily `.jsonl` files, in file then line order
+fn read_events(events_dir: &Path) -> Result<Vec<PersonaEvent>> {
+    let mut events = Vec::new();
     if !events_dir.exists() {
-        return Ok(Vec::new());
+        return Ok(events);
     }
 
-    let mut results = Vec::new();
-
-    // Read event files newest first
-    let mut files: Vec<_> = fs::read_dir(&events_dir)?
+    let mut files: Vec<_> = fs::read_dir(events_dir)?
         .filter_map(|e| e.ok())
         .filter(|e| e.path().extension().is_some_and(|ext| ext == "jsonl"))
         .collect();
-    files.sort_by_key(|b| std::cmp::Reverse(b.file_name()));
+    files.sort_by_key(|a| a.file_name());
 
     for entry in files {
         let content = fs::read_to_string(entry.path())?;
-        for line in content.lines().rev() {
-            if let Ok(event) = serde_json::from_str::<PersonaEvent>(line) {
-                // Filter by domains if specified
-                if let Some(ref filter) = domains {
-                    if !filter.iter().any(|d| event.domains.contains(d)) {
-                        continue;
-                    }
-                }
+        events.extend(
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str::<PersonaEvent>(line).ok()),
+        );
+    }
 
-                results.push(PersonaResult {
-                    content: event.content,
-                    score: 1.0, // No score for list
-                    domains: event.domains,
-                    source: event.source,
-                    timestamp: event.timestamp.to_rfc3339(),
-                });
+    Ok(events)
+}
 
-                if results.len() >= limit {
-                    return Ok(results);
-                }
-            }
+/// Write every event into one JSONL bundle, oldest first.
+///
+/// The sort is stable, so events captured in the same second keep their
+/// append order and a superseding event always follows the one it replaces.
+fn export_events(events_dir: &Path, bundle_path: &Path) -> Result<usize> {
+    let mut events = read_events(events_dir)?;
+    events.sort_by_key(|e| e.timestamp);
+
+    let mut out = String::new();
+    for event in &events {
+        out.push_str(&serde_json::to_string(event)?);
+        out.push('\n');
+    }
+    fs::write(bundle_path, out)
+        .with_context(|| format!("Failed to write bundle: {}", bundle_path.display()))?;
+
+    Ok(events.len())
+}
+
+/// Counts from merging a bundle into the events directory
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportStats {
+    pub imported: usize,
+    pub skipped: usize,
+}
+
+/// Merge a JSONL bundle into the events directory, skipping ids already present.
+///
+/// New events go to today's daily file rather than their original date, so an
+/// incremental `materialize` (which resumes after the last processed id) sees them.
+fn import_events(events_dir: &Path, bundle_path: &Path) -> Result<ImportStats> {
+    let bundle = fs::read_to_string(bundle_path)
+        .with_context(|| format!("Failed to read bundle: {}", bundle_path.display()))?;
+
+    let mut known: std::collections::HashSet<String> =
+        read_events(events_dir)?.into_iter().map(|e| e.id).collect();
+
+    let mut stats = ImportStats::default();
+    let mut lines = Vec::new();
+    for line in bundle.lines().filter(|l| !l.trim().is_empty()) {
+        let event: PersonaEvent =
+            serde_json::from_str(line).context("Invalid persona event in bundle")?;
+        if !known.insert(event.id.clone()) {
+            stats.skipped += 1;
+            continue;
         }
+        lines.push(serde_json::to_string(&event)?);
+        stats.imported += 1;
     }
 
-    Ok(results)
+    if !lines.is_empty() {
+        fs::create_dir_all(events_dir).context("Failed to create events directory")?;
+        let date = Utc::now().format("%Y%m%d").to_string();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(events_dir.join(format!("{}.jsonl", date)))?;
+        for line in &lines {
+            writeln!(file, "{}", line)?;
+        }
+    }
+
+    Ok(stats)
+}
+
+/// Export all persona events to a portable JSONL bundle
+pub fn export(persona: &str, bundle_path: &Path) -> Result<usize> {
+    export_events(&persona_paths::events_dir_for(persona), bundle_path)
+}
+
+/// Import a JSONL bundle into persona events (idempotent: duplicates by id are skipped)
+pub fn import(persona: &str, bundle_path: &Path) -> Result<ImportStats> {
+    import_events(&persona_paths::events_dir_for(persona), bundle_path)
 }
 
 // === CLI execute functions ===
 
 /// Execute persona status command
-pub fn execute_status() -> Result<()> {
-    let s = status()?;
+pub fn execute_status(persona: &str) -> Result<()> {
+    let s = status(persona)?;
 
     println!("🧠 Persona Oracle Status\n");
+    println!("   Persona: {}", persona);
 
     if s.oracle_available {
         println!("   Status: ✓ Available");
@@ -442,13 +933,17 @@ pub fn execute_status() -> Result<()> {
 
 /// Execute persona note command
 pub fn execute_note(
+    persona: &str,
     content: &str,
     domains: Option<Vec<String>>,
     supersedes: Option<String>,
+    ttl: Option<String>,
 ) -> Result<()> {
+    let ttl = ttl.as_deref().map(parse_ttl).transpose()?;
+
     println!("🧠 Persona - Capturing knowledge\n");
 
-    let event_id = note(content, domains.clone(), supersedes.clone())?;
+    let event_id = note(persona, content, domains.clone(), supersedes.clone(), ttl)?;
 
     if let Some(ref d) = domains {
         println!("   Domains: {}", d.join(", "));
@@ -456,6 +951,9 @@ pub fn execute_note(
     if let Some(ref s) = supersedes {
         println!("   Supersedes: {}", s);
     }
+    if let Some(d) = ttl {
+        println!("   Expires: {}", (Utc::now() + d).format("%Y-%m-%d %H:%M"));
+    }
     println!("   Content: {}", content);
     println!("\n✅ Captured: {}", event_id);
 
@@ -463,26 +961,55 @@ pub fn execute_note(
 }
 
 /// Execute persona materialize command
-pub fn execute_materialize() -> Result<()> {
+pub fn execute_materialize(persona: &str, dedup_threshold: f32, force: bool) -> Result<()> {
     println!("🧠 Persona - Materializing knowledge base\n");
-    materialize()?;
+    materialize_with_threshold(persona, dedup_threshold, force)?;
+    Ok(())
+}
+
+/// Execute persona export command
+pub fn execute_export(persona: &str, path: &str) -> Result<()> {
+    println!("🧠 Persona - Exporting knowledge\n");
+    let count = export(persona, Path::new(path))?;
+    println!("✅ Exported {} events to {}", count, path);
+    Ok(())
+}
+
+/// Execute persona import command
+pub fn execute_import(persona: &str, path: &str) -> Result<()> {
+    println!("🧠 Persona - Importing knowledge\n");
+    let stats = import(persona, Path::new(path))?;
+    println!(
+        "   Imported {} events ({} already present)\n",
+        stats.imported, stats.skipped
+    );
+
+    if stats.imported > 0 {
+        materialize(persona)?;
+    }
     Ok(())
 }
 
 /// Execute persona query command
 pub fn execute_query(
+    persona: &str,
     query_text: &str,
     limit: usize,
     min_score: f32,
     domains: Option<Vec<String>>,
+    auto_pull: bool,
 ) -> Result<()> {
+    if auto_pull {
+        ensure_model_available(true)?;
+    }
+
     println!("🧠 Persona - Searching knowledge\n");
     if let Some(ref d) = domains {
         println!("Domains: {}", d.join(", "));
     }
     println!("Query: \"{}\"\n", query_text);
 
-    let results = query(query_text, limit, min_score, domains)?;
+    let results = query(persona, query_text, limit, min_score, domains)?;
 
     if results.is_empty() {
         println!("No results found.");
@@ -515,10 +1042,10 @@ pub fn execute_query(
 }
 
 /// Execute persona list command
-pub fn execute_list(limit: usize, domains: Option<Vec<String>>) -> Result<()> {
+pub fn execute_list(persona: &str, limit: usize, domains: Option<Vec<String>>) -> Result<()> {
     println!("🧠 Persona - Captured knowledge\n");
 
-    let results = list(limit, domains)?;
+    let results = list(persona, limit, domains)?;
 
     if results.is_empty() {
         println!("No knowledge captured yet.");
@@ -554,6 +1081,82 @@ pub fn execute_list(limit: usize, domains: Option<Vec<String>>) -> Result<()> {
     Ok(())
 }
 
+/// Execute persona delete command
+pub fn execute_delete(
+    persona: &str,
+    event_id: Option<String>,
+    matching: Option<String>,
+    dry_run: bool,
+) -> Result<()> {
+    if event_id.is_none() && matching.is_none() {
+        anyhow::bail!("Provide an event id or --matching <text>");
+    }
+
+    println!("🧠 Persona - Deleting knowledge\n");
+
+    let entries = delete(persona, event_id.as_deref(), matching.as_deref(), dry_run)?;
+    if entries.is_empty() {
+        println!("No matching entries.");
+        return Ok(());
+    }
+
+    for entry in &entries {
+        println!("   {}  {}", entry.id, truncate(&entry.content, 80));
+    }
+
+    if dry_run {
+        println!("\nWould delete {} entries (dry run)", entries.len());
+    } else {
+        println!("\n✅ Deleted {} entries", entries.len());
+        println!("   Run: patina persona materialize");
+    }
+    Ok(())
+}
+
+/// Execute persona stats command
+pub fn execute_stats(persona: &str, json: bool) -> Result<()> {
+    let s = stats(persona)?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&s)?);
+        return Ok(());
+    }
+
+    println!("🧠 Persona - Knowledge stats\n");
+
+    if s.total == 0 {
+        println!("No materialized knowledge.");
+        println!("Run: patina persona materialize");
+        return Ok(());
+    }
+
+    println!(
+        "   Entries: {} active, {} superseded ({} total)",
+        s.active, s.superseded, s.total
+    );
+    let day = |ts: &Option<String>| {
+        ts.as_deref()
+            .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "?".to_string())
+    };
+    println!("   Range:   {} → {}", day(&s.earliest), day(&s.latest));
+
+    if s.domains.is_empty() {
+        println!("\n   No domains tagged.");
+    } else {
+        println!("\n   Top domains:");
+        for d in s.domains.iter().take(10) {
+            println!("   {:>5}  {}", d.count, d.domain);
+        }
+        if s.domains.len() > 10 {
+            println!("   ... and {} more", s.domains.len() - 10);
+        }
+    }
+
+    Ok(())
+}
+
 fn truncate(s: &str, max: usize) -> String {
     let s = s.replace('\n', " ").trim().to_string();
     if s.len() <= max {
@@ -584,6 +1187,8 @@ mod tests {
             domains: vec!["rust".to_string()],
             working_project: Some("test".to_string()),
             supersedes: None,
+            deletes: None,
+            expires_at: None,
         };
 
         let date = event.timestamp.format("%Y%m%d").to_string();
@@ -600,6 +1205,578 @@ mod tests {
         Ok(())
     }
 
+    fn test_event(id: &str, secs: i64, content: &str, supersedes: Option<&str>) -> PersonaEvent {
+        PersonaEvent {
+            id: id.to_string(),
+            event_type: "knowledge_captured".to_string(),
+            timestamp: DateTime::from_timestamp(secs, 0).unwrap(),
+            source: "direct".to_string(),
+            content: content.to_string(),
+            domains: vec!["rust".to_string()],
+            working_project: None,
+            supersedes: supersedes.map(|s| s.to_string()),
+            deletes: None,
+            expires_at: None,
+        }
+    }
+
+    fn write_events(dir: &Path, file: &str, events: &[PersonaEvent]) -> Result<()> {
+        fs::create_dir_all(dir)?;
+        let lines: Vec<String> = events
+            .iter()
+            .map(|e| serde_json::to_string(e).unwrap())
+            .collect();
+        fs::write(dir.join(file), format!("{}\n", lines.join("\n")))?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_import_round_trip_is_idempotent() -> Result<()> {
+        let temp = TempDir::new()?;
+        let source_dir = temp.path().join("source");
+        let target_dir = temp.path().join("target");
+        let bundle = temp.path().join("persona.jsonl");
+
+        // Superseding event lives in a later daily file than the one it replaces
+        write_events(
+            &source_dir,
+            "20260101.jsonl",
+            &[
+                test_event("evt_a", 1_767_225_600, "prefer anyhow", None),
+                test_event("evt_b", 1_767_225_700, "use rayon for parallelism", None),
+            ],
+        )?;
+        write_events(
+            &source_dir,
+            "20260102.jsonl",
+            &[test_event(
+                "evt_c",
+                1_767_312_000,
+                "prefer thiserror in libraries, anyhow in bins",
+                Some("evt_a"),
+            )],
+        )?;
+
+        assert_eq!(export_events(&source_dir, &bundle)?, 3);
+
+        let first = import_events(&target_dir, &bundle)?;
+        assert_eq!(
+            first,
+            ImportStats {
+                imported: 3,
+                skipped: 0
+            }
+        );
+
+        let second = import_events(&target_dir, &bundle)?;
+        assert_eq!(
+            second,
+            ImportStats {
+                imported: 0,
+                skipped: 3
+            }
+        );
+
+        let events = read_events(&target_dir)?;
+        let ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(ids, vec!["evt_a", "evt_b", "evt_c"]);
+
+        // Chain survives and the replaced entry precedes its replacement
+        assert_eq!(events[2].supersedes.as_deref(), Some("evt_a"));
+
+        // Materialized from the imported events, only the replacement answers
+        let cache_dir = temp.path().join("cache");
+        let mut embedder = FakeEmbedder;
+        materialize_into(
+            &target_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+        let results = query_in(
+            &cache_dir,
+            &mut embedder,
+            "prefer anyhow",
+            10,
+            0.0,
+            None,
+            Utc::now(),
+        )?;
+        let contents: Vec<&str> = results.iter().map(|r| r.content.as_str()).collect();
+        assert!(contents.contains(&"prefer thiserror in libraries, anyhow in bins"));
+        assert!(!contents.contains(&"prefer anyhow"));
+        assert!(contents.contains(&"use rayon for parallelism"));
+
+        Ok(())
+    }
+
+    /// Deterministic embedder: case and punctuation are ignored, so rewordings
+    /// that differ only in those collapse to the same vector
+    struct FakeEmbedder;
+
+    impl EmbeddingEngine for FakeEmbedder {
+        fn embed(&mut self, text: &str) -> Result<Vec<f32>> {
+            let mut v = vec![0.0f32; 768];
+            for word in text
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|w| !w.is_empty())
+            {
+                let h = word.to_lowercase().bytes().fold(7usize, |acc, b| {
+                    acc.wrapping_mul(31).wrapping_add(b as usize)
+                });
+                v[h % 768] += 1.0;
+            }
+            let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt().max(1e-6);
+            Ok(v.into_iter().map(|x| x / norm).collect())
+        }
+
+        fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            texts.iter().map(|t| self.embed(t)).collect()
+        }
+
+        fn dimension(&self) -> usize {
+            768
+        }
+
+        fn model_name(&self) -> &str {
+            "fake"
+        }
+    }
+
+    #[test]
+    fn test_materialize_dedups_near_identical_notes() -> Result<()> {
+        let temp = TempDir::new()?;
+        let events_dir = temp.path().join("events");
+        let cache_dir = temp.path().join("cache");
+
+        write_events(
+            &events_dir,
+            "20260101.jsonl",
+            &[
+                test_event("evt_old", 1_767_225_600, "Prefer tabs over spaces.", None),
+                test_event(
+                    "evt_other",
+                    1_767_225_650,
+                    "Commit messages stay short",
+                    None,
+                ),
+                test_event("evt_new", 1_767_225_700, "prefer TABS over spaces", None),
+            ],
+        )?;
+
+        let mut embedder = FakeEmbedder;
+        let processed = materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+        assert_eq!(processed, 3);
+
+        let results = query_in(
+            &cache_dir,
+            &mut embedder,
+            "tabs over spaces",
+            10,
+            0.0,
+            None,
+            Utc::now(),
+        )?;
+        let contents: Vec<&str> = results.iter().map(|r| r.content.as_str()).collect();
+        assert!(contents.contains(&"prefer TABS over spaces"));
+        assert!(!contents.contains(&"Prefer tabs over spaces."));
+        assert!(contents.contains(&"Commit messages stay short"));
+
+        Ok(())
+    }
+
+    /// Records the size of every batch call it serves
+    struct BatchCountingEmbedder {
+        batches: Vec<usize>,
+    }
+
+    impl EmbeddingEngine for BatchCountingEmbedder {
+        fn embed(&mut self, text: &str) -> Result<Vec<f32>> {
+            FakeEmbedder.embed(text)
+        }
+
+        fn embed_query_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            self.batches.push(texts.len());
+            FakeEmbedder.embed_batch(texts)
+        }
+
+        fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+            FakeEmbedder.embed_batch(texts)
+        }
+
+        fn dimension(&self) -> usize {
+            768
+        }
+
+        fn model_name(&self) -> &str {
+            "fake-batching"
+        }
+    }
+
+    #[test]
+    fn test_materialize_batches_embeddings_and_tracks_last_event() -> Result<()> {
+        let temp = TempDir::new()?;
+        let events_dir = temp.path().join("events");
+        let cache_dir = temp.path().join("cache");
+
+        let count = EMBED_BATCH_SIZE * 2 + 5;
+        let events: Vec<PersonaEvent> = (0..count)
+            .map(|i| {
+                test_event(
+                    &format!("evt_{:03}", i),
+                    1_767_225_600 + i as i64,
+                    &format!("topic{} detail{}", i, i * 7),
+                    None,
+                )
+            })
+            .collect();
+        write_events(&events_dir, "20260101.jsonl", &events)?;
+
+        let mut embedder = BatchCountingEmbedder {
+            batches: Vec::new(),
+        };
+        let processed = materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+        assert_eq!(processed, count);
+        assert_eq!(
+            embedder.batches,
+            vec![EMBED_BATCH_SIZE, EMBED_BATCH_SIZE, 5]
+        );
+
+        let index = Index::new(&index_options())?;
+        index.load(cache_dir.join("persona.usearch").to_str().unwrap())?;
+        assert_eq!(index.size(), count);
+
+        let conn = open_knowledge_db(&cache_dir.join("persona.db"))?;
+        let rows: usize = conn.query_row("SELECT COUNT(*) FROM knowledge", [], |row| row.get(0))?;
+        assert_eq!(rows, count);
+        let last: String = conn.query_row(
+            "SELECT value FROM metadata WHERE key = 'last_event_id'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(last, format!("evt_{:03}", count - 1));
+
+        // Nothing new on a second run
+        let processed = materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+        assert_eq!(processed, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_corrupt_index_triggers_full_rebuild() -> Result<()> {
+        let temp = TempDir::new()?;
+        let events_dir = temp.path().join("events");
+        let cache_dir = temp.path().join("cache");
+        write_events(
+            &events_dir,
+            "20260101.jsonl",
+            &[
+                test_event("evt_a", 1_767_225_600, "Prefer tabs over spaces", None),
+                test_event("evt_b", 1_767_225_650, "Commit messages stay short", None),
+                test_event("evt_c", 1_767_225_700, "Errors carry context", None),
+            ],
+        )?;
+        let mut embedder = FakeEmbedder;
+        let materialize = |embedder: &mut FakeEmbedder| {
+            materialize_into(
+                &events_dir,
+                &cache_dir,
+                embedder,
+                DEFAULT_DEDUP_THRESHOLD,
+                Utc::now(),
+            )
+        };
+        let indexed = || -> Result<(usize, usize)> {
+            let index = Index::new(&index_options())?;
+            index.load(cache_dir.join("persona.usearch").to_str().unwrap())?;
+            let conn = open_knowledge_db(&cache_dir.join("persona.db"))?;
+            let rows = conn.query_row("SELECT COUNT(*) FROM knowledge", [], |row| row.get(0))?;
+            Ok((index.size(), rows))
+        };
+
+        assert_eq!(materialize(&mut embedder)?, 3);
+
+        // A stale resume point would otherwise skip every event
+        fs::write(cache_dir.join("persona.usearch"), b"not a usearch index")?;
+        assert_eq!(materialize(&mut embedder)?, 3);
+        assert_eq!(indexed()?, (3, 3));
+
+        // --force rebuilds from scratch even when the index is fine
+        reset_materialized(&cache_dir)?;
+        assert!(!cache_dir.join("persona.usearch").exists());
+        assert_eq!(materialize(&mut embedder)?, 3);
+        assert_eq!(indexed()?, (3, 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stats_counts_domains_and_superseded() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = open_knowledge_db(&temp.path().join("persona.db"))?;
+
+        let rows = [
+            (
+                "e1",
+                r#"["rust","errors"]"#,
+                "2026-01-01T00:00:00+00:00",
+                Some("e3"),
+            ),
+            ("e2", r#"["rust"]"#, "2026-02-01T00:00:00+00:00", None),
+            (
+                "e3",
+                r#"["rust","errors"]"#,
+                "2026-03-01T00:00:00+00:00",
+                None,
+            ),
+            ("e4", r#"["git"]"#, "2026-04-01T00:00:00+00:00", None),
+        ];
+        for (id, domains, ts, superseded_by) in rows {
+            conn.execute(
+                "INSERT INTO knowledge (id, event_type, content, source, domains, timestamp, superseded_by)
+                 VALUES (?1, 'knowledge_captured', 'x', 'direct', ?2, ?3, ?4)",
+                params![id, domains, ts, superseded_by],
+            )?;
+        }
+
+        let s = stats_in(&conn)?;
+        assert_eq!((s.total, s.active, s.superseded), (4, 3, 1));
+        assert_eq!(s.earliest.as_deref(), Some("2026-01-01T00:00:00+00:00"));
+        assert_eq!(s.latest.as_deref(), Some("2026-04-01T00:00:00+00:00"));
+        assert_eq!(
+            s.domains,
+            vec![
+                DomainCount {
+                    domain: "rust".to_string(),
+                    count: 2
+                },
+                DomainCount {
+                    domain: "errors".to_string(),
+                    count: 1
+                },
+                DomainCount {
+                    domain: "git".to_string(),
+                    count: 1
+                },
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_delete_tombstone_hides_entry() -> Result<()> {
+        let temp = TempDir::new()?;
+        let events_dir = temp.path().join("events");
+        let cache_dir = temp.path().join("cache");
+
+        write_events(
+            &events_dir,
+            "20260101.jsonl",
+            &[
+                test_event(
+                    "evt_keep",
+                    1_767_225_600,
+                    "use rayon for parallel scans",
+                    None,
+                ),
+                test_event("evt_wrong", 1_767_225_700, "always panic on errors", None),
+            ],
+        )?;
+
+        let mut embedder = FakeEmbedder;
+        materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+
+        // Dry run previews without writing a tombstone
+        let preview = delete_in(&events_dir, None, Some("PANIC"), true)?;
+        assert_eq!(preview.len(), 1);
+        assert_eq!(list_in(&events_dir, 10, None, Utc::now())?.len(), 2);
+
+        let deleted = delete_in(&events_dir, Some("evt_wrong"), None, false)?;
+        assert_eq!(deleted[0].id, "evt_wrong");
+        assert!(delete_in(&events_dir, Some("evt_wrong"), None, false).is_err());
+
+        materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            Utc::now(),
+        )?;
+
+        let listed = list_in(&events_dir, 10, None, Utc::now())?;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].content, "use rayon for parallel scans");
+
+        let results = query_in(
+            &cache_dir,
+            &mut embedder,
+            "panic on errors",
+            10,
+            0.0,
+            None,
+            Utc::now(),
+        )?;
+        assert!(results
+            .iter()
+            .all(|r| r.content != "always panic on errors"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expired_note_hidden_at_query_time() -> Result<()> {
+        let temp = TempDir::new()?;
+        let events_dir = temp.path().join("events");
+        let cache_dir = temp.path().join("cache");
+
+        let captured = DateTime::from_timestamp(1_767_225_600, 0).unwrap();
+        let mut vacation = test_event("evt_ttl", 1_767_225_600, "currently on vacation", None);
+        vacation.expires_at = Some(captured + parse_ttl("7d")?);
+        write_events(
+            &events_dir,
+            "20260101.jsonl",
+            &[
+                vacation,
+                test_event("evt_perm", 1_767_225_700, "vacation policy is async", None),
+            ],
+        )?;
+
+        let before = captured + chrono::Duration::days(1);
+        let after = captured + chrono::Duration::days(8);
+
+        let mut embedder = FakeEmbedder;
+        materialize_into(
+            &events_dir,
+            &cache_dir,
+            &mut embedder,
+            DEFAULT_DEDUP_THRESHOLD,
+            before,
+        )?;
+
+        let unexpired = query_in(&cache_dir, &mut embedder, "vacation", 10, 0.0, None, before)?;
+        assert!(unexpired
+            .iter()
+            .any(|r| r.content == "currently on vacation"));
+
+        let expired = query_in(&cache_dir, &mut embedder, "vacation", 10, 0.0, None, after)?;
+        assert!(expired.iter().all(|r| r.content != "currently on vacation"));
+        assert!(expired
+            .iter()
+            .any(|r| r.content == "vacation policy is async"));
+
+        assert_eq!(list_in(&events_dir, 10, None, before)?.len(), 2);
+        assert_eq!(list_in(&events_dir, 10, None, after)?.len(), 1);
+
+        // Log keeps the expired note for audit
+        assert_eq!(read_events(&events_dir)?.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_personas_keep_separate_knowledge() -> Result<()> {
+        let temp = TempDir::new()?;
+        let home = |name: &str| temp.path().join("personas").join(name);
+
+        let mut work = test_event("evt_work", 1_767_225_600, "deploy with the work VPN", None);
+        work.domains = vec!["ops".to_string()];
+        append_event(&home("work").join("events"), &work)?;
+        let oss = test_event(
+            "evt_oss",
+            1_767_225_700,
+            "deploy docs from the oss fork",
+            None,
+        );
+        append_event(&home("oss").join("events"), &oss)?;
+
+        let mut embedder = FakeEmbedder;
+        for name in ["work", "oss"] {
+            materialize_into(
+                &home(name).join("events"),
+                &home(name).join("cache"),
+                &mut embedder,
+                DEFAULT_DEDUP_THRESHOLD,
+                Utc::now(),
+            )?;
+        }
+
+        let query = |name: &str, embedder: &mut FakeEmbedder| -> Result<Vec<String>> {
+            let results = query_in(
+                &home(name).join("cache"),
+                embedder,
+                "deploy",
+                10,
+                0.0,
+                None,
+                Utc::now(),
+            )?;
+            Ok(results.into_iter().map(|r| r.content).collect())
+        };
+        assert_eq!(
+            query("oss", &mut embedder)?,
+            vec!["deploy docs from the oss fork"]
+        );
+        assert_eq!(
+            query("work", &mut embedder)?,
+            vec!["deploy with the work VPN"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_persona_flag_routes_to_its_own_directories() -> Result<()> {
+        let name = resolve(Some("work"))?;
+        assert_eq!(name, "work");
+        assert!(persona_paths::events_dir_for(&name).ends_with("personas/work/events"));
+        assert!(persona_paths::cache_dir_for(&name).ends_with("cache/personas/work"));
+        assert_ne!(
+            persona_paths::cache_dir_for(&name),
+            persona_paths::cache_dir_for(persona_paths::DEFAULT)
+        );
+
+        // Invalid names are rejected, not mapped to `default`
+        assert!(resolve(Some("../work")).is_err());
+        assert!(resolve(Some("")).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_ttl() {
+        assert_eq!(parse_ttl("30m").unwrap(), chrono::Duration::minutes(30));
+        assert_eq!(parse_ttl("2w").unwrap(), chrono::Duration::weeks(2));
+        assert!(parse_ttl("7").is_err());
+        assert!(parse_ttl("3y").is_err());
+    }
+
     #[test]
     fn test_truncate() {
         assert_eq!(truncate("short", 10), "short");
@@ -222,7 +222,7 @@ fn print_summary() -> Result<()> {
     // Embeddings size
     let embeddings_dir = Path::new(".patina/local/data/embeddings");
     if embeddings_dir.exists() {
-        let size_kb = dir_size(embeddings_dir)? / 1024;
+        let size_kb = patina::paths::dir_size(embeddings_dir)? / 1024;
         println!(
             "   Indices: .patina/local/data/embeddings/ ({} KB)",
             size_kb
@@ -232,23 +232,6 @@ fn print_summary() -> Result<()> {
     Ok(())
 }
 
-/// Calculate total size of a directory
-fn dir_size(path: &Path) -> Result<u64> {
-    let mut total = 0;
-    if path.is_dir() {
-        for entry in std::fs::read_dir(path)? {
-            let entry = entry?;
-            let path = entry.path();
-            if path.is_dir() {
-                total += dir_size(&path)?;
-            } else {
-                total += std::fs::metadata(&path)?.len();
-            }
-        }
-    }
-    Ok(total)
-}
-
 /// Pipe trait for functional chaining
 trait Pipe: Sized {
     fn pipe<F, R>(self, f: F) -> R
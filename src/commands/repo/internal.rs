@@ -1,6 +1,7 @@
 //! Internal implementation for repo command
 //!
-//! Central storage at `~/.patina/cache/repos/` with registry at `~/.patina/registry.yaml`
+//! Central storage at `~/.patina/cache/repos/` with registry at `~/.patina/registry.yaml`.
+//! Local repos are existing checkouts registered in place (no clone, no fetch).
 
 use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
@@ -40,7 +41,12 @@ pub struct RepoEntry {
     #[serde(default)]
     pub name: String,
     pub path: String,
+    /// owner/repo on GitHub (empty for local repos)
+    #[serde(default)]
     pub github: String,
+    /// Existing checkout registered in place, outside the cache
+    #[serde(default)]
+    pub local: bool,
     #[serde(default)]
     pub contrib: bool,
     #[serde(default)]
@@ -49,6 +55,9 @@ pub struct RepoEntry {
     /// SHA of HEAD when we last synced (add or update)
     #[serde(default)]
     pub synced_commit: Option<String>,
+    /// Branch, tag, or SHA the checkout is pinned to (None = default branch)
+    #[serde(default, rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub pinned_ref: Option<String>,
     #[serde(default)]
     pub domains: Vec<String>,
 }
@@ -73,7 +82,11 @@ impl Registry {
         // Validate repo paths against expected cache prefix
         let cache_prefix = paths::repos::cache_dir();
         for (name, entry) in &registry.repos {
-            validate_repo_path(&entry.path, &cache_prefix, name)?;
+            if entry.local {
+                validate_local_path(&entry.path, name)?;
+            } else {
+                validate_repo_path(&entry.path, &cache_prefix, name)?;
+            }
         }
 
         Ok(registry)
@@ -92,10 +105,134 @@ impl Registry {
         fs::write(&path, contents)?;
         Ok(())
     }
+
+    /// Registered repos sorted by name
+    pub fn list(&self) -> Vec<RepoEntry> {
+        let mut repos: Vec<RepoEntry> = self
+            .repos
+            .iter()
+            .map(|(name, entry)| RepoEntry {
+                name: name.clone(),
+                ..entry.clone()
+            })
+            .collect();
+        repos.sort_by(|a, b| a.name.cmp(&b.name));
+        repos
+    }
+
+    /// Database path for a registered repo
+    pub fn db_path(&self, name: &str) -> Result<String> {
+        let entry = self
+            .repos
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("Repository '{}' not found", name))?;
+
+        let db_path = Path::new(&entry.path).join(".patina/local/data/patina.db");
+        if !db_path.exists() {
+            if entry.local {
+                bail!(
+                    "Database not found for '{}'. Run 'patina scrape' in {}.",
+                    name,
+                    entry.path
+                );
+            }
+            bail!(
+                "Database not found for '{}'. Run 'patina repo update {}' to rebuild.",
+                name,
+                name
+            );
+        }
+
+        Ok(db_path.to_string_lossy().to_string())
+    }
+
+    /// Register an existing checkout in place under `name`
+    pub fn register_local(&mut self, path: &Path, name: &str) -> Result<&RepoEntry> {
+        if self.repos.contains_key(name) {
+            bail!("Repository '{}' already registered.", name);
+        }
+        if !path.is_dir() {
+            bail!("Local repo path is not a directory: {}", path.display());
+        }
+        let path = path
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve path: {}", path.display()))?;
+
+        let entry = RepoEntry {
+            name: name.to_string(),
+            path: path.to_string_lossy().to_string(),
+            github: String::new(),
+            local: true,
+            contrib: false,
+            fork: None,
+            registered: chrono::Utc::now().to_rfc3339(),
+            synced_commit: get_head_sha(&path),
+            pinned_ref: None,
+            domains: detect_domains(&path),
+        };
+        Ok(self.repos.entry(name.to_string()).or_insert(entry))
+    }
+}
+
+/// Whether `repo add` was given a filesystem path rather than a GitHub URL
+pub fn is_local_path(source: &str) -> bool {
+    source.starts_with('/')
+        || source.starts_with('.')
+        || source.starts_with('~')
+        || Path::new(source).is_dir()
+}
+
+/// Register a local checkout (no clone, no fetch, no fork)
+pub fn add_local_repo(path: &str, name: Option<&str>) -> Result<()> {
+    let expanded = match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir()
+            .ok_or_else(|| anyhow::anyhow!("Cannot resolve home directory"))?
+            .join(rest),
+        None => std::path::PathBuf::from(path),
+    };
+    let name = match name {
+        Some(n) => n.to_string(),
+        None => expanded
+            .canonicalize()
+            .ok()
+            .and_then(|p| p.file_name().map(|n| n.to_string_lossy().to_string()))
+            .ok_or_else(|| anyhow::anyhow!("Cannot derive a name from '{}'; pass --name", path))?,
+    };
+
+    println!("🚀 Adding local repository: {}\n", name);
+
+    let mut registry = Registry::load()?;
+    let entry = registry.register_local(&expanded, &name)?.clone();
+    registry.save()?;
+
+    println!("✅ Repository registered in place");
+    println!("   Path: {}", entry.path);
+    if Path::new(&entry.path)
+        .join(".patina/local/data/patina.db")
+        .exists()
+    {
+        println!(
+            "\n   Query with: patina scry \"your query\" --repo {}",
+            name
+        );
+    } else {
+        println!(
+            "\n   ⚠️  No patina database yet. Run 'patina init' and 'patina scrape' in {}",
+            entry.path
+        );
+    }
+
+    Ok(())
 }
 
 /// Add a repository
-pub fn add_repo(url: &str, contrib: bool, with_issues: bool, no_oxidize: bool) -> Result<()> {
+pub fn add_repo(
+    url: &str,
+    contrib: bool,
+    with_issues: bool,
+    no_oxidize: bool,
+    pinned_ref: Option<&str>,
+) -> Result<()> {
     // Parse GitHub URL
     let (owner, repo_name) = parse_github_url(url)?;
     let github = format!("{}/{}", owner, repo_name);
@@ -128,6 +265,15 @@ pub fn add_repo(url: &str, contrib: bool, with_issues: bool, no_oxidize: bool) -
     println!("📥 Cloning {}...", github);
     clone_repo(url, &repo_path)?;
 
+    // Pin to the requested ref before branching, so patina builds on it
+    if let Some(git_ref) = pinned_ref {
+        println!("📌 Checking out {}...", git_ref);
+        if let Err(e) = checkout_ref(&repo_path, git_ref) {
+            let _ = fs::remove_dir_all(&repo_path);
+            return Err(e);
+        }
+    }
+
     // Create patina branch
     println!("🌿 Creating patina branch...");
     create_patina_branch(&repo_path)?;
@@ -207,10 +353,12 @@ pub fn add_repo(url: &str, contrib: bool, with_issues: bool, no_oxidize: bool) -
             name: github.clone(),
             path: repo_path.to_string_lossy().to_string(),
             github: github.clone(),
+            local: false,
             contrib: fork.is_some(),
             fork,
             registered: timestamp,
             synced_commit,
+            pinned_ref: pinned_ref.map(String::from),
             domains,
         },
     );
@@ -245,21 +393,11 @@ pub fn add_repo(url: &str, contrib: bool, with_issues: bool, no_oxidize: bool) -
 
 /// List all repositories
 pub fn list_repos() -> Result<Vec<RepoEntry>> {
-    let registry = Registry::load()?;
-    let mut repos: Vec<RepoEntry> = registry
-        .repos
-        .into_iter()
-        .map(|(name, mut entry)| {
-            entry.name = name;
-            entry
-        })
-        .collect();
-    repos.sort_by(|a, b| a.name.cmp(&b.name));
-    Ok(repos)
+    Ok(Registry::load()?.list())
 }
 
 /// Update a specific repository
-pub fn update_repo(name: &str, oxidize: bool, with_issues: bool) -> Result<()> {
+pub fn update_repo(name: &str, oxidize: bool, no_oxidize: bool, with_issues: bool) -> Result<()> {
     let mut registry = Registry::load()?;
     let entry = registry
         .repos
@@ -274,16 +412,23 @@ pub fn update_repo(name: &str, oxidize: bool, with_issues: bool) -> Result<()> {
     // Ensure UID exists (migration for existing ref repos)
     patina::project::create_uid_if_missing(repo_path)?;
 
-    // Git pull
-    println!("📥 Pulling latest changes...");
-    git_pull(repo_path)?;
+    // Git pull (local checkouts are managed by their owner)
+    if entry.local {
+        println!("📂 Local repo - skipping pull");
+    } else if let Some(git_ref) = &entry.pinned_ref {
+        println!("📌 Syncing to pinned ref {}...", git_ref);
+        sync_to_ref(repo_path, git_ref)?;
+    } else {
+        println!("📥 Pulling latest changes...");
+        git_pull(repo_path)?;
+    }
 
     // Re-scrape
     println!("🔍 Re-scraping codebase...");
     let event_count = scrape_repo(repo_path)?;
 
     // Fetch GitHub issues if requested
-    let issue_count = if with_issues {
+    let issue_count = if with_issues && !entry.local {
         let github = &entry.github;
         println!("🐙 Fetching GitHub issues...");
         match scrape_github_issues(repo_path, github) {
@@ -303,7 +448,7 @@ pub fn update_repo(name: &str, oxidize: bool, with_issues: bool) -> Result<()> {
         0
     };
 
-    // Oxidize if requested
+    let oxidize = should_oxidize(repo_path, oxidize, no_oxidize);
     if oxidize {
         println!("\n🧪 Building semantic indices...");
         oxidize_repo(repo_path)?;
@@ -327,7 +472,7 @@ pub fn update_repo(name: &str, oxidize: bool, with_issues: bool) -> Result<()> {
 }
 
 /// Update all repositories
-pub fn update_all_repos(oxidize: bool, with_issues: bool) -> Result<()> {
+pub fn update_all_repos(oxidize: bool, no_oxidize: bool, with_issues: bool) -> Result<()> {
     let repos = list_repos()?;
 
     if repos.is_empty() {
@@ -337,23 +482,75 @@ pub fn update_all_repos(oxidize: bool, with_issues: bool) -> Result<()> {
 
     println!("🔄 Updating {} repositories...\n", repos.len());
 
-    let mut success = 0;
-    for repo in &repos {
-        print!("  {} ... ", repo.name);
-        match update_repo(&repo.name, oxidize, with_issues) {
-            Ok(_) => {
-                println!("✓");
-                success += 1;
-            }
-            Err(e) => println!("✗ {}", e),
+    let summary = update_each(&repos, |repo| {
+        update_repo(&repo.name, oxidize, no_oxidize, with_issues)
+    });
+
+    println!(
+        "\n✅ Updated {}/{} repositories",
+        summary.updated.len(),
+        repos.len()
+    );
+    if !summary.failed.is_empty() {
+        println!("\n❌ {} failed:", summary.failed.len());
+        for (name, error) in &summary.failed {
+            println!("   {} - {}", name, error);
         }
+        bail!(
+            "{} of {} repositories failed to update",
+            summary.failed.len(),
+            repos.len()
+        );
     }
 
-    println!("\n✅ Updated {}/{} repositories", success, repos.len());
-
     Ok(())
 }
 
+/// Per-repo outcome of a batch update
+#[derive(Debug, Default)]
+pub struct UpdateSummary {
+    pub updated: Vec<String>,
+    /// (repo name, error message)
+    pub failed: Vec<(String, String)>,
+}
+
+/// Run `update` for every repo, recording failures instead of stopping at the first
+fn update_each(
+    repos: &[RepoEntry],
+    mut update: impl FnMut(&RepoEntry) -> Result<()>,
+) -> UpdateSummary {
+    let mut summary = UpdateSummary::default();
+    for repo in repos {
+        match update(repo) {
+            Ok(()) => summary.updated.push(repo.name.clone()),
+            Err(e) => summary.failed.push((repo.name.clone(), e.to_string())),
+        }
+    }
+    summary
+}
+
+/// Whether an update oxidizes: when requested, or to keep existing indices
+/// current unless `--no-oxidize`
+fn should_oxidize(repo_path: &Path, requested: bool, no_oxidize: bool) -> bool {
+    requested || (!no_oxidize && has_semantic_indices(repo_path))
+}
+
+/// Whether oxidize has built indices for this repo before
+fn has_semantic_indices(repo_path: &Path) -> bool {
+    let Ok(models) = fs::read_dir(repo_path.join(".patina/local/data/embeddings")) else {
+        return false;
+    };
+    models.filter_map(|e| e.ok()).any(|model| {
+        fs::read_dir(model.path().join("projections"))
+            .map(|files| {
+                files
+                    .filter_map(|f| f.ok())
+                    .any(|f| f.path().extension().is_some_and(|ext| ext == "usearch"))
+            })
+            .unwrap_or(false)
+    })
+}
+
 /// Remove a repository
 pub fn remove_repo(name: &str) -> Result<()> {
     let mut registry = Registry::load()?;
@@ -364,9 +561,9 @@ pub fn remove_repo(name: &str) -> Result<()> {
 
     println!("🗑️  Removing {}...", name);
 
-    // Remove from filesystem
+    // Remove from filesystem (never a local checkout - only the registration goes)
     let repo_path = Path::new(&entry.path);
-    if repo_path.exists() {
+    if !entry.local && repo_path.exists() {
         fs::remove_dir_all(repo_path)
             .with_context(|| format!("Failed to remove directory: {}", repo_path.display()))?;
     }
@@ -389,8 +586,15 @@ pub fn show_repo(name: &str) -> Result<()> {
     let repo_path = Path::new(&entry.path);
 
     println!("📚 Repository: {}\n", name);
-    println!("  GitHub:     {}", entry.github);
+    if entry.local {
+        println!("  Local:      Yes (registered in place)");
+    } else {
+        println!("  GitHub:     {}", entry.github);
+    }
     println!("  Path:       {}", entry.path);
+    if let Some(git_ref) = &entry.pinned_ref {
+        println!("  Pinned:     {}", git_ref);
+    }
     println!("  Contrib:    {}", if entry.contrib { "Yes" } else { "No" });
     if let Some(fork) = &entry.fork {
         println!("  Fork:       {}", fork);
@@ -398,8 +602,12 @@ pub fn show_repo(name: &str) -> Result<()> {
     println!("  Domains:    {}", entry.domains.join(", "));
     println!("  Registered: {}", format_timestamp(&entry.registered));
 
-    // Show upstream status
-    if let Some(upstream) = get_upstream_head(repo_path) {
+    // Show upstream status (a pinned repo is synced by definition)
+    if entry.pinned_ref.is_some() {
+        if let Some(head) = get_head_sha(repo_path) {
+            println!("  HEAD:       {}", &head[..head.len().min(12)]);
+        }
+    } else if let Some(upstream) = get_upstream_head(repo_path) {
         let commit_date =
             get_commit_date_relative(repo_path, &upstream).unwrap_or_else(|| "unknown".to_string());
         println!("  Last commit: {}", commit_date);
@@ -441,22 +649,7 @@ pub fn show_repo(name: &str) -> Result<()> {
 
 /// Get database path for a repo
 pub fn get_repo_db_path(name: &str) -> Result<String> {
-    let registry = Registry::load()?;
-    let entry = registry
-        .repos
-        .get(name)
-        .ok_or_else(|| anyhow::anyhow!("Repository '{}' not found", name))?;
-
-    let db_path = Path::new(&entry.path).join(".patina/local/data/patina.db");
-    if !db_path.exists() {
-        bail!(
-            "Database not found for '{}'. Run 'patina repo update {}' to rebuild.",
-            name,
-            name
-        );
-    }
-
-    Ok(db_path.to_string_lossy().to_string())
+    Registry::load()?.db_path(name)
 }
 
 /// Get the filesystem path for a registered repo
@@ -563,6 +756,69 @@ fn clone_repo(url: &str, target: &Path) -> Result<()> {
     Ok(())
 }
 
+/// Check out a branch, tag, or SHA in a fresh clone
+fn checkout_ref(repo_path: &Path, git_ref: &str) -> Result<()> {
+    let output = Command::new("git")
+        .args(["checkout", "--quiet", git_ref])
+        .current_dir(repo_path)
+        .output()
+        .context("Failed to execute git checkout")?;
+
+    if !output.status.success() {
+        bail!(
+            "Failed to check out '{}': {}",
+            git_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(())
+}
+
+/// Fetch and reset the working branch to a pinned ref
+///
+/// Branches follow `origin/<branch>`; tags and SHAs stay where they are.
+fn sync_to_ref(repo_path: &Path, git_ref: &str) -> Result<()> {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(repo_path)
+            .output()
+            .context("Failed to execute git")
+    };
+
+    // Keep local edits (e.g. .gitignore) across the reset, like git_pull does
+    let stashed = git(&["stash"])?;
+    let stashed = stashed.status.success()
+        && !String::from_utf8_lossy(&stashed.stdout).contains("No local changes");
+
+    let _ = git(&["fetch", "origin", "--tags", "--quiet"])?;
+
+    let remote_branch = format!("origin/{}", git_ref);
+    let target = if git(&["rev-parse", "--verify", "--quiet", &remote_branch])?
+        .status
+        .success()
+    {
+        remote_branch
+    } else {
+        git_ref.to_string()
+    };
+
+    let output = git(&["reset", "--hard", "--quiet", &target])?;
+    if stashed {
+        let _ = git(&["stash", "pop"]);
+    }
+    if !output.status.success() {
+        bail!(
+            "Failed to reset to pinned ref '{}': {}",
+            git_ref,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    Ok(())
+}
+
 /// Create patina branch in repo
 fn create_patina_branch(repo_path: &Path) -> Result<()> {
     // Check if patina branch exists
@@ -1081,6 +1337,21 @@ fn validate_repo_path(path: &str, cache_prefix: &Path, repo_name: &str) -> Resul
     Ok(())
 }
 
+/// Validate a local repo path: absolute and free of traversal components.
+///
+/// Local repos live outside the cache by design, so the prefix check does not
+/// apply; `add` stores the canonical path.
+fn validate_local_path(path: &str, repo_name: &str) -> Result<()> {
+    if path.contains("..") || !Path::new(path).is_absolute() {
+        bail!(
+            "Registry path for local repo '{}' is not a canonical absolute path: {}",
+            repo_name,
+            path
+        );
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1147,4 +1418,148 @@ mod tests {
         let cache = Path::new("/home/user/.patina/cache/repos");
         assert!(validate_repo_path("/tmp/evil", cache, "evil").is_err());
     }
+
+    /// Run git in `dir` with a fixed identity, returning trimmed stdout
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn test_pinned_ref_survives_update() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let origin = temp.path().join("origin");
+        fs::create_dir_all(&origin)?;
+        git(&origin, &["init", "--quiet"]);
+        fs::write(origin.join("lib.rs"), "// v1\n")?;
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "--quiet", "-m", "v1"]);
+        git(&origin, &["tag", "v1.0.0"]);
+        let pinned_sha = git(&origin, &["rev-parse", "HEAD"]);
+        fs::write(origin.join("lib.rs"), "// v2\n")?;
+        git(&origin, &["commit", "--quiet", "-am", "v2"]);
+
+        let url = format!("file://{}", origin.display());
+        let checkout = temp.path().join("checkout");
+        clone_repo(&url, &checkout)?;
+        checkout_ref(&checkout, "v1.0.0")?;
+        create_patina_branch(&checkout)?;
+        assert_eq!(
+            get_head_sha(&checkout).as_deref(),
+            Some(pinned_sha.as_str())
+        );
+
+        // The pin is what gets recorded
+        let entry = RepoEntry {
+            name: "o/r".to_string(),
+            path: checkout.to_string_lossy().to_string(),
+            github: "o/r".to_string(),
+            local: false,
+            contrib: false,
+            fork: None,
+            registered: String::new(),
+            synced_commit: get_head_sha(&checkout),
+            pinned_ref: Some("v1.0.0".to_string()),
+            domains: vec![],
+        };
+        let yaml = serde_yaml::to_string(&entry)?;
+        assert!(yaml.contains("ref: v1.0.0"));
+        let reloaded: RepoEntry = serde_yaml::from_str(&yaml)?;
+        assert_eq!(reloaded.pinned_ref.as_deref(), Some("v1.0.0"));
+
+        // Upstream moves on; updating stays on the tag
+        fs::write(origin.join("lib.rs"), "// v3\n")?;
+        git(&origin, &["commit", "--quiet", "-am", "v3"]);
+        sync_to_ref(&checkout, "v1.0.0")?;
+        assert_eq!(
+            get_head_sha(&checkout).as_deref(),
+            Some(pinned_sha.as_str())
+        );
+        assert_eq!(fs::read_to_string(checkout.join("lib.rs"))?, "// v1\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_all_continues_past_failure() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let mut registry = Registry::default();
+        for name in ["broken", "healthy"] {
+            let dir = temp.path().join(name);
+            fs::create_dir_all(&dir)?;
+            registry.register_local(&dir, name)?;
+        }
+        let repos = registry.list();
+
+        let mut attempted = Vec::new();
+        let summary = update_each(&repos, |repo| {
+            attempted.push(repo.name.clone());
+            if repo.name == "broken" {
+                bail!("git pull failed");
+            }
+            Ok(())
+        });
+
+        assert_eq!(attempted, vec!["broken", "healthy"]);
+        assert_eq!(summary.updated, vec!["healthy"]);
+        assert_eq!(
+            summary.failed,
+            vec![("broken".to_string(), "git pull failed".to_string())]
+        );
+
+        // Indices are refreshed on update only once oxidize has built some
+        let healthy = temp.path().join("healthy");
+        assert!(!has_semantic_indices(&healthy));
+        let projections = healthy.join(".patina/local/data/embeddings/e5-base-v2/projections");
+        fs::create_dir_all(&projections)?;
+        fs::write(projections.join("semantic.usearch"), b"")?;
+        assert!(has_semantic_indices(&healthy));
+        assert!(should_oxidize(&healthy, false, false));
+        assert!(!should_oxidize(&healthy, false, true));
+        assert!(!should_oxidize(&temp.path().join("broken"), false, false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_register_local_repo() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let checkout = temp.path().join("sibling");
+        let data_dir = checkout.join(".patina/local/data");
+        fs::create_dir_all(&data_dir)?;
+        fs::write(data_dir.join("patina.db"), b"")?;
+
+        let mut registry = Registry::default();
+        registry.register_local(&checkout, "foo")?;
+
+        let expected = checkout
+            .canonicalize()?
+            .join(".patina/local/data/patina.db");
+        assert_eq!(registry.db_path("foo")?, expected.to_string_lossy());
+
+        let repos = registry.list();
+        assert_eq!(repos.len(), 1);
+        assert_eq!(repos[0].name, "foo");
+        assert!(repos[0].local);
+        assert!(repos[0].github.is_empty());
+        validate_local_path(&repos[0].path, "foo")?;
+
+        // Survives a registry round-trip
+        let yaml = serde_yaml::to_string(&registry)?;
+        let reloaded: Registry = serde_yaml::from_str(&yaml)?;
+        assert!(reloaded.repos["foo"].local);
+
+        assert!(registry.register_local(&checkout, "foo").is_err());
+        assert!(is_local_path("/path/to/repo"));
+        assert!(is_local_path("../sibling"));
+        assert!(!is_local_path("dojoengine/dojo"));
+
+        Ok(())
+    }
 }
@@ -13,11 +13,20 @@
 //! // Add a repo for contributing (creates fork)
 //! // patina repo https://github.com/dojoengine/dojo --contrib
 //!
+//! // Study a specific release (update stays on the tag)
+//! // patina repo add dojoengine/dojo --ref v1.0.0
+//!
+//! // Register a sibling checkout in place (no clone)
+//! // patina repo add ../other-project --name other
+//!
 //! // List all repos
 //! // patina repo list
 //!
 //! // Query a specific repo
 //! // patina scry "spawn patterns" --repo dojo
+//!
+//! // Compare two repos side by side
+//! // patina scry "spawn patterns" --repo dojo,starknet-foundry
 //! # Ok(())
 //! # }
 //! ```
@@ -33,9 +42,17 @@ pub use internal::RepoEntry;
 pub enum RepoCommands {
     /// Add an external repository
     Add {
-        /// GitHub URL (e.g., https://github.com/owner/repo or owner/repo)
+        /// GitHub URL (e.g., https://github.com/owner/repo or owner/repo) or local path
         url: String,
 
+        /// Registry name for a local path (default: directory name)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Pin to a branch, tag, or SHA (update follows the pin, not the default branch)
+        #[arg(long = "ref")]
+        git_ref: Option<String>,
+
         /// Enable contribution mode (create fork for PRs)
         #[arg(long)]
         contrib: bool,
@@ -56,7 +73,7 @@ pub enum RepoCommands {
         status: bool,
     },
 
-    /// Update a repository (git pull + rescrape)
+    /// Update a repository (git pull + rescrape + refresh existing indices)
     Update {
         /// Repository name (or --all for all repos)
         name: Option<String>,
@@ -65,10 +82,14 @@ pub enum RepoCommands {
         #[arg(long)]
         all: bool,
 
-        /// Also run oxidize to build semantic indices
+        /// Also run oxidize when the repo has no semantic indices yet
         #[arg(long)]
         oxidize: bool,
 
+        /// Don't rebuild existing semantic indices
+        #[arg(long, conflicts_with = "oxidize")]
+        no_oxidize: bool,
+
         /// Also fetch and index GitHub issues
         #[arg(long)]
         with_issues: bool,
@@ -98,11 +119,23 @@ pub fn execute_cli(
     contrib: bool,
     with_issues: bool,
 ) -> Result<()> {
-    let cmd = match (command, url) {
+    execute(parse_command(command, url, contrib, with_issues))
+}
+
+/// Map CLI arguments to a `RepoCommand`
+fn parse_command(
+    command: Option<RepoCommands>,
+    url: Option<String>,
+    contrib: bool,
+    with_issues: bool,
+) -> RepoCommand {
+    match (command, url) {
         // Subcommand form: patina repo add/list/update/etc
         (
             Some(RepoCommands::Add {
                 url,
+                name,
+                git_ref,
                 contrib,
                 with_issues,
                 no_oxidize,
@@ -110,6 +143,8 @@ pub fn execute_cli(
             _,
         ) => RepoCommand::Add {
             url,
+            name,
+            git_ref,
             contrib,
             with_issues,
             no_oxidize,
@@ -120,22 +155,21 @@ pub fn execute_cli(
                 name,
                 all,
                 oxidize,
+                no_oxidize,
                 with_issues,
             }),
             _,
         ) => {
-            if all {
-                RepoCommand::Update {
-                    name: None,
-                    oxidize,
-                    with_issues,
-                }
-            } else {
-                RepoCommand::Update {
-                    name,
-                    oxidize,
-                    with_issues,
-                }
+            if name.is_none() && !all {
+                eprintln!(
+                    "⚠️  'patina repo update' without a name is deprecated; use 'patina repo update --all'"
+                );
+            }
+            RepoCommand::Update {
+                name: if all { None } else { name },
+                oxidize,
+                no_oxidize,
+                with_issues,
             }
         }
         (Some(RepoCommands::Remove { name }), _) => RepoCommand::Remove { name },
@@ -145,6 +179,8 @@ pub fn execute_cli(
         // Note: --no-oxidize not available in shorthand, defaults to false (oxidize runs)
         (None, Some(url)) => RepoCommand::Add {
             url,
+            name: None,
+            git_ref: None,
             contrib,
             with_issues,
             no_oxidize: false,
@@ -152,9 +188,7 @@ pub fn execute_cli(
 
         // No args: show list
         (None, None) => RepoCommand::List { status: false },
-    };
-
-    execute(cmd)
+    }
 }
 
 /// Add an external repository
@@ -165,8 +199,23 @@ pub fn execute_cli(
 /// With `--contrib`, also creates a GitHub fork and sets up push remote.
 /// With `--with-issues`, also fetches and indexes GitHub issues.
 /// With `--no-oxidize`, skips building semantic indices (faster, lexical search only).
-pub fn add(url: &str, contrib: bool, with_issues: bool, no_oxidize: bool) -> Result<()> {
-    internal::add_repo(url, contrib, with_issues, no_oxidize)
+/// With `--ref`, checks out that branch/tag/SHA and records it so updates stay pinned.
+pub fn add(
+    url: &str,
+    contrib: bool,
+    with_issues: bool,
+    no_oxidize: bool,
+    git_ref: Option<&str>,
+) -> Result<()> {
+    internal::add_repo(url, contrib, with_issues, no_oxidize, git_ref)
+}
+
+/// Register an existing local checkout in place
+///
+/// Records the path without cloning or fetching, so `scry --repo <name>` reads
+/// that checkout's own `.patina/local/data/patina.db`.
+pub fn add_local(path: &str, name: Option<&str>) -> Result<()> {
+    internal::add_local_repo(path, name)
 }
 
 /// List all registered repositories
@@ -174,14 +223,16 @@ pub fn list() -> Result<Vec<RepoEntry>> {
     internal::list_repos()
 }
 
-/// Update a repository (git pull + rescrape + optional oxidize + optional issues)
-pub fn update(name: &str, oxidize: bool, with_issues: bool) -> Result<()> {
-    internal::update_repo(name, oxidize, with_issues)
+/// Update a repository (git pull + rescrape + oxidize if indexed or requested + optional issues)
+///
+/// `no_oxidize` skips the index refresh even when the repo already has indices.
+pub fn update(name: &str, oxidize: bool, no_oxidize: bool, with_issues: bool) -> Result<()> {
+    internal::update_repo(name, oxidize, no_oxidize, with_issues)
 }
 
 /// Update all repositories
-pub fn update_all(oxidize: bool, with_issues: bool) -> Result<()> {
-    internal::update_all_repos(oxidize, with_issues)
+pub fn update_all(oxidize: bool, no_oxidize: bool, with_issues: bool) -> Result<()> {
+    internal::update_all_repos(oxidize, no_oxidize, with_issues)
 }
 
 /// Remove a repository
@@ -222,7 +273,7 @@ pub fn migrate_registry_paths() -> bool {
     let mut updated_any = false;
     let mut updates: Vec<(String, String)> = Vec::new(); // (name, new_path)
 
-    for (name, entry) in registry.repos.iter() {
+    for (name, entry) in registry.repos.iter().filter(|(_, e)| !e.local) {
         let expected_path = cache_base.join(name);
         let expected_path_str = expected_path.to_string_lossy().to_string();
 
@@ -267,10 +318,23 @@ pub fn execute(command: RepoCommand) -> Result<()> {
     match command {
         RepoCommand::Add {
             url,
+            name,
+            git_ref,
             contrib,
             with_issues,
             no_oxidize,
-        } => add(&url, contrib, with_issues, no_oxidize),
+        } => {
+            if internal::is_local_path(&url) {
+                if contrib || with_issues || git_ref.is_some() {
+                    anyhow::bail!(
+                        "--contrib, --with-issues, and --ref need a GitHub repo, not a local path"
+                    );
+                }
+                add_local(&url, name.as_deref())
+            } else {
+                add(&url, contrib, with_issues, no_oxidize, git_ref.as_deref())
+            }
+        }
         RepoCommand::List { status } => {
             let repos = list()?;
             if repos.is_empty() {
@@ -306,12 +370,13 @@ pub fn execute(command: RepoCommand) -> Result<()> {
         RepoCommand::Update {
             name,
             oxidize,
+            no_oxidize,
             with_issues,
         } => {
             if let Some(n) = name {
-                update(&n, oxidize, with_issues)
+                update(&n, oxidize, no_oxidize, with_issues)
             } else {
-                update_all(oxidize, with_issues)
+                update_all(oxidize, no_oxidize, with_issues)
             }
         }
         RepoCommand::Remove { name } => remove(&name),
@@ -324,6 +389,8 @@ pub fn execute(command: RepoCommand) -> Result<()> {
 pub enum RepoCommand {
     Add {
         url: String,
+        name: Option<String>,
+        git_ref: Option<String>,
         contrib: bool,
         with_issues: bool,
         no_oxidize: bool,
@@ -334,6 +401,7 @@ pub enum RepoCommand {
     Update {
         name: Option<String>,
         oxidize: bool,
+        no_oxidize: bool,
         with_issues: bool,
     },
     Remove {
@@ -352,6 +420,8 @@ mod tests {
     fn test_repo_command_variants() {
         let add = RepoCommand::Add {
             url: "https://github.com/test/repo".to_string(),
+            name: None,
+            git_ref: None,
             contrib: false,
             with_issues: true,
             no_oxidize: false,
@@ -361,4 +431,40 @@ mod tests {
         let list = RepoCommand::List { status: false };
         assert!(matches!(list, RepoCommand::List { .. }));
     }
+
+    #[test]
+    fn test_update_cli_maps_no_oxidize_and_bare_update() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct Cli {
+            #[command(subcommand)]
+            command: RepoCommands,
+        }
+        let update = |args: &[&str]| {
+            let cli =
+                Cli::try_parse_from(std::iter::once("repo").chain(args.iter().copied())).unwrap();
+            parse_command(Some(cli.command), None, false, false)
+        };
+
+        assert!(matches!(
+            update(&["update", "dojo", "--no-oxidize"]),
+            RepoCommand::Update {
+                name: Some(ref n),
+                oxidize: false,
+                no_oxidize: true,
+                ..
+            } if n == "dojo"
+        ));
+        // Bare `update` still updates everything, like --all
+        assert!(matches!(
+            update(&["update"]),
+            RepoCommand::Update { name: None, .. }
+        ));
+        assert!(matches!(
+            update(&["update", "--all"]),
+            RepoCommand::Update { name: None, .. }
+        ));
+        assert!(Cli::try_parse_from(["repo", "update", "--oxidize", "--no-oxidize"]).is_err());
+    }
 }
@@ -4,20 +4,22 @@
 //! - Inserts belief.surface events into eventlog table
 //! - Creates materialized views (beliefs) from eventlog
 //! - Runs verification queries (Phase 2.5) and stores results
+//! - Snapshots per-belief metrics (Phase 4) for `patina belief diff`
 
 mod verification;
 
 use anyhow::Result;
+use patina::embeddings::id_offsets::{self, classify_key, KeyKind};
 use regex::Regex;
 use rusqlite::Connection;
 use serde_json::json;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use super::database;
 use super::ScrapeStats;
 
-const BELIEFS_DIR: &str = "layer/surface/epistemic/beliefs";
+pub const BELIEFS_DIR: &str = "layer/surface/epistemic/beliefs";
 
 /// Parsed belief from markdown file
 #[derive(Debug)]
@@ -113,7 +115,7 @@ fn create_materialized_views(conn: &Connection) -> Result<()> {
         CREATE INDEX IF NOT EXISTS idx_beliefs_status ON beliefs(status);
         CREATE INDEX IF NOT EXISTS idx_beliefs_entrenchment ON beliefs(entrenchment);
 
-        -- E4.6a-fix: Multi-hop code grounding (belief → commit → file → function)
+        -- E4.6a-fix: Multi-hop code grounding (belief → commit|session → file → function)
         CREATE TABLE IF NOT EXISTS belief_code_reach (
             belief_id TEXT NOT NULL,
             file_path TEXT NOT NULL,
@@ -124,6 +126,17 @@ fn create_materialized_views(conn: &Connection) -> Result<()> {
             PRIMARY KEY (belief_id, file_path)
         );
         CREATE INDEX IF NOT EXISTS idx_belief_code_reach_file ON belief_code_reach(file_path);
+
+        -- Per-scrape snapshots of belief metrics (for `patina belief diff`)
+        CREATE TABLE IF NOT EXISTS belief_snapshots (
+            snapshot_at TEXT NOT NULL,
+            belief_id TEXT NOT NULL,
+            citations INTEGER,
+            evidence_verified INTEGER,
+            grounding_total INTEGER,
+            verification_passed INTEGER,
+            PRIMARY KEY (snapshot_at, belief_id)
+        );
         "#,
     )?;
 
@@ -360,6 +373,127 @@ fn extract_file_metrics(content: &str) -> BeliefMetrics {
     metrics
 }
 
+/// A directed edge in the belief graph, parsed from Supports/Attacks/Evidence sections
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct BeliefEdge {
+    pub from: String,
+    pub to: String,
+    pub relation: &'static str, // supports, attacks, evidence
+    pub defeated: bool,         // attack marked `status: defeated`
+}
+
+/// Belief network: every belief on disk plus edges between them
+#[derive(Debug, Default)]
+pub struct BeliefGraph {
+    pub beliefs: Vec<String>,
+    pub edges: Vec<BeliefEdge>,
+}
+
+/// Extract belief → belief edges from one belief's markdown sections.
+///
+/// - `## Supports` / `## Attacks`: edge from this belief to each `[[target]]`
+/// - `## Attacked-By`: edge from each `[[attacker]]` to this belief
+/// - `## Evidence`: edge from this belief to each `[[target]]` in `known_ids`
+///   (evidence also links sessions/commits, which are not graph nodes)
+fn extract_belief_edges(
+    belief_id: &str,
+    content: &str,
+    known_ids: &std::collections::HashSet<String>,
+) -> Vec<BeliefEdge> {
+    let wikilink_re = Regex::new(r"\[\[([^\]]+)\]\]").unwrap();
+    let mut edges = Vec::new();
+
+    let mut current_section = "";
+    for line in content.lines() {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("## ") {
+            current_section = trimmed;
+            continue;
+        }
+
+        if !trimmed.starts_with("- ") && !trimmed.starts_with("* ") {
+            continue;
+        }
+
+        let defeated = trimmed.contains("status: defeated");
+        for cap in wikilink_re.captures_iter(trimmed) {
+            let target = cap[1].trim().to_string();
+            if target == belief_id {
+                continue;
+            }
+
+            let (from, to, relation) = match current_section {
+                s if s.starts_with("## Supports") => (belief_id.to_string(), target, "supports"),
+                s if s.starts_with("## Attacked-By") => (target, belief_id.to_string(), "attacks"),
+                s if s.starts_with("## Attacks") => (belief_id.to_string(), target, "attacks"),
+                s if s.starts_with("## Evidence") && known_ids.contains(&target) => {
+                    (belief_id.to_string(), target, "evidence")
+                }
+                _ => continue,
+            };
+
+            edges.push(BeliefEdge {
+                from,
+                to,
+                relation,
+                defeated: relation == "attacks" && defeated,
+            });
+        }
+    }
+
+    edges
+}
+
+/// Build the belief graph from all belief files in a directory.
+///
+/// The same attack often appears twice (in the attacker's `## Attacks` and the
+/// target's `## Attacked-By`), so edges are deduplicated by (from, to, relation).
+pub fn build_belief_graph(beliefs_dir: &Path) -> Result<BeliefGraph> {
+    let mut belief_files = Vec::new();
+    if let Ok(entries) = std::fs::read_dir(beliefs_dir) {
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.extension().map(|ext| ext == "md").unwrap_or(false) {
+                belief_files.push(path);
+            }
+        }
+    }
+    belief_files.sort();
+
+    let mut parsed = Vec::new();
+    for path in &belief_files {
+        match parse_belief_file(path) {
+            Ok(belief) => parsed.push(belief),
+            Err(e) => eprintln!("  Warning: failed to parse {}: {}", path.display(), e),
+        }
+    }
+
+    let known_ids: std::collections::HashSet<String> =
+        parsed.iter().map(|b| b.id.clone()).collect();
+
+    let mut graph = BeliefGraph {
+        beliefs: parsed.iter().map(|b| b.id.clone()).collect(),
+        edges: Vec::new(),
+    };
+
+    for belief in &parsed {
+        for edge in extract_belief_edges(&belief.id, &belief.content, &known_ids) {
+            if let Some(existing) = graph
+                .edges
+                .iter_mut()
+                .find(|e| e.from == edge.from && e.to == edge.to && e.relation == edge.relation)
+            {
+                existing.defeated |= edge.defeated;
+            } else {
+                graph.edges.push(edge);
+            }
+        }
+    }
+
+    Ok(graph)
+}
+
 /// Verify evidence lines from a belief file against real files on disk.
 /// Handles both `[[wikilink]]` format and bare `session-YYYYMMDD-HHMMSS:` references.
 fn verify_evidence_section(content: &str, project_root: &Path) -> (i32, i32) {
@@ -589,6 +723,85 @@ fn is_source_code(path: &str) -> bool {
     SOURCE_EXTENSIONS.iter().any(|ext| path.ends_with(ext))
 }
 
+/// Source files reachable from a session event: files touched by commits made
+/// during the session, plus source paths its text mentions
+///
+/// Returns `None` if the event is not a session event.
+fn session_reach_files(
+    conn: &Connection,
+    seq: i64,
+    path_re: &Regex,
+) -> Result<Option<(String, Vec<String>)>> {
+    let event: Option<(String, Option<String>)> = conn
+        .query_row(
+            "SELECT source_id, json_extract(data, '$.content') FROM eventlog
+             WHERE seq = ?1 AND event_type LIKE 'session.%'",
+            [seq],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .ok();
+    let Some((session_id, content)) = event else {
+        return Ok(None);
+    };
+
+    // commit_files is absent until git has been scraped
+    let mut files: Vec<String> = Vec::new();
+    if let Ok(mut stmt) = conn.prepare_cached(
+        "SELECT DISTINCT cf.file_path FROM eventlog e
+         JOIN commit_files cf ON cf.sha = e.source_id
+         WHERE e.event_type = 'git.commit' AND json_extract(e.data, '$.session_id') = ?1",
+    ) {
+        files = stmt
+            .query_map([&session_id], |row| row.get::<_, String>(0))?
+            .filter_map(|r| r.ok())
+            .collect();
+    }
+
+    for m in path_re.find_iter(content.as_deref().unwrap_or("")) {
+        let path = m.as_str().trim_start_matches("./").trim_end_matches('.');
+        if !files.iter().any(|f| f == path) {
+            files.push(path.to_string());
+        }
+    }
+    files.retain(|f| is_source_code(f));
+
+    Ok(Some((session_id, files)))
+}
+
+/// Sensitivity of semantic grounding
+///
+/// Defaults come from `[search]` in `.patina/config.toml`
+/// (`grounding_min_score`, `grounding_limit`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroundingParams {
+    /// Minimum cosine similarity for a neighbor to count
+    pub min_score: f32,
+    /// Nearest neighbors examined per belief
+    pub limit: usize,
+}
+
+impl Default for GroundingParams {
+    fn default() -> Self {
+        let search = patina::project::SearchSection::default();
+        Self {
+            min_score: search.grounding_min_score,
+            limit: search.grounding_limit,
+        }
+    }
+}
+
+impl GroundingParams {
+    /// Read thresholds from project config, falling back to defaults
+    pub fn from_config(project_root: &Path) -> Self {
+        patina::project::load(project_root)
+            .map(|config| Self {
+                min_score: config.search.grounding_min_score,
+                limit: config.search.grounding_limit,
+            })
+            .unwrap_or_default()
+    }
+}
+
 /// Compute semantic grounding metrics for all beliefs (E4.6a step 5)
 ///
 /// Loads the usearch semantic index (built by `patina oxidize`) and computes
@@ -598,36 +811,58 @@ fn is_source_code(path: &str) -> bool {
 /// Runs AFTER Phase 3 insertion so beliefs have rowids in the DB.
 /// After a rebuild, rowids change and won't match the index (grounding = 0).
 /// Next `patina oxidize` + `patina scrape` cycle restores the mapping.
-fn compute_belief_grounding(conn: &Connection) -> Result<()> {
-    use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+///
+/// Returns false when there is no semantic index to ground against.
+pub fn compute_belief_grounding(conn: &Connection, params: GroundingParams) -> Result<bool> {
+    Ok(ground_with_index(conn, &semantic_index_path(), params)?.is_some())
+}
 
+/// Semantic index of the configured embedding model (built by `patina oxidize`)
+pub fn semantic_index_path() -> PathBuf {
     let model = crate::commands::scry::internal::search::get_embedding_model();
-    let index_path = format!(
+    PathBuf::from(format!(
         ".patina/local/data/embeddings/{}/projections/semantic.usearch",
         model
-    );
+    ))
+}
 
-    if !Path::new(&index_path).exists() {
-        return Ok(());
+/// Ground every belief in the database against the index at `index_path`
+///
+/// Belief rows are not re-parsed: only the grounding columns and
+/// `belief_code_reach` are rewritten. Returns how many beliefs were grounded,
+/// or None when the index doesn't exist.
+pub fn ground_with_index(
+    conn: &Connection,
+    index_path: &Path,
+    params: GroundingParams,
+) -> Result<Option<usize>> {
+    use crate::commands::oxidize::index_meta;
+    use usearch::Index;
+
+    if !index_path.exists() {
+        return Ok(None);
     }
 
-    let index_options = IndexOptions {
-        dimensions: 256,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
+    let index_options = index_meta::load_options(index_path);
 
     let index = Index::new(&index_options)?;
-    index.load(&index_path)?;
+    index.load(&index_path.to_string_lossy())?;
+
+    // Older databases may predate belief_code_reach
+    create_materialized_views(conn)?;
+    Ok(Some(ground_beliefs(conn, &index, params)?))
+}
 
-    const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
-    const FORGE_ID_OFFSET: i64 = 5_000_000_000;
-    const CODE_ID_OFFSET: i64 = 1_000_000_000;
-    const PATTERN_ID_OFFSET: i64 = 2_000_000_000;
-    const COMMIT_ID_OFFSET: i64 = 3_000_000_000;
-    const SEARCH_LIMIT: usize = 20;
-    const MIN_SCORE: f32 = 0.85;
+/// Ground every belief against an already-loaded semantic index
+///
+/// Returns how many beliefs had at least one neighbor above `min_score`.
+fn ground_beliefs(
+    conn: &Connection,
+    index: &usearch::Index,
+    params: GroundingParams,
+) -> Result<usize> {
+    // File paths mentioned in session text, e.g. `src/main.rs`
+    let path_re = Regex::new(r"[\w./-]+\.\w+").unwrap();
 
     // Clear previous reach data
     conn.execute("DELETE FROM belief_code_reach", [])?;
@@ -646,9 +881,9 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
     let mut total_lexical_fallbacks = 0u32;
 
     for (rowid, belief_id) in &beliefs {
-        let belief_key = (BELIEF_ID_OFFSET + rowid) as u64;
+        let belief_key = id_offsets::belief_key(*rowid) as u64;
 
-        let mut vector = vec![0.0_f32; 256];
+        let mut vector = vec![0.0_f32; index.dimensions()];
         if index.get(belief_key, &mut vector).is_err() {
             continue;
         }
@@ -658,7 +893,7 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
             continue;
         }
 
-        let matches = match index.search(&vector, SEARCH_LIMIT + 2) {
+        let matches = match index.search(&vector, params.limit + 2) {
             Ok(m) => m,
             Err(_) => continue,
         };
@@ -672,60 +907,54 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
         // E4.6a-fix: Collect commit neighbors for structural hop
         // (sha, cosine_score) for commits above threshold
         let mut commit_neighbors: Vec<(String, f32)> = Vec::new();
+        // Session events (eventlog seq, cosine_score) for the session hop
+        let mut session_neighbors: Vec<(i64, f32)> = Vec::new();
 
         for i in 0..matches.keys.len() {
             let key = matches.keys[i] as i64;
             let score = 1.0 - matches.distances[i];
 
-            // Skip self and pattern entries
-            if key == BELIEF_ID_OFFSET + rowid {
-                continue;
-            }
-            if (PATTERN_ID_OFFSET..COMMIT_ID_OFFSET).contains(&key) {
-                continue;
-            }
-            // Skip other beliefs
-            if (BELIEF_ID_OFFSET..FORGE_ID_OFFSET).contains(&key) && key != BELIEF_ID_OFFSET + rowid
-            {
+            // Skip self, other beliefs, and pattern entries
+            let kind = classify_key(key);
+            if matches!(kind, KeyKind::Belief | KeyKind::Pattern) {
                 continue;
             }
 
-            if score < MIN_SCORE {
+            if score < params.min_score {
                 continue;
             }
 
-            if key >= FORGE_ID_OFFSET {
-                // Forge event (issue or PR)
-                forge_count += 1;
-                total_score += score;
-                total_count += 1;
-            } else if (COMMIT_ID_OFFSET..BELIEF_ID_OFFSET).contains(&key) {
-                commit_count += 1;
-
-                // Resolve commit rowid → SHA for structural hop
-                let commit_rowid = key - COMMIT_ID_OFFSET;
-                if let Ok(sha) = conn.query_row(
-                    "SELECT sha FROM commits WHERE rowid = ?1",
-                    [commit_rowid],
-                    |row| row.get::<_, String>(0),
-                ) {
-                    commit_neighbors.push((sha, score));
+            match kind {
+                KeyKind::Forge => {
+                    // Forge event (issue or PR)
+                    forge_count += 1;
+                }
+                KeyKind::Commit => {
+                    commit_count += 1;
+
+                    // Resolve commit rowid → SHA for structural hop
+                    let commit_rowid = id_offsets::source_id(key);
+                    if let Ok(sha) = conn.query_row(
+                        "SELECT sha FROM commits WHERE rowid = ?1",
+                        [commit_rowid],
+                        |row| row.get::<_, String>(0),
+                    ) {
+                        commit_neighbors.push((sha, score));
+                    }
+                }
+                KeyKind::Session => {
+                    session_count += 1;
+                    session_neighbors.push((key, score));
                 }
-                total_score += score;
-                total_count += 1;
-            } else if key < CODE_ID_OFFSET {
-                session_count += 1;
-                total_score += score;
-                total_count += 1;
-            } else {
                 // Code matches — counted in total_score but NOT in grounding_code_count
-                total_score += score;
-                total_count += 1;
+                _ => {}
             }
+            total_score += score;
+            total_count += 1;
         }
 
         // E4.6a-fix: Structural hop — commit → commit_files → file_path
-        // Aggregate per-file: max score across touching commits, count of commits
+        // Aggregate per-file: max score across hops, plus the hop tags that reached it
         let mut file_reach: std::collections::HashMap<String, (f32, Vec<String>)> =
             std::collections::HashMap::new();
 
@@ -749,7 +978,26 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
                 if *score > entry.0 {
                     entry.0 = *score;
                 }
-                entry.1.push(sha.clone());
+                entry.1.push(format!("commit:{}", &sha[..7.min(sha.len())]));
+            }
+        }
+
+        // Session hop — session → (commits made in it, files it mentions) → file_path
+        for (seq, score) in &session_neighbors {
+            let Some((session_id, files)) = session_reach_files(conn, *seq, &path_re)? else {
+                continue;
+            };
+            let hop_tag = format!("session:{}", session_id);
+            for file_path in files {
+                let entry = file_reach
+                    .entry(file_path)
+                    .or_insert_with(|| (0.0_f32, Vec::new()));
+                if *score > entry.0 {
+                    entry.0 = *score;
+                }
+                if !entry.1.contains(&hop_tag) {
+                    entry.1.push(hop_tag.clone());
+                }
             }
         }
 
@@ -796,7 +1044,7 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
 
         // Insert reach entries — all entries are source code (filtered at the hop)
         let source_file_count = file_reach.len() as i32;
-        for (file_path, (reach_score, shas)) in &file_reach {
+        for (file_path, (reach_score, hops)) in &file_reach {
             // Count functions in this file from function_facts
             let function_count: i32 = conn
                 .query_row(
@@ -806,11 +1054,8 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
                 )
                 .unwrap_or(0);
 
-            let hop_path = shas
-                .iter()
-                .map(|s| format!("commit:{}", &s[..7.min(s.len())]))
-                .collect::<Vec<_>>()
-                .join(",");
+            let hop_path = hops.join(",");
+            let reach_commits = hops.iter().filter(|h| h.starts_with("commit:")).count() as i32;
 
             conn.execute(
                 "INSERT OR REPLACE INTO belief_code_reach (belief_id, file_path, reach_score, commit_count, function_count, hop_path)
@@ -819,7 +1064,7 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
                     belief_id,
                     file_path,
                     reach_score,
-                    shas.len() as i32,
+                    reach_commits,
                     function_count,
                     hop_path,
                 ],
@@ -855,7 +1100,154 @@ fn compute_belief_grounding(conn: &Connection) -> Result<()> {
         total, grounded, total_reach_files, total_source_files, precision, total_lexical_fallbacks
     );
 
-    Ok(())
+    Ok(grounded)
+}
+
+/// One nearest neighbor grounding examined for a belief
+#[derive(Debug, Clone, PartialEq)]
+pub struct TracedNeighbor {
+    pub kind: KeyKind,
+    /// Commit SHA, session id, function, or the raw index key
+    pub label: String,
+    pub score: f32,
+}
+
+/// How a single belief got (or missed) its grounding
+#[derive(Debug)]
+pub struct GroundingTrace {
+    pub belief_id: String,
+    pub params: GroundingParams,
+    /// Neighbors examined, closest first (beliefs and patterns skipped, as in grounding)
+    pub neighbors: Vec<TracedNeighbor>,
+    /// Stored `belief_code_reach` rows: (file_path, reach_score, hop_path)
+    pub reach: Vec<(String, f32, String)>,
+}
+
+impl GroundingTrace {
+    /// Neighbors that cleared `min_score` and count toward grounding
+    pub fn kept(&self) -> impl Iterator<Item = &TracedNeighbor> {
+        self.neighbors
+            .iter()
+            .filter(|n| n.score >= self.params.min_score)
+    }
+
+    /// Borderline neighbors `min_score` filtered out
+    pub fn filtered(&self) -> impl Iterator<Item = &TracedNeighbor> {
+        self.neighbors
+            .iter()
+            .filter(|n| n.score < self.params.min_score)
+    }
+}
+
+/// Re-run one belief's neighbor search and pair it with its stored reach
+///
+/// Mirrors `ground_beliefs` for a single belief but keeps what it discards,
+/// so `belief audit --explain-grounding` can show why a file was (not) reached.
+pub fn explain_grounding(
+    conn: &Connection,
+    index: &usearch::Index,
+    belief_id: &str,
+    params: GroundingParams,
+) -> Result<GroundingTrace> {
+    let rowid: i64 = conn
+        .query_row(
+            "SELECT rowid FROM beliefs WHERE id = ?1",
+            [belief_id],
+            |row| row.get(0),
+        )
+        .map_err(|_| anyhow::anyhow!("Belief '{}' not found", belief_id))?;
+
+    let mut vector = vec![0.0_f32; index.dimensions()];
+    let magnitude = match index.get(id_offsets::belief_key(rowid) as u64, &mut vector) {
+        Ok(_) => vector.iter().map(|v| v * v).sum::<f32>().sqrt(),
+        Err(_) => 0.0,
+    };
+    if magnitude < 0.001 {
+        anyhow::bail!(
+            "Belief '{}' is not in the semantic index. Run `patina oxidize` first.",
+            belief_id
+        );
+    }
+
+    let matches = index.search(&vector, params.limit + 2)?;
+    let mut neighbors = Vec::new();
+    for i in 0..matches.keys.len() {
+        let key = matches.keys[i] as i64;
+        let kind = classify_key(key);
+        if matches!(kind, KeyKind::Belief | KeyKind::Pattern) {
+            continue;
+        }
+        neighbors.push(TracedNeighbor {
+            kind,
+            label: neighbor_label(conn, key, kind).unwrap_or_else(|| key.to_string()),
+            score: 1.0 - matches.distances[i],
+        });
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT file_path, reach_score, hop_path FROM belief_code_reach
+         WHERE belief_id = ?1
+         ORDER BY reach_score DESC, file_path",
+    )?;
+    let reach = stmt
+        .query_map([belief_id], |row| {
+            Ok((
+                row.get(0)?,
+                row.get(1)?,
+                row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+            ))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    Ok(GroundingTrace {
+        belief_id: belief_id.to_string(),
+        params,
+        neighbors,
+        reach,
+    })
+}
+
+/// Human-readable name for an index key, when its source table is present
+fn neighbor_label(conn: &Connection, key: i64, kind: KeyKind) -> Option<String> {
+    let rowid = id_offsets::source_id(key);
+    let query = match kind {
+        KeyKind::Commit => "SELECT substr(sha, 1, 7) FROM commits WHERE rowid = ?1",
+        KeyKind::Code => "SELECT file || '::' || name FROM function_facts WHERE rowid = ?1",
+        KeyKind::Session | KeyKind::Forge => "SELECT source_id FROM eventlog WHERE seq = ?1",
+        KeyKind::Belief | KeyKind::Pattern => return None,
+    };
+    conn.query_row(query, [rowid], |row| row.get(0)).ok()
+}
+
+/// How many scrape snapshots to keep in `belief_snapshots`
+const SNAPSHOT_RETENTION: usize = 50;
+
+/// Copy current `beliefs` metrics into `belief_snapshots` under one timestamp.
+///
+/// Runs at the end of every belief scrape so `patina belief diff` can compare
+/// the latest state against the previous scrape. Older snapshots beyond
+/// `SNAPSHOT_RETENTION` are dropped.
+pub fn snapshot_belief_metrics(conn: &Connection, snapshot_at: &str) -> Result<usize> {
+    let count = conn.execute(
+        "INSERT OR REPLACE INTO belief_snapshots
+            (snapshot_at, belief_id, citations, evidence_verified, grounding_total, verification_passed)
+         SELECT ?1, id,
+                COALESCE(cited_by_beliefs, 0) + COALESCE(cited_by_sessions, 0),
+                COALESCE(evidence_verified, 0),
+                COALESCE(grounding_code_count, 0) + COALESCE(grounding_commit_count, 0) + COALESCE(grounding_session_count, 0),
+                COALESCE(verification_passed, 0)
+         FROM beliefs",
+        [snapshot_at],
+    )?;
+
+    conn.execute(
+        "DELETE FROM belief_snapshots WHERE snapshot_at NOT IN (
+            SELECT DISTINCT snapshot_at FROM belief_snapshots ORDER BY snapshot_at DESC LIMIT ?1
+         )",
+        [SNAPSHOT_RETENTION as i64],
+    )?;
+
+    Ok(count)
 }
 
 /// Insert a parsed belief into eventlog and materialized views
@@ -966,6 +1358,54 @@ fn insert_belief(conn: &Connection, belief: &ParsedBelief) -> Result<()> {
     Ok(())
 }
 
+/// Belief id declared by more than one file
+#[derive(Debug, PartialEq)]
+struct DuplicateBelief {
+    id: String,
+    /// File whose belief is kept (first in sorted order)
+    kept: String,
+    /// Later files declaring the same id, skipped
+    skipped: Vec<String>,
+}
+
+impl std::fmt::Display for DuplicateBelief {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "duplicate belief id '{}' in {} and {} (keeping {})",
+            self.id,
+            self.kept,
+            self.skipped.join(", "),
+            self.kept
+        )
+    }
+}
+
+/// Drop beliefs whose id an earlier file already declared
+///
+/// `insert_belief` replaces by id, so without this the last file silently wins.
+fn drop_duplicate_ids(beliefs: &mut Vec<ParsedBelief>) -> Vec<DuplicateBelief> {
+    let mut duplicates: Vec<DuplicateBelief> = Vec::new();
+    let mut first_file: std::collections::HashMap<String, String> =
+        std::collections::HashMap::new();
+    beliefs.retain(|belief| {
+        let Some(kept) = first_file.get(&belief.id) else {
+            first_file.insert(belief.id.clone(), belief.file_path.clone());
+            return true;
+        };
+        match duplicates.iter_mut().find(|d| d.id == belief.id) {
+            Some(dup) => dup.skipped.push(belief.file_path.clone()),
+            None => duplicates.push(DuplicateBelief {
+                id: belief.id.clone(),
+                kept: kept.clone(),
+                skipped: vec![belief.file_path.clone()],
+            }),
+        }
+        false
+    });
+    duplicates
+}
+
 /// Main entry point for belief scraping
 pub fn run(full: bool) -> Result<ScrapeStats> {
     let start = Instant::now();
@@ -1036,13 +1476,17 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
         }
     }
 
+    for dup in drop_duplicate_ids(&mut all_beliefs) {
+        eprintln!("  Warning: {}", dup);
+    }
+
     // Phase 2: Cross-reference beliefs against each other and sessions
     // This must happen after all beliefs are parsed
     let project_root = Path::new(".");
     cross_reference_beliefs(&mut all_beliefs, project_root);
 
     // Phase 2.5: Run verification queries
-    // Executes SQL queries from ## Verification sections, stores per-query results,
+    // Executes DB and filesystem checks from ## Verification sections, stores per-query results,
     // and computes aggregates. Runs on every scrape (D5: always verify).
     let data_freshness = if full { "full" } else { "incremental" };
     let mut verified_count = 0;
@@ -1053,6 +1497,7 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
                 &belief.id,
                 &belief.verification_queries,
                 data_freshness,
+                project_root,
             );
             belief.verification = aggregates;
             verified_count += 1;
@@ -1107,7 +1552,7 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
     // Uses usearch index from a previous `patina oxidize` run.
     // After rebuild, rowids change and won't match the index → grounding = 0
     // (expected; next oxidize+scrape cycle will fix this).
-    if let Err(e) = compute_belief_grounding(&conn) {
+    if let Err(e) = compute_belief_grounding(&conn, GroundingParams::from_config(project_root)) {
         eprintln!("  Warning: grounding computation failed: {}", e);
     }
 
@@ -1138,6 +1583,11 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
         println!("  Pruned {} stale beliefs", pruned);
     }
 
+    // Phase 4: Snapshot metrics so `patina belief diff` can report deltas
+    if let Err(e) = snapshot_belief_metrics(&conn, &chrono::Utc::now().to_rfc3339()) {
+        eprintln!("  Warning: failed to snapshot belief metrics: {}", e);
+    }
+
     let elapsed = start.elapsed();
     let db_size = std::fs::metadata(db_path)
         .map(|m| m.len() / 1024)
@@ -1205,4 +1655,321 @@ Prefer synchronous code.
         assert_eq!(belief.status, "active");
         assert_eq!(belief.statement, "Prefer synchronous code.");
     }
+
+    #[test]
+    fn test_duplicate_ids_keep_first_file_and_report_both() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, id: &str| {
+            let path = temp_dir.path().join(name);
+            let content = format!("---\ntype: belief\nid: {id}\n---\n\n# {id}\n\nFrom {name}.\n");
+            std::fs::write(&path, content).unwrap();
+            parse_belief_file(&path).unwrap()
+        };
+        let mut beliefs = vec![
+            write("a-sync-first.md", "sync-first"),
+            write("b-other.md", "other"),
+            write("c-sync-first-copy.md", "sync-first"),
+        ];
+        let first = beliefs[0].file_path.clone();
+        let copy = beliefs[2].file_path.clone();
+
+        let duplicates = drop_duplicate_ids(&mut beliefs);
+
+        assert_eq!(
+            duplicates,
+            vec![DuplicateBelief {
+                id: "sync-first".to_string(),
+                kept: first.clone(),
+                skipped: vec![copy.clone()],
+            }]
+        );
+        let warning = duplicates[0].to_string();
+        assert!(warning.starts_with("duplicate belief id 'sync-first'"));
+        assert!(warning.contains(&first) && warning.contains(&copy));
+
+        let kept: Vec<(&str, &str)> = beliefs
+            .iter()
+            .map(|b| (b.id.as_str(), b.statement.as_str()))
+            .collect();
+        assert_eq!(
+            kept,
+            vec![
+                ("sync-first", "From a-sync-first.md."),
+                ("other", "From b-other.md.")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_build_belief_graph_edges() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let write = |id: &str, body: &str| {
+            let content =
+                format!("---\ntype: belief\nid: {id}\n---\n\n# {id}\n\nStatement.\n\n{body}");
+            std::fs::write(temp_dir.path().join(format!("{id}.md")), content).unwrap();
+        };
+        write("helper", "## Supports\n\n- [[target]] — shared rationale\n");
+        write(
+            "rival",
+            "## Attacks\n\n- [[target]] (status: defeated, reason: \"scope too narrow\")\n",
+        );
+        write(
+            "target",
+            "## Evidence\n\n- [[helper]] backs this up\n- [[session-20260101-000000]]\n\n## Attacked-By\n\n- [[rival]] (status: defeated)\n",
+        );
+
+        let graph = build_belief_graph(temp_dir.path()).unwrap();
+        assert_eq!(graph.beliefs, vec!["helper", "rival", "target"]);
+        assert_eq!(graph.edges.len(), 3);
+
+        let find = |from: &str, to: &str| {
+            graph
+                .edges
+                .iter()
+                .find(|e| e.from == from && e.to == to)
+                .unwrap_or_else(|| panic!("missing edge {} -> {}", from, to))
+        };
+
+        let supports = find("helper", "target");
+        assert_eq!(supports.relation, "supports");
+        assert!(!supports.defeated);
+
+        let attacks = find("rival", "target");
+        assert_eq!(attacks.relation, "attacks");
+        assert!(attacks.defeated);
+
+        let evidence = find("target", "helper");
+        assert_eq!(evidence.relation, "evidence");
+        assert!(!evidence.defeated);
+    }
+
+    #[test]
+    fn test_lower_grounding_threshold_grounds_more_beliefs() -> Result<()> {
+        use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+        let conn = Connection::open_in_memory()?;
+        create_materialized_views(&conn)?;
+        conn.execute_batch("INSERT INTO beliefs (id) VALUES ('close-match'), ('loose-match');")?;
+
+        let index = Index::new(&IndexOptions {
+            dimensions: 4,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        })?;
+        index.reserve(4)?;
+        index.add(4_000_000_001, &[1.0_f32, 0.0, 0.0, 0.0])?;
+        index.add(4_000_000_002, &[0.0_f32, 1.0, 0.0, 0.0])?;
+        // Sessions: one near-duplicate of the first belief, one loosely related to the second
+        index.add(10, &[1.0_f32, 0.1, 0.0, 0.0])?;
+        index.add(11, &[0.8_f32, 0.6, 0.0, 0.0])?;
+
+        let grounded_sessions = |conn: &Connection| -> Result<i64> {
+            Ok(conn.query_row(
+                "SELECT SUM(grounding_session_count) FROM beliefs",
+                [],
+                |row| row.get(0),
+            )?)
+        };
+
+        let strict = ground_beliefs(&conn, &index, GroundingParams::default())?;
+        assert_eq!(strict, 1);
+        assert_eq!(grounded_sessions(&conn)?, 1);
+
+        let loose = GroundingParams {
+            min_score: 0.5,
+            ..GroundingParams::default()
+        };
+        assert_eq!(ground_beliefs(&conn, &index, loose)?, 2);
+        assert_eq!(grounded_sessions(&conn)?, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_regrounding_after_index_change_only_touches_grounding() -> Result<()> {
+        use crate::commands::oxidize::index_meta;
+        use usearch::Index;
+
+        let temp = tempfile::TempDir::new()?;
+        let index_path = temp.path().join("semantic.usearch");
+        let write_index = |points: &[(u64, [f32; 2])]| -> Result<()> {
+            let index = Index::new(&index_meta::load_options(&index_path))?;
+            index.reserve(points.len())?;
+            for (key, [x, y]) in points {
+                let mut v = vec![0.0_f32; index.dimensions()];
+                v[0] = *x;
+                v[1] = *y;
+                index.add(*key, &v)?;
+            }
+            index.save(&index_path.to_string_lossy())?;
+            Ok(())
+        };
+        let session_count = |conn: &Connection| -> Result<i64> {
+            Ok(conn.query_row(
+                "SELECT grounding_session_count FROM beliefs WHERE id = 'sync-first'",
+                [],
+                |row| row.get(0),
+            )?)
+        };
+
+        let conn = Connection::open_in_memory()?;
+        create_materialized_views(&conn)?;
+        conn.execute(
+            "INSERT INTO beliefs (id, statement, entrenchment, cited_by_beliefs)
+             VALUES ('sync-first', 'Prefer synchronous code', 'high', 4)",
+            [],
+        )?;
+
+        assert_eq!(
+            ground_with_index(&conn, &index_path, GroundingParams::default())?,
+            None
+        );
+
+        write_index(&[(4_000_000_001, [1.0, 0.0]), (10, [1.0, 0.1])])?;
+        ground_with_index(&conn, &index_path, GroundingParams::default())?;
+        assert_eq!(session_count(&conn)?, 1);
+
+        // Re-oxidized: a second session now sits next to the belief
+        write_index(&[
+            (4_000_000_001, [1.0, 0.0]),
+            (10, [1.0, 0.1]),
+            (11, [1.0, 0.05]),
+        ])?;
+        let grounded = ground_with_index(&conn, &index_path, GroundingParams::default())?;
+        assert_eq!(grounded, Some(1));
+        assert_eq!(session_count(&conn)?, 2);
+
+        let untouched: (String, String, i64) = conn.query_row(
+            "SELECT statement, entrenchment, cited_by_beliefs FROM beliefs WHERE id = 'sync-first'",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+        assert_eq!(
+            untouched,
+            ("Prefer synchronous code".to_string(), "high".to_string(), 4)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_explain_grounding_traces_commit_hop_and_cutoff() -> Result<()> {
+        use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+        let conn = Connection::open_in_memory()?;
+        create_materialized_views(&conn)?;
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, message TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             INSERT INTO beliefs (id) VALUES ('sync-first');
+             INSERT INTO commits VALUES ('abc1234def567', 'Drop the async runtime');
+             INSERT INTO commit_files VALUES
+                ('abc1234def567', 'src/engine.rs'), ('abc1234def567', 'README.md');",
+        )?;
+
+        let index = Index::new(&IndexOptions {
+            dimensions: 4,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        })?;
+        index.reserve(3)?;
+        index.add(4_000_000_001, &[1.0_f32, 0.0, 0.0, 0.0])?;
+        index.add(3_000_000_001, &[1.0_f32, 0.1, 0.0, 0.0])?;
+        // Borderline session: related, but under the default 0.85 cutoff
+        index.add(11, &[0.8_f32, 0.6, 0.0, 0.0])?;
+
+        ground_beliefs(&conn, &index, GroundingParams::default())?;
+        let trace = explain_grounding(&conn, &index, "sync-first", GroundingParams::default())?;
+
+        let kept: Vec<_> = trace.kept().collect();
+        assert_eq!(kept.len(), 1);
+        assert_eq!(
+            (kept[0].kind, kept[0].label.as_str()),
+            (KeyKind::Commit, "abc1234")
+        );
+        assert!(kept[0].score > 0.99);
+
+        let filtered: Vec<_> = trace.filtered().collect();
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].kind, KeyKind::Session);
+        assert!((filtered[0].score - 0.8).abs() < 0.01);
+
+        assert_eq!(trace.reach.len(), 1);
+        let (file, score, hops) = &trace.reach[0];
+        assert_eq!(
+            (file.as_str(), hops.as_str()),
+            ("src/engine.rs", "commit:abc1234")
+        );
+        assert!((score - kept[0].score).abs() < 1e-6);
+
+        assert!(explain_grounding(&conn, &index, "missing", GroundingParams::default()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_session_neighbor_reaches_referenced_file() -> Result<()> {
+        use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+        let temp = tempfile::TempDir::new()?;
+        let conn = database::initialize(&temp.path().join("patina.db"))?;
+        create_materialized_views(&conn)?;
+        conn.execute(
+            "INSERT INTO beliefs (id) VALUES ('oracles-are-pluggable')",
+            [],
+        )?;
+        database::insert_event(
+            &conn,
+            "session.decision",
+            "2026-01-01T12:00:00Z",
+            "20260101-120000",
+            Some("layer/sessions/20260101-120000.md"),
+            &json!({
+                "session_id": "20260101-120000",
+                "content": "Moved oracle registration into `src/retrieval/engine.rs` and updated README.md",
+            })
+            .to_string(),
+        )?;
+        let seq: i64 = conn.query_row("SELECT MAX(seq) FROM eventlog", [], |row| row.get(0))?;
+
+        let index = Index::new(&IndexOptions {
+            dimensions: 4,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        })?;
+        index.reserve(2)?;
+        index.add(4_000_000_001, &[1.0_f32, 0.0, 0.0, 0.0])?;
+        index.add(seq as u64, &[1.0_f32, 0.05, 0.0, 0.0])?;
+
+        assert_eq!(
+            ground_beliefs(&conn, &index, GroundingParams::default())?,
+            1
+        );
+
+        let reach: Vec<(String, String, i32)> = conn
+            .prepare("SELECT file_path, hop_path, commit_count FROM belief_code_reach")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        // Docs mentioned in the session are not code reach
+        assert_eq!(
+            reach,
+            vec![(
+                "src/retrieval/engine.rs".to_string(),
+                "session:20260101-120000".to_string(),
+                0
+            )]
+        );
+
+        let code_count: i32 = conn.query_row(
+            "SELECT grounding_code_count FROM beliefs WHERE id = 'oracles-are-pluggable'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(code_count, 1);
+
+        Ok(())
+    }
 }
@@ -2,8 +2,10 @@
 
 use anyhow::Result;
 use rusqlite::Connection;
+use std::path::Path;
 
 use super::assay;
+use super::filesystem;
 use super::safety;
 use super::temporal;
 use crate::commands::scrape::beliefs::verification::{
@@ -13,9 +15,11 @@ use crate::commands::scrape::beliefs::verification::{
 /// Execute a single verification query and compare against expectation.
 ///
 /// Dispatches to the appropriate executor based on query type.
+/// File and grep queries are evaluated against `project_root`.
 pub fn execute_verification_query(
     conn: &Connection,
     query: &VerificationQuery,
+    project_root: &Path,
 ) -> VerificationResult {
     // Safety check first
     if let Err(e) = safety::validate_query_safety(query) {
@@ -34,6 +38,8 @@ pub fn execute_verification_query(
         "sql" => execute_sql_query(conn, query),
         "assay" => execute_assay_query(conn, query),
         "temporal" => execute_temporal_query(conn, query),
+        "file" => execute_file_query(project_root, query),
+        "grep" => execute_grep_query(project_root, query),
         _ => VerificationResult {
             label: query.label.clone(),
             query_type: query.query_type.clone(),
@@ -171,6 +177,52 @@ fn execute_temporal_query(conn: &Connection, query: &VerificationQuery) -> Verif
     }
 }
 
+/// Execute a file-existence verification query.
+///
+/// Counts how many listed paths exist under the project root.
+fn execute_file_query(project_root: &Path, query: &VerificationQuery) -> VerificationResult {
+    match filesystem::parse_file_query(&query.query_text) {
+        Ok(paths) => build_result_from_value(
+            filesystem::count_existing_paths(project_root, &paths),
+            query,
+        ),
+        Err(e) => VerificationResult {
+            label: query.label.clone(),
+            query_type: query.query_type.clone(),
+            query_text: query.query_text.clone(),
+            expectation: query.expect.clone(),
+            status: VerificationStatus::Error,
+            result: None,
+            error: Some(format!("file parse error: {}", e)),
+        },
+    }
+}
+
+/// Execute a grep verification query.
+///
+/// Counts matching lines under the target path; a missing path is an error.
+fn execute_grep_query(project_root: &Path, query: &VerificationQuery) -> VerificationResult {
+    let result = filesystem::parse_grep_query(&query.query_text)
+        .map_err(|e| format!("grep parse error: {}", e))
+        .and_then(|parsed| {
+            filesystem::count_grep_matches(project_root, &parsed)
+                .map_err(|e| format!("grep execution error: {}", e))
+        });
+
+    match result {
+        Ok(value) => build_result_from_value(value, query),
+        Err(e) => VerificationResult {
+            label: query.label.clone(),
+            query_type: query.query_type.clone(),
+            query_text: query.query_text.clone(),
+            expectation: query.expect.clone(),
+            status: VerificationStatus::Error,
+            result: None,
+            error: Some(e),
+        },
+    }
+}
+
 /// Build a VerificationResult from a numeric value and expectation.
 fn build_result_from_value(value: f64, query: &VerificationQuery) -> VerificationResult {
     match evaluate_expectation(value, &query.expect) {
@@ -396,7 +448,7 @@ mod tests {
             query_text: "SELECT val FROM test".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("42".to_string()));
     }
@@ -414,7 +466,7 @@ mod tests {
             query_text: "SELECT val FROM test".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Contested);
         assert_eq!(result.result, Some("5".to_string()));
     }
@@ -430,7 +482,7 @@ mod tests {
             query_text: "SELECT * FROM nonexistent_table".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Error);
         assert!(result.error.is_some());
     }
@@ -455,7 +507,7 @@ mod tests {
             query_text: r#"callers --pattern "insert_event""#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("3".to_string()));
     }
@@ -478,7 +530,7 @@ mod tests {
             query_text: r#"callers --pattern "insert_event" | count(distinct file)"#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("2".to_string()));
     }
@@ -501,7 +553,7 @@ mod tests {
             query_text: r#"functions --pattern "migration""#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         // 2 match: run_migration_v2, test_migration (migrate_if_needed has "migrate" not "migration")
         assert_eq!(result.result, Some("2".to_string()));
@@ -525,7 +577,7 @@ mod tests {
             query_text: r#"importers --pattern "commands""#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         // 3 rows match "commands" substring
         assert_eq!(result.result, Some("3".to_string()));
@@ -547,7 +599,7 @@ mod tests {
             query_text: r#"callers --pattern "insert_event""#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Contested);
         assert_eq!(result.result, Some("1".to_string()));
     }
@@ -563,7 +615,7 @@ mod tests {
             query_text: r#"callers --pattern "test""#.to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Error);
         assert!(result.error.unwrap().contains("execution error"));
     }
@@ -588,7 +640,7 @@ mod tests {
             query_text: "derive-moments | summary.total_commits".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("3".to_string()));
     }
@@ -612,7 +664,7 @@ mod tests {
             query_text: "derive-moments | summary.rewrite".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("2".to_string()));
     }
@@ -635,7 +687,7 @@ mod tests {
             query_text: "derive-moments | summary.migration".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Pass);
         assert_eq!(result.result, Some("2".to_string()));
     }
@@ -651,8 +703,73 @@ mod tests {
             query_text: "derive-moments | summary.nonexistent".to_string(),
         };
 
-        let result = execute_verification_query(&conn, &query);
+        let result = execute_verification_query(&conn, &query, Path::new("."));
         assert_eq!(result.status, VerificationStatus::Error);
         assert!(result.error.unwrap().contains("safety validation failed"));
     }
+
+    // Filesystem execution tests (temp project root)
+
+    #[test]
+    fn test_execute_file_query_exists() {
+        let conn = Connection::open_in_memory().unwrap();
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("src/retrieval")).unwrap();
+        std::fs::write(
+            root.path().join("src/retrieval/engine.rs"),
+            "fn main() {}\n",
+        )
+        .unwrap();
+
+        let query = VerificationQuery {
+            query_type: "file".to_string(),
+            label: "engine module exists".to_string(),
+            expect: "= 1".to_string(),
+            query_text: "src/retrieval/engine.rs".to_string(),
+        };
+
+        let result = execute_verification_query(&conn, &query, root.path());
+        assert_eq!(result.status, VerificationStatus::Pass);
+        assert_eq!(result.result, Some("1".to_string()));
+    }
+
+    #[test]
+    fn test_execute_grep_query_contested() {
+        let conn = Connection::open_in_memory().unwrap();
+        let root = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(root.path().join("src/retrieval")).unwrap();
+        std::fs::write(
+            root.path().join("src/retrieval/engine.rs"),
+            "let a = x.unwrap();\nlet b = y?;\nlet c = z.unwrap();\n",
+        )
+        .unwrap();
+
+        let query = VerificationQuery {
+            query_type: "grep".to_string(),
+            label: "No unwrap in retrieval".to_string(),
+            expect: "= 0".to_string(),
+            query_text: r"src/retrieval | \.unwrap\(\)".to_string(),
+        };
+
+        let result = execute_verification_query(&conn, &query, root.path());
+        assert_eq!(result.status, VerificationStatus::Contested);
+        assert_eq!(result.result, Some("2".to_string()));
+    }
+
+    #[test]
+    fn test_execute_grep_query_missing_directory() {
+        let conn = Connection::open_in_memory().unwrap();
+        let root = tempfile::tempdir().unwrap();
+
+        let query = VerificationQuery {
+            query_type: "grep".to_string(),
+            label: "No unwrap in missing module".to_string(),
+            expect: "= 0".to_string(),
+            query_text: r"src/nonexistent | \.unwrap\(\)".to_string(),
+        };
+
+        let result = execute_verification_query(&conn, &query, root.path());
+        assert_eq!(result.status, VerificationStatus::Error);
+        assert!(result.error.unwrap().contains("path not found"));
+    }
 }
@@ -0,0 +1,148 @@
+//! Filesystem DSL — file-existence and grep checks against the project tree.
+//!
+//! Unlike sql/assay/temporal, these queries never touch the knowledge DB.
+//! They count things on disk so results feed the same numeric expectations.
+
+use regex::Regex;
+use std::path::{Component, Path};
+
+/// Parsed grep query from DSL text.
+#[derive(Debug, Clone)]
+pub struct ParsedGrepQuery {
+    pub path: String,
+    pub pattern: Regex,
+}
+
+/// Reject paths that could escape the project root.
+fn validate_relative_path(path: &str) -> Result<(), String> {
+    if path.is_empty() {
+        return Err("path cannot be empty".to_string());
+    }
+    let p = Path::new(path);
+    if p.is_absolute() {
+        return Err(format!("path must be relative to project root: '{}'", path));
+    }
+    if p.components().any(|c| matches!(c, Component::ParentDir)) {
+        return Err(format!("path must not contain '..': '{}'", path));
+    }
+    Ok(())
+}
+
+/// Parse a file-existence query.
+///
+/// Format: one relative path per line. The result is how many of them exist.
+///
+/// Example: `src/commands/scry/mod.rs`
+pub fn parse_file_query(text: &str) -> Result<Vec<String>, String> {
+    let paths: Vec<String> = text
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(|l| l.to_string())
+        .collect();
+
+    if paths.is_empty() {
+        return Err("file query requires at least one path".to_string());
+    }
+    for path in &paths {
+        validate_relative_path(path)?;
+    }
+
+    Ok(paths)
+}
+
+/// Parse a grep query.
+///
+/// Format: `<path> | <regex>` — split on the first pipe so the regex may use `|`.
+///
+/// Example: `src/retrieval | \.unwrap\(\)`
+pub fn parse_grep_query(text: &str) -> Result<ParsedGrepQuery, String> {
+    let (path, pattern) = text
+        .trim()
+        .split_once('|')
+        .ok_or_else(|| "grep query requires '<path> | <regex>'".to_string())?;
+
+    let path = path.trim();
+    let pattern = pattern.trim();
+    validate_relative_path(path)?;
+
+    if pattern.is_empty() {
+        return Err("grep pattern cannot be empty".to_string());
+    }
+    let pattern = Regex::new(pattern).map_err(|e| format!("invalid grep regex: {}", e))?;
+
+    Ok(ParsedGrepQuery {
+        path: path.to_string(),
+        pattern,
+    })
+}
+
+/// Count how many of the given paths exist under the project root.
+pub fn count_existing_paths(project_root: &Path, paths: &[String]) -> f64 {
+    paths
+        .iter()
+        .filter(|p| project_root.join(p).exists())
+        .count() as f64
+}
+
+/// Count lines matching the grep pattern in a file or directory tree.
+///
+/// Errors if the target path does not exist — a missing directory means the
+/// belief's claim can't be evaluated, not that it trivially holds.
+pub fn count_grep_matches(project_root: &Path, query: &ParsedGrepQuery) -> Result<f64, String> {
+    let target = project_root.join(&query.path);
+    if !target.exists() {
+        return Err(format!("path not found: {}", query.path));
+    }
+
+    let mut matches = 0usize;
+    for entry in walkdir::WalkDir::new(&target)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+    {
+        // Skip binary/non-UTF-8 files
+        let Ok(content) = std::fs::read_to_string(entry.path()) else {
+            continue;
+        };
+        matches += content
+            .lines()
+            .filter(|line| query.pattern.is_match(line))
+            .count();
+    }
+
+    Ok(matches as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_file_query_multiple_paths() {
+        let paths = parse_file_query("src/main.rs\n\nCargo.toml\n").unwrap();
+        assert_eq!(paths, vec!["src/main.rs", "Cargo.toml"]);
+    }
+
+    #[test]
+    fn test_parse_file_query_rejects_escape() {
+        assert!(parse_file_query("../secrets").is_err());
+        assert!(parse_file_query("/etc/passwd").is_err());
+        assert!(parse_file_query("   ").is_err());
+    }
+
+    #[test]
+    fn test_parse_grep_query_keeps_regex_alternation() {
+        let q = parse_grep_query(r"src/retrieval | unwrap\(\)|expect\(").unwrap();
+        assert_eq!(q.path, "src/retrieval");
+        assert!(q.pattern.is_match("x.expect(\"msg\")"));
+        assert!(q.pattern.is_match("x.unwrap()"));
+    }
+
+    #[test]
+    fn test_parse_grep_query_invalid() {
+        assert!(parse_grep_query("src/retrieval").is_err());
+        assert!(parse_grep_query("src | ").is_err());
+        assert!(parse_grep_query("src | (unclosed").is_err());
+    }
+}
@@ -1,5 +1,6 @@
 pub(crate) mod assay;
 pub(crate) mod exec;
+pub(crate) mod filesystem;
 pub(crate) mod parse;
 pub(crate) mod safety;
 pub(crate) mod temporal;
@@ -1,6 +1,7 @@
 //! Query safety validation — ensure verification queries cannot modify data.
 
 use super::assay;
+use super::filesystem;
 use super::temporal;
 use crate::commands::scrape::beliefs::verification::VerificationQuery;
 
@@ -9,6 +10,7 @@ use crate::commands::scrape::beliefs::verification::VerificationQuery;
 /// For SQL: must be SELECT-only (no INSERT, UPDATE, DELETE, DROP, ALTER, etc.)
 /// For assay: validates command, pattern, and aggregation through the DSL parser
 /// For temporal: validates derive-moments command and summary field
+/// For file/grep: validates paths stay inside the project root (and the grep regex compiles)
 pub fn validate_query_safety(query: &VerificationQuery) -> Result<(), String> {
     match query.query_type.as_str() {
         "sql" => validate_sql_safety(&query.query_text),
@@ -22,6 +24,14 @@ pub fn validate_query_safety(query: &VerificationQuery) -> Result<(), String> {
             temporal::parse_temporal_query(&query.query_text)?;
             Ok(())
         }
+        "file" => {
+            filesystem::parse_file_query(&query.query_text)?;
+            Ok(())
+        }
+        "grep" => {
+            filesystem::parse_grep_query(&query.query_text)?;
+            Ok(())
+        }
         other => Err(format!("unknown query type: '{}'", other)),
     }
 }
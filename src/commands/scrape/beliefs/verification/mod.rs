@@ -11,14 +11,15 @@ mod internal;
 
 use anyhow::Result;
 use rusqlite::Connection;
+use std::path::Path;
 
 /// A parsed verification query from a belief's `## Verification` section
 #[derive(Debug, Clone)]
 pub struct VerificationQuery {
-    pub query_type: String, // "sql", "assay", "temporal"
+    pub query_type: String, // "sql", "assay", "temporal", "file", "grep"
     pub label: String,
     pub expect: String,     // "= 0", "> 5", ">= 1", "< 10"
-    pub query_text: String, // SQL or assay command
+    pub query_text: String, // SQL, assay command, or filesystem check
 }
 
 /// Result of executing a single verification query
@@ -68,17 +69,20 @@ pub fn parse_verification_blocks(content: &str) -> Vec<VerificationQuery> {
 }
 
 /// Run all verification queries for a belief.
+///
+/// `project_root` anchors `file` and `grep` queries; DB queries ignore it.
 pub fn run_verification_queries(
     conn: &Connection,
     belief_id: &str,
     queries: &[VerificationQuery],
     data_freshness: &str,
+    project_root: &Path,
 ) -> (Vec<VerificationResult>, VerificationAggregates) {
     let mut results = Vec::new();
     let mut aggregates = VerificationAggregates::default();
 
     for query in queries {
-        let result = internal::exec::execute_verification_query(conn, query);
+        let result = internal::exec::execute_verification_query(conn, query, project_root);
 
         aggregates.total += 1;
         match result.status {
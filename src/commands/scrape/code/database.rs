@@ -24,6 +24,36 @@ use crate::commands::scrape::database as unified_db;
 // DOMAIN TYPES
 // ============================================================================
 
+/// Function facts, keyed by enclosing type so same-name methods coexist
+/// (`parent` is '' for free functions)
+const FUNCTION_FACTS_SCHEMA: &str = "CREATE TABLE IF NOT EXISTS function_facts (
+    file TEXT NOT NULL,
+    name TEXT NOT NULL,
+    takes_mut_self BOOLEAN DEFAULT FALSE,
+    takes_mut_params BOOLEAN DEFAULT FALSE,
+    returns_result BOOLEAN DEFAULT FALSE,
+    returns_option BOOLEAN DEFAULT FALSE,
+    is_async BOOLEAN DEFAULT FALSE,
+    is_unsafe BOOLEAN DEFAULT FALSE,
+    is_public BOOLEAN DEFAULT FALSE,
+    parameter_count INTEGER DEFAULT 0,
+    generic_count INTEGER DEFAULT 0,
+    parameters TEXT,  -- Comma-separated parameter names
+    return_type TEXT,
+    fingerprint TEXT,  -- Normalized structural hash for clone detection
+    line_count INTEGER DEFAULT 0,
+    parent TEXT NOT NULL DEFAULT '',  -- Enclosing impl/trait type for methods
+    is_const BOOLEAN DEFAULT FALSE,
+    is_generic BOOLEAN DEFAULT FALSE,  -- Has type/lifetime/const parameters
+    PRIMARY KEY (file, name, parent)
+)";
+
+/// Every `function_facts` column, in schema order
+const FUNCTION_FACTS_COLUMNS: &str =
+    "file, name, takes_mut_self, takes_mut_params, returns_result, \
+    returns_option, is_async, is_unsafe, is_public, parameter_count, generic_count, parameters, \
+    return_type, fingerprint, line_count, parent, is_const, is_generic";
+
 /// Symbol extracted from source code
 #[derive(Debug, Clone)]
 pub struct CodeSymbol {
@@ -45,11 +75,18 @@ pub struct FunctionFact {
     pub returns_option: bool,
     pub is_async: bool,
     pub is_unsafe: bool,
+    pub is_const: bool,
     pub is_public: bool,
     pub parameter_count: i32,
     pub generic_count: i32,
     pub parameters: Vec<String>, // Preserved as array!
     pub return_type: Option<String>,
+    /// Normalized body shape (see `fingerprint`); None when no AST is available
+    pub fingerprint: Option<String>,
+    /// Lines spanned by the definition (0 when unknown)
+    pub line_count: i32,
+    /// Type or trait a method is defined on; None for free functions
+    pub parent: Option<String>,
 }
 
 /// Type definition
@@ -130,25 +167,69 @@ impl Database {
         )?;
 
         // Function facts with proper boolean types
-        tx.execute(
-            "CREATE TABLE IF NOT EXISTS function_facts (
-                file TEXT NOT NULL,
-                name TEXT NOT NULL,
-                takes_mut_self BOOLEAN DEFAULT FALSE,
-                takes_mut_params BOOLEAN DEFAULT FALSE,
-                returns_result BOOLEAN DEFAULT FALSE,
-                returns_option BOOLEAN DEFAULT FALSE,
-                is_async BOOLEAN DEFAULT FALSE,
-                is_unsafe BOOLEAN DEFAULT FALSE,
-                is_public BOOLEAN DEFAULT FALSE,
-                parameter_count INTEGER DEFAULT 0,
-                generic_count INTEGER DEFAULT 0,
-                parameters TEXT,  -- Comma-separated parameter names
-                return_type TEXT,
-                PRIMARY KEY (file, name)
-            )",
-            [],
-        )?;
+        tx.execute(FUNCTION_FACTS_SCHEMA, [])?;
+
+        // Migration: clone-detection columns for databases scraped before them
+        let has_fingerprint_col: bool = tx
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('function_facts') WHERE name = 'fingerprint'",
+            )?
+            .exists([])?;
+        if !has_fingerprint_col {
+            tx.execute("ALTER TABLE function_facts ADD COLUMN fingerprint TEXT", [])?;
+            tx.execute(
+                "ALTER TABLE function_facts ADD COLUMN line_count INTEGER DEFAULT 0",
+                [],
+            )?;
+        }
+
+        // Migration: method parent for databases scraped before it
+        let has_parent_col: bool = tx
+            .prepare("SELECT 1 FROM pragma_table_info('function_facts') WHERE name = 'parent'")?
+            .exists([])?;
+        if !has_parent_col {
+            tx.execute("ALTER TABLE function_facts ADD COLUMN parent TEXT", [])?;
+        }
+
+        // Migration: modifier columns for databases scraped before them
+        let has_const_col: bool = tx
+            .prepare("SELECT 1 FROM pragma_table_info('function_facts') WHERE name = 'is_const'")?
+            .exists([])?;
+        if !has_const_col {
+            tx.execute(
+                "ALTER TABLE function_facts ADD COLUMN is_const BOOLEAN DEFAULT FALSE",
+                [],
+            )?;
+            tx.execute(
+                "ALTER TABLE function_facts ADD COLUMN is_generic BOOLEAN DEFAULT FALSE",
+                [],
+            )?;
+        }
+
+        // Migration: methods keyed by parent, so `Foo::new` and `Bar::new` in one
+        // file stop overwriting each other. The key can't be altered in place.
+        let parent_in_key: bool = tx
+            .prepare(
+                "SELECT 1 FROM pragma_table_info('function_facts') WHERE name = 'parent' AND pk > 0",
+            )?
+            .exists([])?;
+        if !parent_in_key {
+            tx.execute(
+                "ALTER TABLE function_facts RENAME TO function_facts_old",
+                [],
+            )?;
+            tx.execute(FUNCTION_FACTS_SCHEMA, [])?;
+            tx.execute(
+                &format!(
+                    "INSERT OR REPLACE INTO function_facts ({cols})
+                     SELECT {old} FROM function_facts_old",
+                    cols = FUNCTION_FACTS_COLUMNS,
+                    old = FUNCTION_FACTS_COLUMNS.replace("parent", "COALESCE(parent, '')"),
+                ),
+                [],
+            )?;
+            tx.execute("DROP TABLE function_facts_old", [])?;
+        }
 
         // Type vocabulary
         tx.execute(
@@ -335,11 +416,15 @@ impl Database {
                     "returns_option": func.returns_option,
                     "is_async": func.is_async,
                     "is_unsafe": func.is_unsafe,
+                    "is_const": func.is_const,
                     "is_public": func.is_public,
                     "parameter_count": func.parameter_count,
                     "generic_count": func.generic_count,
                     "parameters": &func.parameters,
                     "return_type": &func.return_type,
+                    "fingerprint": &func.fingerprint,
+                    "line_count": func.line_count,
+                    "parent": &func.parent,
                 });
 
                 unified_db::insert_event(
@@ -355,7 +440,11 @@ impl Database {
             // 2. Insert into materialized view (existing logic)
             let params_str = func.parameters.join(", ");
             tx.execute(
-                "INSERT OR REPLACE INTO function_facts VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                &format!(
+                    "INSERT OR REPLACE INTO function_facts ({})
+                     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+                    FUNCTION_FACTS_COLUMNS
+                ),
                 params![
                     &func.file,
                     &func.name,
@@ -370,6 +459,11 @@ impl Database {
                     func.generic_count,
                     &params_str,
                     &func.return_type,
+                    &func.fingerprint,
+                    func.line_count,
+                    func.parent.as_deref().unwrap_or(""),
+                    func.is_const,
+                    func.generic_count > 0,
                 ],
             )?;
         }
@@ -717,13 +811,89 @@ mod tests {
             returns_option: false,
             is_async: true,
             is_unsafe: false,
+            is_const: false,
             is_public: true,
             parameter_count: 2,
             generic_count: 1,
             parameters: vec!["data: &mut [u8]".to_string(), "opts: Options".to_string()],
             return_type: Some("Result<()>".to_string()),
+            fingerprint: Some("0123456789abcdef".to_string()),
+            line_count: 12,
+            parent: None,
         }];
         assert_eq!(db.insert_functions(&functions)?, 1);
+        let is_generic: bool = db.db.connection().query_row(
+            "SELECT is_generic FROM function_facts WHERE name = 'process'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert!(is_generic);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_same_name_methods_on_different_types_coexist() -> Result<()> {
+        let fact = |name: &str, parent: Option<&str>| FunctionFact {
+            file: "src/shapes.rs".to_string(),
+            name: name.to_string(),
+            takes_mut_self: false,
+            takes_mut_params: false,
+            returns_result: false,
+            returns_option: false,
+            is_async: false,
+            is_unsafe: false,
+            is_const: false,
+            is_public: true,
+            parameter_count: 0,
+            generic_count: 0,
+            parameters: vec![],
+            return_type: Some("Self".to_string()),
+            fingerprint: None,
+            line_count: 3,
+            parent: parent.map(str::to_string),
+        };
+
+        // A database scraped before the key included parent
+        let mut db = Database::open_in_memory()?;
+        db.db.connection().execute_batch(
+            "CREATE TABLE function_facts (
+                 file TEXT NOT NULL, name TEXT NOT NULL,
+                 takes_mut_self BOOLEAN, takes_mut_params BOOLEAN, returns_result BOOLEAN,
+                 returns_option BOOLEAN, is_async BOOLEAN, is_unsafe BOOLEAN, is_public BOOLEAN,
+                 parameter_count INTEGER, generic_count INTEGER, parameters TEXT, return_type TEXT,
+                 PRIMARY KEY (file, name));
+             INSERT INTO function_facts (file, name) VALUES ('src/old.rs', 'helper');",
+        )?;
+        // Facts only; the eventlog dual-write is covered above
+        db.skip_eventlog = true;
+        db.init_schema()?;
+
+        // impl Foo { fn new() } and impl Bar { fn new() }, scraped twice
+        let functions = vec![
+            fact("new", Some("Foo")),
+            fact("new", Some("Bar")),
+            fact("area", None),
+        ];
+        db.insert_functions(&functions)?;
+        db.insert_functions(&functions)?;
+
+        let conn = db.db.connection();
+        let rows: i64 = conn.query_row("SELECT COUNT(*) FROM function_facts", [], |r| r.get(0))?;
+        assert_eq!(rows, 4);
+        let old_parent: String = conn.query_row(
+            "SELECT parent FROM function_facts WHERE name = 'helper'",
+            [],
+            |r| r.get(0),
+        )?;
+        assert_eq!(old_parent, "");
+
+        // What `assay methods Bar` selects
+        let bar_methods: Vec<String> = conn
+            .prepare("SELECT name FROM function_facts WHERE parent = 'Bar'")?
+            .query_map([], |r| r.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        assert_eq!(bar_methods, vec!["new"]);
 
         Ok(())
     }
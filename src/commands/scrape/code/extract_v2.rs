@@ -10,29 +10,68 @@
 //! - Batch operations for performance
 
 use anyhow::Result;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use ignore::WalkBuilder;
+use rayon::prelude::*;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::time::SystemTime;
 
 use super::database::Database;
 use super::extracted_data::ExtractedData;
 use super::languages::Language;
 use super::types::FilePath;
+use crate::commands::scrape::progress::Progress;
+
+/// Project-specific ignore file, same glob syntax as `.gitignore`
+const PATINA_IGNORE_FILE: &str = ".patinaignore";
+
+/// Build output and dependency trees skipped even without an ignore file
+///
+/// Gitignore syntax. Generic names are anchored to the project root so
+/// `src/build/` stays indexed; tool-owned directories match at any depth.
+const DEFAULT_EXCLUDES: &[&str] = &[
+    ".git/",
+    "/target/",
+    "node_modules/",
+    "/vendor/",
+    "/dist/",
+    "/build/",
+    "__pycache__/",
+    ".venv/",
+    "*.generated.rs",
+];
+
+/// Supported source files under `work_dir`, skipping ignored paths
+///
+/// Honors `.gitignore` (in or out of a git checkout), `.patinaignore`, and
+/// `DEFAULT_EXCLUDES`. The defaults rank below both ignore files, so a
+/// negation there (`!build/`) re-includes a default exclude.
+fn find_source_files(work_dir: &Path) -> Result<Vec<(PathBuf, Language)>> {
+    let defaults = default_excludes(work_dir)?;
+    let includes = gitignore(work_dir, |builder| {
+        for name in [".gitignore", PATINA_IGNORE_FILE] {
+            let path = work_dir.join(name);
+            if path.is_file() {
+                if let Some(e) = builder.add(path) {
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    })?;
 
-/// Process all source files and extract metadata using safe database operations
-pub fn extract_code_metadata_v2(db_path: &str, work_dir: &Path, _force: bool) -> Result<usize> {
-    println!("🧠 Extracting code metadata with embedded SQLite...");
-
-    // Open database connection
-    let mut db = Database::open(db_path)?;
-    db.init_schema()?;
-
-    // Find all supported language files
-    let mut all_files: Vec<(PathBuf, Language)> = Vec::new();
-
+    let mut files = Vec::new();
     for entry in WalkBuilder::new(work_dir)
         .hidden(false)
         .git_ignore(true)
+        .require_git(false)
+        .add_custom_ignore_filename(PATINA_IGNORE_FILE)
+        .filter_entry(move |entry| {
+            let is_dir = entry.file_type().is_some_and(|t| t.is_dir());
+            !defaults.matched(entry.path(), is_dir).is_ignore()
+                || includes.matched(entry.path(), is_dir).is_whitelist()
+        })
         .build()
     {
         let entry = entry?;
@@ -41,17 +80,99 @@ pub fn extract_code_metadata_v2(db_path: &str, work_dir: &Path, _force: bool) ->
         if path.is_file() {
             let language = Language::from_path(path);
             if !matches!(language, Language::Unknown) {
-                all_files.push((path.to_path_buf(), language));
+                files.push((path.to_path_buf(), language));
             }
         }
     }
 
+    // Stable order so parallel runs produce identical output
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(files)
+}
+
+/// Matcher for `DEFAULT_EXCLUDES`, rooted at `work_dir`
+pub(crate) fn default_excludes(work_dir: &Path) -> Result<Gitignore> {
+    gitignore(work_dir, |builder| {
+        for glob in DEFAULT_EXCLUDES {
+            builder.add_line(None, glob)?;
+        }
+        Ok(())
+    })
+}
+
+/// Gitignore matcher rooted at `work_dir`, with rules added by `add`
+fn gitignore(
+    work_dir: &Path,
+    add: impl FnOnce(&mut GitignoreBuilder) -> std::result::Result<(), ignore::Error>,
+) -> Result<Gitignore> {
+    let mut builder = GitignoreBuilder::new(work_dir);
+    add(&mut builder)?;
+    Ok(builder.build()?)
+}
+
+/// Size limits beyond which a source file is skipped (`0` = no limit)
+#[derive(Debug, Clone, Copy)]
+pub struct FileLimits {
+    pub max_lines: usize,
+    pub max_bytes: u64,
+}
+
+impl From<&patina::project::ScrapeSection> for FileLimits {
+    fn from(section: &patina::project::ScrapeSection) -> Self {
+        Self {
+            max_lines: section.max_file_lines,
+            max_bytes: section.max_file_bytes,
+        }
+    }
+}
+
+impl Default for FileLimits {
+    fn default() -> Self {
+        Self::from(&patina::project::ScrapeSection::default())
+    }
+}
+
+impl FileLimits {
+    /// Whether a file of `lines` lines and `bytes` bytes is over either limit
+    fn exceeded_by(&self, lines: usize, bytes: u64) -> bool {
+        (self.max_lines > 0 && lines > self.max_lines)
+            || (self.max_bytes > 0 && bytes > self.max_bytes)
+    }
+}
+
+/// Process all source files and extract metadata using safe database operations
+pub fn extract_code_metadata_v2(
+    db_path: &str,
+    work_dir: &Path,
+    _force: bool,
+    limits: FileLimits,
+) -> Result<usize> {
+    println!("🧠 Extracting code metadata with embedded SQLite...");
+
+    // Open database connection
+    let mut db = Database::open(db_path)?;
+    db.init_schema()?;
+
+    // Find all supported language files
+    let all_files = find_source_files(work_dir)?;
+
     println!("  Found {} source files", all_files.len());
     if all_files.is_empty() {
         println!("  No source files found. Is this a code repository?");
         return Ok(0);
     }
 
+    // Parse files in parallel; each processor builds its own tree-sitter Parser
+    let progress = Mutex::new(Progress::new("files", all_files.len()));
+    let extractions = extract_files(&all_files, work_dir, limits, true, || {
+        if let Ok(mut p) = progress.lock() {
+            p.tick();
+        }
+    });
+    if let Ok(mut p) = progress.lock() {
+        p.finish();
+    }
+
     // Collect all extracted data in memory first
     let mut all_symbols = Vec::new();
     let mut all_functions = Vec::new();
@@ -62,56 +183,52 @@ pub fn extract_code_metadata_v2(db_path: &str, work_dir: &Path, _force: bool) ->
     let mut all_members = Vec::new();
 
     let mut files_with_errors = 0;
-    let mut _files_processed = 0;
-
-    // Process each file and collect data
-    for (file_path, language) in all_files {
-        let relative_path = if let Ok(stripped) = file_path.strip_prefix(work_dir) {
-            format!("./{}", stripped.to_string_lossy())
-        } else {
-            file_path.to_string_lossy().to_string()
-        };
+    let mut files_oversized = 0;
+
+    // Single writer: record index state and merge results in file order
+    for extraction in extractions {
+        let FileExtraction {
+            relative_path,
+            outcome,
+        } = extraction;
 
-        // Read file content
-        let content = match std::fs::read(&file_path) {
-            Ok(content) => content,
-            Err(e) => {
+        match outcome {
+            FileOutcome::ReadFailed(e) => {
                 eprintln!("  ⚠️  Failed to read {}: {}", relative_path, e);
                 files_with_errors += 1;
-                continue;
             }
-        };
-
-        // Get file metadata for index state
-        let mtime = std::fs::metadata(&file_path)
-            .and_then(|m| m.modified())
-            .unwrap_or(SystemTime::now())
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs() as i64;
-
-        let size = content.len() as i64;
-        let line_count = content.iter().filter(|&&b| b == b'\n').count() as i64;
-
-        // Update index state
-        db.update_index_state(&relative_path, mtime, size, None, Some(line_count))?;
-
-        // Process file based on language
-        match process_file_by_language(&relative_path, &content, language) {
-            Ok(extracted) => {
-                all_symbols.extend(extracted.symbols);
-                all_functions.extend(extracted.functions);
-                all_types.extend(extracted.types);
-                all_imports.extend(extracted.imports);
-                all_call_edges.extend(extracted.call_edges);
-                all_constants.extend(extracted.constants);
-                all_members.extend(extracted.members);
-                _files_processed += 1;
+            FileOutcome::Oversized { lines, bytes } => {
+                println!(
+                    "  Skipping oversized {} ({} lines, {} bytes)",
+                    relative_path, lines, bytes
+                );
+                files_oversized += 1;
             }
-            Err(e) => {
-                eprintln!("  ⚠️  Processing error in {}: {}", relative_path, e);
-                db.mark_skipped(&relative_path, &e.to_string())?;
-                files_with_errors += 1;
+            FileOutcome::Parsed {
+                mtime,
+                size,
+                line_count,
+                result,
+            } => {
+                // Update index state
+                db.update_index_state(&relative_path, mtime, size, None, Some(line_count))?;
+
+                match result {
+                    Ok(extracted) => {
+                        all_symbols.extend(extracted.symbols);
+                        all_functions.extend(extracted.functions);
+                        all_types.extend(extracted.types);
+                        all_imports.extend(extracted.imports);
+                        all_call_edges.extend(extracted.call_edges);
+                        all_constants.extend(extracted.constants);
+                        all_members.extend(extracted.members);
+                    }
+                    Err(e) => {
+                        eprintln!("  ⚠️  Processing error in {}: {}", relative_path, e);
+                        db.mark_skipped(&relative_path, &e)?;
+                        files_with_errors += 1;
+                    }
+                }
             }
         }
     }
@@ -132,6 +249,9 @@ pub fn extract_code_metadata_v2(db_path: &str, work_dir: &Path, _force: bool) ->
         symbols_count, functions_count, types_count, imports_count, edges_count, constants_count, members_count
     );
 
+    if files_oversized > 0 {
+        println!("  Skipped {} oversized files", files_oversized);
+    }
     if files_with_errors > 0 {
         println!(
             "  ⚠️  {} files had parsing errors and were skipped",
@@ -142,6 +262,111 @@ pub fn extract_code_metadata_v2(db_path: &str, work_dir: &Path, _force: bool) ->
     Ok(symbols_count + functions_count + types_count + imports_count)
 }
 
+/// What happened to one source file during extraction
+struct FileExtraction {
+    relative_path: String,
+    outcome: FileOutcome,
+}
+
+enum FileOutcome {
+    ReadFailed(String),
+    /// Over a `FileLimits` limit; not parsed
+    Oversized {
+        lines: usize,
+        bytes: u64,
+    },
+    Parsed {
+        mtime: i64,
+        size: i64,
+        line_count: i64,
+        result: std::result::Result<ExtractedData, String>,
+    },
+}
+
+/// Read and parse files, in parallel or sequentially, preserving input order
+///
+/// Parsing is the only parallel step - nothing here touches SQLite, so the
+/// caller stays the single writer.
+fn extract_files(
+    files: &[(PathBuf, Language)],
+    work_dir: &Path,
+    limits: FileLimits,
+    parallel: bool,
+    on_file: impl Fn() + Sync,
+) -> Vec<FileExtraction> {
+    let extract = |(file_path, language): &(PathBuf, Language)| {
+        let extraction = extract_file(file_path, *language, work_dir, limits);
+        on_file();
+        extraction
+    };
+
+    if parallel {
+        files.par_iter().map(extract).collect()
+    } else {
+        files.iter().map(extract).collect()
+    }
+}
+
+fn extract_file(
+    file_path: &Path,
+    language: Language,
+    work_dir: &Path,
+    limits: FileLimits,
+) -> FileExtraction {
+    let relative_path = if let Ok(stripped) = file_path.strip_prefix(work_dir) {
+        format!("./{}", stripped.to_string_lossy())
+    } else {
+        file_path.to_string_lossy().to_string()
+    };
+
+    // Read file content
+    let content = match std::fs::read(file_path) {
+        Ok(content) => content,
+        Err(e) => {
+            return FileExtraction {
+                relative_path,
+                outcome: FileOutcome::ReadFailed(e.to_string()),
+            }
+        }
+    };
+
+    let lines = content.iter().filter(|&&b| b == b'\n').count();
+    if limits.exceeded_by(lines, content.len() as u64) {
+        return FileExtraction {
+            relative_path,
+            outcome: FileOutcome::Oversized {
+                lines,
+                bytes: content.len() as u64,
+            },
+        };
+    }
+
+    // Get file metadata for index state
+    let mtime = std::fs::metadata(file_path)
+        .and_then(|m| m.modified())
+        .unwrap_or(SystemTime::now())
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64;
+
+    let size = content.len() as i64;
+    let line_count = lines as i64;
+
+    // Process file based on language
+    let result =
+        process_file_by_language(&relative_path, &content, language).map_err(|e| e.to_string());
+
+    FileExtraction {
+        relative_path,
+        outcome: FileOutcome::Parsed {
+            mtime,
+            size,
+            line_count,
+            result,
+        },
+    }
+}
+
 /// Process a single file based on its language
 fn process_file_by_language(
     file_path: &str,
@@ -188,6 +413,9 @@ fn process_file_by_language(
         Language::Solidity => {
             process_solidity_file(file_path, content, &mut data)?;
         }
+        Language::Markdown => {
+            process_markdown_file(file_path, content, &mut data);
+        }
         _ => {
             // Skip unknown languages
             return Err(anyhow::anyhow!("Unsupported language: {:?}", language));
@@ -352,6 +580,23 @@ fn process_cairo_file(file_path: &str, content: &[u8], data: &mut ExtractedData)
     }
 }
 
+/// Symbols from a markdown file's code fences, attributed to the markdown path
+///
+/// Doc examples are often fragments, so a fence that fails to parse is
+/// skipped rather than failing the whole file.
+fn process_markdown_file(file_path: &str, content: &[u8], data: &mut ExtractedData) {
+    use super::languages::markdown::code_fences;
+
+    let text = String::from_utf8_lossy(content);
+    for fence in code_fences(&text) {
+        if let Ok(extracted) =
+            process_file_by_language(file_path, fence.code.as_bytes(), fence.language)
+        {
+            data.merge(extracted);
+        }
+    }
+}
+
 fn process_solidity_file(file_path: &str, content: &[u8], data: &mut ExtractedData) -> Result<()> {
     use super::languages::solidity::SolidityProcessor;
 
@@ -370,3 +615,315 @@ fn process_solidity_file(file_path: &str, content: &[u8], data: &mut ExtractedDa
         Err(e) => Err(e),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn symbol_set(extractions: Vec<FileExtraction>) -> Vec<(String, String, String, usize)> {
+        let mut symbols: Vec<_> = extractions
+            .into_iter()
+            .filter_map(|e| match e.outcome {
+                FileOutcome::Parsed {
+                    result: Ok(data), ..
+                } => Some(data.symbols),
+                _ => None,
+            })
+            .flatten()
+            .map(|s| (s.path, s.name, s.kind, s.line))
+            .collect();
+        symbols.sort();
+        symbols
+    }
+
+    #[test]
+    fn test_parallel_extraction_matches_sequential() -> Result<()> {
+        let temp = TempDir::new()?;
+        let mut files = Vec::new();
+        for i in 0..8 {
+            let path = temp.path().join(format!("mod_{}.rs", i));
+            std::fs::write(
+                &path,
+                format!(
+                    "pub struct Item{i} {{ value: u32 }}\n\nimpl Item{i} {{\n    pub fn get_{i}(&self) -> u32 {{ helper_{i}(self.value) }}\n}}\n\nfn helper_{i}(v: u32) -> u32 {{ v + {i} }}\n"
+                ),
+            )?;
+            files.push((path, Language::Rust));
+        }
+        let py = temp.path().join("tool.py");
+        std::fs::write(
+            &py,
+            "def run(x):\n    return x * 2\n\nclass Runner:\n    pass\n",
+        )?;
+        files.push((py, Language::Python));
+
+        let limits = FileLimits::default();
+        let sequential = extract_files(&files, temp.path(), limits, false, || {});
+        let parallel = extract_files(&files, temp.path(), limits, true, || {});
+
+        // Same order of files either way
+        let order = |e: &[FileExtraction]| -> Vec<String> {
+            e.iter().map(|x| x.relative_path.clone()).collect()
+        };
+        assert_eq!(order(&sequential), order(&parallel));
+
+        let sequential = symbol_set(sequential);
+        assert!(!sequential.is_empty());
+        assert_eq!(sequential, symbol_set(parallel));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ignored_paths_are_not_indexed() -> Result<()> {
+        let temp = TempDir::new()?;
+        let root = temp.path().join("project");
+        let files = [
+            "src/lib.rs",
+            "src/generated/schema.rs",
+            "src/api.generated.rs",
+            "fixtures/big.py",
+            "fixtures/keep.py",
+            "target/debug/build/out.rs",
+            "node_modules/pkg/index.js",
+            "web/app.ts",
+            "dist/bundle.js",
+            "src/build/steps.rs",
+            "vendor/patched/lib.rs",
+        ];
+        for name in files {
+            let path = root.join(name);
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            let source = match path.extension().and_then(|e| e.to_str()) {
+                Some("py") => "def handler():\n    pass\n",
+                Some("rs") => "pub fn handler() {}\n",
+                _ => "function handler() {}\n",
+            };
+            std::fs::write(&path, source)?;
+        }
+        std::fs::write(root.join(".gitignore"), "src/generated/\n")?;
+        std::fs::write(
+            root.join(PATINA_IGNORE_FILE),
+            "fixtures/*\n!fixtures/keep.py\n!vendor/\n",
+        )?;
+
+        let found: Vec<String> = find_source_files(&root)?
+            .into_iter()
+            .map(|(path, _)| {
+                path.strip_prefix(&root)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        // Root-anchored defaults spare src/build/; a negation re-includes vendor/
+        assert_eq!(
+            found,
+            vec![
+                "fixtures/keep.py",
+                "src/build/steps.rs",
+                "src/lib.rs",
+                "vendor/patched/lib.rs",
+                "web/app.ts"
+            ]
+        );
+
+        // Ignored files never reach the database
+        let db_path = temp.path().join("patina.db");
+        crate::commands::scrape::database::initialize(&db_path)?;
+        extract_code_metadata_v2(
+            db_path.to_str().unwrap(),
+            &root,
+            true,
+            FileLimits::default(),
+        )?;
+        let conn = rusqlite::Connection::open(&db_path)?;
+        let indexed: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM eventlog WHERE source_id LIKE '%target/%'
+                OR source_id LIKE '%node_modules/%' OR source_id LIKE '%generated%'
+                OR source_id LIKE '%big.py%'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(indexed, 0);
+        let kept: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM eventlog WHERE source_id LIKE '%src/lib.rs%'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert!(kept > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_oversized_files_are_skipped() -> Result<()> {
+        let temp = TempDir::new()?;
+        let root = temp.path().join("project");
+        std::fs::create_dir_all(root.join("src"))?;
+        std::fs::write(root.join("src/lib.rs"), "pub fn small() {}\n")?;
+        let generated: String = (0..500)
+            .map(|i| format!("pub fn generated_{}() {{}}\n", i))
+            .collect();
+        std::fs::write(root.join("src/parser.rs"), &generated)?;
+
+        let limits = FileLimits {
+            max_lines: 100,
+            max_bytes: 0,
+        };
+        let oversized = |limits: FileLimits| -> Result<Vec<(String, usize, u64)>> {
+            let files = find_source_files(&root)?;
+            Ok(extract_files(&files, &root, limits, false, || {})
+                .into_iter()
+                .filter_map(|e| match e.outcome {
+                    FileOutcome::Oversized { lines, bytes } => {
+                        Some((e.relative_path, lines, bytes))
+                    }
+                    _ => None,
+                })
+                .collect())
+        };
+        let size = generated.len() as u64;
+        assert_eq!(
+            oversized(limits)?,
+            vec![("./src/parser.rs".to_string(), 500, size)]
+        );
+
+        // The byte limit applies on its own
+        let by_bytes = FileLimits {
+            max_lines: 0,
+            max_bytes: 64,
+        };
+        assert_eq!(
+            oversized(by_bytes)?,
+            vec![("./src/parser.rs".to_string(), 500, size)]
+        );
+
+        let db_path = temp.path().join("patina.db");
+        crate::commands::scrape::database::initialize(&db_path)?;
+        extract_code_metadata_v2(db_path.to_str().unwrap(), &root, true, limits)?;
+        let conn = rusqlite::Connection::open(&db_path)?;
+        let count = |pattern: &str| -> Result<i64> {
+            Ok(conn.query_row(
+                "SELECT COUNT(*) FROM eventlog WHERE source_id LIKE ?1",
+                [pattern],
+                |row| row.get(0),
+            )?)
+        };
+        assert_eq!(count("%parser.rs%")?, 0);
+        assert!(count("%lib.rs%")? > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_markdown_rust_fence_symbols_attributed_to_markdown() -> Result<()> {
+        let doc = "# Spawning\n\nExample:\n\n```rust,ignore\npub fn spawn_entity(world: &mut World) -> Entity {\n    world.spawn()\n}\n```\n\n```text\nfn not_code() {}\n```\n";
+        let data = process_file_by_language(
+            "./layer/core/spawning.md",
+            doc.as_bytes(),
+            Language::Markdown,
+        )?;
+
+        let function = data
+            .functions
+            .iter()
+            .find(|f| f.name == "spawn_entity")
+            .expect("function in rust fence extracted");
+        assert_eq!(function.file, "./layer/core/spawning.md");
+        assert!(data.functions.iter().all(|f| f.name != "not_code"));
+
+        let symbol = data
+            .symbols
+            .iter()
+            .find(|s| s.name == "spawn_entity")
+            .expect("symbol recorded");
+        assert_eq!(symbol.path, "./layer/core/spawning.md");
+        // Line numbers point into the markdown file
+        assert_eq!(symbol.line, 6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rust_function_modifiers_detected() -> Result<()> {
+        let source = "pub unsafe fn raw_read(ptr: *const u8) -> u8 { *ptr }\n\
+                      pub const fn kib(n: usize) -> usize { n * 1024 }\n\
+                      pub async fn fetch() {}\n\
+                      fn first<T: Clone>(items: &[T]) -> Option<T> { items.first().cloned() }\n";
+        let data = process_file_by_language("./src/lib.rs", source.as_bytes(), Language::Rust)?;
+        let function = |name: &str| {
+            data.functions
+                .iter()
+                .find(|f| f.name == name)
+                .expect("function extracted")
+        };
+
+        let raw_read = function("raw_read");
+        assert!(raw_read.is_unsafe && !raw_read.is_const);
+        let kib = function("kib");
+        assert!(kib.is_const && !kib.is_unsafe);
+        assert!(function("fetch").is_async);
+        let first = function("first");
+        assert_eq!(first.generic_count, 1);
+        assert!(!first.is_unsafe && !first.is_const && !first.is_async);
+
+        Ok(())
+    }
+
+    fn write_tree(root: &Path, reverse: bool) -> Result<()> {
+        let mut names: Vec<String> = (0..6)
+            .map(|i| format!("src/m{}/lib_{}.rs", i % 3, i))
+            .collect();
+        if reverse {
+            names.reverse();
+        }
+        for name in names {
+            let path = root.join(&name);
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            std::fs::write(
+                &path,
+                format!(
+                    "pub fn f_{}() {{}}\npub struct S{};\n",
+                    name.len(),
+                    name.len()
+                ),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn seq_mapping(db_path: &Path) -> Result<Vec<(i64, String)>> {
+        let conn = rusqlite::Connection::open(db_path)?;
+        let mut stmt = conn.prepare("SELECT seq, source_id FROM eventlog ORDER BY seq")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        Ok(rows.collect::<rusqlite::Result<_>>()?)
+    }
+
+    fn scrape_into(tree: &Path, db_path: &Path) -> Result<Vec<(i64, String)>> {
+        crate::commands::scrape::database::initialize(db_path)?;
+        extract_code_metadata_v2(db_path.to_str().unwrap(), tree, true, FileLimits::default())?;
+        seq_mapping(db_path)
+    }
+
+    #[test]
+    fn test_repeated_scrapes_assign_same_seq_to_source_ids() -> Result<()> {
+        let temp = TempDir::new()?;
+        let tree_a = temp.path().join("a");
+        let tree_b = temp.path().join("b");
+        // Same content, files created in opposite order (read_dir order differs)
+        write_tree(&tree_a, false)?;
+        write_tree(&tree_b, true)?;
+
+        let first = scrape_into(&tree_a, &temp.path().join("first.db"))?;
+        let second = scrape_into(&tree_a, &temp.path().join("second.db"))?;
+        let reordered = scrape_into(&tree_b, &temp.path().join("third.db"))?;
+
+        assert!(!first.is_empty());
+        assert_eq!(first, second);
+        assert_eq!(first, reordered);
+
+        Ok(())
+    }
+}
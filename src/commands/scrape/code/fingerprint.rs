@@ -0,0 +1,48 @@
+// ============================================================================
+// STRUCTURAL FINGERPRINTS
+// ============================================================================
+//! Normalized shape of a function body, for clone detection.
+//!
+//! The fingerprint hashes the sequence of syntax node kinds under a function's
+//! body. Identifier and literal text never enters it (only their kinds), and
+//! comments are skipped, so two functions that differ only in naming, constants,
+//! or comments share a fingerprint.
+
+use sha2::{Digest, Sha256};
+use tree_sitter::Node;
+
+/// Fingerprint of a function node's body (the whole node if it has no `body` field)
+pub fn structural_fingerprint(node: &Node) -> String {
+    let body = node.child_by_field_name("body").unwrap_or(*node);
+
+    let mut hasher = Sha256::new();
+    let mut cursor = body.walk();
+    loop {
+        let current = cursor.node();
+        let is_comment = current.kind().contains("comment");
+        if !is_comment {
+            hasher.update(current.kind().as_bytes());
+            hasher.update([0]);
+        }
+
+        // Pre-order walk; comments are skipped with their children
+        if !is_comment && cursor.goto_first_child() {
+            continue;
+        }
+        loop {
+            if cursor.node() == body {
+                let digest = hasher.finalize();
+                return digest[..8].iter().map(|b| format!("{:02x}", b)).collect();
+            }
+            if cursor.goto_next_sibling() {
+                break;
+            }
+            cursor.goto_parent();
+        }
+    }
+}
+
+/// Number of source lines a node spans
+pub fn line_count(node: &Node) -> i32 {
+    (node.end_position().row - node.start_position().row + 1) as i32
+}
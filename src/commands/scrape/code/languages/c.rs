@@ -18,6 +18,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath, SymbolKind};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -179,11 +180,15 @@ fn process_c_function(
         returns_option: false, // C uses NULL
         is_async: false,       // C doesn't have async
         is_unsafe: true,       // All C is unsafe
+        is_const: false,
         is_public,
         parameter_count: params.len() as i32,
         generic_count: 0, // C doesn't have generics
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     });
 }
 
@@ -15,6 +15,7 @@ use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFa
 use crate::commands::scrape::code::extracted_data::ExtractedData;
 use crate::commands::scrape::code::types::FilePath;
 use anyhow::Result;
+use patina_metal::cairo::EntryPoint;
 
 /// Cairo processor for extracting symbols without tree-sitter
 pub struct CairoProcessor;
@@ -33,6 +34,12 @@ impl CairoProcessor {
             } else {
                 format!("fn {}({})", func.name, func.parameters.join(", "))
             };
+            // Contract entry points are tagged like Solidity visibility
+            let signature = match func.entry_point {
+                Some(EntryPoint::External) => format!("external {}", signature),
+                Some(EntryPoint::View) => format!("view {}", signature),
+                None => signature,
+            };
 
             // Create FunctionFact struct
             let function_fact = FunctionFact {
@@ -44,11 +51,15 @@ impl CairoProcessor {
                 returns_option: func.return_type.as_deref().unwrap_or("").contains("Option"),
                 is_async: false,
                 is_unsafe: false,
-                is_public: func.is_public,
+                is_const: false,
+                is_public: func.is_public || func.entry_point.is_some(),
                 parameter_count: func.parameters.len() as i32,
                 generic_count: 0,
                 parameters: func.parameters.clone(),
                 return_type: func.return_type.clone(),
+                fingerprint: None, // cairo-lang-parser exposes no body AST
+                line_count: 0,
+                parent: None,
             };
             data.add_function(function_fact);
 
@@ -141,6 +152,55 @@ impl CairoProcessor {
             data.add_type(type_fact);
         }
 
+        // Extract Starknet contracts, mirroring Solidity's contract facts
+        for c in symbols.contracts {
+            data.add_symbol(CodeSymbol {
+                path: file_path.as_str().to_string(),
+                name: c.name.clone(),
+                kind: "contract".to_string(),
+                line: c.start_line,
+                context: format!("#[starknet::contract] mod {}", c.name),
+            });
+            data.add_type(TypeFact {
+                file: file_path.as_str().to_string(),
+                name: c.name.clone(),
+                definition: format!("mod {}", c.name),
+                kind: "contract".to_string(),
+                visibility: "public".to_string(),
+                usage_count: 0,
+            });
+        }
+
+        // Extract #[storage] members as state variables
+        for v in symbols.storage_vars {
+            data.add_symbol(CodeSymbol {
+                path: file_path.as_str().to_string(),
+                name: v.name.clone(),
+                kind: "state_variable".to_string(),
+                line: v.line,
+                context: format!("{}: {}", v.name, v.type_name),
+            });
+            data.add_type(TypeFact {
+                file: file_path.as_str().to_string(),
+                name: v.name,
+                definition: v.type_name,
+                kind: "state_variable".to_string(),
+                visibility: "private".to_string(),
+                usage_count: 0,
+            });
+        }
+
+        // Extract events as symbols (their shape is already a struct/enum type)
+        for e in symbols.events {
+            data.add_symbol(CodeSymbol {
+                path: file_path.as_str().to_string(),
+                name: format!("event {}", e.name),
+                kind: "event".to_string(),
+                line: e.start_line,
+                context: format!("#[event] {}", e.name),
+            });
+        }
+
         // Extract trait implementations as ConstantFacts
         // Following the same pattern as Rust: impl Trait for Type
         for impl_sym in symbols.impls {
@@ -19,6 +19,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath, SymbolKind};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -713,11 +714,15 @@ fn process_cpp_function_enhanced(
         returns_option: false,
         is_async: false,
         is_unsafe: false,
+        is_const: false,
         is_public: is_public_member(node, source),
         parameter_count: params.len() as i32,
         generic_count: count_template_params(node),
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     });
 }
 
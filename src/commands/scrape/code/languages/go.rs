@@ -17,6 +17,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath, SymbolKind};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -193,11 +194,15 @@ fn process_go_function(
         returns_option: false,   // Go uses nil
         is_async: false,         // Go uses goroutines
         is_unsafe: false,        // Go doesn't have unsafe keyword
+        is_const: false,
         is_public,
         parameter_count: params.len() as i32,
         generic_count: if _generics.is_some() { 1 } else { 0 },
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     });
 }
 
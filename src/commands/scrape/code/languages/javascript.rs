@@ -19,6 +19,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -220,11 +221,15 @@ fn process_function(
         returns_option: false,   // No Option type in JS
         is_async,
         is_unsafe: false, // No unsafe in JS
-        is_public: true,  // JS doesn't have visibility modifiers at function level
+        is_const: false,
+        is_public: true, // JS doesn't have visibility modifiers at function level
         parameter_count: params.len() as i32,
         generic_count: 0, // JS doesn't have generics
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     };
     data.add_function(function);
 
@@ -282,11 +287,15 @@ fn process_method(
         returns_option: false,
         is_async,
         is_unsafe: false,
+        is_const: false,
         is_public: true,
         parameter_count: params.len() as i32,
         generic_count: 0,
         parameters: params,
         return_type: None,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     };
     data.add_function(function);
 
@@ -0,0 +1,95 @@
+// ============================================================================
+// MARKDOWN CODE FENCES
+// ============================================================================
+//! Fenced code blocks inside markdown (patterns, docs, READMEs).
+//!
+//! Markdown itself has no symbols; each ``` / ~~~ fence whose info string
+//! names a supported language is handed to that language's processor under
+//! the markdown file's path, so example code in docs is searchable.
+//!
+//! Fence code is padded with the newlines that precede it, so the line
+//! numbers processors report point into the markdown file.
+
+use super::Language;
+
+/// One fenced block in a supported language
+pub struct CodeFence {
+    pub language: Language,
+    /// Block body, preceded by one newline per markdown line before it
+    pub code: String,
+}
+
+/// Fence opener: marker char and run length, plus the info-string language
+fn parse_opener(line: &str) -> Option<(char, usize, String)> {
+    let trimmed = line.trim_start_matches(' ');
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~')?;
+    let run = trimmed.chars().take_while(|c| *c == marker).count();
+    if run < 3 {
+        return None;
+    }
+    let info = &trimmed[run..];
+    // Backtick fences can't carry backticks in their info string
+    if marker == '`' && info.contains('`') {
+        return None;
+    }
+    // "rust,ignore" / "rust no_run" / "{.python}" all name the first word
+    let tag: String = info
+        .trim()
+        .trim_start_matches(['{', '.'])
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '+' || *c == '#')
+        .collect();
+    Some((marker, run, tag.to_lowercase()))
+}
+
+fn is_closer(line: &str, marker: char, run: usize) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= run && trimmed.chars().all(|c| c == marker)
+}
+
+/// Language named by a fence tag (`rust`, `py`, `c++`, ...)
+fn fence_language(tag: &str) -> Option<Language> {
+    let ext = match tag {
+        "rust" => "rs",
+        "python" | "python3" => "py",
+        "golang" => "go",
+        "javascript" | "node" => "js",
+        "typescript" => "ts",
+        "solidity" => "sol",
+        "c++" => "cpp",
+        other => other,
+    };
+    Language::from_extension(ext).filter(|lang| *lang != Language::Markdown)
+}
+
+/// Fenced blocks in `content` whose tag maps to a supported language
+///
+/// An unclosed fence runs to the end of the document, as in CommonMark.
+pub fn code_fences(content: &str) -> Vec<CodeFence> {
+    let mut fences = Vec::new();
+    let mut lines = content.lines().enumerate();
+
+    while let Some((index, line)) = lines.next() {
+        let Some((marker, run, tag)) = parse_opener(line) else {
+            continue;
+        };
+
+        let mut code = "\n".repeat(index + 1);
+        for (_, body) in lines.by_ref() {
+            if is_closer(body, marker, run) {
+                break;
+            }
+            code.push_str(body);
+            code.push('\n');
+        }
+
+        if let Some(language) = fence_language(&tag) {
+            fences.push(CodeFence { language, code });
+        }
+    }
+
+    fences
+}
@@ -14,6 +14,7 @@ pub mod cairo;
 pub mod cpp;
 pub mod go;
 pub mod javascript;
+pub mod markdown; // Code fences, dispatched to the languages above
 pub mod python;
 pub mod rust;
 pub mod solidity;
@@ -33,9 +34,10 @@ pub enum Language {
     TypeScript,
     TypeScriptTSX, // .tsx files
     Solidity,
-    Cairo, // Future
-    C,     // Future
-    Cpp,   // Future
+    Cairo,    // Future
+    C,        // Future
+    Cpp,      // Future
+    Markdown, // Fenced code blocks only
     Unknown,
 }
 
@@ -62,6 +64,7 @@ impl Language {
             "cairo" => Language::Cairo,
             "c" | "h" => Language::C,
             "cpp" | "cc" | "cxx" | "hpp" | "hxx" => Language::Cpp,
+            "md" | "markdown" => Language::Markdown,
             _ => return None,
         };
         Some(lang)
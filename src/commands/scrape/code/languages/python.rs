@@ -18,6 +18,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -224,11 +225,15 @@ fn process_function(
         returns_option,
         is_async,
         is_unsafe: false, // Python doesn't have unsafe
+        is_const: false,
         is_public,
         parameter_count: params.len() as i32,
         generic_count: 0, // Python doesn't have traditional generics
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     };
     data.add_function(function);
 
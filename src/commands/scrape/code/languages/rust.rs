@@ -10,6 +10,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath, SymbolKind};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -140,8 +141,9 @@ fn process_rust_function(
     let params = extract_params(node, source);
     let return_type = extract_return_type(node, source);
     let is_public = has_visibility_modifier(node);
-    let is_async = has_async(node);
-    let is_unsafe = has_unsafe(node);
+    let is_async = has_modifier(node, "async");
+    let is_unsafe = has_modifier(node, "unsafe");
+    let is_const = has_modifier(node, "const");
 
     // Check for specific patterns
     let takes_mut_self = params.iter().any(|p| p.contains("&mut self"));
@@ -167,11 +169,15 @@ fn process_rust_function(
         returns_option,
         is_async,
         is_unsafe,
+        is_const,
         is_public,
         parameter_count: params.len() as i32,
         generic_count,
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: enclosing_type(node, source),
     };
     data.add_function(function);
 
@@ -647,6 +653,19 @@ fn extract_rust_calls(
 
 // Helper functions (same as original but simplified)
 
+/// Type (for `impl`) or trait a function is declared directly inside, without generics
+fn enclosing_type(node: &Node, source: &[u8]) -> Option<String> {
+    let body = node.parent().filter(|p| p.kind() == "declaration_list")?;
+    let owner = body.parent()?;
+    let name_node = match owner.kind() {
+        "impl_item" => owner.child_by_field_name("type")?,
+        "trait_item" => owner.child_by_field_name("name")?,
+        _ => return None,
+    };
+    let name = name_node.utf8_text(source).ok()?;
+    Some(name.split('<').next().unwrap_or(name).trim().to_string())
+}
+
 fn has_visibility_modifier(node: &Node) -> bool {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
@@ -657,21 +676,20 @@ fn has_visibility_modifier(node: &Node) -> bool {
     false
 }
 
-fn has_async(node: &Node) -> bool {
+/// Whether a function carries `keyword` (`async`, `unsafe`, `const`)
+///
+/// The grammar nests these under a `function_modifiers` child.
+fn has_modifier(node: &Node, keyword: &str) -> bool {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
-        if child.kind() == "async" {
+        if child.kind() == keyword {
             return true;
         }
-    }
-    false
-}
-
-fn has_unsafe(node: &Node) -> bool {
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        if child.kind() == "unsafe" {
-            return true;
+        if child.kind() == "function_modifiers" {
+            let mut inner = child.walk();
+            if child.children(&mut inner).any(|m| m.kind() == keyword) {
+                return true;
+            }
         }
     }
     false
@@ -19,6 +19,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::ExtractedData;
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath, SymbolKind};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -231,11 +232,15 @@ fn process_solidity_function(
         returns_option: false,                     // Solidity doesn't have Option
         is_async: false,                           // Solidity doesn't have async
         is_unsafe,                                 // unchecked blocks
+        is_const: false,
         is_public,
         parameter_count: params.len() as i32,
         generic_count: 0, // Solidity doesn't have generics
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     });
 }
 
@@ -268,11 +273,15 @@ fn process_solidity_event(
         returns_option: false,
         is_async: false,
         is_unsafe: false,
+        is_const: false,
         is_public: true, // Events are always public
         parameter_count: params.len() as i32,
         generic_count: 0,
         parameters: params,
         return_type: None,
+        fingerprint: None, // Events have no body
+        line_count: fingerprint::line_count(node),
+        parent: None,
     });
 }
 
@@ -19,6 +19,7 @@
 
 use crate::commands::scrape::code::database::{CodeSymbol, FunctionFact, ImportFact, TypeFact};
 use crate::commands::scrape::code::extracted_data::{ConstantFact, ExtractedData, MemberFact};
+use crate::commands::scrape::code::fingerprint;
 use crate::commands::scrape::code::types::{CallGraphEntry, CallType, FilePath};
 use anyhow::{Context, Result};
 use tree_sitter::{Node, Parser};
@@ -270,11 +271,15 @@ fn process_function(
             .is_some_and(|rt| rt.contains("undefined") || rt.contains("null") || rt.contains("?")),
         is_async,
         is_unsafe: false, // No unsafe in TS
+        is_const: false,
         is_public,
         parameter_count: params.len() as i32,
         generic_count: count_generics(generics.as_deref()),
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     };
     data.add_function(function);
 
@@ -334,11 +339,15 @@ fn process_method(
             .is_some_and(|rt| rt.contains("undefined") || rt.contains("null") || rt.contains("?")),
         is_async,
         is_unsafe: false,
+        is_const: false,
         is_public: visibility,
         parameter_count: params.len() as i32,
         generic_count: count_generics(generics.as_deref()),
         parameters: params,
         return_type,
+        fingerprint: Some(fingerprint::structural_fingerprint(node)),
+        line_count: fingerprint::line_count(node),
+        parent: None,
     };
     data.add_function(function);
 
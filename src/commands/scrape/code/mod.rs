@@ -29,6 +29,7 @@ use super::ScrapeConfig;
 pub mod database;
 pub mod extract_v2;
 pub mod extracted_data;
+pub mod fingerprint;
 pub mod languages;
 pub mod types;
 
@@ -72,9 +73,18 @@ pub fn run(config: ScrapeConfig) -> Result<super::ScrapeStats> {
         initialize_database(&config.db_path)?;
     }
 
+    // Size limits: project config, then CLI overrides
+    let section = patina::project::load(&work_dir)
+        .map(|c| c.scrape)
+        .unwrap_or_default();
+    let limits = extract_v2::FileLimits {
+        max_lines: config.max_file_lines.unwrap_or(section.max_file_lines),
+        max_bytes: config.max_file_bytes.unwrap_or(section.max_file_bytes),
+    };
+
     // Always use the new embedded SQLite implementation
     let items_processed =
-        extract_v2::extract_code_metadata_v2(&config.db_path, &work_dir, config.force)?;
+        extract_v2::extract_code_metadata_v2(&config.db_path, &work_dir, config.force, limits)?;
 
     // Populate FTS5 index for lexical search
     println!("📝 Building FTS5 lexical index...");
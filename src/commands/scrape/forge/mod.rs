@@ -16,6 +16,7 @@ use std::time::Instant;
 
 use super::database;
 use super::ScrapeStats;
+use crate::commands::exit::Exit;
 use patina::forge::{self, ForgeKind, Issue, IssueState, PrState, PullRequest};
 
 /// Check if we already have this issue at this updated_at timestamp.
@@ -218,6 +219,14 @@ fn insert_prs(conn: &Connection, prs: &[PullRequest]) -> Result<InsertStats> {
             .collect::<Vec<_>>()
             .join("\n");
 
+        // Inline review threads carry the code-level reasoning
+        let review_comments_text: String = pr
+            .review_comments
+            .iter()
+            .map(|c| format!("{}: {}", c.author, c.body))
+            .collect::<Vec<_>>()
+            .join("\n");
+
         // Use created_at as updated_at proxy for PRs (API doesn't always provide updated_at)
         let updated_at = &pr.created_at;
 
@@ -237,6 +246,7 @@ fn insert_prs(conn: &Connection, prs: &[PullRequest]) -> Result<InsertStats> {
                 "url": &pr.url,
                 "linked_issues": &pr.linked_issues,
                 "comments": &comments_text,
+                "review_comments": &review_comments_text,
                 "approvals": pr.approvals,
                 "updated_at": updated_at,
             });
@@ -300,7 +310,7 @@ pub fn populate_fts5_prs(conn: &Connection) -> Result<usize> {
     // Clear existing forge.pr entries to avoid duplicates on re-run
     conn.execute("DELETE FROM code_fts WHERE event_type = 'forge.pr'", [])?;
 
-    // Include PR body and comments for rich search
+    // Include PR body, comments, and review threads for rich search
     let count = conn.execute(
         r#"
         INSERT INTO code_fts (symbol_name, file_path, content, event_type)
@@ -308,7 +318,8 @@ pub fn populate_fts5_prs(conn: &Connection) -> Result<usize> {
             json_extract(data, '$.title') as symbol_name,
             json_extract(data, '$.url') as file_path,
             COALESCE(json_extract(data, '$.body'), '') || ' ' ||
-            COALESCE(json_extract(data, '$.comments'), '') as content,
+            COALESCE(json_extract(data, '$.comments'), '') || ' ' ||
+            COALESCE(json_extract(data, '$.review_comments'), '') as content,
             'forge.pr' as event_type
         FROM eventlog
         WHERE event_type = 'forge.pr'
@@ -353,6 +364,9 @@ pub struct ForgeScrapeConfig {
     pub limit: usize,                 // max issues to fetch
     pub force: bool,                  // full rebuild vs incremental
     pub working_dir: Option<PathBuf>, // target directory (None = cwd)
+    pub labels: Vec<String>,          // only fetch issues with these labels (empty = all)
+    pub no_cache: bool,               // bypass .patina/cache/forge
+    pub require_auth: bool,           // error (exit 5) instead of skipping when unauthenticated
 }
 
 impl Default for ForgeScrapeConfig {
@@ -361,10 +375,73 @@ impl Default for ForgeScrapeConfig {
             limit: 50000,
             force: false,
             working_dir: None,
+            labels: Vec::new(),
+            no_cache: false,
+            require_auth: false,
         }
     }
 }
 
+/// Whether to go on with an unauthenticated GitHub scrape
+///
+/// Skipping is right inside bigger jobs (rebuild, repo add); an explicit
+/// `scrape forge` fails with `Exit::Auth` so scripts can tell.
+fn github_auth_gate(authenticated: bool, config: &ForgeScrapeConfig) -> Result<bool> {
+    if authenticated {
+        return Ok(true);
+    }
+    if config.require_auth {
+        return Err(anyhow::Error::new(Exit::Auth)
+            .context("GitHub detected but `gh` not authenticated. Run `gh auth login`."));
+    }
+    println!("  GitHub detected but `gh` not authenticated. Skipping forge data.");
+    println!("  Run `gh auth login` to enable issue/PR fetching.");
+    Ok(false)
+}
+
+/// Fetch and store issues changed since `since`, returning how many were inserted
+///
+/// Only an unfiltered scrape advances the watermark: a `--label` scrape sees
+/// a subset, and moving `since` past it would hide the unlabelled issues
+/// updated in between from the next incremental run.
+fn scrape_issues(
+    conn: &Connection,
+    reader: &dyn forge::ForgeReader,
+    since: Option<&str>,
+    config: &ForgeScrapeConfig,
+    expected: usize,
+) -> Result<usize> {
+    let issues = reader.list_issues(config.limit, since, &config.labels)?;
+    if issues.is_empty() {
+        println!("  No new issues to process");
+        return Ok(0);
+    }
+
+    println!("  Fetched {}/{} issues", issues.len(), expected);
+    let stats = insert_issues(conn, &issues)?;
+    if stats.skipped > 0 {
+        println!(
+            "  Inserted {} new events, {} unchanged (dedup)",
+            stats.inserted, stats.skipped
+        );
+    } else {
+        println!("  Inserted {} issues", stats.inserted);
+    }
+
+    // Update last scrape timestamp from issues
+    if config.labels.is_empty() {
+        if let Some(latest) = issues.iter().max_by_key(|i| &i.updated_at) {
+            update_last_scrape(conn, &latest.updated_at)?;
+        }
+    }
+
+    // Populate FTS5 index for issues
+    let issue_fts_count = populate_fts5_issues(conn)?;
+    println!("  Indexed {} issues in FTS5", issue_fts_count);
+
+    Ok(stats.inserted)
+}
+
 /// Main entry point for forge scraping.
 pub fn run(config: ForgeScrapeConfig) -> Result<ScrapeStats> {
     let start = Instant::now();
@@ -409,20 +486,17 @@ pub fn run(config: ForgeScrapeConfig) -> Result<ScrapeStats> {
             });
         }
         ForgeKind::Gitea => {
-            println!("  Gitea detected. Forge scraping not yet implemented.");
-            return Ok(ScrapeStats {
-                items_processed: 0,
-                time_elapsed: start.elapsed(),
-                database_size_kb: std::fs::metadata(db_path)
-                    .map(|m| m.len() / 1024)
-                    .unwrap_or(0),
-            });
+            // Public repos work anonymously; the token only unlocks private repos
+            if !forge::gitea::has_token() {
+                println!(
+                    "  Gitea detected, no {} set. Fetching public data only.",
+                    forge::gitea::TOKEN_ENV
+                );
+            }
         }
         ForgeKind::GitHub => {
             // Check authentication
-            if !forge::github::is_authenticated()? {
-                println!("  GitHub detected but `gh` not authenticated. Skipping forge data.");
-                println!("  Run `gh auth login` to enable issue/PR fetching.");
+            if !github_auth_gate(forge::github::is_authenticated()?, &config)? {
                 return Ok(ScrapeStats {
                     items_processed: 0,
                     time_elapsed: start.elapsed(),
@@ -448,7 +522,12 @@ pub fn run(config: ForgeScrapeConfig) -> Result<ScrapeStats> {
     let forge_name = format!("{}/{}", detected.owner, detected.repo);
 
     // Get reader for bulk fetches
-    let reader = forge::reader(&detected);
+    let reader = if config.no_cache {
+        forge::reader(&detected)
+    } else {
+        let root = config.working_dir.as_deref().unwrap_or(Path::new("."));
+        forge::cached_reader(&detected, root)
+    };
 
     // Query counts first for progress reporting
     let issue_count_expected = reader.get_issue_count().unwrap_or(0);
@@ -466,33 +545,13 @@ pub fn run(config: ForgeScrapeConfig) -> Result<ScrapeStats> {
         );
     }
 
-    // Bulk fetch issues
-    let issues = reader.list_issues(config.limit, since.as_deref())?;
-    let issue_count = if issues.is_empty() {
-        println!("  No new issues to process");
-        0
-    } else {
-        println!("  Fetched {}/{} issues", issues.len(), issue_count_expected);
-        let stats = insert_issues(&conn, &issues)?;
-        if stats.skipped > 0 {
-            println!(
-                "  Inserted {} new events, {} unchanged (dedup)",
-                stats.inserted, stats.skipped
-            );
-        } else {
-            println!("  Inserted {} issues", stats.inserted);
-        }
-
-        // Update last scrape timestamp from issues
-        if let Some(latest) = issues.iter().max_by_key(|i| &i.updated_at) {
-            update_last_scrape(&conn, &latest.updated_at)?;
-        }
-
-        // Populate FTS5 index for issues
-        let issue_fts_count = populate_fts5_issues(&conn)?;
-        println!("  Indexed {} issues in FTS5", issue_fts_count);
-        stats.inserted
-    };
+    let issue_count = scrape_issues(
+        &conn,
+        reader.as_ref(),
+        since.as_deref(),
+        &config,
+        issue_count_expected,
+    )?;
 
     // Bulk fetch PRs (same pattern as issues)
     let prs = reader.list_pull_requests(config.limit, since.as_deref())?;
@@ -549,3 +608,174 @@ pub fn run(config: ForgeScrapeConfig) -> Result<ScrapeStats> {
         database_size_kb: db_size,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use patina::forge::Comment;
+    use tempfile::TempDir;
+
+    fn comment(author: &str, body: &str) -> Comment {
+        Comment {
+            author: author.to_string(),
+            body: body.to_string(),
+            created_at: "2026-05-02T10:00:00Z".to_string(),
+        }
+    }
+
+    fn issue(number: i64, labels: &[&str]) -> Issue {
+        Issue {
+            number,
+            title: format!("Issue {}", number),
+            body: None,
+            state: IssueState::Open,
+            author: "alice".to_string(),
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            created_at: "2026-05-01T00:00:00Z".to_string(),
+            updated_at: "2026-05-01T00:00:00Z".to_string(),
+            url: format!("https://github.com/o/r/issues/{}", number),
+        }
+    }
+
+    /// Serves a fixed set of issues, honouring `since` and label filters
+    struct IssueReader(Vec<Issue>);
+
+    impl forge::ForgeReader for IssueReader {
+        fn get_issue_count(&self) -> Result<usize> {
+            Ok(self.0.len())
+        }
+        fn get_pr_count(&self) -> Result<usize> {
+            Ok(0)
+        }
+        fn list_issues(
+            &self,
+            _: usize,
+            since: Option<&str>,
+            labels: &[String],
+        ) -> Result<Vec<Issue>> {
+            Ok(self
+                .0
+                .iter()
+                .filter(|i| since.is_none_or(|s| i.updated_at.as_str() > s))
+                .filter(|i| labels.iter().all(|l| i.labels.contains(l)))
+                .cloned()
+                .collect())
+        }
+        fn list_pull_requests(&self, _: usize, _: Option<&str>) -> Result<Vec<PullRequest>> {
+            Ok(vec![])
+        }
+        fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+            anyhow::bail!("no PR {}", number)
+        }
+        fn get_issue(&self, number: i64) -> Result<Issue> {
+            anyhow::bail!("no issue {}", number)
+        }
+        fn get_max_issue_number(&self) -> Result<i64> {
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn test_label_scrape_keeps_unlabelled_issues_for_incremental_scrape() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = database::initialize(&temp.path().join("patina.db"))?;
+        create_materialized_views(&conn)?;
+
+        let mut unlabelled = issue(1, &[]);
+        unlabelled.updated_at = "2026-05-02T00:00:00Z".to_string();
+        let mut bug = issue(2, &["bug"]);
+        bug.updated_at = "2026-05-03T00:00:00Z".to_string();
+        let reader = IssueReader(vec![unlabelled, bug]);
+
+        let labelled = ForgeScrapeConfig {
+            labels: vec!["bug".to_string()],
+            ..Default::default()
+        };
+        let since = get_last_scrape(&conn)?;
+        assert_eq!(
+            scrape_issues(&conn, &reader, since.as_deref(), &labelled, 2)?,
+            1
+        );
+        assert_eq!(get_last_scrape(&conn)?, None);
+
+        // The next unfiltered incremental scrape still sees issue 1
+        let all = ForgeScrapeConfig::default();
+        let since = get_last_scrape(&conn)?;
+        scrape_issues(&conn, &reader, since.as_deref(), &all, 2)?;
+        let stored: Vec<i64> = conn
+            .prepare("SELECT number FROM forge_issues ORDER BY number")?
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+        assert_eq!(stored, vec![1, 2]);
+        assert_eq!(
+            get_last_scrape(&conn)?.as_deref(),
+            Some("2026-05-03T00:00:00Z")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_review_comments_indexed_in_fts_without_duplicates() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = database::initialize(&temp.path().join("patina.db"))?;
+        create_materialized_views(&conn)?;
+
+        let pr = PullRequest {
+            number: 7,
+            title: "Cache embeddings".to_string(),
+            body: Some("Adds an LRU".to_string()),
+            state: PrState::Merged,
+            author: "alice".to_string(),
+            labels: vec![],
+            created_at: "2026-05-01T00:00:00Z".to_string(),
+            merged_at: Some("2026-05-03T00:00:00Z".to_string()),
+            updated_at: None,
+            url: "https://github.com/o/r/pull/7".to_string(),
+            linked_issues: vec![],
+            comments: vec![comment("bob", "LGTM overall")],
+            review_comments: vec![
+                comment("carol", "Eviction races with the writer thread"),
+                comment("alice", "Guarded with a mutex now"),
+            ],
+            approvals: 1,
+        };
+
+        // Re-running the scrape must not duplicate FTS rows
+        for _ in 0..2 {
+            insert_prs(&conn, std::slice::from_ref(&pr))?;
+            populate_fts5_prs(&conn)?;
+        }
+
+        let contents: Vec<String> = conn
+            .prepare("SELECT content FROM code_fts WHERE event_type = 'forge.pr'")?
+            .query_map([], |row| row.get(0))?
+            .collect::<Result<_, _>>()?;
+        assert_eq!(contents.len(), 1);
+        assert!(contents[0].contains("Eviction races with the writer thread"));
+        assert!(contents[0].contains("LGTM overall"));
+
+        let hits: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM code_fts WHERE code_fts MATCH 'eviction'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(hits, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unauthenticated_explicit_scrape_exits_with_auth_code() {
+        let explicit = ForgeScrapeConfig {
+            require_auth: true,
+            ..Default::default()
+        };
+        let err = github_auth_gate(false, &explicit).unwrap_err();
+        assert_eq!(crate::commands::exit::exit_code(&err), 5);
+
+        // Inside rebuild / repo add the forge step is skipped, not fatal
+        assert!(!github_auth_gate(false, &ForgeScrapeConfig::default()).unwrap());
+        assert!(github_auth_gate(true, &explicit).unwrap());
+    }
+}
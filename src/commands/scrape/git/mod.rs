@@ -3,6 +3,7 @@
 //! Uses unified eventlog pattern:
 //! - Inserts git.commit events into eventlog table
 //! - Creates materialized views (commits, commit_files, co_changes) from eventlog
+//! - Records renames (file_renames) so co-changes follow a file to its current path
 //!
 //! Phase 1 of forge abstraction: includes conventional commit parsing to extract
 //! type, scope, PR references, and issue references from commit messages.
@@ -13,12 +14,13 @@ use anyhow::{Context, Result};
 use chrono;
 use rusqlite::Connection;
 use serde_json::json;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 use std::process::Command;
 use std::time::Instant;
 
 use super::database;
+use super::progress::Progress;
 use super::ScrapeStats;
 
 // ============================================================================
@@ -101,8 +103,12 @@ fn parse_session_tags() -> Result<Vec<SessionBounds>> {
         })
         .collect();
 
-    // Sort by start time
-    bounds.sort_by(|a, b| a.start_time.cmp(&b.start_time));
+    // Sort by start time (session id breaks ties deterministically)
+    bounds.sort_by(|a, b| {
+        a.start_time
+            .cmp(&b.start_time)
+            .then_with(|| a.session_id.cmp(&b.session_id))
+    });
 
     Ok(bounds)
 }
@@ -297,6 +303,8 @@ struct FileChange {
     change_type: String,
     lines_added: i32,
     lines_removed: i32,
+    /// Previous path when git detected a rename
+    renamed_from: Option<String>,
 }
 
 /// Create materialized views for git events
@@ -333,6 +341,15 @@ fn create_materialized_views(conn: &Connection) -> Result<()> {
             PRIMARY KEY (file_a, file_b)
         );
 
+        -- Renames detected in git log (old path -> new path)
+        CREATE TABLE IF NOT EXISTS file_renames (
+            sha TEXT,
+            old_path TEXT,
+            new_path TEXT,
+            timestamp TEXT,
+            PRIMARY KEY (sha, old_path)
+        );
+
         -- Git tracked files (from git ls-files)
         CREATE TABLE IF NOT EXISTS git_tracked_files (
             file_path TEXT PRIMARY KEY,
@@ -370,6 +387,7 @@ fn parse_git_log(since_sha: Option<&str>) -> Result<Vec<GitCommit>> {
         "--pretty=format:%H|%s|%an|%ae|%aI",
         "--numstat",
         "--no-merges",
+        "-M",
     ]);
 
     if let Some(sha) = since_sha {
@@ -389,6 +407,21 @@ fn parse_git_log(since_sha: Option<&str>) -> Result<Vec<GitCommit>> {
     parse_git_log_output(&stdout)
 }
 
+/// Split a numstat rename path into (old, new)
+///
+/// Git writes renames as `old => new`, or `dir/{old => new}/file` when the
+/// paths share a prefix or suffix (either side of the braces may be empty).
+fn parse_rename(path: &str) -> Option<(String, String)> {
+    if let (Some(open), Some(close)) = (path.find('{'), path.rfind('}')) {
+        let (old, new) = path[open + 1..close].split_once(" => ")?;
+        let (prefix, suffix) = (&path[..open], &path[close + 1..]);
+        let join = |middle: &str| format!("{}{}{}", prefix, middle, suffix).replace("//", "/");
+        return Some((join(old), join(new)));
+    }
+    let (old, new) = path.split_once(" => ")?;
+    Some((old.to_string(), new.to_string()))
+}
+
 /// Parse the git log output format
 fn parse_git_log_output(output: &str) -> Result<Vec<GitCommit>> {
     let mut commits = Vec::new();
@@ -425,10 +458,15 @@ fn parse_git_log_output(output: &str) -> Result<Vec<GitCommit>> {
             if stat_parts.len() >= 3 {
                 let lines_added = stat_parts[0].parse().unwrap_or(0);
                 let lines_removed = stat_parts[1].parse().unwrap_or(0);
-                let path = stat_parts[2].to_string();
+                let (path, renamed_from) = match parse_rename(stat_parts[2]) {
+                    Some((old, new)) => (new, Some(old)),
+                    None => (stat_parts[2].to_string(), None),
+                };
 
                 // Determine change type based on lines
-                let change_type = if lines_added > 0 && lines_removed == 0 {
+                let change_type = if renamed_from.is_some() {
+                    "renamed"
+                } else if lines_added > 0 && lines_removed == 0 {
                     "added"
                 } else if lines_added == 0 && lines_removed > 0 {
                     "deleted"
@@ -441,6 +479,7 @@ fn parse_git_log_output(output: &str) -> Result<Vec<GitCommit>> {
                     change_type: change_type.to_string(),
                     lines_added,
                     lines_removed,
+                    renamed_from,
                 });
             }
         }
@@ -476,7 +515,13 @@ fn insert_commits(conn: &Connection, commits: &[GitCommit], skip_eventlog: bool)
         "INSERT OR REPLACE INTO commit_files (sha, file_path, change_type, lines_added, lines_removed) VALUES (?1, ?2, ?3, ?4, ?5)",
     )?;
 
+    let mut rename_stmt = conn.prepare(
+        "INSERT OR REPLACE INTO file_renames (sha, old_path, new_path, timestamp) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+
+    let mut progress = Progress::new("commits", commits.len());
     for commit in commits {
+        progress.tick();
         // Parse conventional commit format (Phase 1 forge abstraction)
         let parsed = commits::parse_conventional(&commit.message);
 
@@ -487,12 +532,18 @@ fn insert_commits(conn: &Connection, commits: &[GitCommit], skip_eventlog: bool)
             "message": &commit.message,
             "author_name": &commit.author_name,
             "author_email": &commit.author_email,
-            "files": commit.files.iter().map(|f| json!({
-                "path": &f.path,
-                "change_type": &f.change_type,
-                "lines_added": f.lines_added,
-                "lines_removed": f.lines_removed,
-            })).collect::<Vec<_>>(),
+            "files": commit.files.iter().map(|f| {
+                let mut file = json!({
+                    "path": &f.path,
+                    "change_type": &f.change_type,
+                    "lines_added": f.lines_added,
+                    "lines_removed": f.lines_removed,
+                });
+                if let Some(ref old) = f.renamed_from {
+                    file["renamed_from"] = json!(old);
+                }
+                file
+            }).collect::<Vec<_>>(),
         });
 
         // Add parsed conventional commit fields if present
@@ -540,27 +591,67 @@ fn insert_commits(conn: &Connection, commits: &[GitCommit], skip_eventlog: bool)
                 file.lines_added,
                 file.lines_removed,
             ])?;
+            if let Some(ref old) = file.renamed_from {
+                rename_stmt.execute([&commit.sha, old, &file.path, &commit.timestamp])?;
+            }
         }
 
         count += 1;
     }
+    progress.finish();
 
     Ok(count)
 }
 
 /// Maximum files per commit to consider for co-change analysis
 /// Commits with more files are skipped (likely bulk operations, not meaningful co-changes)
-const MAX_FILES_PER_COMMIT: usize = 50;
+pub const MAX_FILES_PER_COMMIT: usize = 50;
+
+/// Map every path a file has had to the path it has now
+///
+/// Renames apply oldest first, so chains (`a -> b -> c`) land on the last
+/// name and a file renamed back (`a -> b -> a`) maps to where it ended up.
+pub fn current_paths(conn: &Connection) -> Result<HashMap<String, String>> {
+    let mut stmt = conn
+        .prepare("SELECT old_path, new_path FROM file_renames ORDER BY timestamp, sha, old_path")?;
+    let renames = stmt
+        .query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+
+    let mut current: HashMap<String, String> = HashMap::new();
+    for (old, new) in renames {
+        for target in current.values_mut() {
+            if *target == old {
+                target.clone_from(&new);
+            }
+        }
+        // `new` is a live path again, whatever it used to map to
+        current.remove(&new);
+        current.insert(old, new);
+    }
+    current.retain(|old, new| old != new);
+
+    Ok(current)
+}
 
 /// Rebuild co-change relationships from commit_files
+///
+/// Paths are coalesced through `current_paths`, so history from before a
+/// rename counts toward the file's current name.
 fn rebuild_co_changes(conn: &Connection) -> Result<usize> {
     // Clear existing co-changes
     conn.execute("DELETE FROM co_changes", [])?;
 
+    let renamed = current_paths(conn)?;
+
     // Build co-change map: for each commit, every pair of files changed together
-    let mut co_change_counts: HashMap<(String, String), i32> = HashMap::new();
+    // (BTreeMap so rows are inserted in path order on every machine)
+    let mut co_change_counts: BTreeMap<(String, String), i32> = BTreeMap::new();
 
-    let mut stmt = conn.prepare("SELECT sha, file_path FROM commit_files ORDER BY sha")?;
+    let mut stmt =
+        conn.prepare("SELECT sha, file_path FROM commit_files ORDER BY sha, file_path")?;
     let mut rows = stmt.query([])?;
 
     let mut current_sha: Option<String> = None;
@@ -592,7 +683,10 @@ fn rebuild_co_changes(conn: &Connection) -> Result<usize> {
             current_files.clear();
         }
 
-        current_files.push(file_path);
+        let file_path = renamed.get(&file_path).cloned().unwrap_or(file_path);
+        if !current_files.contains(&file_path) {
+            current_files.push(file_path);
+        }
     }
 
     // Process last commit (with same size limit)
@@ -885,4 +979,59 @@ mod tests {
             "Duration should exceed 24h limit"
         );
     }
+
+    #[test]
+    fn test_parse_rename_paths() {
+        assert_eq!(
+            parse_rename("src/{a.rs => b.rs}"),
+            Some(("src/a.rs".to_string(), "src/b.rs".to_string()))
+        );
+        assert_eq!(
+            parse_rename("src/{ => engine}/tick.rs"),
+            Some(("src/tick.rs".to_string(), "src/engine/tick.rs".to_string()))
+        );
+        assert_eq!(
+            parse_rename("old.rs => new.rs"),
+            Some(("old.rs".to_string(), "new.rs".to_string()))
+        );
+        assert_eq!(parse_rename("src/lib.rs"), None);
+    }
+
+    #[test]
+    fn test_renamed_file_keeps_its_co_changes() -> Result<()> {
+        // Newest first, as git log prints: a.rs changes with c.rs twice, is
+        // renamed to b.rs, then b.rs changes with c.rs once more
+        let log = "\
+3333333333333333333333333333333333333333|Tweak b|Ann|ann@example.com|2025-01-03T10:00:00+00:00
+1\t1\tb.rs
+1\t1\tc.rs
+
+2222222222222222222222222222222222222222|Rename a to b|Ann|ann@example.com|2025-01-02T10:00:00+00:00
+0\t0\ta.rs => b.rs
+
+1111111111111111111111111111111111111111|Touch a and c|Ann|ann@example.com|2025-01-01T11:00:00+00:00
+2\t0\ta.rs
+1\t0\tc.rs
+
+0000000000000000000000000000000000000000|Start|Ann|ann@example.com|2025-01-01T10:00:00+00:00
+5\t0\ta.rs
+5\t0\tc.rs";
+        let commits = parse_git_log_output(log)?;
+        assert_eq!(commits[1].files[0].path, "b.rs");
+        assert_eq!(commits[1].files[0].change_type, "renamed");
+        assert_eq!(commits[1].files[0].renamed_from.as_deref(), Some("a.rs"));
+
+        let conn = Connection::open_in_memory()?;
+        create_materialized_views(&conn)?;
+        insert_commits(&conn, &commits, true)?;
+        rebuild_co_changes(&conn)?;
+
+        let rows: Vec<(String, String, i64)> = conn
+            .prepare("SELECT file_a, file_b, count FROM co_changes ORDER BY file_a, file_b")?
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<rusqlite::Result<_>>()?;
+        assert_eq!(rows, vec![("b.rs".to_string(), "c.rs".to_string(), 3)]);
+
+        Ok(())
+    }
 }
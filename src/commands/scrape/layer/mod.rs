@@ -14,6 +14,7 @@ use std::path::Path;
 use std::time::Instant;
 
 use super::database;
+use super::progress::Progress;
 use super::ScrapeStats;
 
 const CORE_DIR: &str = "layer/core";
@@ -425,7 +426,9 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
     let mut skipped = 0;
     let mut current_file_ids: std::collections::HashSet<String> = std::collections::HashSet::new();
 
+    let mut progress = Progress::new("patterns", pattern_files.len());
     for path in &pattern_files {
+        progress.tick();
         match parse_pattern_file(path) {
             Ok(pattern) => {
                 // Track the frontmatter ID (not file stem) for pruning
@@ -449,6 +452,8 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
         }
     }
 
+    progress.finish();
+
     println!(
         "  Processed {} patterns ({} skipped)",
         processed_count, skipped
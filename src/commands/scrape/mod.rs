@@ -1,4 +1,10 @@
 // Shared utilities for all scrape subcommands
+//
+// Ordering: every scraper walks its inputs in path order (code files, session
+// files, layer docs) or git's own order (commits, tags, tracked files), and
+// derived tables are built from ordered maps. Two scrapes of the same tree
+// therefore assign the same eventlog `seq` to the same `source_id`, which keeps
+// the ID offsets baked into oxidize indices valid across `rebuild`.
 
 pub mod beliefs;
 pub mod code;
@@ -6,17 +12,28 @@ pub mod database;
 pub mod forge;
 pub mod git;
 pub mod layer;
+pub mod progress;
 pub mod sessions;
+pub mod status;
+pub mod watch;
 
 use anyhow::{bail, Result};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use patina::paths;
 
+pub use status::execute_status;
+pub use watch::execute_watch;
+
 /// Common configuration for all scrapers
+#[derive(Clone)]
 pub struct ScrapeConfig {
     pub db_path: String,
     pub force: bool,
+    /// Override `[scrape] max_file_lines` from the project config
+    pub max_file_lines: Option<usize>,
+    /// Override `[scrape] max_file_bytes` from the project config
+    pub max_file_bytes: Option<u64>,
 }
 
 impl ScrapeConfig {
@@ -24,6 +41,8 @@ impl ScrapeConfig {
         Self {
             db_path: database::PATINA_DB.to_string(),
             force,
+            max_file_lines: None,
+            max_file_bytes: None,
         }
     }
 }
@@ -143,7 +162,15 @@ pub fn execute_rebuild() -> Result<()> {
     if is_ref {
         println!("\n🔗 [6/6] Scraping forge (issues/PRs)...");
         // Use full=true to force complete re-fetch since we deleted the database
-        execute_forge(true, false, false, false, None, None)?;
+        execute_forge(
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+            ForgeFetchOptions::default(),
+        )?;
     } else {
         println!("\n📝 [6/6] Skipping forge (run 'patina scrape forge' separately)");
     }
@@ -175,8 +202,11 @@ pub fn execute_rebuild() -> Result<()> {
 ///
 /// For external repos, use `patina repo update <name>` instead.
 pub fn execute_code(init: bool, force: bool) -> Result<()> {
-    let config = ScrapeConfig::new(force);
+    execute_code_with(init, ScrapeConfig::new(force))
+}
 
+/// Execute code scraper with explicit config (CLI size-limit overrides)
+pub fn execute_code_with(init: bool, config: ScrapeConfig) -> Result<()> {
     if init {
         code::initialize(&config)?;
     } else {
@@ -223,6 +253,10 @@ pub fn execute_layer(full: bool) -> Result<()> {
 
 /// Resolve ref repo name to path.
 fn resolve_repo_path(name: &str) -> Result<PathBuf> {
+    // Registered path first (local repos live outside the cache)
+    if let Ok(path) = crate::commands::repo::get_path(name) {
+        return Ok(path);
+    }
     let repo_path = paths::repos::cache_dir().join(name);
     if !repo_path.exists() {
         bail!(
@@ -233,6 +267,17 @@ fn resolve_repo_path(name: &str) -> Result<PathBuf> {
     Ok(repo_path)
 }
 
+/// Fetch-side options for forge scraping.
+#[derive(Debug, Default)]
+pub struct ForgeFetchOptions {
+    /// Only fetch issues with these labels (empty = all)
+    pub labels: Vec<String>,
+    /// Bypass the on-disk forge cache
+    pub no_cache: bool,
+    /// Fail (exit 5) instead of skipping when the forge needs a login
+    pub require_auth: bool,
+}
+
 /// Execute forge scraper (issues and PRs from GitHub/Gitea)
 pub fn execute_forge(
     full: bool,
@@ -241,6 +286,7 @@ pub fn execute_forge(
     log: bool,
     limit: Option<usize>,
     repo: Option<String>,
+    fetch: ForgeFetchOptions,
 ) -> Result<()> {
     // Resolve working directory if --repo provided
     let working_dir = match &repo {
@@ -268,13 +314,16 @@ pub fn execute_forge(
 
     // Handle --limit: foreground sync with cap
     if let Some(limit_val) = limit {
-        return execute_forge_limited(working_dir.as_ref(), &repo_spec, limit_val);
+        return execute_forge_limited(working_dir.as_ref(), &repo_spec, limit_val, fetch.no_cache);
     }
 
     // Default: discovery only (instant)
     let config = forge::ForgeScrapeConfig {
         force: full,
         working_dir,
+        labels: fetch.labels,
+        no_cache: fetch.no_cache,
+        require_auth: fetch.require_auth,
         ..Default::default()
     };
     let stats = forge::run(config)?;
@@ -427,6 +476,7 @@ fn execute_forge_limited(
     working_dir: Option<&PathBuf>,
     repo_spec: &str,
     limit: usize,
+    no_cache: bool,
 ) -> Result<()> {
     use std::process::Command;
 
@@ -449,7 +499,12 @@ fn execute_forge_limited(
     println!("🔄 Syncing up to {} refs in foreground...", limit);
 
     let conn = database::initialize(&db_path)?;
-    let reader = patina::forge::reader(&detected);
+    let reader = if no_cache {
+        patina::forge::reader(&detected)
+    } else {
+        let root = working_dir.map(|d| d.as_path()).unwrap_or(Path::new("."));
+        patina::forge::cached_reader(&detected, root)
+    };
     let stats = patina::forge::sync::sync_limited(&conn, reader.as_ref(), repo_spec, limit)?;
 
     println!("\n📊 Forge Sync Summary:");
@@ -0,0 +1,141 @@
+//! Throttled single-line progress for long scrapes
+//!
+//! "Do X": Show files/commits processed, elapsed time, and a rough ETA while a
+//! scraper runs, so a big repo doesn't look hung.
+//!
+//! Updates go to stderr, at most once per interval, and only when stderr is a
+//! terminal. `patina scrape --quiet` turns them off entirely.
+
+use std::io::{IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Minimum time between progress updates
+const UPDATE_INTERVAL: Duration = Duration::from_millis(250);
+
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+/// Suppress progress output for the rest of the process (`--quiet`)
+pub fn set_quiet(quiet: bool) {
+    QUIET.store(quiet, Ordering::Relaxed);
+}
+
+/// Progress over a known number of items
+pub struct Progress {
+    label: &'static str,
+    total: usize,
+    done: usize,
+    start: Instant,
+    last_update: Instant,
+    interval: Duration,
+    enabled: bool,
+    shown: bool,
+}
+
+impl Progress {
+    /// Progress for `total` items, e.g. `Progress::new("files", 1200)`
+    pub fn new(label: &'static str, total: usize) -> Self {
+        let enabled = !QUIET.load(Ordering::Relaxed) && std::io::stderr().is_terminal();
+        Self::with_interval(label, total, UPDATE_INTERVAL, enabled)
+    }
+
+    fn with_interval(label: &'static str, total: usize, interval: Duration, enabled: bool) -> Self {
+        let start = Instant::now();
+        Self {
+            label,
+            total,
+            done: 0,
+            start,
+            last_update: start,
+            interval,
+            enabled,
+            shown: false,
+        }
+    }
+
+    /// Record one finished item
+    pub fn tick(&mut self) {
+        self.done += 1;
+        if !self.enabled {
+            return;
+        }
+        if let Some(line) = self.update_at(Instant::now()) {
+            let mut stderr = std::io::stderr();
+            let _ = write!(stderr, "\r  {}", line);
+            let _ = stderr.flush();
+        }
+    }
+
+    /// End the progress line (no-op if nothing was shown)
+    pub fn finish(&mut self) {
+        if self.shown {
+            eprintln!("\r  {}", self.line(Instant::now()));
+            self.shown = false;
+        }
+    }
+
+    /// Status line if an update is due at `now`
+    fn update_at(&mut self, now: Instant) -> Option<String> {
+        if now.duration_since(self.last_update) < self.interval {
+            return None;
+        }
+        self.last_update = now;
+        self.shown = true;
+        Some(self.line(now))
+    }
+
+    fn line(&self, now: Instant) -> String {
+        let elapsed = now.duration_since(self.start);
+        let eta = if self.done > 0 && self.done < self.total {
+            let per_item = elapsed.as_secs_f64() / self.done as f64;
+            let remaining = per_item * (self.total - self.done) as f64;
+            format!(", ~{}s left", remaining.ceil() as u64)
+        } else {
+            String::new()
+        };
+        format!(
+            "{}/{} {} ({}s elapsed{})   ",
+            self.done,
+            self.total,
+            self.label,
+            elapsed.as_secs(),
+            eta
+        )
+    }
+}
+
+impl Drop for Progress {
+    fn drop(&mut self) {
+        self.finish();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rapid_ticks_emit_at_most_once_per_interval() {
+        let interval = Duration::from_millis(100);
+        let mut progress = Progress::with_interval("files", 10_000, interval, true);
+        let start = progress.start;
+
+        // 1000 ticks spread over 350ms: updates due at ~100, ~200, ~300ms
+        let mut emitted = Vec::new();
+        for i in 0..1000u64 {
+            progress.done += 1;
+            let now = start + Duration::from_micros(i * 350);
+            if progress.update_at(now).is_some() {
+                emitted.push(now.duration_since(start));
+            }
+        }
+
+        assert_eq!(emitted.len(), 3);
+        for pair in emitted.windows(2) {
+            assert!(pair[1] - pair[0] >= interval);
+        }
+        assert!(progress
+            .line(start + Duration::from_secs(1))
+            .starts_with("1000/10000 files"));
+    }
+}
@@ -13,6 +13,7 @@ use std::path::Path;
 use std::time::Instant;
 
 use super::database;
+use super::progress::Progress;
 use super::ScrapeStats;
 
 const SESSIONS_DIR: &str = "layer/sessions";
@@ -500,7 +501,9 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
     let mut total_observations = 0;
     let mut skipped = 0;
 
+    let mut progress = Progress::new("sessions", session_files.len());
     for entry in &session_files {
+        progress.tick();
         let path = entry.path();
         let id = path
             .file_stem()
@@ -529,6 +532,8 @@ pub fn run(full: bool) -> Result<ScrapeStats> {
         }
     }
 
+    progress.finish();
+
     println!(
         "  Processed {} sessions ({} skipped)",
         processed_count, skipped
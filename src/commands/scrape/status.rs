@@ -0,0 +1,209 @@
+//! Scrape status - what is indexed right now
+//!
+//! "Do X": Summarize row counts and dimension indices without changing anything.
+//!
+//! Complements `patina doctor`, which checks health; this only reports what
+//! scrape and oxidize have produced so far.
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use serde::Serialize;
+use std::path::Path;
+use usearch::Index;
+
+use super::database;
+use crate::commands::oxidize::{self, index_meta};
+use patina::embeddings::id_offsets::KindCoverage;
+
+/// Tables reported, in display order
+const STATUS_TABLES: [&str; 8] = [
+    "function_facts",
+    "call_graph",
+    "co_changes",
+    "commits",
+    "sessions",
+    "patterns",
+    "beliefs",
+    "forge_issues",
+];
+
+/// Projections oxidize builds (one `.usearch` each)
+const DIMENSIONS: [&str; 3] = ["semantic", "temporal", "dependency"];
+
+/// Row count for one table; `rows` is `None` when the table doesn't exist yet
+#[derive(Debug, Serialize)]
+pub struct TableStatus {
+    pub table: &'static str,
+    pub rows: Option<i64>,
+}
+
+/// Presence and on-disk size of one dimension index
+#[derive(Debug, Serialize)]
+pub struct IndexStatus {
+    pub dimension: &'static str,
+    pub exists: bool,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScrapeStatus {
+    pub tables: Vec<TableStatus>,
+    pub indices: Vec<IndexStatus>,
+    /// Per-type share of source rows in the semantic index, when it loads
+    pub coverage: Option<Vec<KindCoverage>>,
+}
+
+/// Load the semantic index and compare its keys against the source tables
+fn semantic_coverage(conn: &Connection, index_path: &Path) -> Result<Vec<KindCoverage>> {
+    let index = Index::new(&index_meta::load_options(index_path))?;
+    index.load(index_path.to_str().context("Non-UTF-8 index path")?)?;
+    oxidize::semantic_coverage(conn, &index)
+}
+
+/// Collect counts from `conn` and index files under `projections_dir`
+pub fn collect_status(conn: &Connection, projections_dir: &Path) -> ScrapeStatus {
+    let tables = STATUS_TABLES
+        .iter()
+        .map(|&table| TableStatus {
+            table,
+            rows: conn
+                .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| {
+                    row.get(0)
+                })
+                .ok(),
+        })
+        .collect();
+
+    let indices = DIMENSIONS
+        .iter()
+        .map(|&dimension| {
+            let metadata =
+                std::fs::metadata(projections_dir.join(format!("{}.usearch", dimension))).ok();
+            IndexStatus {
+                dimension,
+                exists: metadata.is_some(),
+                size_bytes: metadata.map(|m| m.len()).unwrap_or(0),
+            }
+        })
+        .collect();
+
+    let semantic_path = projections_dir.join("semantic.usearch");
+    let coverage = semantic_path
+        .exists()
+        .then(|| semantic_coverage(conn, &semantic_path).ok())
+        .flatten();
+
+    ScrapeStatus {
+        tables,
+        indices,
+        coverage,
+    }
+}
+
+/// Execute `patina scrape status`
+pub fn execute_status(json: bool) -> Result<()> {
+    let db_path = Path::new(database::PATINA_DB);
+    if !db_path.exists() {
+        anyhow::bail!("No database found. Run `patina scrape` first.");
+    }
+    let conn = Connection::open_with_flags(db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+        .with_context(|| format!("Failed to open database: {}", db_path.display()))?;
+
+    let model = crate::commands::scry::internal::search::get_embedding_model();
+    let projections_dir = Path::new(".patina/local/data/embeddings")
+        .join(&model)
+        .join("projections");
+    let status = collect_status(&conn, &projections_dir);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+        return Ok(());
+    }
+
+    let db_size_kb = std::fs::metadata(db_path).map(|m| m.len() / 1024)?;
+    println!(
+        "📊 Scrape Status ({}, {} KB)\n",
+        db_path.display(),
+        db_size_kb
+    );
+    for t in &status.tables {
+        match t.rows {
+            Some(rows) => println!("  • {:<16} {}", t.table, rows),
+            None => println!("  • {:<16} (not scraped)", t.table),
+        }
+    }
+
+    println!("\n🧭 Indices ({})\n", model);
+    for index in &status.indices {
+        if index.exists {
+            println!(
+                "  • {:<16} {} KB",
+                index.dimension,
+                index.size_bytes.div_ceil(1024)
+            );
+        } else {
+            println!(
+                "  • {:<16} (missing - run `patina oxidize`)",
+                index.dimension
+            );
+        }
+    }
+
+    if let Some(coverage) = &status.coverage {
+        println!("\n🎯 Semantic coverage\n");
+        for c in coverage.iter().filter(|c| c.total > 0) {
+            println!(
+                "  • {:<16} {:>5.1}% ({}/{})",
+                c.kind,
+                c.percent(),
+                c.embedded,
+                c.total
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_status_reports_counts_and_indices() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (file TEXT, name TEXT);
+             INSERT INTO function_facts VALUES ('src/main.rs', 'main'), ('src/lib.rs', 'run');
+             CREATE TABLE beliefs (id TEXT PRIMARY KEY);
+             INSERT INTO beliefs VALUES ('sync-first');
+             CREATE TABLE co_changes (file_a TEXT, file_b TEXT, count INTEGER);",
+        )?;
+        std::fs::write(temp.path().join("semantic.usearch"), vec![0u8; 2048])?;
+
+        let status = collect_status(&conn, temp.path());
+        let rows = |table: &str| {
+            status
+                .tables
+                .iter()
+                .find(|t| t.table == table)
+                .and_then(|t| t.rows)
+        };
+        assert_eq!(rows("function_facts"), Some(2));
+        assert_eq!(rows("beliefs"), Some(1));
+        assert_eq!(rows("co_changes"), Some(0));
+        assert_eq!(rows("forge_issues"), None);
+
+        let semantic = &status.indices[0];
+        assert_eq!(semantic.dimension, "semantic");
+        assert!(semantic.exists);
+        assert_eq!(semantic.size_bytes, 2048);
+        assert!(status.indices[1..].iter().all(|i| !i.exists));
+        // A placeholder file isn't a loadable index
+        assert!(status.coverage.is_none());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,192 @@
+//! Scrape watch - re-run the incremental code scrape as files change
+//!
+//! "Do X": Keep the database warm during development without manual re-runs.
+//!
+//! File events are debounced: a burst of saves (formatters, `git checkout`,
+//! editor swap files) settles into one incremental scrape once the tree has
+//! been quiet for `DEBOUNCE`. The scrape itself only reprocesses files whose
+//! mtime changed. Ctrl-C stops the watcher between scrapes.
+
+use anyhow::{Context, Result};
+use ignore::gitignore::Gitignore;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::Duration;
+
+use super::ScrapeConfig;
+
+/// Quiet period after the last event before scraping
+pub const DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// How often an idle watcher checks for Ctrl-C
+const STOP_POLL: Duration = Duration::from_millis(200);
+
+/// Where the database lives; scraping writes here, so its changes are ignored
+const DATA_DIR: &str = ".patina";
+
+static STOP: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn sigint_handler(_: libc::c_int) {
+    STOP.store(true, Ordering::SeqCst);
+}
+
+/// Whether a changed path (relative to the watched root) should trigger a scrape
+///
+/// `excludes` is the scrape's own default exclude list, so paths it would
+/// never index don't wake the watcher either.
+fn is_relevant(path: &Path, excludes: &Gitignore) -> bool {
+    !path.starts_with(DATA_DIR)
+        && !excludes
+            .matched_path_or_any_parents(path, false)
+            .is_ignore()
+}
+
+/// Group changed paths into debounced batches and hand each to `on_batch`
+///
+/// Returns when `stop` is set or every sender is gone (flushing what is
+/// pending first), with the number of batches delivered.
+pub fn debounce_events(
+    events: &Receiver<PathBuf>,
+    excludes: &Gitignore,
+    debounce: Duration,
+    stop: &AtomicBool,
+    mut on_batch: impl FnMut(&BTreeSet<PathBuf>) -> Result<()>,
+) -> Result<usize> {
+    let mut pending = BTreeSet::new();
+    let mut batches = 0;
+
+    while !stop.load(Ordering::SeqCst) {
+        let timeout = if pending.is_empty() {
+            STOP_POLL
+        } else {
+            debounce
+        };
+        match events.recv_timeout(timeout) {
+            Ok(path) => {
+                if is_relevant(&path, excludes) {
+                    pending.insert(path);
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                if !pending.is_empty() {
+                    on_batch(&pending)?;
+                    batches += 1;
+                    pending.clear();
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                if !pending.is_empty() {
+                    on_batch(&pending)?;
+                    batches += 1;
+                }
+                break;
+            }
+        }
+    }
+
+    Ok(batches)
+}
+
+/// Scrape once with `config`, then re-scrape incrementally on every change
+pub fn execute_watch(config: ScrapeConfig) -> Result<()> {
+    unsafe {
+        libc::signal(
+            libc::SIGINT,
+            sigint_handler as *const () as libc::sighandler_t,
+        );
+    }
+
+    super::execute_code_with(false, config.clone())?;
+
+    let root = std::fs::canonicalize(".").context("Failed to resolve working directory")?;
+    let (tx, rx) = mpsc::channel();
+    let watch_root = root.clone();
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        let Ok(event) = res else { return };
+        if matches!(event.kind, EventKind::Access(_)) {
+            return;
+        }
+        for path in event.paths {
+            let relative = path.strip_prefix(&watch_root).unwrap_or(&path);
+            let _ = tx.send(relative.to_path_buf());
+        }
+    })
+    .context("Failed to start file watcher")?;
+    watcher
+        .watch(&root, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", root.display()))?;
+
+    println!("\n👀 Watching for changes (Ctrl-C to stop)...");
+    let incremental = ScrapeConfig {
+        force: false,
+        ..config
+    };
+    let excludes = super::code::extract_v2::default_excludes(&root)?;
+    let batches = debounce_events(&rx, &excludes, DEBOUNCE, &STOP, |changed| {
+        println!("\n🔄 {} path(s) changed, re-scraping...", changed.len());
+        // A failed pass (e.g. a half-written file) shouldn't end the watch
+        if let Err(e) = super::execute_code_with(false, incremental.clone()) {
+            eprintln!("⚠️  Scrape failed: {:#}", e);
+        }
+        Ok(())
+    })?;
+
+    println!("\n✋ Stopped watching after {} re-scrape(s)", batches);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_burst_of_changes_triggers_one_scrape() -> Result<()> {
+        let (tx, rx) = mpsc::channel();
+        let sender = thread::spawn(move || {
+            for path in [
+                "src/lib.rs",
+                "src/main.rs",
+                "src/lib.rs",
+                ".patina/local/data/patina.db",
+                "target/debug/patina",
+                "web/node_modules/left-pad/index.js",
+                "src/lib.rs",
+            ] {
+                tx.send(PathBuf::from(path)).unwrap();
+                thread::sleep(Duration::from_millis(10));
+            }
+            // Stay quiet long enough for the debounce to fire before hanging up
+            thread::sleep(Duration::from_millis(300));
+        });
+
+        let stop = AtomicBool::new(false);
+        let excludes = super::super::code::extract_v2::default_excludes(Path::new("/repo"))?;
+        let mut scrapes = Vec::new();
+        let batches = debounce_events(
+            &rx,
+            &excludes,
+            Duration::from_millis(50),
+            &stop,
+            |changed| {
+                scrapes.push(changed.clone());
+                Ok(())
+            },
+        )?;
+        sender.join().unwrap();
+
+        assert_eq!(batches, 1);
+        assert_eq!(
+            scrapes,
+            vec![BTreeSet::from([
+                PathBuf::from("src/lib.rs"),
+                PathBuf::from("src/main.rs"),
+            ])]
+        );
+
+        Ok(())
+    }
+}
@@ -9,6 +9,9 @@ use anyhow::Result;
 use rusqlite::Connection;
 
 use super::super::ScryResult;
+use patina::embeddings::id_offsets::{
+    BELIEF_ID_OFFSET, CODE_ID_OFFSET, COMMIT_ID_OFFSET, FORGE_ID_OFFSET, PATTERN_ID_OFFSET,
+};
 
 /// Search results from USearch
 pub struct SearchResults {
@@ -25,13 +28,6 @@ pub fn enrich_results(
 ) -> Result<Vec<ScryResult>> {
     let mut enriched = Vec::new();
 
-    // ID offsets to distinguish different content types in semantic index
-    const CODE_ID_OFFSET: i64 = 1_000_000_000;
-    const PATTERN_ID_OFFSET: i64 = 2_000_000_000;
-    const COMMIT_ID_OFFSET: i64 = 3_000_000_000;
-    const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
-    const FORGE_ID_OFFSET: i64 = 5_000_000_000;
-
     match dimension {
         "semantic" => {
             // Semantic index contains eventlog entries, code facts, and patterns
@@ -47,47 +43,8 @@ pub fn enrich_results(
 
                 // Check content type based on ID range (order matters: highest offset first)
                 if key >= FORGE_ID_OFFSET {
-                    // Forge event (issue or PR) — look up in eventlog
-                    let event_seq = key - FORGE_ID_OFFSET;
-                    let result = conn.query_row(
-                        "SELECT event_type, source_id, timestamp,
-                                json_extract(data, '$.title') as title,
-                                json_extract(data, '$.body') as body
-                         FROM eventlog
-                         WHERE seq = ?",
-                        [event_seq],
-                        |row| {
-                            let event_type: String = row.get(0)?;
-                            let source_id: String = row.get(1)?;
-                            let timestamp: String = row.get(2)?;
-                            let title: String =
-                                row.get::<_, Option<String>>(3)?.unwrap_or_default();
-                            let body: String = row.get::<_, Option<String>>(4)?.unwrap_or_default();
-
-                            let kind = if event_type == "forge.pr" {
-                                "PR"
-                            } else {
-                                "Issue"
-                            };
-                            let preview: String = body.chars().take(200).collect();
-                            let content = if preview.is_empty() {
-                                format!("{} #{}: {}", kind, source_id, title)
-                            } else {
-                                format!("{} #{}: {}\n{}", kind, source_id, title, preview)
-                            };
-
-                            Ok(ScryResult {
-                                id: key,
-                                event_type,
-                                source_id: format!("{}#{}", kind.to_lowercase(), source_id),
-                                timestamp,
-                                content,
-                                score,
-                            })
-                        },
-                    );
-
-                    if let Ok(r) = result {
+                    // Forge event (issue or PR) — eventlog seq → number → current view row
+                    if let Some(r) = enrich_forge_result(conn, key, key - FORGE_ID_OFFSET, score) {
                         enriched.push(r);
                     }
                 } else if key >= BELIEF_ID_OFFSET {
@@ -146,6 +103,8 @@ pub fn enrich_results(
                                 timestamp: String::new(),
                                 content,
                                 score,
+                                explain: None,
+                                cochange_count: None,
                             })
                         },
                     );
@@ -169,12 +128,17 @@ pub fn enrich_results(
                             let timestamp: String =
                                 row.get::<_, Option<String>>(3)?.unwrap_or_default();
 
-                            let content = if author.is_empty() {
+                            let mut content = if author.is_empty() {
                                 format!("{}: {}", &sha[..7.min(sha.len())], message)
                             } else {
                                 format!("{}: {} ({})", &sha[..7.min(sha.len())], message, author)
                             };
 
+                            // Inline the merged PR for "why did this change" context
+                            if let Some(pr) = linked_pr(conn, &message) {
+                                content.push_str(&format!("\n{}", pr));
+                            }
+
                             Ok(ScryResult {
                                 id: key,
                                 event_type: "git.commit".to_string(),
@@ -182,6 +146,8 @@ pub fn enrich_results(
                                 timestamp,
                                 content,
                                 score,
+                                explain: None,
+                                cochange_count: None,
                             })
                         },
                     );
@@ -218,6 +184,8 @@ pub fn enrich_results(
                                 timestamp: String::new(),
                                 content: format!("{} ({})", desc, file_path),
                                 score,
+                                explain: None,
+                                cochange_count: None,
                             })
                         },
                     );
@@ -268,6 +236,8 @@ pub fn enrich_results(
                                 timestamp: String::new(),
                                 content: desc,
                                 score,
+                                explain: None,
+                                cochange_count: None,
                             })
                         },
                     );
@@ -291,6 +261,8 @@ pub fn enrich_results(
                                 timestamp: row.get(3)?,
                                 content: row.get::<_, Option<String>>(4)?.unwrap_or_default(),
                                 score,
+                                explain: None,
+                                cochange_count: None,
                             })
                         },
                     );
@@ -337,6 +309,8 @@ pub fn enrich_results(
                         timestamp: String::new(),
                         content: format!("File: {} (temporal co-change relationship)", file_path),
                         score,
+                        explain: None,
+                        cochange_count: None,
                     });
                 }
             }
@@ -377,6 +351,8 @@ pub fn enrich_results(
                         timestamp: String::new(),
                         content: format!("Function: {} (dependency relationship)", func_name),
                         score,
+                        explain: None,
+                        cochange_count: None,
                     });
                 }
             }
@@ -396,6 +372,192 @@ pub fn enrich_results(
     Ok(enriched)
 }
 
+/// "PR #N: title" for the PR a commit message references, if it was synced
+///
+/// Uses the same `pr_ref` parsing as the git scraper, so it works for ref
+/// repos whose commits never reach the eventlog.
+fn linked_pr(conn: &Connection, message: &str) -> Option<String> {
+    use crate::commands::scrape::git::commits::parse_conventional;
+
+    let number = parse_conventional(message).pr_ref?;
+    let title: String = conn
+        .query_row(
+            "SELECT title FROM forge_prs WHERE number = ?",
+            [number],
+            |row| row.get(0),
+        )
+        .ok()?;
+    Some(format!("PR #{}: {}", number, title))
+}
+
+/// Resolve an embedded forge event to its issue/PR, tagged `[ISSUE]` or `[PR]`
+///
+/// The event gives the number; title/body/state come from `forge_issues` /
+/// `forge_prs` when present (latest sync wins), else from the event itself.
+fn enrich_forge_result(
+    conn: &Connection,
+    key: i64,
+    event_seq: i64,
+    score: f32,
+) -> Option<ScryResult> {
+    let (event_type, number, timestamp, event_title, event_body): (
+        String,
+        String,
+        String,
+        Option<String>,
+        Option<String>,
+    ) = conn
+        .query_row(
+            "SELECT event_type, source_id, timestamp,
+                    json_extract(data, '$.title'),
+                    json_extract(data, '$.body')
+             FROM eventlog
+             WHERE seq = ?",
+            [event_seq],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            },
+        )
+        .ok()?;
+
+    let (kind, table) = if event_type == "forge.pr" {
+        ("PR", "forge_prs")
+    } else {
+        ("Issue", "forge_issues")
+    };
+    let current: Option<(String, Option<String>, String)> = conn
+        .query_row(
+            &format!("SELECT title, body, state FROM {} WHERE number = ?", table),
+            [&number],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .ok();
+
+    let (title, body, state) = match current {
+        Some((title, body, state)) => (title, body.unwrap_or_default(), Some(state)),
+        None => (
+            event_title.unwrap_or_default(),
+            event_body.unwrap_or_default(),
+            None,
+        ),
+    };
+
+    let state_display = state.map(|s| format!(" [{}]", s)).unwrap_or_default();
+    let preview: String = body.chars().take(200).collect();
+    let content = if preview.is_empty() {
+        format!("{} #{}{}: {}", kind, number, state_display, title)
+    } else {
+        format!(
+            "{} #{}{}: {}\n{}",
+            kind, number, state_display, title, preview
+        )
+    };
+
+    Some(ScryResult {
+        id: key,
+        event_type,
+        source_id: format!("[{}] #{}", kind.to_uppercase(), number),
+        timestamp,
+        content,
+        score,
+        explain: None,
+        cochange_count: None,
+    })
+}
+
+/// Map a result ID back to its key in the semantic index
+///
+/// Accepts the forms results are displayed with: `path::name` or `path:name`
+/// (code), `belief:<id>` or a bare belief id, a pattern id, a commit SHA or
+/// prefix, and `#N` / `[ISSUE] #N` / `[PR] #N` (forge).
+pub fn semantic_key(conn: &Connection, doc_id: &str) -> Option<i64> {
+    let rowid = |sql: &str, params: &[&str]| -> Option<i64> {
+        conn.query_row(sql, rusqlite::params_from_iter(params), |row| row.get(0))
+            .ok()
+    };
+
+    // Forge items resolve to their latest event, which is what oxidize embeds
+    let (forge_kind, forge_ref) = if let Some(rest) = doc_id.strip_prefix("[PR] ") {
+        (Some("forge.pr"), rest)
+    } else if let Some(rest) = doc_id.strip_prefix("[ISSUE] ") {
+        (Some("forge.issue"), rest)
+    } else {
+        (None, doc_id)
+    };
+    if let Some(number) = forge_ref
+        .strip_prefix('#')
+        .filter(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+    {
+        let seq = match forge_kind {
+            Some(kind) => rowid(
+                "SELECT MAX(seq) FROM eventlog WHERE event_type = ?1 AND source_id = ?2",
+                &[kind, number],
+            ),
+            None => rowid(
+                "SELECT MAX(seq) FROM eventlog
+                 WHERE event_type IN ('forge.issue', 'forge.pr') AND source_id = ?1",
+                &[number],
+            ),
+        };
+        return seq.map(|seq| FORGE_ID_OFFSET + seq);
+    }
+
+    if let Some(id) = doc_id.strip_prefix("belief:") {
+        return rowid("SELECT rowid FROM beliefs WHERE id = ?1", &[id])
+            .map(|r| BELIEF_ID_OFFSET + r);
+    }
+
+    let code_parts = doc_id.rsplit_once("::").or_else(|| doc_id.rsplit_once(':'));
+    if let Some((file, name)) = code_parts {
+        if let Some(r) = rowid(
+            "SELECT rowid FROM function_facts WHERE file = ?1 AND name = ?2",
+            &[file, name],
+        ) {
+            return Some(CODE_ID_OFFSET + r);
+        }
+    }
+
+    rowid("SELECT rowid FROM beliefs WHERE id = ?1", &[doc_id])
+        .map(|r| BELIEF_ID_OFFSET + r)
+        .or_else(|| {
+            rowid("SELECT rowid FROM patterns WHERE id = ?1", &[doc_id])
+                .map(|r| PATTERN_ID_OFFSET + r)
+        })
+        .or_else(|| {
+            let is_sha = doc_id.len() >= 7 && doc_id.chars().all(|c| c.is_ascii_hexdigit());
+            is_sha
+                .then(|| {
+                    rowid(
+                        "SELECT rowid FROM commits WHERE sha LIKE ?1 || '%'",
+                        &[doc_id],
+                    )
+                })
+                .flatten()
+                .map(|r| COMMIT_ID_OFFSET + r)
+        })
+}
+
+/// Stored record behind a result ID, rendered the way semantic results are
+///
+/// The returned score is a placeholder (1.0); callers supply their own.
+pub fn lookup_stored(conn: &Connection, doc_id: &str) -> Option<ScryResult> {
+    let key = semantic_key(conn, doc_id)?;
+    let results = SearchResults {
+        keys: vec![key as u64],
+        distances: vec![0.0],
+    };
+    enrich_results(conn, &results, "semantic", 0.0)
+        .ok()?
+        .into_iter()
+        .next()
+}
+
 /// Truncate content for display
 pub fn truncate_content(content: &str, max_len: usize) -> String {
     let content = content.replace('\n', " ").trim().to_string();
@@ -470,6 +632,131 @@ pub fn find_belief_impact(results: &[ScryResult]) -> Result<HashMap<String, Vec<
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_embedded_issue_resolves_with_issue_tag() -> Result<()> {
+        use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+        let temp = tempfile::TempDir::new()?;
+        let conn = patina::eventlog::initialize(&temp.path().join("patina.db"))?;
+        let seq = patina::eventlog::insert_event(
+            &conn,
+            "forge.issue",
+            "2026-01-01T00:00:00Z",
+            "42",
+            None,
+            r#"{"title":"Scry hangs on huge repos","body":"old text"}"#,
+        )?;
+        conn.execute_batch(
+            "CREATE TABLE forge_issues (number INTEGER PRIMARY KEY, title TEXT, body TEXT, state TEXT);
+             INSERT INTO forge_issues VALUES (42, 'Scry hangs on huge repos', 'Search never returns on a monorepo', 'open');",
+        )?;
+
+        // Stand-in for oxidize: the issue vector sits near the "slow search" query
+        let index = Index::new(&IndexOptions {
+            dimensions: 3,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        })?;
+        index.reserve(2)?;
+        index.add((FORGE_ID_OFFSET + seq) as u64, &[0.9_f32, 0.1, 0.0])?;
+        index.add(1_000_000_001, &[0.0_f32, 0.0, 1.0])?;
+        let matches = index.search(&[1.0_f32, 0.0, 0.0], 1)?;
+
+        let results = SearchResults {
+            keys: matches.keys,
+            distances: matches.distances,
+        };
+        let enriched = enrich_results(&conn, &results, "semantic", 0.0)?;
+
+        assert_eq!(enriched.len(), 1);
+        assert_eq!(enriched[0].source_id, "[ISSUE] #42");
+        assert_eq!(enriched[0].event_type, "forge.issue");
+        assert!(enriched[0]
+            .content
+            .contains("Search never returns on a monorepo"));
+        assert!(enriched[0].content.contains("[open]"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_commit_result_includes_linked_pr_title() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE commits (sha TEXT PRIMARY KEY, message TEXT, author_name TEXT, timestamp TEXT);
+             INSERT INTO commits VALUES ('abc1234def', 'fix(scry): stop hanging on empty index (#87)', 'dev', '2026-01-01');
+             INSERT INTO commits VALUES ('fff0000aaa', 'chore: bump deps', 'dev', '2026-01-02');
+             CREATE TABLE forge_prs (number INTEGER PRIMARY KEY, title TEXT);
+             INSERT INTO forge_prs VALUES (87, 'Guard scry against empty usearch indices');",
+        )?;
+
+        let results = SearchResults {
+            keys: vec![(COMMIT_ID_OFFSET + 1) as u64, (COMMIT_ID_OFFSET + 2) as u64],
+            distances: vec![0.1, 0.2],
+        };
+        let enriched = enrich_results(&conn, &results, "semantic", 0.0)?;
+
+        assert_eq!(enriched.len(), 2);
+        assert!(enriched[0]
+            .content
+            .contains("PR #87: Guard scry against empty usearch indices"));
+        assert!(!enriched[1].content.contains("PR #"));
+
+        Ok(())
+    }
+
+    fn seeded_why_db() -> Result<Connection> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (file TEXT, name TEXT, parameters TEXT, return_type TEXT, is_public INTEGER, is_async INTEGER);
+             INSERT INTO function_facts VALUES ('src/main.rs', 'main', '', '', 0, 0);
+             INSERT INTO function_facts VALUES ('src/retrieval/engine.rs', 'query_local', 'query: &str', 'Result<Vec<FusedResult>>', 1, 0);
+             CREATE TABLE beliefs (id TEXT PRIMARY KEY, statement TEXT, entrenchment TEXT, file_path TEXT,
+                 cited_by_beliefs INTEGER, cited_by_sessions INTEGER, applied_in INTEGER,
+                 evidence_count INTEGER, evidence_verified INTEGER, defeated_attacks INTEGER);
+             INSERT INTO beliefs VALUES ('sync-first', 'Prefer synchronous code', 'high',
+                 'layer/surface/epistemic/beliefs/sync-first.md', 2, 3, 0, 1, 1, 0);",
+        )?;
+        Ok(conn)
+    }
+
+    #[test]
+    fn test_why_resolves_function_result() -> Result<()> {
+        let conn = seeded_why_db()?;
+
+        // Semantic results use `path::name`, lexical ones `path:name`
+        assert_eq!(
+            semantic_key(&conn, "src/retrieval/engine.rs::query_local"),
+            Some(CODE_ID_OFFSET + 2)
+        );
+        let stored = lookup_stored(&conn, "src/retrieval/engine.rs:query_local").unwrap();
+        assert_eq!(stored.event_type, "code.function");
+        assert_eq!(stored.source_id, "src/retrieval/engine.rs::query_local");
+        assert!(stored.content.contains("Function `query_local`"));
+        assert!(stored.content.contains("returns: Result<Vec<FusedResult>>"));
+
+        assert!(lookup_stored(&conn, "src/main.rs:missing").is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_why_resolves_belief_result() -> Result<()> {
+        let conn = seeded_why_db()?;
+
+        // Belief oracle uses `belief:<id>`, semantic results the bare id
+        let prefixed = lookup_stored(&conn, "belief:sync-first").unwrap();
+        let bare = lookup_stored(&conn, "sync-first").unwrap();
+        assert_eq!(prefixed.id, BELIEF_ID_OFFSET + 1);
+        assert_eq!(bare.id, prefixed.id);
+        assert_eq!(prefixed.event_type, "belief.surface");
+        assert!(prefixed.content.starts_with("Prefer synchronous code"));
+        assert!(prefixed.content.contains("use: 2+3"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_truncate_content() {
         assert_eq!(truncate_content("short", 10), "short");
@@ -0,0 +1,103 @@
+//! Typed failures from the scry core
+//!
+//! Core functions still return `anyhow::Result`; these ride inside the
+//! `anyhow::Error` so handlers can `downcast_ref::<ScryError>()` and pick an
+//! HTTP status or JSON-RPC code instead of matching message text. The CLI
+//! just prints them like any other error.
+
+use std::fmt;
+
+use crate::commands::exit::{self, Exit};
+
+/// Dimensions oxidize builds an index for
+pub const DIMENSIONS: [&str; 3] = ["semantic", "temporal", "dependency"];
+
+#[derive(Debug)]
+pub enum ScryError {
+    /// The dimension's index hasn't been built yet
+    IndexMissing { dimension: String, path: String },
+    /// The knowledge database couldn't be opened
+    DbOpen {
+        path: String,
+        source: rusqlite::Error,
+    },
+    /// The requested dimension isn't one oxidize builds
+    UnknownDimension(String),
+    /// The query text couldn't be embedded
+    EmbedFailed(anyhow::Error),
+}
+
+impl ScryError {
+    /// Status for the mother daemon's HTTP responses
+    pub fn http_status(&self) -> u16 {
+        match self {
+            ScryError::UnknownDimension(_) => 400,
+            ScryError::IndexMissing { .. } | ScryError::DbOpen { .. } => 503,
+            ScryError::EmbedFailed(_) => 500,
+        }
+    }
+
+    /// Process exit code (see `commands::exit`)
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ScryError::IndexMissing { .. } | ScryError::DbOpen { .. } => Exit::MissingIndex.code(),
+            ScryError::UnknownDimension(_) | ScryError::EmbedFailed(_) => exit::FAILURE,
+        }
+    }
+
+    /// Code for MCP JSON-RPC error responses (-32000..-32099 are server-defined)
+    pub fn json_rpc_code(&self) -> i32 {
+        match self {
+            ScryError::UnknownDimension(_) => -32602,
+            ScryError::IndexMissing { .. } => -32001,
+            ScryError::DbOpen { .. } => -32002,
+            ScryError::EmbedFailed(_) => -32003,
+        }
+    }
+}
+
+impl fmt::Display for ScryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScryError::IndexMissing { dimension, path } => write!(
+                f,
+                "{} index not found: {}. Run 'patina oxidize' first.",
+                dimension, path
+            ),
+            ScryError::DbOpen { path, source } => {
+                write!(f, "Failed to open database {}: {}", path, source)
+            }
+            ScryError::UnknownDimension(dimension) => write!(
+                f,
+                "Unknown dimension '{}'. Available: {}",
+                dimension,
+                DIMENSIONS.join(", ")
+            ),
+            ScryError::EmbedFailed(e) => write!(f, "Failed to embed query: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ScryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScryError::DbOpen { source, .. } => Some(source),
+            ScryError::EmbedFailed(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// JSON-RPC code for any error, falling back to internal error (-32603)
+pub fn json_rpc_code(error: &anyhow::Error) -> i32 {
+    error
+        .downcast_ref::<ScryError>()
+        .map_or(-32603, ScryError::json_rpc_code)
+}
+
+/// HTTP status for any error, falling back to 500
+pub fn http_status(error: &anyhow::Error) -> u16 {
+    error
+        .downcast_ref::<ScryError>()
+        .map_or(500, ScryError::http_status)
+}
@@ -5,11 +5,65 @@
 
 use anyhow::Result;
 
-use crate::retrieval::{QueryEngine, QueryOptions};
+use crate::commands::exit;
+use crate::retrieval::{FusedResult, QueryEngine, QueryOptions, RetrievalConfig};
 
 use super::super::{ScryOptions, ScryResult};
 use super::enrichment::truncate_content;
 use super::logging::log_scry_query;
+use super::path_filter;
+use super::query_prep::{is_too_generic, GENERIC_QUERY_HINT};
+
+/// QueryEngine for these options: model pulled if requested, oracles restricted
+fn hybrid_engine(options: &ScryOptions) -> Result<QueryEngine> {
+    // Pull a missing model up front so the embedding oracles can load it
+    if options.auto_pull {
+        patina::embeddings::ensure_model_available(true)?;
+    }
+
+    let mut engine = QueryEngine::in_project(&options.project_root, RetrievalConfig::default());
+    if let Some(oracle) = options.mode.oracle() {
+        engine.restrict_to(&[oracle.to_string()])?;
+    } else if !options.oracles.is_empty() {
+        engine.restrict_to(&options.oracles)?;
+    }
+    Ok(engine)
+}
+
+fn hybrid_query_options(options: &ScryOptions) -> QueryOptions {
+    QueryOptions {
+        repo: options.repo.clone(),
+        all_repos: options.all_repos,
+        include_issues: options.include_issues,
+        oracle_weights: options.oracle_weights.clone(),
+        rrf_k: options.rrf_k,
+    }
+}
+
+/// Hybrid RRF search without printing or logging
+pub fn search_hybrid(query: &str, options: &ScryOptions) -> Result<Vec<FusedResult>> {
+    let engine = hybrid_engine(options)?;
+    let query_opts = hybrid_query_options(options);
+    path_filter::scoped(
+        options,
+        |options| engine.query_with_options(query, options.limit, &query_opts),
+        |r| r.doc_id.as_str(),
+    )
+}
+
+/// Flatten a fused result into the common `ScryResult` shape
+pub fn fused_to_scry(result: &FusedResult) -> ScryResult {
+    ScryResult {
+        id: 0,
+        source_id: result.doc_id.clone(),
+        score: result.fused_score,
+        event_type: result.metadata.event_type.clone().unwrap_or_default(),
+        content: result.content.clone(),
+        timestamp: String::new(),
+        explain: None,
+        cochange_count: None,
+    }
+}
 
 /// Execute hybrid search using QueryEngine with RRF fusion
 pub fn execute_hybrid(query: Option<&str>, options: &ScryOptions) -> Result<()> {
@@ -17,39 +71,57 @@ pub fn execute_hybrid(query: Option<&str>, options: &ScryOptions) -> Result<()>
 
     println!("Mode: Hybrid (RRF fusion of all oracles)\n");
     println!("Query: \"{}\"\n", query);
+    if let Some(path) = &options.path {
+        println!("Path: {}\n", path);
+    }
+    if is_too_generic(query) {
+        println!("💡 {}; relying on semantic oracles\n", GENERIC_QUERY_HINT);
+    }
 
-    let engine = QueryEngine::new();
+    let engine = hybrid_engine(options)?;
 
-    // Show available oracles
-    let available = engine.available_oracles();
-    println!("Oracles: {}\n", available.join(", "));
+    // Show the oracles this query fuses
+    println!("Oracles: {}\n", engine.active_oracles().join(", "));
 
-    // Build query options
-    let query_opts = QueryOptions {
-        repo: options.repo.clone(),
-        all_repos: options.all_repos,
-        include_issues: options.include_issues,
-    };
+    let query_opts = hybrid_query_options(options);
 
-    let results = engine.query_with_options(query, options.limit, &query_opts)?;
+    if options.explain {
+        let weights = engine.fusion_weights(query, &query_opts);
+        let shown: Vec<String> = weights
+            .entries()
+            .iter()
+            .map(|(name, w)| format!("{}={:.2}", name, w))
+            .collect();
+        println!(
+            "Weights: {} (rrf_k={})\n",
+            shown.join(", "),
+            engine.rrf_k(&query_opts)
+        );
+    }
+
+    let started = std::time::Instant::now();
+    let results = path_filter::scoped(
+        options,
+        |options| engine.query_with_options(query, options.limit, &query_opts),
+        |r| r.doc_id.as_str(),
+    )?;
+    if options.timing {
+        for (oracle, timings) in engine.oracle_timings() {
+            eprintln!("⏱  {}: {}", oracle, timings.report());
+        }
+        eprintln!(
+            "⏱  query total: {:.1}ms",
+            started.elapsed().as_secs_f64() * 1000.0
+        );
+    }
 
     // Log query for feedback loop (Phase 3) - convert at boundary
-    let log_results: Vec<ScryResult> = results
-        .iter()
-        .map(|r| ScryResult {
-            id: 0,
-            source_id: r.doc_id.clone(),
-            score: r.fused_score,
-            event_type: r.metadata.event_type.clone().unwrap_or_default(),
-            content: r.content.clone(),
-            timestamp: String::new(),
-        })
-        .collect();
+    let log_results: Vec<ScryResult> = results.iter().map(fused_to_scry).collect();
     let query_id = log_scry_query(query, "hybrid", &log_results);
 
     if results.is_empty() {
         println!("No results found.");
-        return Ok(());
+        return exit::ensure_found(0);
     }
 
     println!("Found {} results:\n", results.len());
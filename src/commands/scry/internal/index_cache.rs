@@ -0,0 +1,181 @@
+//! Process-lifetime cache of loaded USearch indices
+//!
+//! One invocation can open the same index many times (every repo in
+//! `--all-repos`, each subcall of a composite search). Indices are kept in
+//! memory by path and reused. The key also covers the open options and the
+//! file's size and mtime, so another dimension, or an index rewritten by
+//! `patina oxidize` under a long-running daemon, loads afresh. A load drops
+//! whatever was cached for the same path, so rewrites don't pile up stale
+//! indices in memory.
+
+use anyhow::{Context, Result};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+use usearch::{Index, IndexOptions};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    path: PathBuf,
+    dimensions: usize,
+    metric: i32,
+    quantization: i32,
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl CacheKey {
+    fn new(path: &Path, options: &IndexOptions) -> Self {
+        let metadata = std::fs::metadata(path).ok();
+        Self {
+            path: path.to_path_buf(),
+            dimensions: options.dimensions,
+            metric: options.metric.repr,
+            quantization: options.quantization.repr,
+            len: metadata.as_ref().map_or(0, |m| m.len()),
+            modified: metadata.and_then(|m| m.modified().ok()),
+        }
+    }
+}
+
+/// Loaded indices by path and open options
+#[derive(Default)]
+pub struct IndexCache {
+    entries: Mutex<HashMap<CacheKey, Arc<Index>>>,
+}
+
+impl IndexCache {
+    /// The cached index for `path`, or `load` it (and keep it) on a miss
+    ///
+    /// A miss replaces any other entry for `path`: only the latest load of a
+    /// file stays cached.
+    pub fn get_or_load(
+        &self,
+        path: &Path,
+        options: &IndexOptions,
+        load: impl FnOnce(&Path, &IndexOptions) -> Result<Index>,
+    ) -> Result<Arc<Index>> {
+        let key = CacheKey::new(path, options);
+        if let Some(index) = self.entries.lock().ok().and_then(|e| e.get(&key).cloned()) {
+            return Ok(index);
+        }
+
+        // Load outside the lock; a racing thread at worst loads twice
+        let index = Arc::new(load(path, options)?);
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|cached, _| cached.path != key.path);
+            entries.insert(key, Arc::clone(&index));
+        }
+        Ok(index)
+    }
+}
+
+/// Read an index from disk
+fn load_from_disk(path: &Path, options: &IndexOptions) -> Result<Index> {
+    let index = Index::new(options).with_context(|| "Failed to create index")?;
+    let path_str = path.to_str().context("Non-UTF-8 index path")?;
+    index
+        .load(path_str)
+        .with_context(|| format!("Failed to load index: {}", path.display()))?;
+    Ok(index)
+}
+
+/// Load `path` with `options`, reusing this process's earlier load
+pub fn load(path: &Path, options: &IndexOptions) -> Result<Arc<Index>> {
+    static CACHE: OnceLock<IndexCache> = OnceLock::new();
+    CACHE
+        .get_or_init(IndexCache::default)
+        .get_or_load(path, options, load_from_disk)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use usearch::{MetricKind, ScalarKind};
+
+    fn write_index(path: &Path, dimensions: usize) -> Result<IndexOptions> {
+        let options = IndexOptions {
+            dimensions,
+            metric: MetricKind::Cos,
+            quantization: ScalarKind::F32,
+            ..Default::default()
+        };
+        let index = Index::new(&options)?;
+        index.reserve(1)?;
+        index.add(0, &vec![1.0_f32; dimensions])?;
+        index.save(path.to_str().unwrap())?;
+        Ok(options)
+    }
+
+    #[test]
+    fn test_each_index_file_loads_once() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let semantic = temp.path().join("semantic.usearch");
+        let temporal = temp.path().join("temporal.usearch");
+        let semantic_options = write_index(&semantic, 4)?;
+        let temporal_options = write_index(&temporal, 8)?;
+
+        let cache = IndexCache::default();
+        let loads = RefCell::new(Vec::new());
+        let counting = |path: &Path, options: &IndexOptions| {
+            loads.borrow_mut().push(path.to_path_buf());
+            load_from_disk(path, options)
+        };
+
+        // Two queries, each touching both dimensions
+        for _ in 0..2 {
+            let index = cache.get_or_load(&semantic, &semantic_options, counting)?;
+            assert_eq!(index.dimensions(), 4);
+            let index = cache.get_or_load(&temporal, &temporal_options, counting)?;
+            assert_eq!(index.dimensions(), 8);
+        }
+        assert_eq!(*loads.borrow(), vec![semantic.clone(), temporal.clone()]);
+
+        // Other open options for the same file are a separate entry
+        let wider = IndexOptions {
+            dimensions: 8,
+            ..semantic_options.clone()
+        };
+        cache.get_or_load(&semantic, &wider, counting).ok();
+        assert_eq!(loads.borrow().len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rewritten_index_replaces_stale_entry() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let semantic = temp.path().join("semantic.usearch");
+        let temporal = temp.path().join("temporal.usearch");
+        let options = write_index(&semantic, 4)?;
+        write_index(&temporal, 4)?;
+
+        let cache = IndexCache::default();
+        let stale = cache.get_or_load(&semantic, &options, load_from_disk)?;
+        cache.get_or_load(&temporal, &options, load_from_disk)?;
+
+        // Oxidize rewrites the semantic index with more vectors
+        let index = Index::new(&options)?;
+        index.reserve(2)?;
+        index.add(0, &[1.0_f32; 4])?;
+        index.add(1, &[0.5_f32; 4])?;
+        index.save(semantic.to_str().unwrap())?;
+
+        let fresh = cache.get_or_load(&semantic, &options, load_from_disk)?;
+        assert_eq!(fresh.size(), 2);
+
+        let entries = cache.entries.lock().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(
+            entries.keys().filter(|k| k.path == semantic).count(),
+            1,
+            "stale semantic entry should be evicted"
+        );
+        drop(entries);
+        assert_eq!(Arc::strong_count(&stale), 1);
+
+        Ok(())
+    }
+}
@@ -25,7 +25,7 @@ pub struct EdgeInfo {
 /// Routing context captured during graph-based routing
 #[derive(Debug, Clone, Default)]
 pub struct RoutingContext {
-    /// Routing strategy used ("graph" or "all")
+    /// Routing strategy used ("graph", "all", or "repos" for explicit `--repo a,b`)
     pub strategy: String,
     /// Source project for the query
     pub source_project: String,
@@ -81,15 +81,131 @@ pub fn get_active_session_id() -> Option<String> {
     None
 }
 
-/// Log a scry query to the eventlog for feedback loop analysis
+/// Recorded queries kept in scry_queries; older ones are pruned on write
+const MAX_RECORDED_QUERIES: usize = 1000;
+
+/// Create the scry_queries table if missing
 ///
-/// Best-effort logging - failures are silently ignored to not disrupt scry.
+/// Every executed query's ranked doc_ids land here so `scry open/copy/feedback`
+/// resolve without an active session (eventlog logging requires one).
+fn ensure_queries_table(conn: &Connection) -> Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS scry_queries (
+            query_id TEXT PRIMARY KEY,
+            query TEXT NOT NULL,
+            mode TEXT NOT NULL,
+            results TEXT NOT NULL,
+            created_at TEXT NOT NULL
+        )",
+    )?;
+    Ok(())
+}
+
+/// Store a query and its ranked (doc_id, score) results
+///
+/// Only the newest `MAX_RECORDED_QUERIES` are kept.
+pub fn record_query(
+    conn: &Connection,
+    query_id: &str,
+    query: &str,
+    mode: &str,
+    ranked: &[(String, f32)],
+) -> Result<()> {
+    ensure_queries_table(conn)?;
+    let results: Vec<serde_json::Value> = ranked
+        .iter()
+        .map(|(doc_id, score)| serde_json::json!({ "doc_id": doc_id, "score": score }))
+        .collect();
+    conn.execute(
+        "INSERT OR REPLACE INTO scry_queries (query_id, query, mode, results, created_at)
+         VALUES (?, ?, ?, ?, ?)",
+        rusqlite::params![
+            query_id,
+            query,
+            mode,
+            serde_json::Value::Array(results).to_string(),
+            chrono::Utc::now().to_rfc3339()
+        ],
+    )?;
+    prune_queries(conn, MAX_RECORDED_QUERIES)
+}
+
+/// Drop all but the `keep` most recently written queries
+fn prune_queries(conn: &Connection, keep: usize) -> Result<()> {
+    // INSERT OR REPLACE assigns a fresh rowid, so rowid order is write order
+    conn.execute(
+        "DELETE FROM scry_queries WHERE rowid NOT IN
+            (SELECT rowid FROM scry_queries ORDER BY rowid DESC LIMIT ?)",
+        [keep as i64],
+    )?;
+    Ok(())
+}
+
+/// Ranked results of a recorded query, in rank order (None if unknown)
+pub fn lookup_query(conn: &Connection, query_id: &str) -> Result<Option<Vec<(String, f32)>>> {
+    use rusqlite::OptionalExtension;
+
+    ensure_queries_table(conn)?;
+    let data: Option<String> = conn
+        .query_row(
+            "SELECT results FROM scry_queries WHERE query_id = ?",
+            [query_id],
+            |row| row.get(0),
+        )
+        .optional()?;
+    let Some(data) = data else {
+        return Ok(None);
+    };
+
+    let parsed: serde_json::Value = serde_json::from_str(&data)?;
+    Ok(Some(ranked_from_json(&parsed)))
+}
+
+/// (doc_id, score) pairs from a logged results array
+fn ranked_from_json(results: &serde_json::Value) -> Vec<(String, f32)> {
+    results
+        .as_array()
+        .map(|results| {
+            results
+                .iter()
+                .map(|r| {
+                    (
+                        r["doc_id"].as_str().unwrap_or("").to_string(),
+                        r["score"].as_f64().unwrap_or(0.0) as f32,
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Best-effort write to scry_queries; true if the query is now resolvable
+fn persist_query(query_id: &str, query: &str, mode: &str, ranked: &[(String, f32)]) -> bool {
+    Connection::open(eventlog::PATINA_DB)
+        .map_err(anyhow::Error::from)
+        .and_then(|conn| record_query(&conn, query_id, query, mode, ranked))
+        .is_ok()
+}
+
+/// Log a scry query for open/copy/feedback and feedback loop analysis
+///
+/// The ranked results always go to scry_queries; the eventlog entry is only
+/// written during an active session. Best-effort - failures are silently
+/// ignored to not disrupt scry.
 /// Returns the query_id for reference by open/copy/feedback commands.
 pub fn log_scry_query(query: &str, mode: &str, results: &[ScryResult]) -> Option<String> {
-    let session_id = get_active_session_id()?;
-
     let query_id = generate_query_id();
 
+    let ranked: Vec<(String, f32)> = results
+        .iter()
+        .map(|r| (r.source_id.clone(), r.score))
+        .collect();
+    let persisted = persist_query(&query_id, query, mode, &ranked);
+
+    let Some(session_id) = get_active_session_id() else {
+        return persisted.then(|| remember_last_query(&query_id));
+    };
+
     // Build results array for logging
     let results_json: Vec<serde_json::Value> = results
         .iter()
@@ -127,15 +243,15 @@ pub fn log_scry_query(query: &str, mode: &str, results: &[ScryResult]) -> Option
         Ok(())
     })();
 
-    if insert_result.is_ok() {
-        // Store as last query for open/copy/feedback without explicit query_id
-        if let Ok(mut last) = LAST_QUERY_ID.lock() {
-            *last = Some(query_id.clone());
-        }
-        Some(query_id)
-    } else {
-        None
+    (persisted || insert_result.is_ok()).then(|| remember_last_query(&query_id))
+}
+
+/// Store as last query for open/copy/feedback without explicit query_id
+fn remember_last_query(query_id: &str) -> String {
+    if let Ok(mut last) = LAST_QUERY_ID.lock() {
+        *last = Some(query_id.to_string());
     }
+    query_id.to_string()
 }
 
 /// Result with source repo for routing-aware logging
@@ -155,10 +271,18 @@ pub fn log_scry_query_with_routing(
     results: &[RoutedResult],
     routing: &RoutingContext,
 ) -> Option<String> {
-    let session_id = get_active_session_id()?;
-
     let query_id = generate_query_id();
 
+    let ranked: Vec<(String, f32)> = results
+        .iter()
+        .map(|r| (r.result.source_id.clone(), r.result.score))
+        .collect();
+    let persisted = persist_query(&query_id, query, "graph", &ranked);
+
+    let Some(session_id) = get_active_session_id() else {
+        return persisted.then(|| remember_last_query(&query_id));
+    };
+
     // Build results array with source repo info
     let results_json: Vec<serde_json::Value> = results
         .iter()
@@ -221,15 +345,7 @@ pub fn log_scry_query_with_routing(
         Ok(())
     })();
 
-    if insert_result.is_ok() {
-        // Store as last query for open/copy/feedback without explicit query_id
-        if let Ok(mut last) = LAST_QUERY_ID.lock() {
-            *last = Some(query_id.clone());
-        }
-        Some(query_id)
-    } else {
-        None
-    }
+    (persisted || insert_result.is_ok()).then(|| remember_last_query(&query_id))
 }
 
 /// Log usage of a scry result (scry.use event)
@@ -337,8 +453,14 @@ pub fn log_scry_feedback(query_id: &str, signal: &str, comment: Option<&str>) {
 }
 
 /// Get results from a previous query by query_id
+///
+/// Checks scry_queries first, then falls back to the eventlog for queries
+/// logged before scry_queries existed (and MCP queries).
 pub fn get_query_results(query_id: &str) -> Result<Vec<(String, f32)>> {
     let conn = Connection::open(eventlog::PATINA_DB)?;
+    if let Some(ranked) = lookup_query(&conn, query_id)? {
+        return Ok(ranked);
+    }
 
     let data: String = conn.query_row(
         "SELECT data FROM eventlog WHERE event_type = 'scry.query' AND source_id = ?",
@@ -347,17 +469,73 @@ pub fn get_query_results(query_id: &str) -> Result<Vec<(String, f32)>> {
     )?;
 
     let parsed: serde_json::Value = serde_json::from_str(&data)?;
-    let results = parsed["results"]
-        .as_array()
-        .ok_or_else(|| anyhow::anyhow!("No results in query"))?;
+    if !parsed["results"].is_array() {
+        anyhow::bail!("No results in query");
+    }
 
-    Ok(results
-        .iter()
-        .map(|r| {
-            (
-                r["doc_id"].as_str().unwrap_or("").to_string(),
-                r["score"].as_f64().unwrap_or(0.0) as f32,
-            )
-        })
-        .collect())
+    Ok(ranked_from_json(&parsed["results"]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(source_id: &str, score: f32) -> ScryResult {
+        ScryResult {
+            id: 0,
+            content: String::new(),
+            score,
+            event_type: "code.function".to_string(),
+            source_id: source_id.to_string(),
+            timestamp: String::new(),
+            explain: None,
+            cochange_count: None,
+        }
+    }
+
+    #[test]
+    fn test_recorded_query_resolves_rank() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        let results = vec![
+            result("src/retrieval/fusion.rs::rrf_fuse", 0.9),
+            result("src/retrieval/engine.rs::query", 0.7),
+        ];
+        let ranked: Vec<(String, f32)> = results
+            .iter()
+            .map(|r| (r.source_id.clone(), r.score))
+            .collect();
+
+        let query_id = generate_query_id();
+        record_query(&conn, &query_id, "rrf fusion", "hybrid", &ranked)?;
+
+        let resolved = lookup_query(&conn, &query_id)?.expect("query recorded");
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(resolved[0].0, "src/retrieval/fusion.rs::rrf_fuse");
+        assert!((resolved[0].1 - 0.9).abs() < 1e-6);
+        assert_eq!(resolved[1].0, "src/retrieval/engine.rs::query");
+
+        assert!(lookup_query(&conn, "q_missing")?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recorded_queries_are_capped() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        for i in 0..5 {
+            record_query(&conn, &format!("q_{}", i), "rrf", "hybrid", &[])?;
+        }
+        // Re-recording an old query makes it the newest
+        record_query(&conn, "q_0", "rrf", "hybrid", &[])?;
+
+        prune_queries(&conn, 3)?;
+        let kept: Vec<String> = conn
+            .prepare("SELECT query_id FROM scry_queries ORDER BY rowid")?
+            .query_map([], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()?;
+        assert_eq!(kept, vec!["q_3", "q_4", "q_0"]);
+        assert!(lookup_query(&conn, "q_1")?.is_none());
+
+        Ok(())
+    }
 }
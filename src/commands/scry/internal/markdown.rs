@@ -0,0 +1,128 @@
+//! Markdown rendering of scry results (`--format markdown`)
+//!
+//! "Do X": Turn results into a list that pastes cleanly into a PR or issue.
+//!
+//! Each result is a bullet with its source (linked when it names a file),
+//! event type and score, followed by its content in a fenced block nested
+//! under the bullet.
+
+use super::super::ScryResult;
+
+/// Snippet length in lines unless `--full` is given
+const SNIPPET_LINES: usize = 12;
+
+/// File a source id points at, for code results (`src/foo.rs::bar` → `src/foo.rs`)
+fn source_file(result: &ScryResult) -> Option<&str> {
+    if !result.event_type.starts_with("code.") {
+        return None;
+    }
+    let path = result
+        .source_id
+        .split_once("::")
+        .map_or(result.source_id.as_str(), |(path, _)| path);
+    Some(path.trim_start_matches("./"))
+}
+
+/// Fence longer than any backtick run in `content`, so snippets can't close it early
+fn fence_for(content: &str) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat(longest.max(2) + 1)
+}
+
+/// Render `results` for `query` as a markdown list
+pub fn render_markdown(query: Option<&str>, results: &[ScryResult], full: bool) -> String {
+    let mut out = match query {
+        Some(query) => format!("### Scry results for `{}`\n\n", query),
+        None => "### Scry results\n\n".to_string(),
+    };
+
+    if results.is_empty() {
+        out.push_str("_No results found._\n");
+        return out;
+    }
+
+    for result in results {
+        let source = match source_file(result) {
+            Some(path) => format!("[`{}`]({})", result.source_id, path),
+            None => format!("`{}`", result.source_id),
+        };
+        out.push_str(&format!(
+            "- {} ({}, score {:.3})\n\n",
+            source, result.event_type, result.score
+        ));
+
+        let lines: Vec<&str> = result.content.trim_end().lines().collect();
+        let shown = if full {
+            lines.len()
+        } else {
+            lines.len().min(SNIPPET_LINES)
+        };
+        let language = source_file(result)
+            .and_then(|path| path.rsplit_once('.'))
+            .map_or("", |(_, ext)| ext);
+        let fence = fence_for(&result.content);
+
+        out.push_str(&format!("  {}{}\n", fence, language));
+        for line in &lines[..shown] {
+            if line.is_empty() {
+                out.push('\n');
+            } else {
+                out.push_str(&format!("  {}\n", line));
+            }
+        }
+        if shown < lines.len() {
+            out.push_str("  ...\n");
+        }
+        out.push_str(&format!("  {}\n\n", fence));
+    }
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(source_id: &str, event_type: &str, score: f32, content: &str) -> ScryResult {
+        ScryResult {
+            id: 0,
+            content: content.to_string(),
+            score,
+            event_type: event_type.to_string(),
+            source_id: source_id.to_string(),
+            timestamp: String::new(),
+            explain: None,
+            cochange_count: None,
+        }
+    }
+
+    #[test]
+    fn test_markdown_has_bullet_score_and_fence_per_result() {
+        let results = vec![
+            result(
+                "src/eventlog.rs::initialize",
+                "code.function",
+                0.8421,
+                "pub fn initialize(db_path: &Path) -> Result<Connection> {\n    open(db_path)\n}",
+            ),
+            result("abc1234", "git.commit", 0.5, "Use ``` fences in docs"),
+        ];
+
+        let md = render_markdown(Some("open database"), &results, false);
+
+        assert!(md.starts_with("### Scry results for `open database`\n"));
+        let bullets: Vec<&str> = md.lines().filter(|l| l.starts_with("- ")).collect();
+        assert_eq!(
+            bullets,
+            vec![
+                "- [`src/eventlog.rs::initialize`](src/eventlog.rs) (code.function, score 0.842)",
+                "- `abc1234` (git.commit, score 0.500)",
+            ]
+        );
+        assert!(
+            md.contains("  ```rs\n  pub fn initialize(db_path: &Path) -> Result<Connection> {\n")
+        );
+        // Content with its own backticks gets a longer fence
+        assert!(md.contains("  ````\n  Use ``` fences in docs\n  ````\n"));
+    }
+}
@@ -4,8 +4,12 @@
 //! The external interface in `mod.rs` re-exports only what's needed.
 
 pub mod enrichment;
+pub mod error;
 pub mod hybrid;
+pub mod index_cache;
 pub mod logging;
+pub mod markdown;
+pub mod path_filter;
 pub mod query_prep;
 pub mod routing;
 pub mod search;
@@ -0,0 +1,140 @@
+//! Directory scoping for `scry --path`
+//!
+//! Results are filtered after retrieval, by the file a result belongs to
+//! (`src/auth.rs` for `src/auth.rs::login`). A filter containing `*`, `?` or
+//! `[` is a glob, anything else a path prefix matched on whole components
+//! (`src/a` covers `src/a/lib.rs`, not `src/ab/lib.rs`). Retrieval
+//! over-fetches so a scoped query can still fill `limit` once out-of-scope
+//! results are dropped.
+//!
+//! Mother and multi-repo searches scope each source's results the same way,
+//! by the path within that source's repo.
+
+use anyhow::Result;
+
+use super::super::ScryOptions;
+
+/// Candidates retrieved per requested result while a path filter is active
+const OVERFETCH: usize = 5;
+
+/// A parsed `--path` filter
+#[derive(Debug)]
+pub enum PathScope {
+    Prefix(String),
+    Glob(glob::Pattern),
+}
+
+impl PathScope {
+    pub fn parse(filter: &str) -> Result<Self> {
+        let filter = filter.trim_start_matches("./");
+        if filter.contains(['*', '?', '[']) {
+            let pattern = glob::Pattern::new(filter)
+                .map_err(|e| anyhow::anyhow!("Invalid --path glob '{}': {}", filter, e))?;
+            Ok(PathScope::Glob(pattern))
+        } else {
+            Ok(PathScope::Prefix(filter.trim_end_matches('/').to_string()))
+        }
+    }
+
+    /// Whether the file behind `source_id` falls inside this scope
+    pub fn contains(&self, source_id: &str) -> bool {
+        let path = source_id.split("::").next().unwrap_or(source_id);
+        let path = path.trim_start_matches("./");
+        match self {
+            PathScope::Prefix(prefix) => {
+                prefix.is_empty()
+                    || path
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            }
+            PathScope::Glob(pattern) => pattern.matches(path),
+        }
+    }
+}
+
+/// Run `retrieve`, keeping only results whose `path_of` is under `options.path`
+///
+/// Without a filter this is just `retrieve(options)`.
+pub fn scoped<T>(
+    options: &ScryOptions,
+    retrieve: impl FnOnce(&ScryOptions) -> Result<Vec<T>>,
+    path_of: impl Fn(&T) -> &str,
+) -> Result<Vec<T>> {
+    let Some(filter) = &options.path else {
+        return retrieve(options);
+    };
+    let scope = PathScope::parse(filter)?;
+
+    let widened = ScryOptions {
+        limit: options.limit.saturating_mul(OVERFETCH),
+        ..options.clone()
+    };
+    let mut results = retrieve(&widened)?;
+    results.retain(|r| scope.contains(path_of(r)));
+    results.truncate(options.limit);
+    Ok(results)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::scry::ScryResult;
+
+    #[test]
+    fn test_path_glob_excludes_other_directories() -> Result<()> {
+        let hit = |source_id: &str| ScryResult {
+            id: 0,
+            content: String::new(),
+            score: 1.0,
+            event_type: "code.function".to_string(),
+            source_id: source_id.to_string(),
+            timestamp: String::new(),
+            explain: None,
+            cochange_count: None,
+        };
+        let options = ScryOptions {
+            limit: 2,
+            path: Some("src/server/*".to_string()),
+            ..Default::default()
+        };
+
+        let mut requested = 0;
+        let results = scoped(
+            &options,
+            |opts| {
+                requested = opts.limit;
+                Ok(vec![
+                    hit("src/client/auth.rs::login"),
+                    hit("src/server/auth.rs::login"),
+                    hit("./src/server/session/store.rs::save"),
+                    hit("src/server/routes.rs::mount"),
+                ])
+            },
+            |r| r.source_id.as_str(),
+        )?;
+
+        assert_eq!(requested, 10);
+        let files: Vec<&str> = results.iter().map(|r| r.source_id.as_str()).collect();
+        assert_eq!(
+            files,
+            vec![
+                "src/server/auth.rs::login",
+                "./src/server/session/store.rs::save"
+            ]
+        );
+
+        let prefix = PathScope::parse("src/client")?;
+        assert!(prefix.contains("src/client/auth.rs::login"));
+        assert!(!prefix.contains("src/server/auth.rs::login"));
+
+        // Prefixes stop at path components
+        let prefix = PathScope::parse("./src/a/")?;
+        assert!(prefix.contains("src/a/lib.rs::run"));
+        assert!(!prefix.contains("src/ab/lib.rs::run"));
+        assert!(!prefix.contains("src/a.rs::run"));
+        assert!(PathScope::parse("src/a/lib.rs")?.contains("src/a/lib.rs::run"));
+        assert!(PathScope::parse("src/[server").is_err());
+
+        Ok(())
+    }
+}
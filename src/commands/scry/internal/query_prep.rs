@@ -4,6 +4,8 @@
 //! Extracts technical terms and handles code-like queries appropriately.
 
 use std::collections::HashSet;
+use std::path::Path;
+use std::sync::OnceLock;
 
 /// Prepare query for FTS5 - extract technical terms for better matching
 ///
@@ -37,6 +39,25 @@ pub fn prepare_fts_query(query: &str) -> String {
     }
 }
 
+/// Shown when a query has nothing FTS5 can match on
+pub const GENERIC_QUERY_HINT: &str =
+    "Query too generic for keyword search - try including a symbol or technical term";
+
+/// Check if query is all stop words, leaving FTS5 only a whole-sentence phrase
+pub fn is_too_generic(query: &str) -> bool {
+    let trimmed = query.trim();
+    !is_code_like(trimmed) && extract_technical_terms(trimmed).is_empty()
+}
+
+/// Individual terms of a query built by `prepare_fts_query`
+pub fn fts_terms(fts_query: &str) -> Vec<String> {
+    fts_query
+        .split(" OR ")
+        .map(|t| t.trim().trim_matches('"').to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
 /// Check if query looks like code (not natural language)
 pub fn is_code_like(query: &str) -> bool {
     query.contains("::")
@@ -45,131 +66,196 @@ pub fn is_code_like(query: &str) -> bool {
         || query.chars().all(|c| c.is_alphanumeric() || c == '_')
 }
 
+/// Built-in stop words: question words, common verbs, articles and prepositions
+const DEFAULT_STOP_WORDS: &[&str] = &[
+    // Question words
+    "how",
+    "what",
+    "why",
+    "when",
+    "where",
+    "which",
+    "who",
+    // Common verbs
+    "does",
+    "do",
+    "is",
+    "are",
+    "was",
+    "were",
+    "can",
+    "could",
+    "will",
+    "would",
+    "work",
+    "works",
+    "working",
+    "handle",
+    "handles",
+    "handling",
+    "perform",
+    "performs",
+    "performing",
+    "combine",
+    "combines",
+    "combining",
+    "coordinate",
+    "coordinates",
+    "extract",
+    "extracts",
+    "build",
+    "builds",
+    "get",
+    "gets",
+    "set",
+    "sets",
+    "use",
+    "uses",
+    "using",
+    "create",
+    "creates",
+    "manage",
+    "manages",
+    "ensure",
+    "ensures",
+    "apply",
+    "applies",
+    // Articles and prepositions
+    "the",
+    "a",
+    "an",
+    "for",
+    "from",
+    "with",
+    "to",
+    "in",
+    "on",
+    "of",
+    "by",
+    // Other common words
+    "and",
+    "or",
+    "but",
+    "this",
+    "that",
+    "these",
+    "those",
+    "multiple",
+    "different",
+    "various",
+    "specific",
+];
+
+/// Words term extraction drops: the built-in list plus `[search] stop_words`
+///
+/// The built-ins are English; projects queried in other languages (or full of
+/// jargon that matches everywhere) add their own in `.patina/config.toml`.
+#[derive(Debug, Clone)]
+pub struct StopWords(HashSet<String>);
+
+impl StopWords {
+    /// Built-in list merged with `extra` (compared case-insensitively)
+    pub fn with_extra<S: AsRef<str>>(extra: impl IntoIterator<Item = S>) -> Self {
+        let words = DEFAULT_STOP_WORDS
+            .iter()
+            .map(|w| w.to_string())
+            .chain(extra.into_iter().map(|w| w.as_ref().trim().to_lowercase()))
+            .filter(|w| !w.is_empty())
+            .collect();
+        Self(words)
+    }
+
+    /// Built-ins plus the current project's list, loaded once per process
+    pub fn configured() -> &'static StopWords {
+        static CONFIGURED: OnceLock<StopWords> = OnceLock::new();
+        CONFIGURED.get_or_init(|| {
+            let extra = patina::project::load(Path::new("."))
+                .map(|config| config.search.stop_words)
+                .unwrap_or_default();
+            Self::with_extra(extra)
+        })
+    }
+
+    pub fn contains(&self, word: &str) -> bool {
+        self.0.contains(&word.to_lowercase())
+    }
+}
+
 /// Extract technical terms from natural language query
 pub fn extract_technical_terms(query: &str) -> Vec<String> {
-    // Words to filter out (question words, common verbs, articles)
-    let stop_words: HashSet<&str> = [
-        // Question words
-        "how",
-        "what",
-        "why",
-        "when",
-        "where",
-        "which",
-        "who",
-        // Common verbs
-        "does",
-        "do",
-        "is",
-        "are",
-        "was",
-        "were",
-        "can",
-        "could",
-        "will",
-        "would",
-        "work",
-        "works",
-        "working",
-        "handle",
-        "handles",
-        "handling",
-        "perform",
-        "performs",
-        "performing",
-        "combine",
-        "combines",
-        "combining",
-        "coordinate",
-        "coordinates",
-        "extract",
-        "extracts",
-        "build",
-        "builds",
-        "get",
-        "gets",
-        "set",
-        "sets",
-        "use",
-        "uses",
-        "using",
-        "create",
-        "creates",
-        "manage",
-        "manages",
-        "ensure",
-        "ensures",
-        "apply",
-        "applies",
-        // Articles and prepositions
-        "the",
-        "a",
-        "an",
-        "for",
-        "from",
-        "with",
-        "to",
-        "in",
-        "on",
-        "of",
-        "by",
-        // Other common words
-        "and",
-        "or",
-        "but",
-        "this",
-        "that",
-        "these",
-        "those",
-        "multiple",
-        "different",
-        "various",
-        "specific",
-    ]
-    .into_iter()
-    .collect();
+    extract_terms(query, StopWords::configured())
+}
 
+/// Extract technical terms, skipping `stop_words`
+///
+/// Dotted paths (`module.submodule.func`) yield the whole path as a phrase
+/// plus each component that would count as a term on its own.
+pub fn extract_terms(query: &str, stop_words: &StopWords) -> Vec<String> {
     let mut terms = Vec::new();
 
     for word in query.split_whitespace() {
+        if let Some(components) = dotted_path(word) {
+            terms.push(format!("\"{}\"", components.join(".")));
+            terms.extend(
+                components
+                    .into_iter()
+                    .filter_map(|c| technical_term(c, stop_words)),
+            );
+            continue;
+        }
+
         // Clean punctuation
         let cleaned: String = word
             .chars()
             .filter(|c| c.is_alphanumeric() || *c == '_' || *c == '-')
             .collect();
 
-        if cleaned.is_empty() {
-            continue;
+        if let Some(term) = technical_term(&cleaned, stop_words) {
+            terms.push(term);
         }
+    }
 
-        let lower = cleaned.to_lowercase();
+    terms
+}
 
-        // Skip stop words
-        if stop_words.contains(lower.as_str()) {
-            continue;
-        }
+/// Components of a dotted path like `module.submodule.func` (sentence
+/// punctuation around it ignored), or None for anything else
+fn dotted_path(word: &str) -> Option<Vec<&str>> {
+    let core = word.trim_matches(|c: char| !(c.is_alphanumeric() || c == '_'));
+    let components: Vec<&str> = core.split('.').collect();
+    let is_path = components.len() > 1
+        && components
+            .iter()
+            .all(|c| !c.is_empty() && c.chars().all(|ch| ch.is_alphanumeric() || ch == '_'));
+    is_path.then_some(components)
+}
 
-        // Keep if:
-        // 1. Contains underscore (snake_case)
-        // 2. Contains uppercase in middle (CamelCase)
-        // 3. Is all uppercase (acronym like RRF, MCP, JSON)
-        // 4. Is a technical term (length > 2 and not common)
-        let is_snake_case = cleaned.contains('_');
-        let is_camel_case = cleaned.chars().skip(1).any(|c| c.is_uppercase());
-        let is_acronym = cleaned.len() >= 2 && cleaned.chars().all(|c| c.is_uppercase());
-        let is_technical = cleaned.len() > 2;
-
-        if is_snake_case || is_camel_case || is_acronym || is_technical {
-            // Quote hyphenated terms to prevent FTS5 interpreting - as NOT
-            if cleaned.contains('-') {
-                terms.push(format!("\"{}\"", cleaned));
-            } else {
-                terms.push(cleaned);
-            }
-        }
+/// A cleaned word as an FTS5 term, or None if it's a stop word or too short
+fn technical_term(cleaned: &str, stop_words: &StopWords) -> Option<String> {
+    if cleaned.is_empty() || stop_words.contains(cleaned) {
+        return None;
     }
 
-    terms
+    // Keep if:
+    // 1. Contains underscore (snake_case)
+    // 2. Contains uppercase in middle (CamelCase)
+    // 3. Is all uppercase (acronym like RRF, MCP, JSON)
+    // 4. Is a technical term (length > 2 and not common)
+    let is_snake_case = cleaned.contains('_');
+    let is_camel_case = cleaned.chars().skip(1).any(|c| c.is_uppercase());
+    let is_acronym = cleaned.len() >= 2 && cleaned.chars().all(|c| c.is_uppercase());
+    let is_technical = cleaned.len() > 2;
+
+    if !(is_snake_case || is_camel_case || is_acronym || is_technical) {
+        return None;
+    }
+
+    // Quote hyphenated terms to prevent FTS5 interpreting - as NOT
+    if cleaned.contains('-') {
+        Some(format!("\"{}\"", cleaned))
+    } else {
+        Some(cleaned.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -225,4 +311,36 @@ mod tests {
         assert!(result.contains("fusion"));
         assert!(result.contains(" OR "));
     }
+
+    #[test]
+    fn test_dotted_path_split_into_components() {
+        let stop_words = StopWords::with_extra(Vec::<String>::new());
+        let terms = extract_terms("Where is retrieval.fusion.rrf_fuse called?", &stop_words);
+        assert_eq!(
+            terms,
+            vec![
+                "\"retrieval.fusion.rrf_fuse\"",
+                "retrieval",
+                "fusion",
+                "rrf_fuse",
+                "called"
+            ]
+        );
+
+        // A sentence-ending period is not a path
+        assert_eq!(
+            extract_terms("It merges oracles.", &stop_words),
+            vec!["merges", "oracles"]
+        );
+    }
+
+    #[test]
+    fn test_user_stop_words_merged_with_defaults() {
+        let stop_words = StopWords::with_extra(["Wie", "funktioniert"]);
+        let terms = extract_terms("Wie funktioniert die Fusion?", &stop_words);
+        assert_eq!(terms, vec!["die", "Fusion"]);
+
+        // Built-ins still apply
+        assert!(extract_terms("how does it work", &stop_words).is_empty());
+    }
 }
@@ -5,54 +5,97 @@
 
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 
 use patina::mother::{self, EdgeType, Graph};
 
 use crate::commands::persona;
+use crate::commands::repo::RepoEntry;
 
 use super::super::{ScryOptions, ScryResult};
 use super::enrichment::truncate_content;
 use super::logging::{log_scry_query_with_routing, EdgeInfo, RoutedResult, RoutingContext};
+use super::path_filter;
 use super::search::scry_text;
 
-/// Execute scry via mother daemon
-pub fn execute_via_mother(query: Option<&str>, options: &ScryOptions) -> Result<()> {
-    let address = mother::get_address().unwrap_or_else(|| "unknown".to_string());
-    println!("🔮 Scry - Querying mother at {}\n", address);
+/// `scry_text` over one repo, keeping only results under `--path`
+///
+/// Each repo's source ids are relative to its own root, so the filter applies
+/// per repo, before results are merged.
+fn scoped_text(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
+    path_filter::scoped(
+        options,
+        |options| scry_text(query, options),
+        |r| r.source_id.as_str(),
+    )
+}
 
+/// Query the mother daemon and return its results (no printing)
+pub fn search_via_mother(query: Option<&str>, options: &ScryOptions) -> Result<Vec<ScryResult>> {
     // File-based queries not supported via mother yet
     if options.file.is_some() {
         anyhow::bail!("File-based queries (--file) not supported via mother. Run locally.");
     }
+    if !options.repos.is_empty() {
+        anyhow::bail!("Multiple --repo names not supported via mother. Run locally.");
+    }
 
     let query = query.ok_or_else(|| anyhow::anyhow!("Query text required"))?;
-    println!("Query: \"{}\"\n", query);
 
-    // Build request
-    let request = mother::ScryRequest {
-        query: query.to_string(),
-        dimension: options.dimension.clone(),
-        repo: options.repo.clone(),
-        all_repos: options.all_repos,
-        include_issues: options.include_issues,
-        include_persona: options.include_persona,
-        limit: options.limit,
-        min_score: options.min_score,
-    };
+    // The daemon doesn't know --path, so results are scoped here
+    path_filter::scoped(
+        options,
+        |options| {
+            let request = mother::ScryRequest {
+                query: query.to_string(),
+                dimension: options.dimension.clone(),
+                repo: options.repo.clone(),
+                all_repos: options.all_repos,
+                include_issues: options.include_issues,
+                include_persona: options.include_persona,
+                limit: options.limit,
+                min_score: options.min_score,
+            };
+
+            let response = mother::scry(request)?;
+            Ok(response
+                .results
+                .into_iter()
+                .map(|r| ScryResult {
+                    id: r.id,
+                    content: r.content,
+                    score: r.score,
+                    event_type: r.event_type,
+                    source_id: r.source_id,
+                    timestamp: r.timestamp,
+                    explain: None,
+                    cochange_count: None,
+                })
+                .collect())
+        },
+        |r| r.source_id.as_str(),
+    )
+}
 
-    // Execute query
-    let response = mother::scry(request)?;
+/// Execute scry via mother daemon
+pub fn execute_via_mother(query: Option<&str>, options: &ScryOptions) -> Result<()> {
+    let address = mother::get_address().unwrap_or_else(|| "unknown".to_string());
+    println!("🔮 Scry - Querying mother at {}\n", address);
 
-    if response.results.is_empty() {
+    let results = search_via_mother(query, options)?;
+    if let Some(query) = query {
+        println!("Query: \"{}\"\n", query);
+    }
+
+    if results.is_empty() {
         println!("No results found.");
         return Ok(());
     }
 
-    println!("Found {} results:\n", response.count);
+    println!("Found {} results:\n", results.len());
     println!("{}", "─".repeat(60));
 
-    for (i, result) in response.results.iter().enumerate() {
+    for (i, result) in results.iter().enumerate() {
         let timestamp_display = if result.timestamp.is_empty() {
             String::new()
         } else {
@@ -74,7 +117,30 @@ pub fn execute_via_mother(query: Option<&str>, options: &ScryOptions) -> Result<
     Ok(())
 }
 
-/// Execute query using graph-based routing (sole cross-repo strategy)
+/// Outcome of graph routing, before display or logging
+pub struct GraphSearch {
+    pub current_project: String,
+    /// Repos related to the current project, before domain filtering
+    pub related: Vec<String>,
+    pub routing: RoutingContext,
+    /// Weighted results, best first (`source_repo` is "persona" for persona hits)
+    pub results: Vec<RoutedResult>,
+    /// Sources whose search failed: (repo, error)
+    pub failures: Vec<(String, String)>,
+}
+
+impl GraphSearch {
+    /// Display label for a result's source
+    fn source_label(&self, result: &RoutedResult) -> String {
+        if result.source_repo == self.current_project {
+            "[PROJECT]".to_string()
+        } else {
+            format!("[{}]", result.source_repo.to_uppercase())
+        }
+    }
+}
+
+/// Graph-routed federated search (no printing or logging)
 ///
 /// Smart routing flow:
 /// 1. Detect current project from graph
@@ -82,36 +148,15 @@ pub fn execute_via_mother(query: Option<&str>, options: &ScryOptions) -> Result<
 /// 3. Filter by domain match if query contains domain terms
 /// 4. Execute federated search on project + related repos
 /// 5. Weight results by relationship strength
-pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Result<()> {
-    let query = query.ok_or_else(|| anyhow::anyhow!("Query required for graph routing"))?;
-
-    println!("Mode: Graph Routing (smart cross-project search)\n");
-    println!("Query: \"{}\"\n", query);
-
+pub fn search_graph(query: &str, options: &ScryOptions) -> Result<GraphSearch> {
     // 1. Open graph and detect current project
     let graph = Graph::open()?;
     let current_project = detect_current_project(&graph)?;
 
-    println!("📍 Current project: {}", current_project);
-
     // 2. Get related nodes from graph
     let edge_types = [EdgeType::Uses, EdgeType::TestsWith, EdgeType::LearnsFrom];
     let related_nodes = graph.get_related(&current_project, &edge_types)?;
 
-    if related_nodes.is_empty() {
-        println!("⚠️  No related repos in graph. Falling back to current project only.");
-        println!("   Tip: Use 'patina mother link' to add relationships.\n");
-    } else {
-        println!(
-            "🔗 Related repos: {}",
-            related_nodes
-                .iter()
-                .map(|n| n.id.as_str())
-                .collect::<Vec<_>>()
-                .join(", ")
-        );
-    }
-
     // 3. Filter by domain match (optional - check if query terms match node domains)
     let query_lower = query.to_lowercase();
     let filtered_nodes: Vec<_> = if should_filter_by_domain(&query_lower) {
@@ -121,16 +166,6 @@ pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Resu
             .collect();
 
         if !filtered.is_empty() && filtered.len() < related_nodes.len() {
-            println!(
-                "🎯 Domain filter: {} (matched {} of {} related)",
-                filtered
-                    .iter()
-                    .map(|n| n.id.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", "),
-                filtered.len(),
-                related_nodes.len()
-            );
             filtered.into_iter().cloned().collect()
         } else {
             related_nodes.clone()
@@ -161,130 +196,146 @@ pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Resu
     // Track whether domain filtering was applied
     let domain_filter_applied = filtered_nodes.len() < related_nodes.len();
 
-    println!();
-
     // 4. Execute federated search
-    // Tuple: (source_label, repo_id, weight, result)
-    let mut all_results: Vec<(String, String, f32, ScryResult)> = Vec::new();
+    let mut results: Vec<RoutedResult> = Vec::new();
+    let mut failures = Vec::new();
 
     // Search current project
     let in_project = Path::new(".patina/local/data/patina.db").exists();
     if in_project {
-        println!("📂 Searching current project...");
         let project_options = ScryOptions {
             repo: None,
+            repos: Vec::new(),
             all_repos: false,
             ..options.clone()
         };
-        match scry_text(query, &project_options) {
-            Ok(results) => {
-                println!("   Found {} results", results.len());
-                for r in results {
-                    // Current project gets weight 1.0 (baseline)
-                    all_results.push(("[PROJECT]".to_string(), current_project.clone(), 1.0, r));
-                }
-            }
-            Err(e) => {
-                eprintln!("   ⚠️  Project search failed: {}", e);
+        match scoped_text(query, &project_options) {
+            Ok(found) => {
+                // Current project gets weight 1.0 (baseline)
+                results.extend(found.into_iter().map(|r| RoutedResult {
+                    source_repo: current_project.clone(),
+                    weight: 1.0,
+                    result: r,
+                }));
             }
+            Err(e) => failures.push((current_project.clone(), e.to_string())),
         }
     }
 
     // Search related repos
-    let repos_searched = repos_to_search.len();
-
     for repo_id in &repos_to_search {
-        println!("📚 Searching {}...", repo_id);
         let repo_options = ScryOptions {
             repo: Some(repo_id.clone()),
+            repos: Vec::new(),
             all_repos: false,
             ..options.clone()
         };
-        match scry_text(query, &repo_options) {
-            Ok(results) => {
-                println!("   Found {} results", results.len());
-
+        match scoped_text(query, &repo_options) {
+            Ok(found) => {
                 // 5. Apply relationship weighting
                 let weight = get_relationship_weight(&edges, repo_id);
-
-                for r in results {
-                    all_results.push((
-                        format!("[{}]", repo_id.to_uppercase()),
-                        repo_id.clone(),
-                        weight,
-                        r,
-                    ));
-                }
-            }
-            Err(e) => {
-                eprintln!("   ⚠️  {} search failed: {}", repo_id, e);
+                results.extend(found.into_iter().map(|r| RoutedResult {
+                    source_repo: repo_id.clone(),
+                    weight,
+                    result: r,
+                }));
             }
+            Err(e) => failures.push((repo_id.clone(), e.to_string())),
         }
     }
 
-    // Query persona if enabled
-    if options.include_persona {
-        println!("🧠 Searching persona...");
-        if let Ok(persona_results) = persona::query(query, options.limit, options.min_score, None) {
-            println!("   Found {} results", persona_results.len());
+    // Query persona if enabled (persona notes have no path for --path to keep)
+    if options.include_persona && options.path.is_none() {
+        let persona_results = persona::active_for_search().and_then(|name| {
+            persona::query(&name, query, options.limit, options.min_score, None).ok()
+        });
+        if let Some(persona_results) = persona_results {
             for p in persona_results {
-                all_results.push((
-                    "[PERSONA]".to_string(),
-                    "persona".to_string(), // Persona is a special source
-                    1.0,                   // Persona gets baseline weight
-                    ScryResult {
+                results.push(RoutedResult {
+                    source_repo: "persona".to_string(), // Persona is a special source
+                    weight: 1.0,                        // Persona gets baseline weight
+                    result: ScryResult {
                         id: 0,
                         content: p.content,
                         score: p.score,
                         event_type: p.source.clone(),
                         source_id: p.domains.join(", "),
                         timestamp: p.timestamp,
+                        explain: None,
+                        cochange_count: None,
                     },
-                ));
+                });
             }
         }
     }
 
     // Sort by weighted score and take top limit
-    // Tuple: (source_label, repo_id, weight, result)
-    all_results.sort_by(|a, b| {
-        let weighted_a = a.3.score * a.2;
-        let weighted_b = b.3.score * b.2;
+    results.sort_by(|a, b| {
+        let weighted_a = a.result.score * a.weight;
+        let weighted_b = b.result.score * b.weight;
         weighted_b
             .partial_cmp(&weighted_a)
             .unwrap_or(std::cmp::Ordering::Equal)
     });
-    all_results.truncate(options.limit);
+    results.truncate(options.limit);
 
-    // Build routing context for logging (G2.5)
     let total_repos = crate::commands::repo::list().map(|r| r.len()).unwrap_or(0);
-    let routing_context = RoutingContext {
+    let routing = RoutingContext {
         strategy: "graph".to_string(),
         source_project: current_project.clone(),
         edges_used,
-        repos_searched: repos_to_search.clone(),
+        repos_searched: repos_to_search,
         repos_available: total_repos + 1, // +1 for current project
         domain_filter_applied,
     };
 
-    // Convert to RoutedResult for logging
-    let routed_results: Vec<RoutedResult> = all_results
-        .iter()
-        .map(|(_, repo_id, weight, result)| RoutedResult {
-            source_repo: repo_id.clone(),
-            weight: *weight,
-            result: result.clone(),
-        })
-        .collect();
+    Ok(GraphSearch {
+        current_project,
+        related: related_nodes.into_iter().map(|n| n.id).collect(),
+        routing,
+        results,
+        failures,
+    })
+}
+
+/// Execute query using graph-based routing (sole cross-repo strategy)
+pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Result<()> {
+    let query = query.ok_or_else(|| anyhow::anyhow!("Query required for graph routing"))?;
+
+    println!("Mode: Graph Routing (smart cross-project search)\n");
+    println!("Query: \"{}\"\n", query);
+
+    let search = search_graph(query, options)?;
+    let routing = &search.routing;
+
+    println!("📍 Current project: {}", search.current_project);
+    if search.related.is_empty() {
+        println!("⚠️  No related repos in graph. Falling back to current project only.");
+        println!("   Tip: Use 'patina mother link' to add relationships.\n");
+    } else {
+        println!("🔗 Related repos: {}", search.related.join(", "));
+    }
+    if routing.domain_filter_applied {
+        println!(
+            "🎯 Domain filter: {} (matched {} of {} related)",
+            routing.repos_searched.join(", "),
+            routing.repos_searched.len(),
+            search.related.len()
+        );
+    }
+    for (source, error) in &search.failures {
+        eprintln!("   ⚠️  {} search failed: {}", source, error);
+    }
 
     // Log query with routing context (G2.5)
-    let query_id = log_scry_query_with_routing(query, &routed_results, &routing_context);
+    let query_id = log_scry_query_with_routing(query, &search.results, routing);
 
     // Record edge usage for each edge that contributed (G2.5)
-    if let Some(ref qid) = query_id {
-        for edge in &routing_context.edges_used {
+    if let (Some(qid), Ok(graph)) = (&query_id, Graph::open()) {
+        for edge in &routing.edges_used {
             // Find best rank for this edge's target repo in results
-            let best_rank = routed_results
+            let best_rank = search
+                .results
                 .iter()
                 .enumerate()
                 .find(|(_, r)| r.source_repo == edge.to_node)
@@ -297,7 +348,7 @@ pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Resu
 
     println!();
 
-    if all_results.is_empty() {
+    if search.results.is_empty() {
         println!("No results found.");
         return Ok(());
     }
@@ -305,27 +356,28 @@ pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Resu
     // Report routing efficiency
     println!(
         "Found {} results (searched {} of {} repos):\n",
-        all_results.len(),
-        repos_searched + 1, // +1 for current project
-        total_repos + 1
+        search.results.len(),
+        routing.repos_searched.len() + 1, // +1 for current project
+        routing.repos_available
     );
     println!("{}", "─".repeat(60));
 
-    for (i, (source, _repo_id, weight, result)) in all_results.iter().enumerate() {
+    for (i, routed) in search.results.iter().enumerate() {
+        let result = &routed.result;
         let timestamp_display = if result.timestamp.is_empty() {
             String::new()
         } else {
             format!(" | {}", result.timestamp)
         };
-        let weight_display = if (*weight - 1.0).abs() > 0.01 {
-            format!(" (w={:.2})", weight)
+        let weight_display = if (routed.weight - 1.0).abs() > 0.01 {
+            format!(" (w={:.2})", routed.weight)
         } else {
             String::new()
         };
         println!(
             "\n[{}] {} Score: {:.3}{} | {} | {}{}",
             i + 1,
-            source,
+            search.source_label(routed),
             result.score,
             weight_display,
             result.event_type,
@@ -345,6 +397,125 @@ pub fn execute_graph_routing(query: Option<&str>, options: &ScryOptions) -> Resu
     Ok(())
 }
 
+/// Search just the named repos, tagging each result with its repo (no printing)
+///
+/// Every name is checked against the registry before any search runs, so a
+/// typo fails fast instead of after the other repos were queried.
+pub fn search_repos(query: &str, options: &ScryOptions) -> Result<Vec<RoutedResult>> {
+    let registered = crate::commands::repo::list()?;
+    search_repos_with(&options.repos, &registered, options.limit, |name| {
+        let repo_options = ScryOptions {
+            repo: Some(name.to_string()),
+            repos: Vec::new(),
+            all_repos: false,
+            ..options.clone()
+        };
+        scoped_text(query, &repo_options)
+    })
+}
+
+/// `search_repos` with the registry and per-repo search supplied
+fn search_repos_with(
+    names: &[String],
+    registered: &[RepoEntry],
+    limit: usize,
+    mut search: impl FnMut(&str) -> Result<Vec<ScryResult>>,
+) -> Result<Vec<RoutedResult>> {
+    let repos = names
+        .iter()
+        .map(|name| {
+            registered
+                .iter()
+                .find(|r| r.name.eq_ignore_ascii_case(name))
+                .map(|r| r.name.clone())
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "Repository '{}' not found in registry. Use 'patina repo list' to see registered repos.",
+                        name
+                    )
+                })
+        })
+        .collect::<Result<Vec<String>>>()?;
+
+    let mut results = Vec::new();
+    for repo in &repos {
+        let found = search(repo).with_context(|| format!("Search failed for repo '{}'", repo))?;
+        results.extend(found.into_iter().map(|r| RoutedResult {
+            source_repo: repo.clone(),
+            weight: 1.0,
+            result: r,
+        }));
+    }
+
+    results.sort_by(|a, b| {
+        b.result
+            .score
+            .partial_cmp(&a.result.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    results.truncate(limit);
+    Ok(results)
+}
+
+/// Execute a search over several named repos (`--repo a,b`)
+pub fn execute_multi_repo(query: Option<&str>, options: &ScryOptions) -> Result<()> {
+    let query = query.ok_or_else(|| anyhow::anyhow!("Query required for multi-repo search"))?;
+
+    println!("Mode: Multi-repo ({})\n", options.repos.join(", "));
+    println!("Query: \"{}\"\n", query);
+
+    let results = search_repos(query, options)?;
+
+    let source_project = std::env::current_dir()
+        .ok()
+        .and_then(|d| d.file_name().map(|n| n.to_string_lossy().to_string()))
+        .unwrap_or_else(|| "unknown".to_string());
+    let routing = RoutingContext {
+        strategy: "repos".to_string(),
+        source_project,
+        edges_used: Vec::new(),
+        repos_searched: options.repos.clone(),
+        repos_available: crate::commands::repo::list().map(|r| r.len()).unwrap_or(0),
+        domain_filter_applied: false,
+    };
+    let query_id = log_scry_query_with_routing(query, &results, &routing);
+
+    if results.is_empty() {
+        println!("No results found.");
+        return Ok(());
+    }
+
+    println!("Found {} results:\n", results.len());
+    println!("{}", "─".repeat(60));
+
+    for (i, routed) in results.iter().enumerate() {
+        let result = &routed.result;
+        let timestamp_display = if result.timestamp.is_empty() {
+            String::new()
+        } else {
+            format!(" | {}", result.timestamp)
+        };
+        println!(
+            "\n[{}] [{}] Score: {:.3} | {} | {}{}",
+            i + 1,
+            routed.source_repo.to_uppercase(),
+            result.score,
+            result.event_type,
+            result.source_id,
+            timestamp_display
+        );
+        println!("    {}", truncate_content(&result.content, 200));
+    }
+
+    println!("\n{}", "─".repeat(60));
+
+    if let Some(ref qid) = query_id {
+        println!("\nQuery ID: {} (use with 'scry open/copy/feedback')", qid);
+    }
+
+    Ok(())
+}
+
 /// Detect current project from graph
 ///
 /// Looks up the current working directory in the graph nodes.
@@ -441,3 +612,69 @@ fn get_relationship_weight(edges: &[mother::Edge], repo_id: &str) -> f32 {
     }
     1.0 // Default weight
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::repo::internal::Registry;
+
+    fn hit(source_id: &str, score: f32) -> ScryResult {
+        ScryResult {
+            id: 0,
+            content: format!("content of {}", source_id),
+            score,
+            event_type: "code.function".to_string(),
+            source_id: source_id.to_string(),
+            timestamp: String::new(),
+            explain: None,
+            cochange_count: None,
+        }
+    }
+
+    #[test]
+    fn test_search_repos_only_queries_named_repos() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let mut registry = Registry::default();
+        for name in ["alpha", "beta", "gamma"] {
+            let dir = temp.path().join(name);
+            std::fs::create_dir_all(&dir)?;
+            registry.register_local(&dir, name)?;
+        }
+        let registered = registry.list();
+
+        let names = vec!["gamma".to_string(), "Alpha".to_string()];
+        let mut searched = Vec::new();
+        let results = search_repos_with(&names, &registered, 10, |repo| {
+            searched.push(repo.to_string());
+            let score = if repo == "alpha" { 0.9 } else { 0.5 };
+            Ok(vec![hit(&format!("{}::spawn", repo), score)])
+        })?;
+
+        assert_eq!(searched, vec!["gamma", "alpha"]);
+        let tagged: Vec<(&str, &str)> = results
+            .iter()
+            .map(|r| (r.source_repo.as_str(), r.result.source_id.as_str()))
+            .collect();
+        assert_eq!(
+            tagged,
+            vec![("alpha", "alpha::spawn"), ("gamma", "gamma::spawn")]
+        );
+
+        // An unregistered name fails before anything is searched
+        let mut called = false;
+        let err = search_repos_with(
+            &["alpha".to_string(), "delta".to_string()],
+            &registered,
+            10,
+            |_| {
+                called = true;
+                Ok(Vec::new())
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("'delta' not found"));
+        assert!(!called);
+
+        Ok(())
+    }
+}
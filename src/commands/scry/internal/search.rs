@@ -3,16 +3,22 @@
 //! Implements semantic vector search, lexical FTS5 search, and file-based queries.
 
 use std::path::Path;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use rusqlite::Connection;
-use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+
+use crate::commands::oxidize::index_meta;
+use crate::retrieval::PhaseTimings;
 
 use patina::embeddings::create_embedder;
+use patina::embeddings::id_offsets::belief_key;
 
-use super::super::{ScryOptions, ScryResult};
-use super::enrichment::{enrich_results, SearchResults};
-use super::query_prep::prepare_fts_query;
+use super::super::{MatchExplanation, ScryOptions, ScryResult};
+use super::enrichment::{enrich_results, semantic_key, SearchResults};
+use super::error::{ScryError, DIMENSIONS};
+use super::index_cache;
+use super::query_prep::{fts_terms, is_too_generic, prepare_fts_query};
 
 /// Get database and embeddings paths (handles --repo flag)
 pub fn get_paths(options: &ScryOptions) -> Result<(String, String)> {
@@ -24,22 +30,76 @@ pub fn get_paths(options: &ScryOptions) -> Result<(String, String)> {
         Ok((db_path, embeddings_dir))
     } else {
         // For local project, read model from config
-        let model = get_embedding_model();
+        let root = &options.project_root;
+        let model = embedding_model_in(root);
+        let data_dir = root.join(".patina/local/data");
         Ok((
-            ".patina/local/data/patina.db".to_string(),
-            format!(".patina/local/data/embeddings/{}/projections", model),
+            data_dir.join("patina.db").display().to_string(),
+            data_dir
+                .join(format!("embeddings/{}/projections", model))
+                .display()
+                .to_string(),
         ))
     }
 }
 
 /// Get embedding model from project config (defaults to e5-base-v2)
 pub fn get_embedding_model() -> String {
-    patina::project::load(std::path::Path::new("."))
+    embedding_model_in(std::path::Path::new("."))
+}
+
+/// Embedding model from the config of the project at `root`
+pub fn embedding_model_in(root: &std::path::Path) -> String {
+    patina::project::load(root)
         .ok()
         .map(|c| c.embeddings.model)
         .unwrap_or_else(|| "e5-base-v2".to_string())
 }
 
+/// Open the knowledge database (`ScryError::DbOpen` on failure)
+fn open_db(db_path: &str) -> Result<Connection> {
+    Connection::open(db_path).map_err(|source| {
+        ScryError::DbOpen {
+            path: db_path.to_string(),
+            source,
+        }
+        .into()
+    })
+}
+
+/// Reject dimensions oxidize never builds (`ScryError::UnknownDimension`)
+fn check_dimension(dimension: &str) -> Result<()> {
+    if DIMENSIONS.contains(&dimension) {
+        Ok(())
+    } else {
+        Err(ScryError::UnknownDimension(dimension.to_string()).into())
+    }
+}
+
+/// Path to a dimension's index (`ScryError::IndexMissing` when not built)
+fn require_index(embeddings_dir: &str, dimension: &str) -> Result<String> {
+    let index_path = format!("{}/{}.usearch", embeddings_dir, dimension);
+    if Path::new(&index_path).exists() {
+        Ok(index_path)
+    } else {
+        Err(ScryError::IndexMissing {
+            dimension: dimension.to_string(),
+            path: index_path,
+        }
+        .into())
+    }
+}
+
+/// Embed query text with the configured model (`ScryError::EmbedFailed`)
+fn embed_query_text(query: &str) -> Result<Vec<f32>> {
+    typed_embed(|| create_embedder()?.embed_query(query))
+}
+
+/// Run `embed`, classifying any failure as `ScryError::EmbedFailed`
+fn typed_embed(embed: impl FnOnce() -> Result<Vec<f32>>) -> Result<Vec<f32>> {
+    embed().map_err(|e| ScryError::EmbedFailed(e).into())
+}
+
 /// Text-based scry - embed query and search (for semantic dimension)
 pub fn scry_text(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
     let (db_path, embeddings_dir) = get_paths(options)?;
@@ -47,6 +107,7 @@ pub fn scry_text(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult>>
     // Determine which dimension to search
     // For reference repos, only dependency is available; for projects, prefer semantic
     let dimension = if let Some(ref dim) = options.dimension {
+        check_dimension(dim)?;
         dim.as_str()
     } else {
         detect_best_dimension(&embeddings_dir)
@@ -60,78 +121,181 @@ pub fn scry_text(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult>>
             dimension
         );
         eprintln!("   Run 'patina oxidize' for vector search\n");
-        println!("Mode: Lexical (FTS5) [fallback]\n");
         return scry_lexical(query, options);
     }
 
-    // Create embedder and embed query
-    println!("Embedding query...");
-    let mut embedder = create_embedder()?;
-    let query_embedding = embedder.embed_query(query)?;
+    patina::embeddings::ensure_model_available(options.auto_pull)?;
+    let projection_path = format!("{}/{}.safetensors", embeddings_dir, dimension);
+    let (results, timings) = vector_search(
+        || embed_query_text(query),
+        Path::new(&projection_path),
+        &index_path,
+        options.limit,
+    )?;
+    if options.timing {
+        eprintln!("⏱  {}: {}", dimension, timings.report());
+    }
+
+    // Enrich with metadata from SQLite
+    let conn = open_db(&db_path)?;
+
+    let mut enriched = enrich_results(&conn, &results, dimension, options.min_score)?;
+    if !options.include_issues {
+        // Issues/PRs are opt-in, matching lexical search
+        enriched.retain(|r| !r.event_type.starts_with("forge."));
+    }
+    if options.explain {
+        explain_vector_results(&mut enriched, dimension);
+    }
+
+    Ok(enriched)
+}
+
+/// Embed, project and search one index, timing each phase
+fn vector_search(
+    embed: impl FnOnce() -> Result<Vec<f32>>,
+    projection_path: &Path,
+    index_path: &str,
+    limit: usize,
+) -> Result<(SearchResults, PhaseTimings)> {
+    let mut timings = PhaseTimings::default();
+
+    let started = Instant::now();
+    let query_embedding = embed()?;
+    timings.embed = started.elapsed();
 
     // Load projection and project query embedding
-    let projection_path = format!("{}/{}.safetensors", embeddings_dir, dimension);
-    let projected = if Path::new(&projection_path).exists() {
+    let started = Instant::now();
+    let projected = if projection_path.exists() {
         use crate::commands::oxidize::trainer::Projection;
-        let projection = Projection::load_safetensors(Path::new(&projection_path))?;
+        let projection = Projection::load_safetensors(projection_path)?;
         projection.forward(&query_embedding)
     } else {
         query_embedding
     };
+    timings.projection = started.elapsed();
 
-    // Search index
-    println!("Searching {} index...", dimension);
-
-    // Create index with matching options (256-dim projection output, cosine)
-    let index_options = IndexOptions {
-        dimensions: 256,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
-
-    let index = Index::new(&index_options).with_context(|| "Failed to create index")?;
-
-    index
-        .load(&index_path)
-        .with_context(|| format!("Failed to load index: {}", index_path))?;
+    // Open with the options oxidize recorded (projection width, quantization, cosine)
+    let started = Instant::now();
+    let index_options = index_meta::load_options(Path::new(index_path));
+    let index = index_cache::load(Path::new(index_path), &index_options)?;
+    timings.index_load = started.elapsed();
 
+    index_meta::ensure_dimensions(&index, &projected)?;
+    let started = Instant::now();
     let matches = index
-        .search(&projected, options.limit)
+        .search(&projected, limit)
         .with_context(|| "Vector search failed")?;
+    timings.search = started.elapsed();
 
-    // Convert to our SearchResults struct
     let results = SearchResults {
         keys: matches.keys,
         distances: matches.distances,
     };
+    Ok((results, timings))
+}
 
-    // Enrich with metadata from SQLite
-    let conn = Connection::open(&db_path)
-        .with_context(|| format!("Failed to open database: {}", db_path))?;
-
-    let enriched = enrich_results(&conn, &results, dimension, options.min_score)?;
+/// Attach vector match details (enrichment scores are `1 - distance`)
+fn explain_vector_results(results: &mut [ScryResult], dimension: &str) {
+    for result in results {
+        result.explain = Some(MatchExplanation {
+            dimension: dimension.to_string(),
+            raw_score: 1.0 - result.score,
+            matched_terms: Vec::new(),
+        });
+    }
+}
 
-    Ok(enriched)
+/// Attach lexical match details (scores are negated BM25)
+fn explain_lexical_results(results: &mut [ScryResult], fts_query: &str) {
+    let terms = fts_terms(fts_query);
+    for result in results {
+        let content = result.content.to_lowercase();
+        result.explain = Some(MatchExplanation {
+            dimension: "lexical".to_string(),
+            raw_score: -result.score,
+            matched_terms: terms
+                .iter()
+                .filter(|t| content.contains(&t.to_lowercase()))
+                .cloned()
+                .collect(),
+        });
+    }
 }
 
 /// File-based scry - look up file's vector and find neighbors (for temporal/dependency)
 pub fn scry_file(file_path: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
     let (db_path, embeddings_dir) = get_paths(options)?;
+    scry_file_at(&db_path, &embeddings_dir, file_path, options)
+}
 
-    let dimension = options.dimension.as_deref().unwrap_or("temporal");
-    let index_path = format!("{}/{}.usearch", embeddings_dir, dimension);
+/// How often a pair of files changed together
+#[derive(Debug, Default)]
+struct CoChange {
+    count: i64,
+    /// Most recent commit touching both (when commit history is scraped)
+    last: Option<String>,
+}
 
-    if !Path::new(&index_path).exists() {
-        anyhow::bail!(
-            "Index not found: {}. Run 'patina oxidize' first.",
-            index_path
-        );
-    }
+/// Co-change strength of two files from `co_changes`, dated from `commits`
+fn cochange_between(conn: &Connection, a: &str, b: &str) -> Result<CoChange> {
+    let count: i64 = conn.query_row(
+        "SELECT COALESCE(SUM(count), 0) FROM co_changes
+         WHERE (file_a = ?1 AND file_b = ?2) OR (file_a = ?2 AND file_b = ?1)",
+        [a, b],
+        |row| row.get(0),
+    )?;
+
+    let has_history: bool = conn.query_row(
+        "SELECT COUNT(*) = 2 FROM sqlite_master
+         WHERE type = 'table' AND name IN ('commits', 'commit_files')",
+        [],
+        |row| row.get(0),
+    )?;
+    let last = if count > 0 && has_history {
+        conn.query_row(
+            "SELECT MAX(c.timestamp)
+             FROM commit_files x
+             JOIN commit_files y ON y.sha = x.sha
+             JOIN commits c ON c.sha = x.sha
+             WHERE x.file_path = ?1 AND y.file_path = ?2",
+            [a, b],
+            |row| row.get(0),
+        )?
+    } else {
+        None
+    };
+
+    Ok(CoChange { count, last })
+}
+
+/// Result text for a co-change partner, e.g. "... (changed together 14 times, last 2026-01-05)"
+fn cochange_content(file_path: &str, cochange: &CoChange) -> String {
+    let times = if cochange.count == 1 { "time" } else { "times" };
+    let last = cochange
+        .last
+        .as_deref()
+        .map(|t| format!(", last {}", t.split('T').next().unwrap_or(t)))
+        .unwrap_or_default();
+    format!(
+        "Co-changes with: {} (changed together {} {}{})",
+        file_path, cochange.count, times, last
+    )
+}
+
+/// File-based scry against an explicit database and embeddings directory
+fn scry_file_at(
+    db_path: &str,
+    embeddings_dir: &str,
+    file_path: &str,
+    options: &ScryOptions,
+) -> Result<Vec<ScryResult>> {
+    let dimension = options.dimension.as_deref().unwrap_or("temporal");
+    check_dimension(dimension)?;
+    let index_path = require_index(embeddings_dir, dimension)?;
 
     // Open database to find file index
-    let conn = Connection::open(&db_path)
-        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    let conn = open_db(db_path)?;
 
     // Get list of files in the temporal index
     let files: Vec<String> = {
@@ -153,39 +317,41 @@ pub fn scry_file(file_path: &str, options: &ScryOptions) -> Result<Vec<ScryResul
     let file_index = files
         .iter()
         .position(|f| f == file_path || f.ends_with(file_path) || file_path.ends_with(f))
-        .ok_or_else(|| anyhow::anyhow!("File '{}' not found in {} index", file_path, dimension))?;
-
-    println!("Found file at index {} in {} index", file_index, dimension);
+        .ok_or_else(|| {
+            let mut message = format!("File '{}' not found in {} index", file_path, dimension);
+            let suggestions = suggest_files(file_path, &files);
+            if !suggestions.is_empty() {
+                message.push_str(&format!("; did you mean {}?", suggestions.join(", ")));
+            }
+            anyhow::anyhow!(message)
+        })?;
 
     // Load index
-    let index_options = IndexOptions {
-        dimensions: 256,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
-
-    let index = Index::new(&index_options).with_context(|| "Failed to create index")?;
-
-    index
-        .load(&index_path)
-        .with_context(|| format!("Failed to load index: {}", index_path))?;
+    let index_options = index_meta::load_options(Path::new(&index_path));
+    let index = index_cache::load(Path::new(&index_path), &index_options)?;
 
     // Get the file's existing vector from the index
-    let mut file_vector = vec![0.0_f32; 256];
+    let mut file_vector = vec![0.0_f32; index.dimensions()];
     index
         .get(file_index as u64, &mut file_vector)
         .with_context(|| format!("Failed to get vector for file index {}", file_index))?;
 
-    println!("Searching for neighbors...");
-
-    // Search for neighbors (request extra to filter out self)
+    // Search for neighbors (request extra to filter out self; every file when
+    // weak partners get dropped, so the limit is still filled)
+    let temporal = dimension == "temporal";
+    let candidates = if temporal && options.min_count > 0 {
+        files.len()
+    } else {
+        options.limit + 1
+    };
     let matches = index
-        .search(&file_vector, options.limit + 1)
+        .search(&file_vector, candidates)
         .with_context(|| "Vector search failed")?;
 
-    // Build results, filtering out the query file itself
-    let mut results = Vec::new();
+    // Build results, filtering out the query file itself; temporal partners
+    // carry their co-change count
+    let anchor = &files[file_index];
+    let mut results: Vec<ScryResult> = Vec::new();
     for i in 0..matches.keys.len() {
         let key = matches.keys[i] as usize;
         let distance = matches.distances[i];
@@ -202,13 +368,28 @@ pub fn scry_file(file_path: &str, options: &ScryOptions) -> Result<Vec<ScryResul
 
         if key < files.len() {
             let related_file = &files[key];
+            let cochange = if temporal {
+                cochange_between(&conn, anchor, related_file)?
+            } else {
+                CoChange::default()
+            };
+            if temporal && cochange.count < options.min_count {
+                continue;
+            }
+            let content = if temporal {
+                cochange_content(file_path, &cochange)
+            } else {
+                format!("Co-changes with: {}", file_path)
+            };
             results.push(ScryResult {
                 id: key as i64,
                 event_type: "file.cochange".to_string(),
                 source_id: related_file.clone(),
-                timestamp: String::new(),
-                content: format!("Co-changes with: {}", file_path),
+                timestamp: cochange.last.unwrap_or_default(),
+                content,
                 score,
+                explain: None,
+                cochange_count: temporal.then_some(cochange.count),
             });
         }
 
@@ -217,23 +398,58 @@ pub fn scry_file(file_path: &str, options: &ScryOptions) -> Result<Vec<ScryResul
         }
     }
 
-    // Sort by score descending
+    // Sort by score descending, stronger co-change first on ties
     results.sort_by(|a, b| {
         b.score
             .partial_cmp(&a.score)
             .unwrap_or(std::cmp::Ordering::Equal)
+            .then(b.cochange_count.cmp(&a.cochange_count))
     });
+    if options.explain {
+        explain_vector_results(&mut results, dimension);
+    }
 
     Ok(results)
 }
 
+/// Indexed paths closest to a `--file` that matched nothing, best first
+///
+/// A path containing the input wins outright; otherwise the input is compared
+/// against each trailing run of path components, so a typo in a short suffix
+/// like `auth/sesion.rs` still finds `src/auth/session.rs`.
+fn suggest_files<'a>(file_path: &str, files: &'a [String]) -> Vec<&'a str> {
+    const MAX_SUGGESTIONS: usize = 3;
+    let max_distance = (file_path.chars().count() / 4).max(2);
+
+    let mut scored: Vec<(usize, &str)> = files
+        .iter()
+        .filter_map(|f| {
+            let distance = if f.contains(file_path) {
+                0
+            } else {
+                std::iter::once(0)
+                    .chain(f.match_indices('/').map(|(i, _)| i + 1))
+                    .map(|start| crate::commands::assay::edit_distance(file_path, &f[start..]))
+                    .min()
+                    .unwrap_or(usize::MAX)
+            };
+            (distance <= max_distance).then_some((distance, f.as_str()))
+        })
+        .collect();
+    scored.sort();
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, f)| f)
+        .collect()
+}
+
 /// Belief-based scry - look up belief's vector and find neighbors across all content types
 pub fn scry_belief(belief_id: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
     let (db_path, embeddings_dir) = get_paths(options)?;
 
     // Look up belief rowid from beliefs table
-    let conn = Connection::open(&db_path)
-        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    let conn = open_db(&db_path)?;
 
     let rowid: i64 = conn
         .query_row(
@@ -243,32 +459,16 @@ pub fn scry_belief(belief_id: &str, options: &ScryOptions) -> Result<Vec<ScryRes
         )
         .with_context(|| format!("Belief '{}' not found in database", belief_id))?;
 
-    const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
-    let belief_index = (BELIEF_ID_OFFSET + rowid) as u64;
+    let belief_index = belief_key(rowid) as u64;
 
     // Load semantic index (beliefs live in semantic space)
-    let index_path = format!("{}/semantic.usearch", embeddings_dir);
-    if !Path::new(&index_path).exists() {
-        anyhow::bail!(
-            "Semantic index not found: {}. Run 'patina oxidize' first.",
-            index_path
-        );
-    }
-
-    let index_options = IndexOptions {
-        dimensions: 256,
-        metric: MetricKind::Cos,
-        quantization: ScalarKind::F32,
-        ..Default::default()
-    };
+    let index_path = require_index(&embeddings_dir, "semantic")?;
 
-    let index = Index::new(&index_options).with_context(|| "Failed to create index")?;
-    index
-        .load(&index_path)
-        .with_context(|| format!("Failed to load index: {}", index_path))?;
+    let index_options = index_meta::load_options(Path::new(&index_path));
+    let index = index_cache::load(Path::new(&index_path), &index_options)?;
 
     // Get the belief's existing vector from the index
-    let mut belief_vector = vec![0.0_f32; 256];
+    let mut belief_vector = vec![0.0_f32; index.dimensions()];
     index
         .get(belief_index, &mut belief_vector)
         .with_context(|| {
@@ -278,8 +478,6 @@ pub fn scry_belief(belief_id: &str, options: &ScryOptions) -> Result<Vec<ScryRes
             )
         })?;
 
-    println!("Searching for neighbors of belief '{}'...", belief_id);
-
     // Request extra results to account for self-filtering (belief + pattern entries)
     // and type filtering (code may be sparse in top results)
     let search_limit = if options.content_type.is_some() {
@@ -309,17 +507,97 @@ pub fn scry_belief(belief_id: &str, options: &ScryOptions) -> Result<Vec<ScryRes
 
     // Apply content type filter if specified
     if let Some(ref type_filter) = options.content_type {
-        enriched.retain(|r| match type_filter.as_str() {
-            "code" => r.event_type.starts_with("code."),
-            "commits" => r.event_type == "git.commit",
-            "sessions" => r.event_type.starts_with("session."),
-            "patterns" => r.event_type.starts_with("pattern."),
-            "beliefs" => r.event_type == "belief.surface",
-            _ => true,
-        });
+        enriched.retain(|r| is_content_type(r, type_filter));
     }
 
     enriched.truncate(options.limit);
+    if options.explain {
+        explain_vector_results(&mut enriched, "semantic");
+    }
+    Ok(enriched)
+}
+
+/// Whether a result belongs to a `--content-type` (unknown types match everything)
+fn is_content_type(result: &ScryResult, type_filter: &str) -> bool {
+    match type_filter {
+        "code" => result.event_type.starts_with("code."),
+        "commits" => result.event_type == "git.commit",
+        "sessions" => result.event_type.starts_with("session."),
+        "patterns" => result.event_type.starts_with("pattern."),
+        "beliefs" => result.event_type == "belief.surface",
+        _ => true,
+    }
+}
+
+/// Neighbor scry - "more like this" for any result in the semantic index
+pub fn scry_neighbors(doc_id: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
+    let (db_path, embeddings_dir) = get_paths(options)?;
+    scry_neighbors_at(&db_path, &embeddings_dir, doc_id, options)
+}
+
+/// `scry_neighbors` against an explicit database and projections directory
+///
+/// The result's stored vector is the query, so nothing is embedded; the
+/// item itself is dropped from its own neighbors.
+fn scry_neighbors_at(
+    db_path: &str,
+    embeddings_dir: &str,
+    doc_id: &str,
+    options: &ScryOptions,
+) -> Result<Vec<ScryResult>> {
+    let conn = open_db(db_path)?;
+    let key = semantic_key(&conn, doc_id).ok_or_else(|| {
+        anyhow::anyhow!(
+            "'{}' not found; use a result ID (path::fn, belief id, pattern id, SHA, or #N)",
+            doc_id
+        )
+    })? as u64;
+
+    let index_path = require_index(embeddings_dir, "semantic")?;
+    let index_options = index_meta::load_options(Path::new(&index_path));
+    let index = index_cache::load(Path::new(&index_path), &index_options)?;
+
+    let mut vector = vec![0.0_f32; index.dimensions()];
+    let found = index
+        .get(key, &mut vector)
+        .with_context(|| format!("Failed to get vector for '{}' (index {})", doc_id, key))?;
+    if found == 0 {
+        anyhow::bail!(
+            "'{}' has no vector in the semantic index. Run 'patina oxidize' to embed it.",
+            doc_id
+        );
+    }
+
+    // +1 for the item itself; type filtering needs a deeper pool
+    let search_limit = if options.content_type.is_some() {
+        options.limit * 5 + 1
+    } else {
+        options.limit + 1
+    };
+    let matches = index
+        .search(&vector, search_limit)
+        .with_context(|| "Vector search failed")?;
+
+    let (keys, distances) = matches
+        .keys
+        .into_iter()
+        .zip(matches.distances)
+        .filter(|(k, _)| *k != key)
+        .unzip();
+    let results = SearchResults { keys, distances };
+
+    let mut enriched = enrich_results(&conn, &results, "semantic", options.min_score)?;
+    if !options.include_issues {
+        enriched.retain(|r| !r.event_type.starts_with("forge."));
+    }
+    if let Some(ref type_filter) = options.content_type {
+        enriched.retain(|r| is_content_type(r, type_filter));
+    }
+
+    enriched.truncate(options.limit);
+    if options.explain {
+        explain_vector_results(&mut enriched, "semantic");
+    }
     Ok(enriched)
 }
 
@@ -365,19 +643,30 @@ pub fn is_lexical_query(query: &str) -> bool {
 pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult>> {
     let (db_path, _) = get_paths(options)?;
 
-    let conn = Connection::open(&db_path)
-        .with_context(|| format!("Failed to open database: {}", db_path))?;
+    let conn = open_db(&db_path)?;
+
+    lexical_search(&conn, query, options)
+}
+
+/// FTS5 search over code, commits, and patterns in an open database
+fn lexical_search(
+    conn: &Connection,
+    query: &str,
+    options: &ScryOptions,
+) -> Result<Vec<ScryResult>> {
+    // A phrase of stop words matches nothing useful; semantic search covers these
+    if is_too_generic(query) {
+        return Ok(Vec::new());
+    }
 
     // Prepare the FTS5 query
     let fts_query = prepare_fts_query(query);
 
-    println!("FTS5 query: {}", fts_query);
-
     let mut collected: Vec<ScryResult> = Vec::new();
 
     // 1. Search code_fts
     let event_type_filter = if options.include_issues {
-        "event_type LIKE 'code.%' OR event_type = 'github.issue'"
+        "event_type LIKE 'code.%' OR event_type IN ('forge.issue', 'forge.pr')"
     } else {
         "event_type LIKE 'code.%'"
     };
@@ -408,8 +697,10 @@ pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult
 
             // Use file_path directly - it's already source_id format (path::name)
             // Don't append symbol again (was causing path::name:name doubling)
-            let source_id = if event_type == "github.issue" {
+            let source_id = if event_type == "forge.issue" {
                 format!("[ISSUE] {}", symbol)
+            } else if event_type == "forge.pr" {
+                format!("[PR] {}", symbol)
             } else {
                 file_path.clone()
             };
@@ -422,6 +713,8 @@ pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult
                 event_type,
                 source_id,
                 timestamp: String::new(),
+                explain: None,
+                cochange_count: None,
             })
         })?;
     collected.extend(code_results.filter_map(|r| r.ok()));
@@ -452,6 +745,8 @@ pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult
                     event_type: "git.commit".to_string(),
                     source_id: sha,
                     timestamp: String::new(),
+                    explain: None,
+                    cochange_count: None,
                 })
             })?;
         collected.extend(commit_results.filter_map(|r| r.ok()));
@@ -493,6 +788,8 @@ pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult
                     event_type: format!("pattern.{}", layer),
                     source_id: id,
                     timestamp: String::new(),
+                    explain: None,
+                    cochange_count: None,
                 })
             })?;
         collected.extend(pattern_results.filter_map(|r| r.ok()));
@@ -508,6 +805,9 @@ pub fn scry_lexical(query: &str, options: &ScryOptions) -> Result<Vec<ScryResult
 
     // Filter by min_score
     collected.retain(|r| r.score >= options.min_score);
+    if options.explain {
+        explain_lexical_results(&mut collected, &fts_query);
+    }
 
     Ok(collected)
 }
@@ -535,3 +835,377 @@ pub fn detect_best_dimension(embeddings_dir: &str) -> &'static str {
     // Default to semantic (will trigger fallback to FTS5)
     "semantic"
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::scry::internal::error::{http_status, json_rpc_code};
+    use usearch::Index;
+
+    #[test]
+    fn test_lexical_explain_lists_matched_terms() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE code_fts USING fts5(
+                symbol_name, file_path, content, event_type,
+                tokenize='porter unicode61'
+            );
+            INSERT INTO code_fts VALUES (
+                'rrf_fuse', 'src/retrieval/fusion.rs::rrf_fuse',
+                'Reciprocal rank fusion of oracle results', 'code.function'
+            );",
+        )?;
+
+        let options = ScryOptions {
+            explain: true,
+            ..Default::default()
+        };
+        let results = lexical_search(&conn, "how does rank fusion work", &options)?;
+        assert_eq!(results.len(), 1);
+        let explain = results[0].explain.as_ref().expect("explain populated");
+        assert_eq!(explain.dimension, "lexical");
+        assert_eq!(explain.matched_terms, vec!["rank", "fusion"]);
+        assert_eq!(explain.raw_score, -results[0].score);
+
+        // Without --explain the debug fields stay empty
+        let results = lexical_search(&conn, "how does rank fusion work", &ScryOptions::default())?;
+        assert!(results[0].explain.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all_stop_words_query_skips_fts() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE VIRTUAL TABLE code_fts USING fts5(
+                symbol_name, file_path, content, event_type,
+                tokenize='porter unicode61'
+            );
+            INSERT INTO code_fts VALUES (
+                'explain', 'src/docs.rs::explain',
+                '// how does it do that', 'code.function'
+            );",
+        )?;
+
+        // Without the guard this would phrase-match the comment above
+        assert!(is_too_generic("How does it do that?"));
+        let results = lexical_search(&conn, "How does it do that?", &ScryOptions::default())?;
+        assert!(results.is_empty());
+
+        // One technical term is enough to search
+        assert!(!is_too_generic("how does it explain that"));
+        assert_eq!(
+            lexical_search(&conn, "how does it explain that", &ScryOptions::default())?.len(),
+            1
+        );
+
+        Ok(())
+    }
+
+    fn scry_error(err: &anyhow::Error) -> &ScryError {
+        err.downcast_ref::<ScryError>()
+            .unwrap_or_else(|| panic!("expected ScryError, got: {}", err))
+    }
+
+    #[test]
+    fn test_unknown_dimension_is_typed() {
+        let options = ScryOptions {
+            dimension: Some("spatial".to_string()),
+            ..Default::default()
+        };
+        let err = scry_file("src/main.rs", &options).unwrap_err();
+        assert!(matches!(scry_error(&err), ScryError::UnknownDimension(d) if d == "spatial"));
+        assert_eq!(json_rpc_code(&err), -32602);
+    }
+
+    #[test]
+    fn test_missing_index_is_typed() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let err = require_index(temp.path().to_str().unwrap(), "temporal").unwrap_err();
+        assert!(matches!(
+            scry_error(&err),
+            ScryError::IndexMissing { dimension, .. } if dimension == "temporal"
+        ));
+        assert_eq!(http_status(&err), 503);
+        assert_eq!(crate::commands::exit::exit_code(&err), 4);
+    }
+
+    /// Temporal index whose key `i` is `points[i]` in the first two dimensions
+    fn write_temporal_index(dir: &Path, points: &[(f32, f32)]) -> Result<()> {
+        let index_path = dir.join("temporal.usearch");
+        let index = Index::new(&index_meta::load_options(&index_path))?;
+        index.reserve(points.len())?;
+        let dims = index.dimensions();
+        for (key, &(x, y)) in points.iter().enumerate() {
+            let mut v = vec![0.0_f32; dims];
+            v[0] = x;
+            v[1] = y;
+            index.add(key as u64, &v)?;
+        }
+        index.save(index_path.to_str().unwrap())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbors_returns_nearby_functions_by_similarity() -> Result<()> {
+        use patina::embeddings::id_offsets::code_key;
+
+        let temp = tempfile::TempDir::new()?;
+        let db_path = temp.path().join("patina.db");
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch(
+            "CREATE TABLE function_facts (file TEXT, name TEXT, parameters TEXT, return_type TEXT, is_public INTEGER, is_async INTEGER);
+             INSERT INTO function_facts VALUES
+                ('src/engine.rs', 'tick', '', '', 1, 0),
+                ('src/engine.rs', 'step', '', '', 1, 0),
+                ('src/render.rs', 'draw', '', '', 1, 0),
+                ('src/net.rs', 'connect', '', '', 1, 0);",
+        )?;
+        drop(conn);
+
+        // rowids 1-4 in order of distance from `tick`
+        let index_path = temp.path().join("semantic.usearch");
+        let index = Index::new(&index_meta::load_options(&index_path))?;
+        index.reserve(4)?;
+        let dims = index.dimensions();
+        for (rowid, (x, y)) in [
+            (1, (1.0, 0.0)),
+            (2, (0.9, 0.2)),
+            (3, (0.6, 0.6)),
+            (4, (0.0, 1.0)),
+        ] {
+            let mut v = vec![0.0_f32; dims];
+            v[0] = x;
+            v[1] = y;
+            index.add(code_key(rowid) as u64, &v)?;
+        }
+        index.save(index_path.to_str().unwrap())?;
+
+        let options = ScryOptions {
+            limit: 2,
+            ..Default::default()
+        };
+        let neighbors = |doc_id: &str| {
+            scry_neighbors_at(
+                db_path.to_str().unwrap(),
+                temp.path().to_str().unwrap(),
+                doc_id,
+                &options,
+            )
+        };
+
+        let results = neighbors("src/engine.rs::tick")?;
+        let ids: Vec<&str> = results.iter().map(|r| r.source_id.as_str()).collect();
+        assert_eq!(ids, vec!["src/engine.rs::step", "src/render.rs::draw"]);
+        assert!(results[0].score > results[1].score);
+
+        let err = neighbors("src/engine.rs::missing").unwrap_err();
+        assert!(err.to_string().contains("not found"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_temporal_file_results_show_cochange_count() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let db_path = temp.path().join("patina.db");
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch(
+            "CREATE TABLE co_changes (file_a TEXT, file_b TEXT, count INTEGER);
+             CREATE TABLE commits (sha TEXT PRIMARY KEY, timestamp TEXT);
+             CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             INSERT INTO co_changes VALUES
+                ('src/engine.rs', 'src/scheduler.rs', 14),
+                ('src/engine.rs', 'src/util.rs', 2);
+             INSERT INTO commits VALUES
+                ('a1', '2026-01-02T09:00:00+00:00'), ('b2', '2026-01-05T09:00:00+00:00');
+             INSERT INTO commit_files VALUES
+                ('a1', 'src/engine.rs'), ('a1', 'src/scheduler.rs'),
+                ('b2', 'src/engine.rs'), ('b2', 'src/scheduler.rs');",
+        )?;
+        drop(conn);
+
+        // Keys follow the sorted file list: engine, scheduler, util
+        write_temporal_index(temp.path(), &[(1.0, 0.0), (0.9, 0.1), (0.5, 0.5)])?;
+
+        let options = ScryOptions {
+            limit: 5,
+            ..Default::default()
+        };
+        let results = scry_file_at(
+            db_path.to_str().unwrap(),
+            temp.path().to_str().unwrap(),
+            "src/engine.rs",
+            &options,
+        )?;
+
+        let contents: Vec<(&str, &str)> = results
+            .iter()
+            .map(|r| (r.source_id.as_str(), r.content.as_str()))
+            .collect();
+        assert_eq!(
+            contents,
+            vec![
+                (
+                    "src/scheduler.rs",
+                    "Co-changes with: src/engine.rs (changed together 14 times, last 2026-01-05)"
+                ),
+                (
+                    "src/util.rs",
+                    "Co-changes with: src/engine.rs (changed together 2 times)"
+                ),
+            ]
+        );
+        assert!(results[0].timestamp.starts_with("2026-01-05"));
+        let counts: Vec<Option<i64>> = results.iter().map(|r| r.cochange_count).collect();
+        assert_eq!(counts, vec![Some(14), Some(2)]);
+        let json = serde_json::to_value(&results)?;
+        assert_eq!(json[0]["cochange_count"], 14);
+        assert_eq!(json[1]["cochange_count"], 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_search_times_every_phase() -> Result<()> {
+        use crate::commands::oxidize::trainer::Projection;
+
+        let temp = tempfile::TempDir::new()?;
+        let projection = Projection::new(8, 16, 4);
+        projection.save_safetensors(&temp.path().join("semantic.safetensors"))?;
+
+        // Width comes from the sibling projection
+        let index_path = temp.path().join("semantic.usearch");
+        let index = Index::new(&index_meta::load_options(&index_path))?;
+        index.reserve(2)?;
+        index.add(0, &[1.0, 0.0, 0.0, 0.0])?;
+        index.add(1, &[0.0, 1.0, 0.0, 0.0])?;
+        index.save(index_path.to_str().unwrap())?;
+
+        let embed = || {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            Ok(vec![0.5_f32; 8])
+        };
+        let (results, timings) = vector_search(
+            embed,
+            &temp.path().join("semantic.safetensors"),
+            index_path.to_str().unwrap(),
+            2,
+        )?;
+
+        assert_eq!(results.keys.len(), 2);
+        assert!(timings.embed >= std::time::Duration::from_millis(1));
+        for (phase, elapsed) in [
+            ("projection", timings.projection),
+            ("index load", timings.index_load),
+            ("search", timings.search),
+        ] {
+            assert!(!elapsed.is_zero(), "{} not timed", phase);
+        }
+        assert!(timings.report().starts_with("embed "));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_file_suggests_close_matches() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let db_path = temp.path().join("patina.db");
+        Connection::open(&db_path)?.execute_batch(
+            "CREATE TABLE co_changes (file_a TEXT, file_b TEXT, count INTEGER);
+             INSERT INTO co_changes VALUES
+                ('src/auth/session.rs', 'src/auth/token.rs', 4),
+                ('src/auth/session.rs', 'src/db/pool.rs', 1);",
+        )?;
+        write_temporal_index(temp.path(), &[(1.0, 0.0), (0.9, 0.1), (0.5, 0.5)])?;
+
+        let scry = |file: &str| {
+            scry_file_at(
+                db_path.to_str().unwrap(),
+                temp.path().to_str().unwrap(),
+                file,
+                &ScryOptions::default(),
+            )
+        };
+
+        let err = scry("auth/sesion.rs").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "File 'auth/sesion.rs' not found in temporal index; did you mean src/auth/session.rs?"
+        );
+
+        // Nothing close: no suggestion tacked on
+        let err = scry("docs/readme.md").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "File 'docs/readme.md' not found in temporal index"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_min_count_drops_weak_temporal_partners() -> Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        let db_path = temp.path().join("patina.db");
+        let conn = Connection::open(&db_path)?;
+        conn.execute_batch(
+            "CREATE TABLE co_changes (file_a TEXT, file_b TEXT, count INTEGER);
+             INSERT INTO co_changes VALUES
+                ('src/engine.rs', 'src/scheduler.rs', 5),
+                ('src/engine.rs', 'src/util.rs', 2);",
+        )?;
+        drop(conn);
+
+        // The weak partner (util) is also the nearest neighbour
+        write_temporal_index(temp.path(), &[(1.0, 0.0), (0.5, 0.5), (0.9, 0.1)])?;
+
+        let partners = |min_count: i64| -> Result<Vec<String>> {
+            let options = ScryOptions {
+                limit: 1,
+                min_count,
+                ..Default::default()
+            };
+            let results = scry_file_at(
+                db_path.to_str().unwrap(),
+                temp.path().to_str().unwrap(),
+                "src/engine.rs",
+                &options,
+            )?;
+            Ok(results.into_iter().map(|r| r.source_id).collect())
+        };
+
+        assert_eq!(partners(0)?, vec!["src/util.rs"]);
+        assert_eq!(partners(3)?, vec!["src/scheduler.rs"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_db_open_failure_is_typed() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("missing/dir/patina.db");
+        let err = open_db(db_path.to_str().unwrap()).unwrap_err();
+        assert!(matches!(scry_error(&err), ScryError::DbOpen { .. }));
+        assert!(std::error::Error::source(scry_error(&err)).is_some());
+    }
+
+    #[test]
+    fn test_embed_failure_is_typed() -> Result<()> {
+        let err = typed_embed(|| anyhow::bail!("model not cached")).unwrap_err();
+        assert!(matches!(scry_error(&err), ScryError::EmbedFailed(_)));
+        assert_eq!(http_status(&err), 500);
+        assert!(err.to_string().contains("model not cached"));
+
+        assert_eq!(typed_embed(|| Ok(vec![1.0]))?, vec![1.0]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_untyped_errors_map_to_internal() {
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(json_rpc_code(&err), -32603);
+        assert_eq!(http_status(&err), 500);
+    }
+}
@@ -4,11 +4,13 @@
 
 use anyhow::{Context, Result};
 use rusqlite::Connection;
+use std::path::Path;
 
-use crate::retrieval::{QueryEngine, QueryOptions};
+use crate::commands::exit;
+use crate::retrieval::{OracleContribution, QueryEngine, QueryOptions};
 use patina::eventlog;
 
-use super::enrichment::truncate_content;
+use super::enrichment::{lookup_stored, truncate_content};
 use super::logging::{get_query_results, log_scry_feedback, log_scry_use};
 
 // ============================================================================
@@ -21,6 +23,7 @@ pub struct OrientResult {
     pub path: String,
     pub score: f64,
     pub importer_count: i64,
+    pub centrality: f64,
     pub activity_level: String,
     pub is_entry_point: bool,
     pub is_test_file: bool,
@@ -39,19 +42,69 @@ pub fn execute_orient(dir_path: &str, limit: usize) -> Result<()> {
     let conn = Connection::open(eventlog::PATINA_DB)
         .with_context(|| "Failed to open database. Run 'patina scrape' first.")?;
 
-    // Check if module_signals table exists
-    let table_exists: bool = conn
-        .query_row(
-            "SELECT 1 FROM sqlite_master WHERE type='table' AND name='module_signals'",
-            [],
-            |_| Ok(true),
-        )
-        .unwrap_or(false);
+    let (results, derived) = rank_orient(&conn, dir_path, limit)?;
+    if !derived {
+        println!("Note: 'patina assay derive' has not been run; ranking by commit history only.\n");
+    }
+
+    if results.is_empty() {
+        println!("No files found in '{}' with structural signals.", dir_path);
+        println!("\nHint: Run 'patina assay derive' to compute signals for all files.");
+        return Ok(());
+    }
+
+    println!("Mode: Structural (file-level importance)\n");
+    println!("Found {} files:\n", results.len());
+    println!("{}", "─".repeat(70));
+
+    for (i, result) in results.iter().enumerate() {
+        let mut flags = Vec::new();
+        if result.is_entry_point {
+            flags.push("entry_point");
+        }
+        if result.is_test_file {
+            flags.push("test");
+        }
+        let flags_str = if flags.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", flags.join(", "))
+        };
 
-    if !table_exists {
-        anyhow::bail!("module_signals table not found. Run 'patina assay derive' first.");
+        println!("\n[{}] {} (score: {:.0})", i + 1, result.path, result.score);
+        println!(
+            "    {} importers | {:.2} centrality | {} activity | {} commits{}",
+            result.importer_count,
+            result.centrality,
+            result.activity_level,
+            result.commit_count,
+            flags_str
+        );
     }
 
+    println!("\n{}", "─".repeat(70));
+
+    Ok(())
+}
+
+fn table_exists(conn: &Connection, name: &str) -> bool {
+    conn.query_row(
+        "SELECT 1 FROM sqlite_master WHERE type='table' AND name=?",
+        [name],
+        |_| Ok(true),
+    )
+    .unwrap_or(false)
+}
+
+/// Rank files under `dir_path` by structural importance
+///
+/// Uses the `module_signals` table from `patina assay derive` when present
+/// (returns `true`); otherwise falls back to commit counts from `commit_files`.
+fn rank_orient(
+    conn: &Connection,
+    dir_path: &str,
+    limit: usize,
+) -> Result<(Vec<OrientResult>, bool)> {
     // Normalize path for matching (strip trailing slash, ensure ./ prefix)
     let normalized_path = dir_path.trim_end_matches('/');
     let normalized_path = if normalized_path.starts_with("./") {
@@ -60,10 +113,22 @@ pub fn execute_orient(dir_path: &str, limit: usize) -> Result<()> {
         format!("./{}", normalized_path)
     };
 
+    if table_exists(conn, "module_signals") {
+        return Ok((rank_by_signals(conn, &normalized_path, limit)?, true));
+    }
+    if table_exists(conn, "commit_files") {
+        return Ok((rank_by_commits(conn, &normalized_path, limit)?, false));
+    }
+    anyhow::bail!("module_signals table not found. Run 'patina assay derive' first.");
+}
+
+/// Composite ranking over derived module signals
+fn rank_by_signals(conn: &Connection, dir_path: &str, limit: usize) -> Result<Vec<OrientResult>> {
     // Query files in directory, compute composite score, rank
     // Composite score formula:
     // - is_entry_point: +20 (entry points are critical for orientation)
     // - importer_count: +2 per importer (up to 20)
+    // - centrality_score: +10 per unit of call-graph degree (up to 10)
     // - activity_level: high=10, medium=5, low=2, dormant=0
     // - commit_count: tiered (1-5: +2, 6-20: +5, 21-50: +8, 51+: +10)
     // - is_test_file: -5 (deprioritize tests for orientation)
@@ -72,6 +137,7 @@ pub fn execute_orient(dir_path: &str, limit: usize) -> Result<()> {
             path,
             COALESCE(is_entry_point, 0) * 20 +
             MIN(COALESCE(importer_count, 0) * 2, 20) +
+            MIN(COALESCE(centrality_score, 0.0) * 10, 10) +
             CASE COALESCE(activity_level, 'dormant')
                 WHEN 'high' THEN 10
                 WHEN 'medium' THEN 5
@@ -88,86 +154,115 @@ pub fn execute_orient(dir_path: &str, limit: usize) -> Result<()> {
             COALESCE(is_test_file, 0) * 5
             AS composite_score,
             COALESCE(importer_count, 0) as importer_count,
+            COALESCE(centrality_score, 0.0) as centrality_score,
             COALESCE(activity_level, 'unknown') as activity_level,
             COALESCE(is_entry_point, 0) as is_entry_point,
             COALESCE(is_test_file, 0) as is_test_file,
             COALESCE(commit_count, 0) as commit_count
         FROM module_signals
         WHERE path LIKE ?
-        ORDER BY composite_score DESC
+        ORDER BY composite_score DESC, path
         LIMIT ?
     ";
 
-    let pattern = format!("{}%", normalized_path);
+    let pattern = format!("{}%", dir_path);
     let mut stmt = conn.prepare(sql)?;
-    let results: Vec<OrientResult> = stmt
+    let results = stmt
         .query_map(rusqlite::params![pattern, limit as i64], |row| {
             Ok(OrientResult {
                 path: row.get(0)?,
                 score: row.get(1)?,
                 importer_count: row.get(2)?,
-                activity_level: row.get(3)?,
-                is_entry_point: row.get::<_, i64>(4)? != 0,
-                is_test_file: row.get::<_, i64>(5)? != 0,
-                commit_count: row.get(6)?,
+                centrality: row.get(3)?,
+                activity_level: row.get(4)?,
+                is_entry_point: row.get::<_, i64>(5)? != 0,
+                is_test_file: row.get::<_, i64>(6)? != 0,
+                commit_count: row.get(7)?,
             })
         })?
         .filter_map(|r| r.ok())
         .collect();
+    Ok(results)
+}
 
-    if results.is_empty() {
-        println!("No files found in '{}' with structural signals.", dir_path);
-        println!("\nHint: Run 'patina assay derive' to compute signals for all files.");
-        return Ok(());
-    }
-
-    println!("Mode: Structural (file-level importance)\n");
-    println!("Found {} files:\n", results.len());
-    println!("{}", "─".repeat(70));
-
-    for (i, result) in results.iter().enumerate() {
-        let mut flags = Vec::new();
-        if result.is_entry_point {
-            flags.push("entry_point");
-        }
-        if result.is_test_file {
-            flags.push("test");
-        }
-        let flags_str = if flags.is_empty() {
-            String::new()
-        } else {
-            format!(" [{}]", flags.join(", "))
-        };
-
-        println!("\n[{}] {} (score: {:.0})", i + 1, result.path, result.score);
-        println!(
-            "    {} importers | {} activity | {} commits{}",
-            result.importer_count, result.activity_level, result.commit_count, flags_str
-        );
-    }
-
-    println!("\n{}", "─".repeat(70));
+/// Fallback ranking from git history alone (before `assay derive`)
+fn rank_by_commits(conn: &Connection, dir_path: &str, limit: usize) -> Result<Vec<OrientResult>> {
+    // commit_files paths have no ./ prefix
+    let pattern = format!("{}%", dir_path.trim_start_matches("./"));
+    let mut stmt = conn.prepare(
+        "SELECT file_path, COUNT(DISTINCT sha) FROM commit_files
+         WHERE file_path LIKE ?
+         GROUP BY file_path",
+    )?;
+    let mut results: Vec<OrientResult> = stmt
+        .query_map([pattern], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
+        })?
+        .filter_map(|r| r.ok())
+        .map(|(path, commit_count)| {
+            let entry_point = crate::commands::assay::is_entry_point(&path);
+            let test_file = crate::commands::assay::is_test_file(&path);
+            let score = (commit_count.min(50) as f64) / 5.0 + if entry_point { 20.0 } else { 0.0 }
+                - if test_file { 5.0 } else { 0.0 };
+            OrientResult {
+                path,
+                score,
+                importer_count: 0,
+                centrality: 0.0,
+                activity_level: "unknown".to_string(),
+                is_entry_point: entry_point,
+                is_test_file: test_file,
+                commit_count,
+            }
+        })
+        .collect();
 
-    Ok(())
+    results.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    results.truncate(limit);
+    Ok(results)
 }
 
 // ============================================================================
 // Scry Recent - Temporal-first ranking
 // ============================================================================
 
+/// Most recent commit touching a file, within the `scry recent` window
+#[derive(Debug)]
+pub struct RecentChange {
+    pub path: String,
+    pub timestamp: String,
+    pub message: String,
+    pub author: String,
+    pub change_count: usize,
+}
+
 /// Execute recent subcommand - show recently changed files
 ///
 /// From spec-observable-scry.md:
 /// - Temporal-first reranking
 /// - "What changed related to X?"
-pub fn execute_recent(query: Option<&str>, days: u32, limit: usize) -> Result<()> {
+///
+/// Recency comes from commit dates, never file mtimes (checkouts reset those).
+pub fn execute_recent(
+    query: Option<&str>,
+    days: u32,
+    limit: usize,
+    author: Option<&str>,
+) -> Result<()> {
     println!(
-        "🔮 Scry Recent - What changed{}\n",
+        "🔮 Scry Recent - What changed{}{}\n",
         query
             .map(|q| format!(" related to '{}'", q))
-            .unwrap_or_default()
+            .unwrap_or_default(),
+        author.map(|a| format!(" by '{}'", a)).unwrap_or_default()
     );
 
+    exit::require_db(Path::new(eventlog::PATINA_DB))?;
     let conn = Connection::open(eventlog::PATINA_DB)
         .with_context(|| "Failed to open database. Run 'patina scrape' first.")?;
 
@@ -175,80 +270,20 @@ pub fn execute_recent(query: Option<&str>, days: u32, limit: usize) -> Result<()
     let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
     let cutoff_str = cutoff.format("%Y-%m-%d").to_string();
 
-    // Query recent commits with file changes
-    let sql = if query.is_some() {
-        // Filter by query pattern in file path
-        "SELECT
-            cf.file_path,
-            c.timestamp,
-            c.message,
-            c.author_name,
-            COUNT(*) OVER (PARTITION BY cf.file_path) as change_count
-        FROM commits c
-        JOIN commit_files cf ON c.sha = cf.sha
-        WHERE c.timestamp >= ?
-          AND cf.file_path LIKE ?
-        ORDER BY c.timestamp DESC
-        LIMIT ?"
-    } else {
-        // All recent changes
-        "SELECT
-            cf.file_path,
-            c.timestamp,
-            c.message,
-            c.author_name,
-            COUNT(*) OVER (PARTITION BY cf.file_path) as change_count
-        FROM commits c
-        JOIN commit_files cf ON c.sha = cf.sha
-        WHERE c.timestamp >= ?
-        ORDER BY c.timestamp DESC
-        LIMIT ?"
-    };
+    let mut changes = recent_changes(&conn, &cutoff_str, author)?;
 
-    let mut stmt = conn.prepare(sql)?;
-
-    let results: Vec<(String, String, String, String, i64)> = if let Some(q) = query {
-        let pattern = format!("%{}%", q);
-        stmt.query_map(
-            rusqlite::params![cutoff_str, pattern, limit as i64 * 3],
-            |row| {
-                Ok((
-                    row.get::<_, String>(0)?,
-                    row.get::<_, String>(1)?,
-                    row.get::<_, String>(2)?,
-                    row.get::<_, String>(3)?,
-                    row.get::<_, i64>(4)?,
-                ))
-            },
-        )?
-        .filter_map(|r| r.ok())
-        .collect()
-    } else {
-        stmt.query_map(rusqlite::params![cutoff_str, limit as i64 * 3], |row| {
-            Ok((
-                row.get::<_, String>(0)?,
-                row.get::<_, String>(1)?,
-                row.get::<_, String>(2)?,
-                row.get::<_, String>(3)?,
-                row.get::<_, i64>(4)?,
-            ))
-        })?
-        .filter_map(|r| r.ok())
-        .collect()
-    };
+    // Keep files whose path or commit message mentions the query, plus files
+    // the retrieval engine considers related to it
+    if let Some(q) = query {
+        retain_related(&mut changes, q, &related_files(q));
+    }
 
-    if results.is_empty() {
+    if changes.is_empty() {
         println!("No changes found in the last {} days.", days);
-        return Ok(());
+        return exit::ensure_found(0);
     }
 
-    // Deduplicate by file path, keeping most recent
-    let mut seen = std::collections::HashSet::new();
-    let unique_results: Vec<_> = results
-        .into_iter()
-        .filter(|(path, _, _, _, _)| seen.insert(path.clone()))
-        .take(limit)
-        .collect();
+    let unique_results: Vec<_> = changes.into_iter().take(limit).collect();
 
     println!("Mode: Temporal (last {} days)\n", days);
     println!(
@@ -257,8 +292,14 @@ pub fn execute_recent(query: Option<&str>, days: u32, limit: usize) -> Result<()
     );
     println!("{}", "─".repeat(70));
 
-    for (i, (path, timestamp, message, author, _change_count)) in unique_results.iter().enumerate()
-    {
+    for (i, change) in unique_results.iter().enumerate() {
+        let RecentChange {
+            path,
+            timestamp,
+            message,
+            author,
+            change_count,
+        } = change;
         // Parse and format timestamp
         let date = timestamp.split('T').next().unwrap_or(timestamp);
         let short_msg: String = message.chars().take(50).collect();
@@ -268,7 +309,12 @@ pub fn execute_recent(query: Option<&str>, days: u32, limit: usize) -> Result<()
             short_msg
         };
 
-        println!("\n[{}] {} ({})", i + 1, path, date);
+        let changes = if *change_count > 1 {
+            format!(" ({} changes)", change_count)
+        } else {
+            String::new()
+        };
+        println!("\n[{}] {} ({}){}", i + 1, path, date, changes);
         println!("    {} - {}", author, short_msg);
     }
 
@@ -277,15 +323,137 @@ pub fn execute_recent(query: Option<&str>, days: u32, limit: usize) -> Result<()
     Ok(())
 }
 
+/// Per-file latest commit since `cutoff`, newest first
+///
+/// Reads the `files` array of `git.commit` events. Reference repos skip the
+/// eventlog for commits, so those fall back to `commits` + `commit_files`.
+/// With `author`, only that author's commits count (case-insensitive substring).
+fn recent_changes(
+    conn: &Connection,
+    cutoff: &str,
+    author: Option<&str>,
+) -> Result<Vec<RecentChange>> {
+    let has_events: bool = conn
+        .query_row(
+            "SELECT EXISTS(SELECT 1 FROM eventlog WHERE event_type = 'git.commit')",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(false);
+
+    let sql = if has_events {
+        "SELECT json_extract(f.value, '$.path'), e.timestamp,
+                COALESCE(json_extract(e.data, '$.message'), ''),
+                COALESCE(json_extract(e.data, '$.author_name'), '')
+         FROM eventlog e, json_each(e.data, '$.files') f
+         WHERE e.event_type = 'git.commit' AND e.timestamp >= ?
+         ORDER BY e.timestamp DESC, e.seq DESC"
+    } else {
+        "SELECT cf.file_path, c.timestamp, COALESCE(c.message, ''), COALESCE(c.author_name, '')
+         FROM commits c
+         JOIN commit_files cf ON c.sha = cf.sha
+         WHERE c.timestamp >= ?
+         ORDER BY c.timestamp DESC"
+    };
+
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt
+        .query_map([cutoff], |row| {
+            Ok((
+                row.get::<_, Option<String>>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+            ))
+        })?
+        .filter_map(|r| r.ok());
+
+    // Rows arrive newest first, so the first sighting of a path is its latest change
+    let mut changes: Vec<RecentChange> = Vec::new();
+    let mut by_path: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    let wanted = author.map(str::to_lowercase);
+    for (path, timestamp, message, author) in rows {
+        let Some(path) = path else { continue };
+        if wanted
+            .as_ref()
+            .is_some_and(|w| !author.to_lowercase().contains(w.as_str()))
+        {
+            continue;
+        }
+        match by_path.get(&path) {
+            Some(&i) => changes[i].change_count += 1,
+            None => {
+                by_path.insert(path.clone(), changes.len());
+                changes.push(RecentChange {
+                    path,
+                    timestamp,
+                    message,
+                    author,
+                    change_count: 1,
+                });
+            }
+        }
+    }
+    Ok(changes)
+}
+
+/// Keep changes whose path or message mentions `query`, or whose file is in `related`
+fn retain_related(
+    changes: &mut Vec<RecentChange>,
+    query: &str,
+    related: &std::collections::HashSet<String>,
+) {
+    let needle = query.to_lowercase();
+    changes.retain(|c| {
+        c.path.to_lowercase().contains(&needle)
+            || c.message.to_lowercase().contains(&needle)
+            || related.contains(c.path.trim_start_matches("./"))
+    });
+}
+
+/// Files the retrieval engine ranks as related to `query` (empty if unavailable)
+fn related_files(query: &str) -> std::collections::HashSet<String> {
+    let engine = QueryEngine::new();
+    engine
+        .query_with_options(query, 50, &QueryOptions::default())
+        .map(|results| related_paths(results.into_iter().filter_map(|r| r.metadata.file_path)))
+        .unwrap_or_default()
+}
+
+/// Bare file paths behind result paths (`./src/x.rs::f` -> `src/x.rs`)
+///
+/// Semantic results carry a leading `./` that git paths don't.
+fn related_paths(paths: impl Iterator<Item = String>) -> std::collections::HashSet<String> {
+    paths
+        .map(|p| {
+            let file = p.split_once("::").map_or(p.as_str(), |(file, _)| file);
+            file.trim_start_matches("./").to_string()
+        })
+        .collect()
+}
+
 // ============================================================================
 // Scry Why - Explain single result
 // ============================================================================
 
+/// Whether two result IDs name the same document
+///
+/// Oracles emit code as `path::name` or `path:name` and beliefs with or
+/// without a `belief:` prefix; suffix matches allow short paths.
+fn doc_ids_match(a: &str, b: &str) -> bool {
+    let normalize = |id: &str| id.strip_prefix("belief:").unwrap_or(id).replace("::", ":");
+    let (a, b) = (normalize(a), normalize(b));
+    a == b || a.ends_with(&b) || b.ends_with(&a)
+}
+
 /// Execute why subcommand - explain why a result was returned
 ///
 /// From spec-observable-scry.md:
 /// - Explain single result provenance
 /// - Shows all oracle contributions for a specific doc
+///
+/// Works for any result type: files, code functions, beliefs, patterns,
+/// commits, and issues/PRs resolve to their stored record by ID range.
 pub fn execute_why(doc_id: &str, query: &str) -> Result<()> {
     println!("🔮 Scry Why - Explaining '{}'\n", doc_id);
     println!("Query: \"{}\"\n", query);
@@ -296,43 +464,55 @@ pub fn execute_why(doc_id: &str, query: &str) -> Result<()> {
     // Run the query to get full results with contributions
     let results = engine.query_with_options(query, 50, &options)?;
 
-    // Find the specific doc_id in results
-    let matching = results
-        .iter()
-        .find(|r| r.doc_id == doc_id || r.doc_id.ends_with(doc_id) || doc_id.ends_with(&r.doc_id));
-
-    match matching {
-        Some(result) => {
-            println!(
-                "Found in results at rank {}:\n",
-                results
-                    .iter()
-                    .position(|r| r.doc_id == result.doc_id)
-                    .unwrap_or(0)
-                    + 1
-            );
+    // Stored record (function, belief, pattern, commit, issue) behind the ID
+    let stored = Connection::open(eventlog::PATINA_DB)
+        .ok()
+        .and_then(|conn| lookup_stored(&conn, doc_id));
+
+    // Find the specific doc_id in results, under either spelling of its ID
+    let position = results.iter().position(|r| {
+        doc_ids_match(&r.doc_id, doc_id)
+            || stored
+                .as_ref()
+                .is_some_and(|s| doc_ids_match(&r.doc_id, &s.source_id))
+    });
+
+    match position {
+        Some(index) => {
+            let result = &results[index];
+            println!("Found in results at rank {}:\n", index + 1);
             println!("{}", "─".repeat(60));
 
             println!("\n**Document:** {}", result.doc_id);
             println!("**Fused Score:** {:.4}", result.fused_score);
             println!(
                 "**Type:** {}",
-                result.metadata.event_type.as_deref().unwrap_or("unknown")
+                stored
+                    .as_ref()
+                    .map(|s| s.event_type.as_str())
+                    .or(result.metadata.event_type.as_deref())
+                    .unwrap_or("unknown")
             );
 
+            // The oracle that ranked it highest is the dimension it matched on
+            if let Some((oracle_name, contrib)) =
+                result.contributions.iter().min_by_key(|(_, c)| c.rank)
+            {
+                println!(
+                    "**Matched Dimension:** {} ({})",
+                    oracle_name,
+                    format_contribution_score(contrib)
+                );
+            }
+
             println!("\n## Oracle Contributions\n");
 
             for (oracle_name, contrib) in &result.contributions {
-                let score_display = match contrib.score_type {
-                    "co_change_count" => format!("{} co-changes", contrib.raw_score as i32),
-                    "bm25" => format!("{:.2} BM25", contrib.raw_score),
-                    "cosine" => format!("{:.3} cosine", contrib.raw_score),
-                    _ => format!("{:.3} {}", contrib.raw_score, contrib.score_type),
-                };
-
                 println!(
                     "- **{}**: rank #{} ({})",
-                    oracle_name, contrib.rank, score_display
+                    oracle_name,
+                    contrib.rank,
+                    format_contribution_score(contrib)
                 );
 
                 if let Some(ref matches) = contrib.matches {
@@ -361,12 +541,20 @@ pub fn execute_why(doc_id: &str, query: &str) -> Result<()> {
             }
 
             println!("\n## Content Preview\n");
-            println!("{}", truncate_content(&result.content, 300));
+            let content = stored
+                .as_ref()
+                .map(|s| s.content.as_str())
+                .unwrap_or(&result.content);
+            println!("{}", truncate_content(content, 300));
 
             println!("\n{}", "─".repeat(60));
         }
         None => {
             println!("'{}' not found in top 50 results for this query.", doc_id);
+            if let Some(ref s) = stored {
+                println!("\n## Stored Content ({})\n", s.event_type);
+                println!("{}", truncate_content(&s.content, 300));
+            }
             println!("\nTop 5 results were:");
             for (i, r) in results.iter().take(5).enumerate() {
                 println!("  {}. {}", i + 1, r.doc_id);
@@ -377,6 +565,16 @@ pub fn execute_why(doc_id: &str, query: &str) -> Result<()> {
     Ok(())
 }
 
+/// Render an oracle's raw score with its unit
+fn format_contribution_score(contrib: &OracleContribution) -> String {
+    match contrib.score_type {
+        "co_change_count" => format!("{} co-changes", contrib.raw_score as i32),
+        "bm25" => format!("{:.2} BM25", contrib.raw_score),
+        "cosine" => format!("{:.3} cosine", contrib.raw_score),
+        _ => format!("{:.3} {}", contrib.raw_score, contrib.score_type),
+    }
+}
+
 // ============================================================================
 // Scry Open - Open file and log usage (Phase 3)
 // ============================================================================
@@ -464,6 +662,8 @@ pub fn execute_open(query_id: &str, rank: usize) -> Result<()> {
 /// From spec-observable-scry.md:
 /// - Copies to clipboard, logs usage automatically
 /// - Automatic capture (no user effort required)
+///
+/// Headless environments without a clipboard tool get a temp file instead.
 pub fn execute_copy(query_id: &str, rank: usize) -> Result<()> {
     println!(
         "🔮 Scry Copy - Copying result #{} from {}\n",
@@ -488,83 +688,156 @@ pub fn execute_copy(query_id: &str, rank: usize) -> Result<()> {
     // Log usage before copying
     log_scry_use(query_id, doc_id, rank);
 
-    // Copy to clipboard using platform-specific command
-    let copy_result = copy_to_clipboard(doc_id);
-
-    match copy_result {
-        Ok(()) => {
-            println!("Copied to clipboard: {}", doc_id);
-            println!("Usage logged: {} rank #{}", query_id, rank);
+    // Copy to clipboard, or a temp file when no clipboard tool is usable
+    let fallback = std::env::temp_dir().join(format!("patina-copy-{}-{}.txt", query_id, rank));
+    match copy_with(doc_id, clipboard_backends(), backend_available, &fallback) {
+        Ok(CopyOutcome::Clipboard(backend)) => {
+            println!("Copied to clipboard ({}): {}", backend, doc_id);
+        }
+        Ok(CopyOutcome::File { path, tried }) => {
+            if tried.is_empty() {
+                println!("No clipboard backend for this platform");
+            } else {
+                println!("No usable clipboard backend (tried: {})", tried.join(", "));
+            }
+            println!("Document ID: {}", doc_id);
+            println!("Written to: {}", path.display());
         }
         Err(e) => {
-            println!("Failed to copy to clipboard: {}", e);
+            println!("Failed to copy: {}", e);
             println!("Document ID: {}", doc_id);
-            println!("(Usage still logged for feedback analysis)");
         }
     }
+    println!("Usage logged: {} rank #{}", query_id, rank);
 
     Ok(())
 }
 
-/// Copy text to system clipboard
-fn copy_to_clipboard(text: &str) -> Result<()> {
+/// A clipboard command that reads the text on stdin
+struct ClipboardBackend {
+    program: &'static str,
+    args: &'static [&'static str],
+    /// Environment variable that must be set for the tool to work (display server)
+    requires_env: Option<&'static str>,
+}
+
+/// Where copied text ended up
+#[derive(Debug, PartialEq)]
+enum CopyOutcome {
+    Clipboard(&'static str),
+    File {
+        path: std::path::PathBuf,
+        tried: Vec<&'static str>,
+    },
+}
+
+/// Clipboard backends for this platform, in preference order
+fn clipboard_backends() -> &'static [ClipboardBackend] {
     #[cfg(target_os = "macos")]
     {
-        use std::io::Write;
-        let mut child = std::process::Command::new("pbcopy")
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        child.wait()?;
-        Ok(())
+        &[ClipboardBackend {
+            program: "pbcopy",
+            args: &[],
+            requires_env: None,
+        }]
     }
 
     #[cfg(target_os = "linux")]
     {
-        use std::io::Write;
-        // Try xclip first, then xsel
-        let result = std::process::Command::new("xclip")
-            .args(["-selection", "clipboard"])
-            .stdin(std::process::Stdio::piped())
-            .spawn();
-
-        let mut child = match result {
-            Ok(c) => c,
-            Err(_) => std::process::Command::new("xsel")
-                .args(["--clipboard", "--input"])
-                .stdin(std::process::Stdio::piped())
-                .spawn()?,
-        };
-
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        child.wait()?;
-        Ok(())
+        &[
+            ClipboardBackend {
+                program: "wl-copy",
+                args: &[],
+                requires_env: Some("WAYLAND_DISPLAY"),
+            },
+            ClipboardBackend {
+                program: "xclip",
+                args: &["-selection", "clipboard"],
+                requires_env: Some("DISPLAY"),
+            },
+            ClipboardBackend {
+                program: "xsel",
+                args: &["--clipboard", "--input"],
+                requires_env: Some("DISPLAY"),
+            },
+        ]
     }
 
     #[cfg(target_os = "windows")]
     {
-        use std::io::Write;
-        let mut child = std::process::Command::new("cmd")
-            .args(["/C", "clip"])
-            .stdin(std::process::Stdio::piped())
-            .spawn()?;
-        if let Some(mut stdin) = child.stdin.take() {
-            stdin.write_all(text.as_bytes())?;
-        }
-        child.wait()?;
-        Ok(())
+        &[ClipboardBackend {
+            program: "clip",
+            args: &[],
+            requires_env: None,
+        }]
     }
 
     #[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
     {
-        anyhow::bail!("Clipboard not supported on this platform");
+        &[]
     }
 }
 
+/// Whether a backend's tool is on PATH and its display server is reachable
+///
+/// Without this check, containers and SSH sessions fail at spawn time or,
+/// worse, xclip hangs waiting for an X server.
+fn backend_available(backend: &ClipboardBackend) -> bool {
+    if let Some(var) = backend.requires_env {
+        if std::env::var_os(var).is_none_or(|v| v.is_empty()) {
+            return false;
+        }
+    }
+    let Some(path) = std::env::var_os("PATH") else {
+        return false;
+    };
+    std::env::split_paths(&path).any(|dir| {
+        dir.join(backend.program).is_file()
+            || dir.join(format!("{}.exe", backend.program)).is_file()
+    })
+}
+
+/// Run one clipboard backend with `text` on stdin
+fn run_backend(backend: &ClipboardBackend, text: &str) -> Result<()> {
+    use std::io::Write;
+
+    let mut child = std::process::Command::new(backend.program)
+        .args(backend.args)
+        .stdin(std::process::Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        anyhow::bail!("{} exited with {}", backend.program, status);
+    }
+    Ok(())
+}
+
+/// Copy via the first available backend that succeeds, else write `fallback`
+fn copy_with(
+    text: &str,
+    backends: &[ClipboardBackend],
+    available: impl Fn(&ClipboardBackend) -> bool,
+    fallback: &std::path::Path,
+) -> Result<CopyOutcome> {
+    let mut tried = Vec::new();
+    for backend in backends {
+        tried.push(backend.program);
+        if available(backend) && run_backend(backend, text).is_ok() {
+            return Ok(CopyOutcome::Clipboard(backend.program));
+        }
+    }
+
+    std::fs::write(fallback, text)
+        .with_context(|| format!("Failed to write {}", fallback.display()))?;
+    Ok(CopyOutcome::File {
+        path: fallback.to_path_buf(),
+        tried,
+    })
+}
+
 // ============================================================================
 // Scry Feedback - Explicit rating (Phase 3)
 // ============================================================================
@@ -599,3 +872,189 @@ pub fn execute_feedback(query_id: &str, signal: &str, comment: Option<&str>) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_recent_changes_come_from_commit_events() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = eventlog::initialize(&temp.path().join("patina.db"))?;
+        let commit = |timestamp: &str, sha: &str, message: &str, files: &[&str]| {
+            let files: Vec<_> = files
+                .iter()
+                .map(|f| serde_json::json!({ "path": f, "change_type": "M" }))
+                .collect();
+            let data = serde_json::json!({
+                "sha": sha,
+                "message": message,
+                "author_name": "dev",
+                "files": files,
+            });
+            eventlog::insert_event(&conn, "git.commit", timestamp, sha, None, &data.to_string())
+        };
+        commit(
+            "2025-11-02T09:00:00+00:00",
+            "a1",
+            "old work",
+            &["src/old.rs", "src/main.rs"],
+        )?;
+        commit(
+            "2026-01-03T09:00:00+00:00",
+            "b2",
+            "tweak main",
+            &["src/main.rs"],
+        )?;
+        commit(
+            "2026-01-05T09:00:00+00:00",
+            "c3",
+            "add scry recent",
+            &["src/main.rs", "src/recent.rs"],
+        )?;
+
+        let changes = recent_changes(&conn, "2026-01-01", None)?;
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/main.rs", "src/recent.rs"]);
+        assert_eq!(changes[0].message, "add scry recent");
+        assert_eq!(changes[0].change_count, 2);
+        assert!(changes[0].timestamp.starts_with("2026-01-05"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recent_author_filter_keeps_only_their_files() -> Result<()> {
+        let temp = TempDir::new()?;
+        let conn = eventlog::initialize(&temp.path().join("patina.db"))?;
+        let commit = |timestamp: &str, sha: &str, author: &str, files: &[&str]| {
+            let files: Vec<_> = files
+                .iter()
+                .map(|f| serde_json::json!({ "path": f, "change_type": "M" }))
+                .collect();
+            let data = serde_json::json!({
+                "sha": sha,
+                "message": "work",
+                "author_name": author,
+                "files": files,
+            });
+            eventlog::insert_event(&conn, "git.commit", timestamp, sha, None, &data.to_string())
+        };
+        commit(
+            "2026-01-02T09:00:00+00:00",
+            "a1",
+            "Alice Smith",
+            &["src/engine.rs", "src/shared.rs"],
+        )?;
+        // Bob's later commit to shared.rs must not hide Alice's change to it
+        commit(
+            "2026-01-03T09:00:00+00:00",
+            "b1",
+            "Bob",
+            &["src/shared.rs", "src/bob.rs"],
+        )?;
+
+        let changes = recent_changes(&conn, "2026-01-01", Some("alice"))?;
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/engine.rs", "src/shared.rs"]);
+        assert!(changes.iter().all(|c| c.author == "Alice Smith"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recent_related_matches_semantic_and_git_paths() {
+        let change = |path: &str| RecentChange {
+            path: path.to_string(),
+            timestamp: "2026-01-05T09:00:00+00:00".to_string(),
+            message: "refactor".to_string(),
+            author: "dev".to_string(),
+            change_count: 1,
+        };
+        let mut changes = vec![
+            change("src/engine.rs"),
+            change("./src/world.rs"),
+            change("src/unrelated.rs"),
+        ];
+        // Semantic results spell paths with "./", lexical ones without
+        let related = related_paths(
+            [
+                "./src/engine.rs::tick".to_string(),
+                "src/world.rs".to_string(),
+            ]
+            .into_iter(),
+        );
+
+        retain_related(&mut changes, "scheduling", &related);
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/engine.rs", "./src/world.rs"]);
+    }
+
+    #[test]
+    fn test_orient_ranks_central_entry_point_above_dormant_test() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE module_signals (path TEXT PRIMARY KEY, importer_count INTEGER, activity_level TEXT,
+                 centrality_score REAL, commit_count INTEGER, is_entry_point INTEGER, is_test_file INTEGER);
+             INSERT INTO module_signals VALUES ('./src/tests/fixtures.rs', 0, 'dormant', 0.0, 1, 0, 1);
+             INSERT INTO module_signals VALUES ('./src/util.rs', 1, 'low', 0.1, 3, 0, 0);
+             INSERT INTO module_signals VALUES ('./src/main.rs', 4, 'high', 1.5, 60, 1, 0);
+             INSERT INTO module_signals VALUES ('./docs/notes.rs', 9, 'high', 2.0, 80, 1, 0);",
+        )?;
+
+        let (results, derived) = rank_orient(&conn, "src/", 10)?;
+        assert!(derived);
+        let paths: Vec<&str> = results.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["./src/main.rs", "./src/util.rs", "./src/tests/fixtures.rs"]
+        );
+        assert_eq!(results[0].centrality, 1.5);
+        assert!(results[2].score < 0.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_orient_falls_back_to_commits_without_derive() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE commit_files (sha TEXT, file_path TEXT);
+             INSERT INTO commit_files VALUES ('a', 'src/lib.rs'), ('b', 'src/lib.rs'), ('a', 'src/tests/it.rs');",
+        )?;
+
+        let (results, derived) = rank_orient(&conn, "src", 10)?;
+        assert!(!derived);
+        assert_eq!(results[0].path, "src/lib.rs");
+        assert_eq!(results[0].commit_count, 2);
+
+        assert!(rank_orient(&Connection::open_in_memory()?, "src", 10).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_copy_falls_back_to_file_without_backend() -> Result<()> {
+        let temp = TempDir::new()?;
+        let fallback = temp.path().join("copy.txt");
+        let backends = [ClipboardBackend {
+            program: "xclip",
+            args: &["-selection", "clipboard"],
+            requires_env: Some("DISPLAY"),
+        }];
+
+        let outcome = copy_with("src/main.rs::main", &backends, |_| false, &fallback)?;
+
+        assert_eq!(
+            outcome,
+            CopyOutcome::File {
+                path: fallback.clone(),
+                tried: vec!["xclip"],
+            }
+        );
+        assert_eq!(std::fs::read_to_string(&fallback)?, "src/main.rs::main");
+
+        Ok(())
+    }
+}
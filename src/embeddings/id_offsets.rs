@@ -0,0 +1,216 @@
+//! Key layout of the shared semantic USearch index
+//!
+//! Session events keep their eventlog `seq`; every other content type is
+//! offset so keys never collide. Forge items are keyed by eventlog `seq` too,
+//! functions, patterns, commits, and beliefs by their table rowid. Everything
+//! that writes or reads semantic keys goes through here so the ranges can't
+//! drift apart.
+
+/// function_facts rowid
+pub const CODE_ID_OFFSET: i64 = 1_000_000_000;
+/// patterns rowid
+pub const PATTERN_ID_OFFSET: i64 = 2_000_000_000;
+/// commits rowid
+pub const COMMIT_ID_OFFSET: i64 = 3_000_000_000;
+/// beliefs rowid
+pub const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
+/// eventlog seq of the latest forge.issue / forge.pr event
+pub const FORGE_ID_OFFSET: i64 = 5_000_000_000;
+
+/// Content type a semantic index key belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum KeyKind {
+    Session,
+    Code,
+    Pattern,
+    Commit,
+    Belief,
+    Forge,
+}
+
+impl KeyKind {
+    /// All kinds, in key order
+    pub const ALL: [KeyKind; 6] = [
+        KeyKind::Session,
+        KeyKind::Code,
+        KeyKind::Pattern,
+        KeyKind::Commit,
+        KeyKind::Belief,
+        KeyKind::Forge,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyKind::Session => "sessions",
+            KeyKind::Code => "functions",
+            KeyKind::Pattern => "patterns",
+            KeyKind::Commit => "commits",
+            KeyKind::Belief => "beliefs",
+            KeyKind::Forge => "forge",
+        }
+    }
+
+    /// First key of this kind's range
+    pub fn offset(&self) -> i64 {
+        match self {
+            KeyKind::Session => 0,
+            KeyKind::Code => CODE_ID_OFFSET,
+            KeyKind::Pattern => PATTERN_ID_OFFSET,
+            KeyKind::Commit => COMMIT_ID_OFFSET,
+            KeyKind::Belief => BELIEF_ID_OFFSET,
+            KeyKind::Forge => FORGE_ID_OFFSET,
+        }
+    }
+}
+
+/// Classify a key by its range
+pub fn classify_key(key: i64) -> KeyKind {
+    match key {
+        k if k >= FORGE_ID_OFFSET => KeyKind::Forge,
+        k if k >= BELIEF_ID_OFFSET => KeyKind::Belief,
+        k if k >= COMMIT_ID_OFFSET => KeyKind::Commit,
+        k if k >= PATTERN_ID_OFFSET => KeyKind::Pattern,
+        k if k >= CODE_ID_OFFSET => KeyKind::Code,
+        _ => KeyKind::Session,
+    }
+}
+
+/// Source row id (rowid or eventlog seq) a key was built from
+pub fn source_id(key: i64) -> i64 {
+    key - classify_key(key).offset()
+}
+
+/// Key for a function_facts rowid
+pub fn code_key(rowid: i64) -> i64 {
+    CODE_ID_OFFSET + rowid
+}
+
+/// Key for a patterns rowid
+pub fn pattern_key(rowid: i64) -> i64 {
+    PATTERN_ID_OFFSET + rowid
+}
+
+/// Key for a commits rowid
+pub fn commit_key(rowid: i64) -> i64 {
+    COMMIT_ID_OFFSET + rowid
+}
+
+/// Key for a beliefs rowid
+pub fn belief_key(rowid: i64) -> i64 {
+    BELIEF_ID_OFFSET + rowid
+}
+
+/// Key for a forge event's eventlog seq
+pub fn forge_key(seq: i64) -> i64 {
+    FORGE_ID_OFFSET + seq
+}
+
+/// How many of one kind's source rows have a key in the index
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct KindCoverage {
+    pub kind: &'static str,
+    pub total: usize,
+    pub embedded: usize,
+}
+
+impl KindCoverage {
+    /// Embedded share in percent; an empty kind counts as fully covered
+    pub fn percent(&self) -> f64 {
+        if self.total == 0 {
+            100.0
+        } else {
+            self.embedded as f64 * 100.0 / self.total as f64
+        }
+    }
+}
+
+/// Coverage per kind (in [`KeyKind::ALL`] order) of `keys` that should be
+/// indexed, where `contains` reports whether the index has a key
+pub fn coverage_by_kind(
+    keys: impl IntoIterator<Item = i64>,
+    contains: impl Fn(i64) -> bool,
+) -> Vec<KindCoverage> {
+    let mut coverage: Vec<KindCoverage> = KeyKind::ALL
+        .iter()
+        .map(|kind| KindCoverage {
+            kind: kind.as_str(),
+            total: 0,
+            embedded: 0,
+        })
+        .collect();
+
+    for key in keys {
+        let slot = &mut coverage[classify_key(key) as usize];
+        slot.total += 1;
+        if contains(key) {
+            slot.embedded += 1;
+        }
+    }
+
+    coverage
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_classify_key_boundaries() {
+        assert_eq!(classify_key(0), KeyKind::Session);
+        for kind in &KeyKind::ALL[1..] {
+            let offset = kind.offset();
+            let below = KeyKind::ALL[*kind as usize - 1];
+            assert_eq!(classify_key(offset - 1), below, "one below {:?}", kind);
+            assert_eq!(classify_key(offset), *kind, "at {:?}", kind);
+            assert_eq!(classify_key(offset + 1), *kind, "one above {:?}", kind);
+        }
+        assert_eq!(classify_key(CODE_ID_OFFSET - 1), KeyKind::Session);
+        assert_eq!(classify_key(FORGE_ID_OFFSET + 1), KeyKind::Forge);
+    }
+
+    #[test]
+    fn test_keys_round_trip_to_source_ids() {
+        assert_eq!(classify_key(belief_key(7)), KeyKind::Belief);
+        assert_eq!(source_id(belief_key(7)), 7);
+        assert_eq!(classify_key(code_key(1)), KeyKind::Code);
+        assert_eq!(classify_key(pattern_key(1)), KeyKind::Pattern);
+        assert_eq!(classify_key(commit_key(1)), KeyKind::Commit);
+        assert_eq!(source_id(forge_key(42)), 42);
+        assert_eq!(source_id(123), 123);
+    }
+
+    #[test]
+    fn test_coverage_full_when_every_row_has_a_key() {
+        let keys = vec![7, code_key(1), code_key(2), belief_key(1)];
+        let indexed: HashSet<i64> = keys.iter().copied().collect();
+
+        let coverage = coverage_by_kind(keys, |k| indexed.contains(&k));
+        assert!(coverage.iter().all(|c| c.percent() == 100.0));
+        assert_eq!(coverage[KeyKind::Code as usize].total, 2);
+    }
+
+    #[test]
+    fn test_coverage_lags_when_keys_missing() {
+        let keys = vec![
+            code_key(1),
+            code_key(2),
+            code_key(3),
+            code_key(4),
+            commit_key(1),
+            forge_key(40),
+        ];
+        // Functions 3 and 4 were scraped after the last oxidize
+        let indexed: HashSet<i64> = [code_key(1), code_key(2), commit_key(1)]
+            .into_iter()
+            .collect();
+
+        let coverage = coverage_by_kind(keys, |k| indexed.contains(&k));
+        let functions = &coverage[KeyKind::Code as usize];
+        assert_eq!(functions.kind, "functions");
+        assert_eq!((functions.embedded, functions.total), (2, 4));
+        assert!(functions.percent() < 100.0);
+        assert_eq!(coverage[KeyKind::Commit as usize].percent(), 100.0);
+        assert_eq!(coverage[KeyKind::Forge as usize].percent(), 0.0);
+    }
+}
@@ -4,6 +4,7 @@
 //! Supports multiple embedding models via configuration.
 
 mod database;
+pub mod id_offsets;
 pub mod models;
 mod onnx;
 mod similarity;
@@ -43,7 +44,26 @@ pub trait EmbeddingEngine: Send {
     }
 
     /// Generate embeddings for multiple texts (batch processing)
-    fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>>;
+    ///
+    /// Default implementation calls embed() per text; engines that can run
+    /// one forward pass over many inputs should override it.
+    fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        texts.iter().map(|text| self.embed(text)).collect()
+    }
+
+    /// Generate query embeddings for multiple texts, in input order
+    ///
+    /// Default implementation calls embed_query() per text.
+    fn embed_query_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        texts.iter().map(|text| self.embed_query(text)).collect()
+    }
+
+    /// Generate passage embeddings for multiple texts, in input order
+    ///
+    /// Default implementation calls embed_passage() per text.
+    fn embed_passage_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        texts.iter().map(|text| self.embed_passage(text)).collect()
+    }
 
     /// Get embedding dimension (e.g., 384 for all-MiniLM-L6-v2)
     fn dimension(&self) -> usize;
@@ -60,6 +80,21 @@ pub fn create_embedder() -> Result<Box<dyn EmbeddingEngine>> {
     create_embedder_from_config()
 }
 
+/// Check the configured model is on disk before creating an embedder
+///
+/// A missing model is pulled to the mother cache when `auto_pull` (the flag)
+/// or `[embeddings] auto_pull` is set, unless offline mode is on.
+pub fn ensure_model_available(auto_pull: bool) -> Result<()> {
+    let config = Config::load()?;
+    let policy = crate::models::PullPolicy {
+        auto_pull: auto_pull || config.embeddings.auto_pull,
+        offline: config.embeddings.offline || crate::models::PullPolicy::offline_from_env(),
+    };
+    let model_def = config.get_model_definition()?;
+    crate::models::ensure_model(&model_def.name, policy)?;
+    Ok(())
+}
+
 /// Create embedder from configuration file
 fn create_embedder_from_config() -> Result<Box<dyn EmbeddingEngine>> {
     // Load user config (creates default if doesn't exist)
@@ -68,6 +68,12 @@ pub struct Config {
 #[derive(Debug, Deserialize)]
 pub struct EmbeddingsConfig {
     pub model: String,
+    /// Pull the model on first use when it isn't cached
+    #[serde(default)]
+    pub auto_pull: bool,
+    /// Never download models (also `PATINA_OFFLINE=1`)
+    #[serde(default)]
+    pub offline: bool,
 }
 
 impl ModelRegistry {
@@ -119,6 +125,8 @@ model = "all-minilm-l6-v2"
         Ok(Config {
             embeddings: EmbeddingsConfig {
                 model: "all-minilm-l6-v2".to_string(),
+                auto_pull: false,
+                offline: false,
             },
         })
     }
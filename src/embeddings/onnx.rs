@@ -15,6 +15,17 @@ const DEFAULT_MODEL_NAME: &str = "all-MiniLM-L6-v2";
 const DEFAULT_MODEL_SHA256: &str =
     "afdb6f1a0e45b715d0bb9b11772f032c399babd23bfc31fed1c170afc848bdb1";
 
+/// Most inputs sent through the model in one inference call
+const MAX_BATCH_SIZE: usize = 32;
+
+/// Prefix each text for asymmetric models (no-op without a prefix)
+fn with_prefix(prefix: Option<&str>, texts: &[String]) -> Vec<String> {
+    match prefix {
+        Some(prefix) => texts.iter().map(|t| format!("{}{}", prefix, t)).collect(),
+        None => texts.to_vec(),
+    }
+}
+
 /// ONNX-based embedding generator
 pub struct OnnxEmbedder {
     session: Session,
@@ -159,6 +170,91 @@ impl OnnxEmbedder {
         pooled.iter().map(|&x| x / mask_sum).collect()
     }
 
+    /// Embed texts in one inference call, padding each to the longest
+    ///
+    /// Padding positions carry a zero attention mask, so they neither attend
+    /// nor count toward mean pooling; each row matches embedding it alone.
+    fn run_batch(&mut self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Tokenize
+        let encoded = texts
+            .iter()
+            .map(|text| self.tokenize(text))
+            .collect::<Result<Vec<_>>>()?;
+        let batch_size = encoded.len();
+        let max_len = encoded.iter().map(|(ids, _)| ids.len()).max().unwrap_or(0);
+
+        // Prepare padded inputs as Array2
+        let mut input_ids = Vec::with_capacity(batch_size * max_len);
+        let mut attention_masks = Vec::with_capacity(batch_size * max_len);
+        for (ids, mask) in &encoded {
+            input_ids.extend_from_slice(ids);
+            input_ids.resize(input_ids.len() + max_len - ids.len(), 0);
+            attention_masks.extend_from_slice(mask);
+            attention_masks.resize(attention_masks.len() + max_len - mask.len(), 0);
+        }
+        let input_ids_array = Array2::from_shape_vec((batch_size, max_len), input_ids)
+            .context("Failed to create input_ids array")?;
+        let attention_mask_array = Array2::from_shape_vec((batch_size, max_len), attention_masks)
+            .context("Failed to create attention_mask array")?;
+
+        // Token type IDs - all zeros for single-sentence embeddings
+        let token_type_ids_array =
+            Array2::from_shape_vec((batch_size, max_len), vec![0i64; batch_size * max_len])
+                .context("Failed to create token_type_ids array")?;
+
+        // Run inference and extract data (need to finish with outputs before using self methods)
+        let token_embeddings: Vec<Array2<f32>> = {
+            let outputs = self
+                .session
+                .run(inputs![
+                    "input_ids" => Value::from_array(input_ids_array)?,
+                    "attention_mask" => Value::from_array(attention_mask_array)?,
+                    "token_type_ids" => Value::from_array(token_type_ids_array)?
+                ])
+                .context("ONNX inference failed")?;
+
+            // Extract token embeddings from last_hidden_state
+            let (shape, data) = outputs["last_hidden_state"]
+                .try_extract_tensor::<f32>()
+                .context("Failed to extract last_hidden_state tensor")?;
+
+            // Shape is [batch_size, seq_len, hidden_dim=384]
+            let shape_dims = shape.as_ref();
+            if shape_dims.len() != 3 || shape_dims[0] as usize != batch_size {
+                bail!(
+                    "Expected 3D tensor for {} inputs, got shape: {:?}",
+                    batch_size,
+                    shape_dims
+                );
+            }
+
+            let seq_len = shape_dims[1] as usize;
+            let hidden_dim = shape_dims[2] as usize;
+
+            // Split flat data into one Array2 per batch item
+            let item_len = seq_len * hidden_dim;
+            data.chunks(item_len)
+                .take(batch_size)
+                .map(|item| {
+                    Array2::from_shape_vec((seq_len, hidden_dim), item.to_vec())
+                        .context("Failed to reshape token embeddings")
+                })
+                .collect::<Result<_>>()?
+            // outputs is dropped here, releasing the mutable borrow
+        };
+
+        // Mean pooling, then L2 normalize
+        Ok(token_embeddings
+            .iter()
+            .zip(&encoded)
+            .map(|(tokens, (_, mask))| self.normalize(&self.mean_pooling(tokens, mask)))
+            .collect())
+    }
+
     /// L2 normalize a vector
     fn normalize(&self, vec: &[f32]) -> Vec<f32> {
         let norm: f32 = vec.iter().map(|x| x * x).sum::<f32>().sqrt();
@@ -208,68 +304,28 @@ impl EmbeddingEngine for OnnxEmbedder {
     }
 
     fn embed(&mut self, text: &str) -> Result<Vec<f32>> {
-        // Tokenize
-        let (input_ids, attention_mask) = self.tokenize(text)?;
-
-        // Prepare inputs as Array2
-        let seq_len = input_ids.len();
-        let input_ids_array = Array2::from_shape_vec((1, seq_len), input_ids.clone())
-            .context("Failed to create input_ids array")?;
-
-        let attention_mask_array =
-            Array2::from_shape_vec((1, attention_mask.len()), attention_mask.clone())
-                .context("Failed to create attention_mask array")?;
-
-        // Token type IDs - all zeros for single-sentence embeddings
-        let token_type_ids = vec![0i64; seq_len];
-        let token_type_ids_array = Array2::from_shape_vec((1, seq_len), token_type_ids)
-            .context("Failed to create token_type_ids array")?;
-
-        // Run inference and extract data (need to finish with outputs before using self methods)
-        let token_embeddings_2d = {
-            let outputs = self
-                .session
-                .run(inputs![
-                    "input_ids" => Value::from_array(input_ids_array)?,
-                    "attention_mask" => Value::from_array(attention_mask_array)?,
-                    "token_type_ids" => Value::from_array(token_type_ids_array)?
-                ])
-                .context("ONNX inference failed")?;
-
-            // Extract token embeddings from last_hidden_state
-            let (shape, data) = outputs["last_hidden_state"]
-                .try_extract_tensor::<f32>()
-                .context("Failed to extract last_hidden_state tensor")?;
-
-            // Shape is [batch_size=1, seq_len, hidden_dim=384]
-            let shape_dims = shape.as_ref();
-            if shape_dims.len() != 3 {
-                bail!("Expected 3D tensor, got shape: {:?}", shape_dims);
-            }
-
-            let seq_len = shape_dims[1] as usize;
-            let hidden_dim = shape_dims[2] as usize;
-
-            // Convert flat data to Array2 for the first batch item
-            let batch_offset = seq_len * hidden_dim;
-            Array2::from_shape_vec((seq_len, hidden_dim), data[0..batch_offset].to_vec())
-                .context("Failed to reshape token embeddings")?
-            // outputs is dropped here, releasing the mutable borrow
-        };
-
-        // Mean pooling
-        let embedding = self.mean_pooling(&token_embeddings_2d, &attention_mask);
+        self.run_batch(&[text])?
+            .pop()
+            .context("ONNX inference returned no embedding")
+    }
 
-        // L2 normalize
-        let normalized = self.normalize(&embedding);
+    fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for chunk in texts.chunks(MAX_BATCH_SIZE) {
+            let refs: Vec<&str> = chunk.iter().map(String::as_str).collect();
+            embeddings.extend(self.run_batch(&refs)?);
+        }
+        Ok(embeddings)
+    }
 
-        Ok(normalized)
+    fn embed_query_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let inputs = with_prefix(self.query_prefix.as_deref(), texts);
+        self.embed_batch(&inputs)
     }
 
-    fn embed_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
-        // Simple sequential processing for now
-        // TODO: Optimize with true batch processing
-        texts.iter().map(|t| self.embed(t)).collect()
+    fn embed_passage_batch(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let inputs = with_prefix(self.passage_prefix.as_deref(), texts);
+        self.embed_batch(&inputs)
     }
 
     fn dimension(&self) -> usize {
@@ -329,6 +385,29 @@ mod tests {
         assert_relative_eq!(norm, 1.0, epsilon = 1e-5);
     }
 
+    #[test]
+    fn test_embed_batch_matches_single_queries() {
+        let mut embedder = get_test_embedder();
+        embedder.query_prefix = Some("query: ".to_string());
+
+        // Different lengths so the shorter inputs get padded
+        let texts = vec![
+            "short".to_string(),
+            "A considerably longer sentence about embedding many inputs at once".to_string(),
+            "medium length text".to_string(),
+        ];
+        let batched = embedder.embed_query_batch(&texts).unwrap();
+        assert_eq!(batched.len(), texts.len());
+
+        for (text, vector) in texts.iter().zip(&batched) {
+            let single = embedder.embed_query(text).unwrap();
+            assert_eq!(single.len(), vector.len());
+            for (a, b) in single.iter().zip(vector) {
+                assert_relative_eq!(a, b, epsilon = 1e-4);
+            }
+        }
+    }
+
     #[test]
     fn test_semantic_similarity() {
         let mut embedder = get_test_embedder();
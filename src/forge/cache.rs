@@ -0,0 +1,380 @@
+//! On-disk cache for forge fetches.
+//!
+//! "Do X": Avoid refetching unchanged issues and PRs from the forge API.
+//!
+//! `CachedReader` wraps any `ForgeReader`. Raw issue/PR JSON lives under
+//! `.patina/cache/forge/<owner>/<repo>/`, keyed by number plus a version
+//! (`updated_at` for issues; state, `updated_at` and comment count for PRs).
+//! List calls always hit the network - they are how staleness is detected -
+//! and refresh the cache as a side effect. Single-item fetches are served
+//! from cache unless a list reported a newer version or the entry is older
+//! than `TTL_SECS`, so a session that never lists still revalidates.
+
+use anyhow::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::{ForgeReader, Issue, PullRequest};
+
+/// Seconds a cached entry is trusted without a list call confirming it
+const TTL_SECS: u64 = 24 * 60 * 60;
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+    version: String,
+    /// Unix seconds when written (0 for entries predating the TTL, so they expire)
+    #[serde(default)]
+    fetched_at: u64,
+    data: T,
+}
+
+/// ForgeReader decorator that consults an on-disk cache before the network.
+pub struct CachedReader<R: ForgeReader> {
+    inner: R,
+    dir: PathBuf,
+    // Latest versions reported by list calls this session
+    issue_versions: RefCell<HashMap<i64, String>>,
+    pr_versions: RefCell<HashMap<i64, String>>,
+}
+
+impl<R: ForgeReader> CachedReader<R> {
+    pub fn new(inner: R, dir: PathBuf) -> Self {
+        Self {
+            inner,
+            dir,
+            issue_versions: RefCell::new(HashMap::new()),
+            pr_versions: RefCell::new(HashMap::new()),
+        }
+    }
+
+    fn path(&self, kind: &str, number: i64) -> PathBuf {
+        self.dir.join(format!("{}-{}.json", kind, number))
+    }
+
+    fn store<T: Serialize>(&self, kind: &str, number: i64, version: String, data: &T) {
+        // Cache writes are best-effort: a failure only costs a future refetch
+        let entry = CacheEntry {
+            version,
+            fetched_at: now_secs(),
+            data,
+        };
+        if fs::create_dir_all(&self.dir).is_ok() {
+            if let Ok(json) = serde_json::to_string(&entry) {
+                let _ = fs::write(self.path(kind, number), json);
+            }
+        }
+    }
+
+    /// Cached value, unless a list call this session reported a different
+    /// version or the entry has outlived the TTL.
+    fn load<T: DeserializeOwned>(
+        &self,
+        kind: &str,
+        number: i64,
+        known: Option<&String>,
+    ) -> Option<T> {
+        let entry: CacheEntry<T> = read_entry(&self.path(kind, number))?;
+        if now_secs().saturating_sub(entry.fetched_at) > TTL_SECS {
+            return None;
+        }
+        match known {
+            // List versions carry no comment count, so compare up to the '#'
+            Some(version) if entry.version.split('#').next() != Some(version.as_str()) => None,
+            _ => Some(entry.data),
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn read_entry<T: DeserializeOwned>(path: &Path) -> Option<CacheEntry<T>> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn issue_version(issue: &Issue) -> String {
+    issue.updated_at.clone()
+}
+
+/// State plus last activity; all a list call can report about a PR
+fn pr_list_version(pr: &PullRequest) -> String {
+    let updated = pr
+        .updated_at
+        .as_deref()
+        .or(pr.merged_at.as_deref())
+        .unwrap_or(&pr.created_at);
+    format!("{:?}:{}", pr.state, updated)
+}
+
+/// List version plus comment count, for a fully fetched PR
+fn pr_version(pr: &PullRequest) -> String {
+    format!(
+        "{}#{}",
+        pr_list_version(pr),
+        pr.comments.len() + pr.review_comments.len()
+    )
+}
+
+impl<R: ForgeReader> ForgeReader for CachedReader<R> {
+    fn get_issue_count(&self) -> Result<usize> {
+        self.inner.get_issue_count()
+    }
+
+    fn get_pr_count(&self) -> Result<usize> {
+        self.inner.get_pr_count()
+    }
+
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        let issues = self.inner.list_issues(limit, since, labels)?;
+        let mut versions = self.issue_versions.borrow_mut();
+        for issue in &issues {
+            let version = issue_version(issue);
+            versions.insert(issue.number, version.clone());
+            self.store("issue", issue.number, version, issue);
+        }
+        Ok(issues)
+    }
+
+    fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>> {
+        // List output lacks comments/reviews, so only versions are recorded here;
+        // the full PR is cached by get_pull_request
+        let prs = self.inner.list_pull_requests(limit, since)?;
+        let mut versions = self.pr_versions.borrow_mut();
+        for pr in &prs {
+            versions.insert(pr.number, pr_list_version(pr));
+        }
+        Ok(prs)
+    }
+
+    fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+        let known = self.pr_versions.borrow().get(&number).cloned();
+        if let Some(pr) = self.load("pr", number, known.as_ref()) {
+            return Ok(pr);
+        }
+
+        let pr = self.inner.get_pull_request(number)?;
+        self.store("pr", number, pr_version(&pr), &pr);
+        Ok(pr)
+    }
+
+    fn get_issue(&self, number: i64) -> Result<Issue> {
+        let known = self.issue_versions.borrow().get(&number).cloned();
+        if let Some(issue) = self.load("issue", number, known.as_ref()) {
+            return Ok(issue);
+        }
+
+        let issue = self.inner.get_issue(number)?;
+        self.store("issue", number, issue_version(&issue), &issue);
+        Ok(issue)
+    }
+
+    fn get_max_issue_number(&self) -> Result<i64> {
+        self.inner.get_max_issue_number()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::{Comment, IssueState, PrState};
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    /// Reader whose issue/PR updated_at can change, counting network calls.
+    struct CountingReader {
+        updated_at: RefCell<String>,
+        comments: Cell<usize>,
+        issue_fetches: Cell<usize>,
+        pr_fetches: Cell<usize>,
+    }
+
+    impl CountingReader {
+        fn issue(&self) -> Issue {
+            Issue {
+                number: 7,
+                title: "Flaky test".to_string(),
+                body: None,
+                state: IssueState::Open,
+                author: "alice".to_string(),
+                labels: vec![],
+                created_at: "2026-05-01T00:00:00Z".to_string(),
+                updated_at: self.updated_at.borrow().clone(),
+                url: "https://github.com/o/r/issues/7".to_string(),
+            }
+        }
+
+        fn pr(&self, with_comments: bool) -> PullRequest {
+            let comment = |i| Comment {
+                author: "bob".to_string(),
+                body: format!("comment {}", i),
+                created_at: "2026-05-02T00:00:00Z".to_string(),
+            };
+            PullRequest {
+                number: 8,
+                title: "Fix flaky test".to_string(),
+                body: None,
+                state: PrState::Open,
+                author: "alice".to_string(),
+                labels: vec![],
+                created_at: "2026-05-01T00:00:00Z".to_string(),
+                merged_at: None,
+                updated_at: Some(self.updated_at.borrow().clone()),
+                url: "https://github.com/o/r/pull/8".to_string(),
+                linked_issues: vec![],
+                comments: if with_comments {
+                    (0..self.comments.get()).map(comment).collect()
+                } else {
+                    vec![]
+                },
+                review_comments: vec![],
+                approvals: 0,
+            }
+        }
+    }
+
+    impl ForgeReader for CountingReader {
+        fn get_issue_count(&self) -> Result<usize> {
+            Ok(1)
+        }
+        fn get_pr_count(&self) -> Result<usize> {
+            Ok(0)
+        }
+        fn list_issues(&self, _: usize, _: Option<&str>, _: &[String]) -> Result<Vec<Issue>> {
+            Ok(vec![self.issue()])
+        }
+        fn list_pull_requests(&self, _: usize, _: Option<&str>) -> Result<Vec<PullRequest>> {
+            // Like `gh pr list`: no comments in list output
+            Ok(vec![self.pr(false)])
+        }
+        fn get_pull_request(&self, _: i64) -> Result<PullRequest> {
+            self.pr_fetches.set(self.pr_fetches.get() + 1);
+            Ok(self.pr(true))
+        }
+        fn get_issue(&self, _: i64) -> Result<Issue> {
+            self.issue_fetches.set(self.issue_fetches.get() + 1);
+            Ok(self.issue())
+        }
+        fn get_max_issue_number(&self) -> Result<i64> {
+            Ok(7)
+        }
+    }
+
+    fn counting(updated_at: &str) -> CountingReader {
+        CountingReader {
+            updated_at: RefCell::new(updated_at.to_string()),
+            comments: Cell::new(1),
+            issue_fetches: Cell::new(0),
+            pr_fetches: Cell::new(0),
+        }
+    }
+
+    #[test]
+    fn test_unchanged_issue_served_from_cache() -> Result<()> {
+        let temp = TempDir::new()?;
+
+        let first = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        first.get_issue(7)?;
+        assert_eq!(first.inner.issue_fetches.get(), 1);
+
+        // New session, same cache dir: no network call
+        let second = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        let issue = second.get_issue(7)?;
+        assert_eq!(issue.title, "Flaky test");
+        assert_eq!(second.inner.issue_fetches.get(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_changed_issue_refetched() -> Result<()> {
+        let temp = TempDir::new()?;
+
+        let first = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        first.get_issue(7)?;
+
+        let second = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        *second.inner.updated_at.borrow_mut() = "2026-06-01T00:00:00Z".to_string();
+        // Listing learns the new version (and refreshes the cache with it)
+        second.list_issues(10, None, &[])?;
+        let issue = second.get_issue(7)?;
+        assert_eq!(issue.updated_at, "2026-06-01T00:00:00Z");
+        assert_eq!(second.inner.issue_fetches.get(), 0);
+
+        // A stale entry with no list refresh is refetched once versions diverge
+        fs::write(
+            temp.path().join("issue-7.json"),
+            r#"{"version":"old","data":{"number":7,"title":"Old","body":null,"state":"open","author":"a","labels":[],"created_at":"x","updated_at":"old","url":"u"}}"#,
+        )?;
+        let issue = second.get_issue(7)?;
+        assert_eq!(issue.title, "Flaky test");
+        assert_eq!(second.inner.issue_fetches.get(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_updated_pr_replaces_cached_copy() -> Result<()> {
+        let temp = TempDir::new()?;
+
+        let first = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        first.get_pull_request(8)?;
+        assert_eq!(first.inner.pr_fetches.get(), 1);
+
+        // Unchanged upstream: the list confirms the cached copy
+        let second = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        second.list_pull_requests(10, None)?;
+        assert_eq!(second.get_pull_request(8)?.comments.len(), 1);
+        assert_eq!(second.inner.pr_fetches.get(), 0);
+
+        // A new comment bumps updated_at upstream
+        let third = CachedReader::new(counting("2026-06-01T00:00:00Z"), temp.path().into());
+        third.inner.comments.set(2);
+        third.list_pull_requests(10, None)?;
+        assert_eq!(third.get_pull_request(8)?.comments.len(), 2);
+        assert_eq!(third.inner.pr_fetches.get(), 1);
+
+        // The refreshed copy is what later sessions see, even without a list
+        let fourth = CachedReader::new(counting("2026-06-01T00:00:00Z"), temp.path().into());
+        let pr = fourth.get_pull_request(8)?;
+        assert_eq!(pr.updated_at.as_deref(), Some("2026-06-01T00:00:00Z"));
+        assert_eq!(pr.comments.len(), 2);
+        assert_eq!(fourth.inner.pr_fetches.get(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expired_entry_revalidated_without_list() -> Result<()> {
+        let temp = TempDir::new()?;
+
+        let first = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        first.get_pull_request(8)?;
+
+        // Age the entry past the TTL
+        let path = temp.path().join("pr-8.json");
+        let mut entry: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path)?)?;
+        entry["fetched_at"] = serde_json::json!(now_secs() - TTL_SECS - 1);
+        fs::write(&path, entry.to_string())?;
+
+        let second = CachedReader::new(counting("2026-05-01T00:00:00Z"), temp.path().into());
+        second.inner.comments.set(3);
+        assert_eq!(second.get_pull_request(8)?.comments.len(), 3);
+        assert_eq!(second.inner.pr_fetches.get(), 1);
+
+        Ok(())
+    }
+}
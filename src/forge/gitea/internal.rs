@@ -0,0 +1,467 @@
+//! Internal implementation for Gitea ForgeReader.
+//!
+//! Contains REST calls and JSON parsing.
+//! Not exposed in public interface.
+
+use anyhow::{bail, Context, Result};
+use regex::Regex;
+use reqwest::blocking::Client;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::forge::{Comment, Issue, IssueState, PrState, PullRequest};
+
+/// Gitea caps page size at 50 by default.
+const PAGE_SIZE: usize = 50;
+
+// ============================================================================
+// Gitea API JSON types (internal, match /api/v1 output format)
+// ============================================================================
+
+#[derive(Debug, Deserialize)]
+struct GtIssue {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GtLabel>,
+    user: GtUser,
+    created_at: String,
+    updated_at: String,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtPullRequest {
+    number: i64,
+    title: String,
+    body: Option<String>,
+    state: String,
+    #[serde(default)]
+    labels: Vec<GtLabel>,
+    user: GtUser,
+    created_at: String,
+    updated_at: String,
+    merged_at: Option<String>,
+    #[serde(default)]
+    merged: bool,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtLabel {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtUser {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtComment {
+    user: GtUser,
+    body: String,
+    created_at: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GtReview {
+    user: Option<GtUser>,
+    #[serde(default)]
+    body: String,
+    state: String,
+    #[serde(default)]
+    submitted_at: String,
+}
+
+// ============================================================================
+// API client (called by GiteaReader)
+// ============================================================================
+
+/// Thin wrapper over the Gitea REST API for one repository.
+pub(crate) struct GiteaApi {
+    client: Client,
+    base: String,
+    token: Option<String>,
+}
+
+impl GiteaApi {
+    pub(crate) fn new(host: &str, owner: &str, repo: &str, token: Option<String>) -> Self {
+        let client = Client::builder()
+            .user_agent("patina")
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+
+        Self {
+            client,
+            base: format!("https://{}/api/v1/repos/{}/{}", host, owner, repo),
+            token: token.filter(|t| !t.is_empty()),
+        }
+    }
+
+    /// GET a repo-relative path, returning parsed JSON and the `X-Total-Count` header.
+    fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+    ) -> Result<(T, Option<usize>)> {
+        let url = format!("{}{}", self.base, path);
+        let mut req = self.client.get(&url).query(query);
+        if let Some(ref token) = self.token {
+            req = req.header("Authorization", format!("token {}", token));
+        }
+
+        let resp = req
+            .send()
+            .with_context(|| format!("Failed to reach Gitea API: {}", url))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().unwrap_or_default();
+            bail!("Gitea API {} failed ({}): {}", path, status, body.trim());
+        }
+
+        let total = resp
+            .headers()
+            .get("x-total-count")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse().ok());
+
+        let body = resp
+            .json()
+            .with_context(|| format!("Failed to parse Gitea JSON from {}", path))?;
+
+        Ok((body, total))
+    }
+
+    /// Walk pages until `limit` items are collected or `keep` rejects an item.
+    fn paginate<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, String)],
+        limit: usize,
+        keep: impl Fn(&T) -> bool,
+    ) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        while items.len() < limit {
+            let mut q = query.to_vec();
+            q.push(("limit", PAGE_SIZE.to_string()));
+            q.push(("page", page.to_string()));
+
+            let (batch, _): (Vec<T>, _) = self.get(path, &q)?;
+            let exhausted = batch.len() < PAGE_SIZE;
+
+            for item in batch {
+                if !keep(&item) {
+                    return Ok(items);
+                }
+                items.push(item);
+                if items.len() >= limit {
+                    break;
+                }
+            }
+
+            if exhausted {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(items)
+    }
+
+    /// Get total issue count from the `X-Total-Count` header.
+    pub(crate) fn fetch_issue_count(&self) -> Result<usize> {
+        let (_, total): (Vec<GtIssue>, _) = self.get(
+            "/issues",
+            &[
+                ("state", "all".into()),
+                ("type", "issues".into()),
+                ("limit", "1".into()),
+            ],
+        )?;
+        Ok(total.unwrap_or(0))
+    }
+
+    /// Get total PR count from the `X-Total-Count` header.
+    pub(crate) fn fetch_pr_count(&self) -> Result<usize> {
+        let (_, total): (Vec<GtPullRequest>, _) =
+            self.get("/pulls", &[("state", "all".into()), ("limit", "1".into())])?;
+        Ok(total.unwrap_or(0))
+    }
+
+    /// Fetch issues (PRs excluded), newest activity first.
+    pub(crate) fn fetch_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        let mut query = vec![("state", "all".to_string()), ("type", "issues".to_string())];
+        if let Some(timestamp) = since {
+            query.push(("since", timestamp.to_string()));
+        }
+        if !labels.is_empty() {
+            query.push(("labels", labels.join(",")));
+        }
+
+        let issues: Vec<GtIssue> = self.paginate("/issues", &query, limit, |_| true)?;
+        Ok(issues.into_iter().map(into_issue).collect())
+    }
+
+    /// Fetch pull requests. The pulls endpoint has no `since`, so results are
+    /// sorted by recent update and paging stops at the first older PR.
+    pub(crate) fn fetch_pull_requests(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+    ) -> Result<Vec<PullRequest>> {
+        let query = [
+            ("state", "all".to_string()),
+            ("sort", "recentupdate".to_string()),
+        ];
+
+        let prs: Vec<GtPullRequest> =
+            self.paginate("/pulls", &query, limit, |pr: &GtPullRequest| {
+                since.is_none_or(|s| pr.updated_at.as_str() >= s)
+            })?;
+        Ok(prs
+            .into_iter()
+            .map(|pr| into_pull_request(pr, Vec::new(), Vec::new()))
+            .collect())
+    }
+
+    /// Fetch single issue by number.
+    pub(crate) fn fetch_issue(&self, number: i64) -> Result<Issue> {
+        let (issue, _): (GtIssue, _) = self
+            .get(&format!("/issues/{}", number), &[])
+            .with_context(|| format!("Gitea issue #{} fetch failed", number))?;
+        Ok(into_issue(issue))
+    }
+
+    /// Fetch single PR with comments and reviews.
+    pub(crate) fn fetch_pull_request(&self, number: i64) -> Result<PullRequest> {
+        let (pr, _): (GtPullRequest, _) = self
+            .get(&format!("/pulls/{}", number), &[])
+            .with_context(|| format!("Gitea PR #{} fetch failed", number))?;
+        // PR conversation comments live on the issue endpoint
+        let (comments, _): (Vec<GtComment>, _) =
+            self.get(&format!("/issues/{}/comments", number), &[])?;
+        let (reviews, _): (Vec<GtReview>, _) =
+            self.get(&format!("/pulls/{}/reviews", number), &[])?;
+
+        Ok(into_pull_request(pr, comments, reviews))
+    }
+
+    /// Fetch the highest issue number (for backlog population).
+    /// Returns 0 if no issues exist.
+    pub(crate) fn fetch_max_issue_number(&self) -> Result<i64> {
+        let (issues, _): (Vec<GtIssue>, _) = self.get(
+            "/issues",
+            &[
+                ("state", "all".into()),
+                ("type", "issues".into()),
+                ("limit", "1".into()),
+            ],
+        )?;
+        Ok(issues.first().map(|i| i.number).unwrap_or(0))
+    }
+}
+
+// ============================================================================
+// Conversion functions
+// ============================================================================
+
+fn into_issue(gt: GtIssue) -> Issue {
+    Issue {
+        number: gt.number,
+        title: gt.title,
+        body: gt.body,
+        state: parse_issue_state(&gt.state),
+        author: gt.user.login,
+        labels: gt.labels.into_iter().map(|l| l.name).collect(),
+        created_at: gt.created_at,
+        updated_at: gt.updated_at,
+        url: gt.html_url,
+    }
+}
+
+fn into_pull_request(
+    gt: GtPullRequest,
+    comments: Vec<GtComment>,
+    reviews: Vec<GtReview>,
+) -> PullRequest {
+    // Combine comments and reviews into unified comments list
+    let mut all_comments: Vec<Comment> = comments
+        .into_iter()
+        .map(|c| Comment {
+            author: c.user.login,
+            body: c.body,
+            created_at: c.created_at,
+        })
+        .collect();
+
+    // Add review comments (filter empty ones)
+    for review in reviews.iter() {
+        if !review.body.is_empty() {
+            all_comments.push(Comment {
+                author: review
+                    .user
+                    .as_ref()
+                    .map(|u| u.login.clone())
+                    .unwrap_or_default(),
+                body: review.body.clone(),
+                created_at: review.submitted_at.clone(),
+            });
+        }
+    }
+
+    // Count approvals
+    let approvals = reviews.iter().filter(|r| r.state == "APPROVED").count() as i32;
+
+    // Gitea has no closing-references API; read keywords from the body
+    let linked_issues = gt.body.as_deref().map(closing_refs).unwrap_or_default();
+
+    PullRequest {
+        number: gt.number,
+        title: gt.title,
+        body: gt.body,
+        state: parse_pr_state(&gt.state, gt.merged || gt.merged_at.is_some()),
+        author: gt.user.login,
+        labels: gt.labels.into_iter().map(|l| l.name).collect(),
+        created_at: gt.created_at,
+        merged_at: gt.merged_at,
+        updated_at: Some(gt.updated_at),
+        url: gt.html_url,
+        linked_issues,
+        comments: all_comments,
+        review_comments: Vec::new(),
+        approvals,
+    }
+}
+
+/// Issue numbers referenced by closing keywords (`closes #12`, `fixes #3`, ...).
+fn closing_refs(body: &str) -> Vec<i64> {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    let re = RE.get_or_init(|| {
+        Regex::new(r"(?i)\b(?:close[sd]?|fix(?:e[sd])?|resolve[sd]?)\s+#(\d+)").unwrap()
+    });
+
+    let mut refs: Vec<i64> = re
+        .captures_iter(body)
+        .filter_map(|c| c[1].parse().ok())
+        .collect();
+    refs.sort_unstable();
+    refs.dedup();
+    refs
+}
+
+fn parse_issue_state(state: &str) -> IssueState {
+    match state.to_lowercase().as_str() {
+        "open" => IssueState::Open,
+        _ => IssueState::Closed,
+    }
+}
+
+fn parse_pr_state(state: &str, merged: bool) -> PrState {
+    if merged {
+        PrState::Merged
+    } else {
+        match state.to_lowercase().as_str() {
+            "open" => PrState::Open,
+            _ => PrState::Closed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Trimmed responses recorded from codeberg.org /api/v1
+    const ISSUES_FIXTURE: &str = r#"[
+        {
+            "id": 1201, "number": 42, "title": "Crash on empty config",
+            "body": "Steps to reproduce...", "state": "open",
+            "labels": [{"id": 1, "name": "bug", "color": "ee0701"}, {"id": 2, "name": "p1", "color": "000000"}],
+            "user": {"id": 7, "login": "alice"},
+            "created_at": "2026-05-01T10:00:00Z", "updated_at": "2026-05-02T11:00:00Z",
+            "html_url": "https://codeberg.org/owner/repo/issues/42",
+            "pull_request": null
+        },
+        {
+            "id": 1202, "number": 40, "title": "Docs typo", "body": null, "state": "closed",
+            "labels": [], "user": {"id": 8, "login": "bob"},
+            "created_at": "2026-04-01T10:00:00Z", "updated_at": "2026-04-03T09:00:00Z",
+            "html_url": "https://codeberg.org/owner/repo/issues/40"
+        }
+    ]"#;
+
+    const PR_FIXTURE: &str = r#"{
+        "id": 900, "number": 43, "title": "Handle empty config",
+        "body": "Fixes #42. Also closes #40 and fixes #42 again.",
+        "state": "closed", "merged": true, "merged_at": "2026-05-03T12:00:00Z",
+        "labels": [{"id": 1, "name": "bug"}],
+        "user": {"id": 7, "login": "alice"},
+        "created_at": "2026-05-02T12:00:00Z", "updated_at": "2026-05-03T12:00:00Z",
+        "html_url": "https://codeberg.org/owner/repo/pulls/43"
+    }"#;
+
+    const COMMENTS_FIXTURE: &str = r#"[
+        {"id": 1, "user": {"login": "bob"}, "body": "Why not default to {}?", "created_at": "2026-05-02T13:00:00Z"}
+    ]"#;
+
+    const REVIEWS_FIXTURE: &str = r#"[
+        {"id": 5, "user": {"login": "carol"}, "body": "", "state": "APPROVED", "submitted_at": "2026-05-03T10:00:00Z"},
+        {"id": 6, "user": {"login": "dave"}, "body": "Needs a test", "state": "REQUEST_CHANGES", "submitted_at": "2026-05-02T15:00:00Z"}
+    ]"#;
+
+    #[test]
+    fn test_issue_fixture_maps_labels_and_state() {
+        let issues: Vec<GtIssue> = serde_json::from_str(ISSUES_FIXTURE).unwrap();
+        let issues: Vec<Issue> = issues.into_iter().map(into_issue).collect();
+
+        assert_eq!(issues[0].number, 42);
+        assert_eq!(issues[0].state, IssueState::Open);
+        assert_eq!(issues[0].labels, vec!["bug", "p1"]);
+        assert_eq!(issues[0].author, "alice");
+        assert_eq!(issues[0].url, "https://codeberg.org/owner/repo/issues/42");
+
+        assert_eq!(issues[1].state, IssueState::Closed);
+        assert!(issues[1].body.is_none());
+        assert!(issues[1].labels.is_empty());
+    }
+
+    #[test]
+    fn test_pr_fixture_maps_merge_reviews_and_links() {
+        let pr: GtPullRequest = serde_json::from_str(PR_FIXTURE).unwrap();
+        let comments: Vec<GtComment> = serde_json::from_str(COMMENTS_FIXTURE).unwrap();
+        let reviews: Vec<GtReview> = serde_json::from_str(REVIEWS_FIXTURE).unwrap();
+
+        let pr = into_pull_request(pr, comments, reviews);
+
+        // Gitea reports merged PRs as "closed" + merged flag
+        assert_eq!(pr.state, PrState::Merged);
+        assert_eq!(pr.merged_at.as_deref(), Some("2026-05-03T12:00:00Z"));
+        assert_eq!(pr.labels, vec!["bug"]);
+        assert_eq!(pr.linked_issues, vec![40, 42]);
+        assert_eq!(pr.approvals, 1);
+
+        // Empty review bodies are dropped
+        let authors: Vec<&str> = pr.comments.iter().map(|c| c.author.as_str()).collect();
+        assert_eq!(authors, vec!["bob", "dave"]);
+    }
+
+    #[test]
+    fn test_pr_state_closed_unmerged() {
+        assert_eq!(parse_pr_state("closed", false), PrState::Closed);
+        assert_eq!(parse_pr_state("open", false), PrState::Open);
+    }
+}
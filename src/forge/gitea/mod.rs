@@ -0,0 +1,74 @@
+//! Gitea ForgeReader implementation.
+//!
+//! "Do X": Fetch code review data from Gitea, Codeberg, or Forgejo.
+//!
+//! Uses the Gitea REST API (`/api/v1`) directly. Public repos work anonymously;
+//! set `GITEA_TOKEN` for private repos and higher rate limits.
+//! All HTTP interaction hidden in internal.rs.
+
+mod internal;
+
+use anyhow::Result;
+
+use super::{Forge, ForgeReader, Issue, PullRequest};
+
+/// Environment variable holding the Gitea access token.
+pub const TOKEN_ENV: &str = "GITEA_TOKEN";
+
+/// Gitea implementation of ForgeReader.
+pub struct GiteaReader {
+    api: internal::GiteaApi,
+}
+
+impl GiteaReader {
+    pub fn new(forge: &Forge) -> Self {
+        Self {
+            api: internal::GiteaApi::new(
+                &forge.host,
+                &forge.owner,
+                &forge.repo,
+                std::env::var(TOKEN_ENV).ok(),
+            ),
+        }
+    }
+}
+
+impl ForgeReader for GiteaReader {
+    fn get_issue_count(&self) -> Result<usize> {
+        self.api.fetch_issue_count()
+    }
+
+    fn get_pr_count(&self) -> Result<usize> {
+        self.api.fetch_pr_count()
+    }
+
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        self.api.fetch_issues(limit, since, labels)
+    }
+
+    fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>> {
+        self.api.fetch_pull_requests(limit, since)
+    }
+
+    fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+        self.api.fetch_pull_request(number)
+    }
+
+    fn get_issue(&self, number: i64) -> Result<Issue> {
+        self.api.fetch_issue(number)
+    }
+
+    fn get_max_issue_number(&self) -> Result<i64> {
+        self.api.fetch_max_issue_number()
+    }
+}
+
+/// Check if a Gitea token is configured.
+pub fn has_token() -> bool {
+    std::env::var(TOKEN_ENV).is_ok_and(|t| !t.is_empty())
+}
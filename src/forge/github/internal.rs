@@ -38,6 +38,8 @@ struct GhPullRequest {
     author: GhAuthor,
     created_at: String,
     merged_at: Option<String>,
+    #[serde(default)]
+    updated_at: Option<String>,
     url: String,
     #[serde(default)]
     comments: Vec<GhComment>,
@@ -80,6 +82,18 @@ struct GhIssueRef {
     number: i64,
 }
 
+/// Inline review comment, flattened by `--jq` in fetch_review_comments.
+#[derive(Debug, Deserialize)]
+struct GhReviewComment {
+    number: i64,
+    author: String,
+    body: String,
+    created_at: String,
+}
+
+/// jq filter mapping REST review comments to GhReviewComment lines.
+const REVIEW_COMMENT_JQ: &str = ".[] | {number: (.pull_request_url | split(\"/\") | last | tonumber), author: .user.login, body, created_at}";
+
 // ============================================================================
 // Public functions (called by GitHubReader)
 // ============================================================================
@@ -144,10 +158,14 @@ pub(crate) fn fetch_pr_count(repo: &str) -> Result<usize> {
     Ok(count)
 }
 
-/// Fetch issues via gh CLI.
-pub(crate) fn fetch_issues(repo: &str, limit: usize, since: Option<&str>) -> Result<Vec<Issue>> {
-    let mut cmd = Command::new("gh");
-    cmd.args([
+/// Build `gh issue list` arguments.
+fn issue_list_args(
+    repo: &str,
+    limit: usize,
+    since: Option<&str>,
+    labels: &[String],
+) -> Vec<String> {
+    let mut args: Vec<String> = [
         "issue",
         "list",
         "--repo",
@@ -158,15 +176,38 @@ pub(crate) fn fetch_issues(repo: &str, limit: usize, since: Option<&str>) -> Res
         "all",
         "--json",
         "number,title,body,state,labels,author,createdAt,updatedAt,url",
-    ]);
+    ]
+    .iter()
+    .map(|s| s.to_string())
+    .collect();
 
     // Add search filter for incremental updates
     if let Some(timestamp) = since {
         let date = &timestamp[..10.min(timestamp.len())]; // Extract YYYY-MM-DD
-        cmd.args(["--search", &format!("updated:>={}", date)]);
+        args.push("--search".to_string());
+        args.push(format!("updated:>={}", date));
+    }
+
+    // Repeated --label flags: gh returns issues carrying all of them
+    for label in labels {
+        args.push("--label".to_string());
+        args.push(label.clone());
     }
 
-    let output = cmd.output().context("Failed to run `gh issue list`")?;
+    args
+}
+
+/// Fetch issues via gh CLI.
+pub(crate) fn fetch_issues(
+    repo: &str,
+    limit: usize,
+    since: Option<&str>,
+    labels: &[String],
+) -> Result<Vec<Issue>> {
+    let output = Command::new("gh")
+        .args(issue_list_args(repo, limit, since, labels))
+        .output()
+        .context("Failed to run `gh issue list`")?;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -196,7 +237,7 @@ pub(crate) fn fetch_pull_requests(
         "--state",
         "all",
         "--json",
-        "number,title,body,state,labels,author,createdAt,mergedAt,url",
+        "number,title,body,state,labels,author,createdAt,mergedAt,updatedAt,url",
     ]);
 
     // Add search filter for incremental updates
@@ -215,7 +256,57 @@ pub(crate) fn fetch_pull_requests(
     let gh_prs: Vec<GhPullRequest> =
         serde_json::from_slice(&output.stdout).context("Failed to parse GitHub PRs JSON")?;
 
-    Ok(gh_prs.into_iter().map(into_pull_request).collect())
+    let mut prs: Vec<PullRequest> = gh_prs.into_iter().map(into_pull_request).collect();
+
+    // One repo-wide call instead of one per PR; review threads are optional context
+    let mut endpoint = format!("repos/{}/pulls/comments", repo);
+    if let Some(timestamp) = since {
+        endpoint.push_str(&format!("?since={}", timestamp));
+    }
+    match fetch_review_comments(&endpoint) {
+        Ok(review_comments) => attach_review_comments(&mut prs, review_comments),
+        Err(e) => eprintln!("  Warning: skipping PR review comments: {}", e),
+    }
+
+    Ok(prs)
+}
+
+/// Fetch inline review comments from a REST endpoint (all pages).
+fn fetch_review_comments(endpoint: &str) -> Result<Vec<GhReviewComment>> {
+    let output = Command::new("gh")
+        .args(["api", endpoint, "--paginate", "--jq", REVIEW_COMMENT_JQ])
+        .output()
+        .context("Failed to run `gh api` for review comments")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        bail!("gh api {} failed: {}", endpoint, stderr);
+    }
+
+    parse_review_comment_lines(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Parse newline-delimited review comment objects emitted by `--jq`.
+fn parse_review_comment_lines(stdout: &str) -> Result<Vec<GhReviewComment>> {
+    stdout
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| serde_json::from_str(l).context("Failed to parse GitHub review comment JSON"))
+        .collect()
+}
+
+/// Attach review comments to their PRs by number (oldest first).
+fn attach_review_comments(prs: &mut [PullRequest], mut review_comments: Vec<GhReviewComment>) {
+    review_comments.sort_by(|a, b| a.created_at.cmp(&b.created_at));
+    for rc in review_comments {
+        if let Some(pr) = prs.iter_mut().find(|p| p.number == rc.number) {
+            pr.review_comments.push(Comment {
+                author: rc.author,
+                body: rc.body,
+                created_at: rc.created_at,
+            });
+        }
+    }
 }
 
 /// Fetch single issue by number.
@@ -254,7 +345,7 @@ pub(crate) fn fetch_pull_request(repo: &str, number: i64) -> Result<PullRequest>
             "--repo",
             repo,
             "--json",
-            "number,title,body,state,labels,author,createdAt,mergedAt,url,comments,reviews,closingIssuesReferences",
+            "number,title,body,state,labels,author,createdAt,mergedAt,updatedAt,url,comments,reviews,closingIssuesReferences",
         ])
         .output()
         .context("Failed to run `gh pr view`")?;
@@ -267,7 +358,16 @@ pub(crate) fn fetch_pull_request(repo: &str, number: i64) -> Result<PullRequest>
     let gh_pr: GhPullRequest =
         serde_json::from_slice(&output.stdout).context("Failed to parse GitHub PR JSON")?;
 
-    Ok(into_pull_request(gh_pr))
+    let mut pr = into_pull_request(gh_pr);
+    // Review threads are optional context, as in fetch_pull_requests
+    match fetch_review_comments(&format!("repos/{}/pulls/{}/comments", repo, number)) {
+        Ok(review_comments) => {
+            attach_review_comments(std::slice::from_mut(&mut pr), review_comments)
+        }
+        Err(e) => eprintln!("  Warning: skipping PR review comments: {}", e),
+    }
+
+    Ok(pr)
 }
 
 /// Fetch the highest issue number (for backlog population).
@@ -349,9 +449,11 @@ fn into_pull_request(gh: GhPullRequest) -> PullRequest {
         labels: gh.labels.into_iter().map(|l| l.name).collect(),
         created_at: gh.created_at,
         merged_at: gh.merged_at,
+        updated_at: gh.updated_at,
         url: gh.url,
         linked_issues: gh.closing_issues.into_iter().map(|i| i.number).collect(),
         comments,
+        review_comments: Vec::new(),
         approvals,
     }
 }
@@ -374,3 +476,53 @@ fn parse_pr_state(state: &str, has_merged_at: bool) -> PrState {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_list_args_with_labels() {
+        let base = issue_list_args("o/r", 50, None, &[]);
+        assert!(!base.contains(&"--label".to_string()));
+
+        let labels = vec!["bug".to_string(), "good-first-issue".to_string()];
+        let args = issue_list_args("o/r", 50, Some("2026-05-01T00:00:00Z"), &labels);
+        let tail: Vec<&str> = args[base.len()..].iter().map(|s| s.as_str()).collect();
+        assert_eq!(
+            tail,
+            vec![
+                "--search",
+                "updated:>=2026-05-01",
+                "--label",
+                "bug",
+                "--label",
+                "good-first-issue"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_review_comments_attach_by_pr_number() {
+        let stdout = r#"{"number":7,"author":"bob","body":"Why clone here?","created_at":"2026-05-02T10:00:00Z"}
+{"number":9,"author":"carol","body":"Unrelated PR","created_at":"2026-05-01T10:00:00Z"}
+{"number":7,"author":"alice","body":"Borrow checker, see above","created_at":"2026-05-01T09:00:00Z"}
+"#;
+        let gh_pr: GhPullRequest = serde_json::from_str(
+            r#"{"number":7,"title":"t","body":null,"state":"OPEN","labels":[],
+                "author":{"login":"alice"},"createdAt":"2026-05-01T00:00:00Z","mergedAt":null,
+                "url":"https://github.com/o/r/pull/7"}"#,
+        )
+        .unwrap();
+        let mut prs = vec![into_pull_request(gh_pr)];
+
+        attach_review_comments(&mut prs, parse_review_comment_lines(stdout).unwrap());
+
+        let bodies: Vec<&str> = prs[0]
+            .review_comments
+            .iter()
+            .map(|c| c.body.as_str())
+            .collect();
+        assert_eq!(bodies, vec!["Borrow checker, see above", "Why clone here?"]);
+    }
+}
@@ -39,8 +39,13 @@ impl ForgeReader for GitHubReader {
         internal::fetch_pr_count(&self.repo_spec())
     }
 
-    fn list_issues(&self, limit: usize, since: Option<&str>) -> Result<Vec<Issue>> {
-        internal::fetch_issues(&self.repo_spec(), limit, since)
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        internal::fetch_issues(&self.repo_spec(), limit, since, labels)
     }
 
     fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>> {
@@ -17,16 +17,21 @@
 //!
 //! let forge = detect("git@github.com:owner/repo.git");
 //! let reader = reader(&forge);
-//! let issues = reader.list_issues(100, None)?;
+//! let issues = reader.list_issues(100, None, &[])?;
 //! ```
 
+pub mod cache;
+pub mod retry;
 mod types;
 pub mod writer;
 
+pub mod gitea;
 pub mod github;
 mod none;
 pub mod sync;
 
+pub use cache::CachedReader;
+pub use retry::{RetryPolicy, RetryingReader};
 pub use types::*;
 pub use writer::{ForgeWriter, GitHubWriter, NoneWriter};
 
@@ -46,7 +51,15 @@ pub trait ForgeReader {
     fn get_pr_count(&self) -> Result<usize>;
 
     /// Fetch issues (with optional since filter for incremental updates).
-    fn list_issues(&self, limit: usize, since: Option<&str>) -> Result<Vec<Issue>>;
+    ///
+    /// Non-empty `labels` restricts results to labelled issues; matching
+    /// semantics follow the forge's own label filter.
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>>;
 
     /// Fetch pull requests.
     fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>>;
@@ -62,6 +75,41 @@ pub trait ForgeReader {
     fn get_max_issue_number(&self) -> Result<i64>;
 }
 
+impl<T: ForgeReader + ?Sized> ForgeReader for Box<T> {
+    fn get_issue_count(&self) -> Result<usize> {
+        (**self).get_issue_count()
+    }
+
+    fn get_pr_count(&self) -> Result<usize> {
+        (**self).get_pr_count()
+    }
+
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        (**self).list_issues(limit, since, labels)
+    }
+
+    fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>> {
+        (**self).list_pull_requests(limit, since)
+    }
+
+    fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+        (**self).get_pull_request(number)
+    }
+
+    fn get_issue(&self, number: i64) -> Result<Issue> {
+        (**self).get_issue(number)
+    }
+
+    fn get_max_issue_number(&self) -> Result<i64> {
+        (**self).get_max_issue_number()
+    }
+}
+
 /// Detect forge from git remote URL.
 ///
 /// Parses URLs like:
@@ -89,14 +137,26 @@ pub fn detect(remote_url: &str) -> Forge {
 }
 
 /// Get a ForgeReader for the detected forge.
+///
+/// Network readers retry transient failures (see `RetryPolicy::from_env`).
 pub fn reader(forge: &Forge) -> Box<dyn ForgeReader> {
+    let policy = RetryPolicy::from_env();
     match forge.kind {
-        ForgeKind::GitHub => Box::new(github::GitHubReader::new(forge)),
-        ForgeKind::Gitea => Box::new(none::NoneReader), // TODO: implement GiteaReader
+        ForgeKind::GitHub => Box::new(RetryingReader::new(
+            github::GitHubReader::new(forge),
+            policy,
+        )),
+        ForgeKind::Gitea => Box::new(RetryingReader::new(gitea::GiteaReader::new(forge), policy)),
         ForgeKind::None => Box::new(none::NoneReader),
     }
 }
 
+/// Get a ForgeReader backed by the project's on-disk forge cache.
+pub fn cached_reader(forge: &Forge, project_root: &std::path::Path) -> Box<dyn ForgeReader> {
+    let dir = crate::paths::project::forge_cache_dir(project_root, &forge.owner, &forge.repo);
+    Box::new(CachedReader::new(reader(forge), dir))
+}
+
 /// Parse remote URL into (host, owner, repo).
 fn parse_remote_url(url: &str) -> (String, String, String) {
     // SSH format: git@github.com:owner/repo.git
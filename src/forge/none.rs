@@ -19,7 +19,12 @@ impl ForgeReader for NoneReader {
         Ok(0) // No forge = no PRs
     }
 
-    fn list_issues(&self, _limit: usize, _since: Option<&str>) -> Result<Vec<Issue>> {
+    fn list_issues(
+        &self,
+        _limit: usize,
+        _since: Option<&str>,
+        _labels: &[String],
+    ) -> Result<Vec<Issue>> {
         Ok(vec![]) // No forge = no issues
     }
 
@@ -0,0 +1,249 @@
+//! Retry with backoff for forge fetches.
+//!
+//! "Do X": Ride out transient forge failures instead of aborting a scrape.
+//!
+//! `RetryingReader` wraps any `ForgeReader`. A failed call is retried with
+//! exponential backoff when its error looks transient - rate limiting (429,
+//! "rate limit") or a server error (5xx) - and returned at once otherwise, so
+//! a 404 costs one call. Errors come from `gh` stderr or Gitea responses, so
+//! classification matches on the message.
+
+use anyhow::Result;
+use regex::Regex;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use super::{ForgeReader, Issue, PullRequest};
+
+/// Environment variable overriding `RetryPolicy::default().max_attempts`
+pub const MAX_ATTEMPTS_ENV: &str = "PATINA_FORGE_MAX_ATTEMPTS";
+
+/// Longest wait between two attempts
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// How many times to try a forge call, and how long to wait in between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first (1 disables retrying)
+    pub max_attempts: u32,
+    /// Wait before the second attempt; doubles after each retry
+    pub base_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Default policy, with `max_attempts` from `PATINA_FORGE_MAX_ATTEMPTS` when set
+    pub fn from_env() -> Self {
+        let default = Self::default();
+        let max_attempts = std::env::var(MAX_ATTEMPTS_ENV)
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .filter(|&n: &u32| n > 0)
+            .unwrap_or(default.max_attempts);
+        Self {
+            max_attempts,
+            ..default
+        }
+    }
+
+    /// Wait before attempt `attempt + 1` (after `attempt` failures)
+    fn delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.base_delay.saturating_mul(factor).min(MAX_DELAY)
+    }
+}
+
+/// Matches transient failures: 429 / 5xx from `gh` ("HTTP 502") or Gitea
+/// ("(503 Service Unavailable)"), rate limiting, and timeouts
+fn transient_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)\bHTTP (?:429|5\d\d)\b|\((?:429|5\d\d) |rate limit|timed out")
+            .expect("Invalid transient error regex")
+    })
+}
+
+/// Whether a failed call is worth repeating (not-found, auth and parse errors are not)
+pub fn is_retryable(err: &anyhow::Error) -> bool {
+    err.chain()
+        .any(|cause| transient_regex().is_match(&cause.to_string()))
+}
+
+/// ForgeReader decorator that retries transient failures with backoff.
+pub struct RetryingReader<R: ForgeReader> {
+    inner: R,
+    policy: RetryPolicy,
+}
+
+impl<R: ForgeReader> RetryingReader<R> {
+    pub fn new(inner: R, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    fn retry<T>(&self, mut call: impl FnMut(&R) -> Result<T>) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            match call(&self.inner) {
+                Ok(value) => return Ok(value),
+                Err(err) if attempt < self.policy.max_attempts && is_retryable(&err) => {
+                    let delay = self.policy.delay(attempt);
+                    eprintln!(
+                        "  ⚠️  Forge call failed (attempt {}/{}), retrying in {:.1}s: {}",
+                        attempt,
+                        self.policy.max_attempts,
+                        delay.as_secs_f32(),
+                        err
+                    );
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+impl<R: ForgeReader> ForgeReader for RetryingReader<R> {
+    fn get_issue_count(&self) -> Result<usize> {
+        self.retry(|r| r.get_issue_count())
+    }
+
+    fn get_pr_count(&self) -> Result<usize> {
+        self.retry(|r| r.get_pr_count())
+    }
+
+    fn list_issues(
+        &self,
+        limit: usize,
+        since: Option<&str>,
+        labels: &[String],
+    ) -> Result<Vec<Issue>> {
+        self.retry(|r| r.list_issues(limit, since, labels))
+    }
+
+    fn list_pull_requests(&self, limit: usize, since: Option<&str>) -> Result<Vec<PullRequest>> {
+        self.retry(|r| r.list_pull_requests(limit, since))
+    }
+
+    fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+        self.retry(|r| r.get_pull_request(number))
+    }
+
+    fn get_issue(&self, number: i64) -> Result<Issue> {
+        self.retry(|r| r.get_issue(number))
+    }
+
+    fn get_max_issue_number(&self) -> Result<i64> {
+        self.retry(|r| r.get_max_issue_number())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forge::IssueState;
+    use std::cell::Cell;
+
+    /// Reader whose `get_issue` fails with `error` a set number of times first.
+    struct FlakyReader {
+        error: &'static str,
+        failures_left: Cell<usize>,
+        calls: Cell<usize>,
+    }
+
+    impl ForgeReader for FlakyReader {
+        fn get_issue_count(&self) -> Result<usize> {
+            Ok(1)
+        }
+        fn get_pr_count(&self) -> Result<usize> {
+            Ok(0)
+        }
+        fn list_issues(&self, _: usize, _: Option<&str>, _: &[String]) -> Result<Vec<Issue>> {
+            Ok(vec![])
+        }
+        fn list_pull_requests(&self, _: usize, _: Option<&str>) -> Result<Vec<PullRequest>> {
+            Ok(vec![])
+        }
+        fn get_pull_request(&self, number: i64) -> Result<PullRequest> {
+            anyhow::bail!("no PR #{}", number)
+        }
+        fn get_issue(&self, number: i64) -> Result<Issue> {
+            self.calls.set(self.calls.get() + 1);
+            if self.failures_left.get() > 0 {
+                self.failures_left.set(self.failures_left.get() - 1);
+                anyhow::bail!("gh issue view #{} failed: {}", number, self.error);
+            }
+            Ok(Issue {
+                number,
+                title: "Flaky test".to_string(),
+                body: None,
+                state: IssueState::Open,
+                author: "alice".to_string(),
+                labels: vec![],
+                created_at: "2026-05-01T00:00:00Z".to_string(),
+                updated_at: "2026-05-01T00:00:00Z".to_string(),
+                url: "https://github.com/o/r/issues/500".to_string(),
+            })
+        }
+        fn get_max_issue_number(&self) -> Result<i64> {
+            Ok(7)
+        }
+    }
+
+    fn flaky(error: &'static str, failures: usize) -> RetryingReader<FlakyReader> {
+        let reader = FlakyReader {
+            error,
+            failures_left: Cell::new(failures),
+            calls: Cell::new(0),
+        };
+        let policy = RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::ZERO,
+        };
+        RetryingReader::new(reader, policy)
+    }
+
+    #[test]
+    fn test_transient_failures_retried_until_success() -> Result<()> {
+        let reader = flaky("HTTP 502: Bad Gateway", 2);
+        assert_eq!(reader.get_issue(500)?.title, "Flaky test");
+        assert_eq!(reader.inner.calls.get(), 3);
+
+        // Out of attempts: the last error surfaces
+        let reader = flaky("API rate limit exceeded", 3);
+        assert!(reader.get_issue(500).is_err());
+        assert_eq!(reader.inner.calls.get(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_not_found_is_not_retried() {
+        let reader = flaky("HTTP 404: Not Found", 1);
+        let err = reader.get_issue(500).unwrap_err();
+        assert!(err.to_string().contains("404"));
+        assert_eq!(reader.inner.calls.get(), 1);
+    }
+
+    #[test]
+    fn test_backoff_doubles_up_to_cap() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1), Duration::from_secs(1));
+        assert_eq!(policy.delay(3), Duration::from_secs(4));
+        assert_eq!(policy.delay(10), MAX_DELAY);
+
+        let gitea = anyhow::anyhow!("Gitea API /repos/o/r failed (503 Service Unavailable): down");
+        assert!(is_retryable(&gitea));
+        assert!(!is_retryable(&anyhow::anyhow!(
+            "Gitea API /repos/o/r/issues/500 failed (404 Not Found): "
+        )));
+    }
+}
@@ -47,10 +47,16 @@ pub struct PullRequest {
     pub labels: Vec<String>,
     pub created_at: String,
     pub merged_at: Option<String>,
+    /// Last upstream activity (absent in caches written before it was tracked)
+    #[serde(default)]
+    pub updated_at: Option<String>,
     pub url: String,
     // The valuable context
     pub linked_issues: Vec<i64>,
     pub comments: Vec<Comment>,
+    /// Inline code review comments (diff threads), kept apart from conversation comments
+    #[serde(default)]
+    pub review_comments: Vec<Comment>,
     pub approvals: i32,
 }
 
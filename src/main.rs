@@ -31,6 +31,59 @@ impl Dimension {
     }
 }
 
+/// Output format for scry results
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ScryFormat {
+    /// Console output with ranks and oracle contributions
+    Text,
+    /// Markdown list with file links and fenced snippets, for PRs and issues
+    Markdown,
+}
+
+/// Search mode for scry text queries
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ScryMode {
+    /// Pick lexical or semantic from the query's shape
+    Auto,
+    /// Vector search only
+    Semantic,
+    /// FTS5 keyword search only
+    Lexical,
+}
+
+impl ScryMode {
+    pub fn into_search_mode(self) -> commands::scry::SearchMode {
+        use commands::scry::SearchMode;
+        match self {
+            ScryMode::Auto => SearchMode::Auto,
+            ScryMode::Semantic => SearchMode::Semantic,
+            ScryMode::Lexical => SearchMode::Lexical,
+        }
+    }
+}
+
+/// Index vector quantization for oxidize
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Quant {
+    /// 32-bit floats (default, exact)
+    F32,
+    /// 16-bit floats (half the size)
+    F16,
+    /// 8-bit integers (quarter the size)
+    I8,
+}
+
+impl Quant {
+    pub fn into_quantization(self) -> commands::oxidize::index_meta::Quantization {
+        use commands::oxidize::index_meta::Quantization;
+        match self {
+            Quant::F32 => Quantization::F32,
+            Quant::F16 => Quantization::F16,
+            Quant::I8 => Quantization::I8,
+        }
+    }
+}
+
 /// LLM adapter for project initialization
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
 pub enum Llm {
@@ -109,6 +162,10 @@ enum Commands {
         /// Output results as JSON
         #[arg(short, long)]
         json: bool,
+
+        /// Also audit disk usage (e.g. embeddings left over from other models)
+        #[arg(long)]
+        audit: bool,
     },
 
     /// Manage project versioning (semver: MAJOR.MINOR.PATCH)
@@ -133,6 +190,10 @@ enum Commands {
         /// Rebuild database from scratch (for ref repos: removes old eventlog bloat)
         #[arg(long)]
         rebuild: bool,
+
+        /// Suppress progress updates (for scripting)
+        #[arg(long, global = true)]
+        quiet: bool,
     },
 
     /// Build embeddings and projections from recipe
@@ -140,6 +201,18 @@ enum Commands {
         /// Build for a registered external repo (e.g., clawdbot/clawdbot)
         #[arg(long)]
         repo: Option<String>,
+
+        /// Scalar type for index vectors (f16/i8 shrink indices)
+        #[arg(long, value_enum, default_value = "f32")]
+        quant: Quant,
+
+        /// Retrain projections and rebuild indices (default: re-embed only changed rows)
+        #[arg(long)]
+        force: bool,
+
+        /// Pull the embedding model first if it isn't cached (skipped when PATINA_OFFLINE is set)
+        #[arg(long)]
+        auto_pull: bool,
     },
 
     /// Rebuild .patina/ from layer/ and local sources (portability)
@@ -163,9 +236,10 @@ enum Commands {
 
     /// Search codebase knowledge — fast hybrid search over symbols, functions,
     /// types, git history, session learnings, and project beliefs
+    #[command(after_help = commands::exit::HELP)]
     Scry {
         #[command(subcommand)]
-        command: Option<ScryCommands>,
+        command: Option<Box<ScryCommands>>,
 
         /// Query text to search for (optional if --file is provided)
         #[arg(conflicts_with = "command")]
@@ -179,21 +253,29 @@ enum Commands {
         #[arg(long, conflicts_with_all = ["command", "file"])]
         belief: Option<String>,
 
-        /// Filter results by content type (used with --belief): code, commits, sessions, patterns, beliefs
+        /// Find items similar to a known result: "file::fn", a belief id, a pattern id, a SHA, or "#N"
+        #[arg(long, value_name = "DOC_ID", conflicts_with_all = ["command", "file", "belief"])]
+        neighbors: Option<String>,
+
+        /// Filter results by content type (used with --belief or --neighbors): code, commits, sessions, patterns, beliefs
         #[arg(long, value_name = "TYPE")]
         content_type: Option<String>,
 
-        /// Maximum number of results (default: 10)
-        #[arg(long, default_value = "10")]
-        limit: usize,
+        /// Only keep results under this directory prefix or glob (e.g., "src/server/*")
+        #[arg(long, value_name = "PREFIX|GLOB")]
+        path: Option<String>,
 
-        /// Minimum similarity score (0.0-1.0, default: 0.0)
-        #[arg(long, default_value = "0.0")]
-        min_score: f32,
+        /// Maximum number of results (default: [scry] limit in config, else 10)
+        #[arg(long)]
+        limit: Option<usize>,
 
-        /// Query a specific external repo (registered via 'patina repo')
+        /// Minimum similarity score (0.0-1.0, default: [scry] min_score in config, else 0.0)
         #[arg(long)]
-        repo: Option<String>,
+        min_score: Option<f32>,
+
+        /// Query external repos registered via 'patina repo' (comma-separated or repeated)
+        #[arg(long, value_delimiter = ',')]
+        repo: Vec<String>,
 
         /// Query all registered repos (current project + reference repos)
         #[arg(long)]
@@ -203,6 +285,10 @@ enum Commands {
         #[arg(long)]
         include_issues: bool,
 
+        /// Include persona knowledge even when [scry] include_persona = false
+        #[arg(long, conflicts_with = "no_persona")]
+        include_persona: bool,
+
         /// Exclude persona knowledge from results
         #[arg(long)]
         no_persona: bool,
@@ -211,6 +297,22 @@ enum Commands {
         #[arg(long)]
         explain: bool,
 
+        /// Scale an oracle's RRF contribution, e.g. lexical=2.0 (repeatable; unlisted = 1.0)
+        #[arg(long = "oracle-weight", value_name = "NAME=WEIGHT")]
+        oracle_weight: Vec<String>,
+
+        /// RRF smoothing constant for fusion (default: 60; lower lets top ranks dominate)
+        #[arg(long = "rrf-k", value_name = "K")]
+        rrf_k: Option<usize>,
+
+        /// Only fuse results from this oracle, e.g. lexical (repeatable; default: all available)
+        #[arg(long = "oracle", value_name = "NAME")]
+        oracle: Vec<String>,
+
+        /// Pull the embedding model first if it isn't cached (skipped when PATINA_OFFLINE is set)
+        #[arg(long)]
+        auto_pull: bool,
+
         /// Show belief impact for code results — which beliefs may be affected (E4.6a)
         #[arg(long)]
         impact: bool,
@@ -230,6 +332,26 @@ enum Commands {
         /// Use legacy single-oracle search (deprecated, removed in v0.12.0)
         #[arg(long, conflicts_with = "command")]
         legacy: bool,
+
+        /// Force lexical or semantic search instead of routing by query shape
+        #[arg(long, value_enum, default_value = "auto", conflicts_with = "oracle")]
+        mode: ScryMode,
+
+        /// Output format: text (default) or markdown for pasting into PRs/issues
+        #[arg(long, value_enum, default_value = "text", conflicts_with_all = ["command", "detail", "explain"])]
+        format: ScryFormat,
+
+        /// Skip --file partners that changed together fewer than N times (temporal)
+        #[arg(long, value_name = "N", default_value = "0", requires = "file")]
+        min_count: i64,
+
+        /// Print time spent embedding, projecting, loading the index and searching (stderr)
+        #[arg(long, conflicts_with = "command")]
+        timing: bool,
+
+        /// Write results to this file (JSON, or markdown with --format markdown); stdout shows a summary
+        #[arg(long, value_name = "PATH", conflicts_with_all = ["command", "detail", "explain"])]
+        output: Option<String>,
     },
 
     /// Get project patterns and conventions — USE THIS to understand design rules
@@ -250,6 +372,14 @@ enum Commands {
         /// Show real-world precision from session feedback loop (Phase 3)
         #[arg(long)]
         feedback: bool,
+
+        /// Output results as JSON (suppresses tables)
+        #[arg(long)]
+        json: bool,
+
+        /// Curated query set (bench retrieval JSON format) to use as ground truth
+        #[arg(long)]
+        query_set: Option<String>,
     },
 
     /// Benchmark retrieval quality with ground truth
@@ -262,6 +392,10 @@ enum Commands {
     Persona {
         #[command(subcommand)]
         command: PersonaCommands,
+
+        /// Persona to use, e.g. work or oss (default: $PATINA_PERSONA, else "default")
+        #[arg(long, global = true, value_name = "NAME")]
+        persona: Option<String>,
     },
 
     /// Manage external repositories for cross-project knowledge
@@ -385,6 +519,7 @@ enum Commands {
     },
 
     /// Query codebase structure (modules, imports, call graph)
+    #[command(after_help = commands::exit::HELP)]
     Assay {
         #[command(subcommand)]
         command: Option<AssayCommands>,
@@ -407,6 +542,10 @@ enum Commands {
         /// Query all registered repos (current project + reference repos)
         #[arg(long)]
         all_repos: bool,
+
+        /// Write results as JSON to this file; stdout shows a summary
+        #[arg(long, global = true, value_name = "PATH")]
+        output: Option<String>,
     },
 }
 
@@ -437,6 +576,10 @@ enum AssayCommands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Show the indexed file each import resolves to
+        #[arg(long)]
+        resolve: bool,
     },
     /// What modules import a given module
     Importers {
@@ -463,6 +606,18 @@ enum AssayCommands {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Rank by edit distance to the pattern, tolerating typos
+        #[arg(long, requires = "pattern")]
+        fuzzy: bool,
+
+        /// Only `unsafe` functions
+        #[arg(long = "unsafe")]
+        unsafe_only: bool,
+
+        /// Only `const` functions
+        #[arg(long = "const")]
+        const_only: bool,
     },
     /// What functions call a given function
     Callers {
@@ -477,6 +632,19 @@ enum AssayCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Methods defined on a type (impl blocks and traits)
+    Methods {
+        /// Type or trait name (exact match)
+        type_name: String,
+
+        /// Maximum number of results
+        #[arg(long, default_value = "100")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// What functions a given function calls
     Callees {
         /// Function name to search for
@@ -490,11 +658,61 @@ enum AssayCommands {
         #[arg(long)]
         json: bool,
     },
+    /// Clusters of structurally identical functions (duplication report)
+    Clones {
+        /// Ignore functions shorter than this many lines
+        #[arg(long, default_value = "5")]
+        min_lines: usize,
+
+        /// Maximum number of clusters
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Run a tree-sitter query over indexed files and list its captures
+    Query {
+        /// Language the pattern is written for (rust, python, go, ...)
+        #[arg(long)]
+        lang: String,
+
+        /// S-expression query, e.g. '(function_item name: (identifier) @n)'
+        #[arg(long)]
+        pattern: String,
+
+        /// Maximum number of captures
+        #[arg(long, default_value = "100")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List files (and their functions) a contributor has modified
+    Authored {
+        /// Author name to match (case-insensitive substring)
+        #[arg(long = "by")]
+        author: String,
+
+        /// Maximum number of files
+        #[arg(long, default_value = "50")]
+        limit: usize,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Compute structural signals for all modules (is_used, activity, centrality)
     Derive {
         /// Output as JSON
         #[arg(long)]
         json: bool,
+
+        /// Only count commits since a date (YYYY-MM-DD) or N days ago (e.g. 30d)
+        #[arg(long, value_name = "DATE|Nd")]
+        since: Option<String>,
     },
     /// Derive temporal moments from git history (genesis, breaking, migration, etc.)
     #[command(name = "derive-moments")]
@@ -503,6 +721,12 @@ enum AssayCommands {
         #[arg(long)]
         json: bool,
     },
+    /// One-screen overview: totals, top hotspots, dormant modules, import cycles, coverage
+    Summary {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 /// Common arguments for all scrape subcommands
@@ -515,6 +739,18 @@ struct ScrapeArgs {
     /// Force full re-index (ignore incremental updates)
     #[arg(long)]
     force: bool,
+
+    /// Skip source files with more lines than this (overrides [scrape] max_file_lines; 0 = no limit)
+    #[arg(long, value_name = "LINES")]
+    max_file_lines: Option<usize>,
+
+    /// Skip source files larger than this many bytes (overrides [scrape] max_file_bytes; 0 = no limit)
+    #[arg(long, value_name = "BYTES")]
+    max_file_bytes: Option<u64>,
+
+    /// Keep running and re-scrape incrementally whenever files change
+    #[arg(long, conflicts_with = "init")]
+    watch: bool,
 }
 
 #[derive(Subcommand)]
@@ -543,6 +779,7 @@ enum ScrapeCommands {
         full: bool,
     },
     /// Fetch issues and PRs from forge (GitHub, Gitea, etc.)
+    #[command(after_help = commands::exit::HELP)]
     Forge {
         /// Full rebuild (ignore incremental)
         #[arg(long)]
@@ -567,6 +804,20 @@ enum ScrapeCommands {
         /// Target a ref repo instead of current project
         #[arg(long)]
         repo: Option<String>,
+
+        /// Only fetch issues with these labels (comma-separated, e.g., bug,good-first-issue)
+        #[arg(long, value_delimiter = ',')]
+        labels: Vec<String>,
+
+        /// Always hit the forge API, ignoring .patina/cache/forge
+        #[arg(long)]
+        no_cache: bool,
+    },
+    /// Show what is indexed: row counts per table and which indices exist
+    Status {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
@@ -574,9 +825,9 @@ enum ScrapeCommands {
 enum BenchCommands {
     /// Benchmark retrieval quality
     Retrieval {
-        /// Path to query set JSON file
-        #[arg(long, short = 'q')]
-        query_set: String,
+        /// Query set JSON file; repeat to run a suite, `-` reads one from stdin
+        #[arg(long, short = 'q', required = true)]
+        query_set: Vec<String>,
 
         /// Number of results per query (default: 10)
         #[arg(long, default_value = "10")]
@@ -590,6 +841,10 @@ enum BenchCommands {
         #[arg(long, short)]
         verbose: bool,
 
+        /// Write per-query metrics as CSV to this path (for diffing across commits)
+        #[arg(long, value_name = "PATH")]
+        csv: Option<String>,
+
         /// Override RRF k value (default: from config or 60)
         #[arg(long)]
         rrf_k: Option<usize>,
@@ -651,11 +906,15 @@ enum ScryCommands {
         /// Maximum number of results (default: 10)
         #[arg(long, default_value = "10")]
         limit: usize,
+
+        /// Only changes by this author (case-insensitive substring)
+        #[arg(long)]
+        author: Option<String>,
     },
 
     /// Explain why a specific result was returned
     Why {
-        /// Document ID to explain (e.g., "src/retrieval/engine.rs")
+        /// Result ID to explain: a file, "file::fn", a belief id, a pattern id, a SHA, or "#N"
         doc_id: String,
 
         /// The query that returned this result
@@ -708,6 +967,10 @@ enum PersonaCommands {
         /// Event ID this supersedes (replaces old knowledge)
         #[arg(long)]
         supersedes: Option<String>,
+
+        /// Hide from search after this long (e.g., 30m, 12h, 7d, 2w)
+        #[arg(long)]
+        ttl: Option<String>,
     },
 
     /// Search persona knowledge
@@ -726,6 +989,10 @@ enum PersonaCommands {
         /// Filter by domains (comma-separated)
         #[arg(long, value_delimiter = ',')]
         domains: Option<Vec<String>>,
+
+        /// Pull the embedding model first if it isn't cached (skipped when PATINA_OFFLINE is set)
+        #[arg(long)]
+        auto_pull: bool,
     },
 
     /// List captured knowledge
@@ -739,8 +1006,50 @@ enum PersonaCommands {
         domains: Option<Vec<String>>,
     },
 
-    /// Process events into searchable index
-    Materialize,
+    /// Process new events into the searchable index (incremental unless --force)
+    Materialize {
+        /// Similarity above which an older near-identical note is superseded by the newer one
+        #[arg(long, default_value_t = commands::persona::DEFAULT_DEDUP_THRESHOLD)]
+        dedup_threshold: f32,
+
+        /// Drop the index and re-embed every event instead of resuming
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Delete an entry by event id or content match (appends a tombstone)
+    Delete {
+        /// Event ID to delete
+        #[arg(conflicts_with = "matching")]
+        event_id: Option<String>,
+
+        /// Delete all active entries whose content contains this text (case-insensitive)
+        #[arg(long)]
+        matching: Option<String>,
+
+        /// Show what would be deleted without writing
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Show domain counts and active/superseded split
+    Stats {
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Export all persona events to a portable JSONL bundle
+    Export {
+        /// Output bundle path
+        path: String,
+    },
+
+    /// Import a JSONL bundle (skips events already present, then materializes)
+    Import {
+        /// Bundle path to import
+        path: String,
+    },
 
     /// Check persona oracle status
     Status,
@@ -810,7 +1119,18 @@ enum BumpType {
     Patch,
 }
 
-fn main() -> Result<()> {
+fn main() {
+    if let Err(e) = run() {
+        let code = commands::exit::exit_code(&e);
+        // "No results" was already reported by the command itself
+        if code != commands::exit::Exit::NoResults.code() {
+            eprintln!("Error: {:?}", e);
+        }
+        std::process::exit(code);
+    }
+}
+
+fn run() -> Result<()> {
     // Run migrations early (before any command)
     patina::migration::migrate_if_needed();
     commands::repo::migrate_registry_paths();
@@ -877,20 +1197,37 @@ fn main() -> Result<()> {
                 commands::dev::update_fixtures::execute(fixture.as_deref())?;
             }
         },
-        Some(Commands::Scrape { command, rebuild }) => {
+        Some(Commands::Scrape {
+            command,
+            rebuild,
+            quiet,
+        }) => {
+            commands::scrape::progress::set_quiet(quiet);
             if rebuild {
                 commands::scrape::execute_rebuild()?;
             } else {
                 match command {
                     None => commands::scrape::execute_all()?,
                     Some(ScrapeCommands::Code { args }) => {
-                        commands::scrape::execute_code(args.init, args.force)?
+                        let config = commands::scrape::ScrapeConfig {
+                            max_file_lines: args.max_file_lines,
+                            max_file_bytes: args.max_file_bytes,
+                            ..commands::scrape::ScrapeConfig::new(args.force)
+                        };
+                        if args.watch {
+                            commands::scrape::execute_watch(config)?
+                        } else {
+                            commands::scrape::execute_code_with(args.init, config)?
+                        }
                     }
                     Some(ScrapeCommands::Git { full }) => commands::scrape::execute_git(full)?,
                     Some(ScrapeCommands::Sessions { full }) => {
                         commands::scrape::execute_sessions(full)?
                     }
                     Some(ScrapeCommands::Layer { full }) => commands::scrape::execute_layer(full)?,
+                    Some(ScrapeCommands::Status { json }) => {
+                        commands::scrape::execute_status(json)?
+                    }
                     Some(ScrapeCommands::Forge {
                         full,
                         status,
@@ -898,15 +1235,39 @@ fn main() -> Result<()> {
                         log,
                         limit,
                         repo,
-                    }) => commands::scrape::execute_forge(full, status, sync, log, limit, repo)?,
+                        labels,
+                        no_cache,
+                    }) => commands::scrape::execute_forge(
+                        full,
+                        status,
+                        sync,
+                        log,
+                        limit,
+                        repo,
+                        commands::scrape::ForgeFetchOptions {
+                            labels,
+                            no_cache,
+                            require_auth: true,
+                        },
+                    )?,
                 }
             }
         }
-        Some(Commands::Oxidize { repo }) => {
+        Some(Commands::Oxidize {
+            repo,
+            quant,
+            force,
+            auto_pull,
+        }) => {
+            let options = commands::oxidize::OxidizeOptions {
+                quant: quant.into_quantization(),
+                force,
+                auto_pull,
+            };
             if let Some(repo_name) = repo {
-                commands::oxidize::oxidize_for_repo(&repo_name)?;
+                commands::oxidize::oxidize_for_repo(&repo_name, options)?;
             } else {
-                commands::oxidize::oxidize()?;
+                commands::oxidize::oxidize_with(options)?;
             }
         }
         Some(Commands::Rebuild {
@@ -928,28 +1289,50 @@ fn main() -> Result<()> {
             query,
             file,
             belief,
+            neighbors,
             content_type,
+            path,
             limit,
             min_score,
             repo,
             all_repos,
             include_issues,
+            include_persona,
             no_persona,
             explain,
+            oracle_weight,
+            rrf_k,
+            oracle,
+            auto_pull,
             impact,
             detail,
             rank,
             full,
             legacy,
+            mode,
+            format,
+            min_count,
+            timing,
+            output,
         }) => {
             // Handle subcommands first
             if let Some(subcmd) = command {
-                match subcmd {
+                match *subcmd {
                     ScryCommands::Orient { path, limit } => {
                         commands::scry::execute_orient(&path, limit)?;
                     }
-                    ScryCommands::Recent { query, days, limit } => {
-                        commands::scry::execute_recent(query.as_deref(), days, limit)?;
+                    ScryCommands::Recent {
+                        query,
+                        days,
+                        limit,
+                        author,
+                    } => {
+                        commands::scry::execute_recent(
+                            query.as_deref(),
+                            days,
+                            limit,
+                            author.as_deref(),
+                        )?;
                     }
                     ScryCommands::Why { doc_id, query } => {
                         commands::scry::execute_why(&doc_id, &query)?;
@@ -972,21 +1355,51 @@ fn main() -> Result<()> {
                 // D3: --detail mode — fetch full content for one result
                 commands::scry::execute_detail(query_id, rank)?;
             } else {
+                let oracle_weights = oracle_weight
+                    .iter()
+                    .map(|spec| retrieval::parse_oracle_weight(spec))
+                    .collect::<Result<Vec<_>>>()?;
+                // One repo keeps the single-repo path; several are searched side by side
+                let (repo, repos) = if repo.len() > 1 {
+                    (None, repo)
+                } else {
+                    (repo.into_iter().next(), Vec::new())
+                };
+                // CLI flags override the project's [scry] defaults
+                let defaults = commands::scry::ScryOptions::configured(std::path::Path::new("."));
                 let options = commands::scry::ScryOptions {
-                    limit,
-                    min_score,
+                    limit: limit.unwrap_or(defaults.limit),
+                    min_score: min_score.unwrap_or(defaults.min_score),
                     dimension: None,
                     file,
                     repo,
+                    repos,
                     all_repos,
                     include_issues,
-                    include_persona: !no_persona,
+                    // Either flag beats [scry] include_persona, which beats the default
+                    include_persona: match (include_persona, no_persona) {
+                        (true, _) => true,
+                        (_, true) => false,
+                        _ => defaults.include_persona,
+                    },
                     explain,
+                    oracle_weights,
+                    rrf_k,
+                    oracles: oracle,
                     belief,
+                    neighbors,
                     content_type,
                     impact,
                     full,
                     legacy,
+                    auto_pull,
+                    markdown: format == ScryFormat::Markdown,
+                    mode: mode.into_search_mode(),
+                    min_count,
+                    output,
+                    timing,
+                    path,
+                    project_root: defaults.project_root,
                 };
                 commands::scry::execute(query.as_deref(), options)?;
             }
@@ -997,11 +1410,17 @@ fn main() -> Result<()> {
         Some(Commands::Eval {
             dimension,
             feedback,
+            json,
+            query_set,
         }) => {
             if feedback {
                 commands::eval::execute_feedback()?;
             } else {
-                commands::eval::execute(dimension.map(|d| d.as_str().to_string()))?;
+                commands::eval::execute(
+                    dimension.map(|d| d.as_str().to_string()),
+                    json,
+                    query_set,
+                )?;
             }
         }
         Some(Commands::Bench { command }) => match command {
@@ -1010,16 +1429,18 @@ fn main() -> Result<()> {
                 limit,
                 json,
                 verbose,
+                csv,
                 rrf_k,
                 fetch_multiplier,
                 oracle,
                 repo,
             } => {
                 let options = commands::bench::BenchOptions {
-                    query_set,
+                    query_sets: query_set,
                     limit,
                     json,
                     verbose,
+                    csv,
                     rrf_k,
                     fetch_multiplier,
                     oracle,
@@ -1041,34 +1462,60 @@ fn main() -> Result<()> {
                 commands::bench::generate(options)?;
             }
         },
-        Some(Commands::Persona { command }) => match command {
-            PersonaCommands::Note {
-                content,
-                domains,
-                supersedes,
-            } => {
-                commands::persona::execute_note(&content, domains, supersedes)?;
-            }
-            PersonaCommands::Query {
-                query,
-                limit,
-                min_score,
-                domains,
-            } => {
-                commands::persona::execute_query(&query, limit, min_score, domains)?;
-            }
-            PersonaCommands::List { limit, domains } => {
-                commands::persona::execute_list(limit, domains)?;
-            }
-            PersonaCommands::Materialize => {
-                commands::persona::execute_materialize()?;
-            }
-            PersonaCommands::Status => {
-                commands::persona::execute_status()?;
+        Some(Commands::Persona { command, persona }) => {
+            let persona = commands::persona::resolve(persona.as_deref())?;
+            match command {
+                PersonaCommands::Note {
+                    content,
+                    domains,
+                    supersedes,
+                    ttl,
+                } => {
+                    commands::persona::execute_note(&persona, &content, domains, supersedes, ttl)?;
+                }
+                PersonaCommands::Query {
+                    query,
+                    limit,
+                    min_score,
+                    domains,
+                    auto_pull,
+                } => {
+                    commands::persona::execute_query(
+                        &persona, &query, limit, min_score, domains, auto_pull,
+                    )?;
+                }
+                PersonaCommands::List { limit, domains } => {
+                    commands::persona::execute_list(&persona, limit, domains)?;
+                }
+                PersonaCommands::Materialize {
+                    dedup_threshold,
+                    force,
+                } => {
+                    commands::persona::execute_materialize(&persona, dedup_threshold, force)?;
+                }
+                PersonaCommands::Delete {
+                    event_id,
+                    matching,
+                    dry_run,
+                } => {
+                    commands::persona::execute_delete(&persona, event_id, matching, dry_run)?;
+                }
+                PersonaCommands::Stats { json } => {
+                    commands::persona::execute_stats(&persona, json)?;
+                }
+                PersonaCommands::Export { path } => {
+                    commands::persona::execute_export(&persona, &path)?;
+                }
+                PersonaCommands::Import { path } => {
+                    commands::persona::execute_import(&persona, &path)?;
+                }
+                PersonaCommands::Status => {
+                    commands::persona::execute_status(&persona)?;
+                }
             }
-        },
-        Some(Commands::Doctor { json }) => {
-            let exit_code = commands::doctor::execute(json)?;
+        }
+        Some(Commands::Doctor { json, audit }) => {
+            let exit_code = commands::doctor::execute(json, audit)?;
             if exit_code != 0 {
                 std::process::exit(exit_code);
             }
@@ -1140,6 +1587,7 @@ fn main() -> Result<()> {
             json,
             repo,
             all_repos,
+            output,
         }) => {
             let options = match command {
                 None => commands::assay::AssayOptions {
@@ -1149,6 +1597,13 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
                 Some(AssayCommands::Inventory {
                     pattern,
@@ -1161,11 +1616,19 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
                 Some(AssayCommands::Imports {
                     module,
                     limit,
                     json,
+                    resolve,
                 }) => commands::assay::AssayOptions {
                     query_type: commands::assay::QueryType::Imports,
                     pattern: Some(module),
@@ -1173,6 +1636,13 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve,
+                    since: None,
                 },
                 Some(AssayCommands::Importers {
                     module,
@@ -1185,11 +1655,21 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
                 Some(AssayCommands::Functions {
                     pattern,
                     limit,
                     json,
+                    fuzzy,
+                    unsafe_only,
+                    const_only,
                 }) => commands::assay::AssayOptions {
                     query_type: commands::assay::QueryType::Functions,
                     pattern,
@@ -1197,6 +1677,13 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy,
+                    min_lines: 0,
+                    unsafe_only,
+                    const_only,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
                 Some(AssayCommands::Callers {
                     function,
@@ -1209,6 +1696,32 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
+                },
+                Some(AssayCommands::Methods {
+                    type_name,
+                    limit,
+                    json,
+                }) => commands::assay::AssayOptions {
+                    query_type: commands::assay::QueryType::Methods,
+                    pattern: Some(type_name),
+                    limit,
+                    json,
+                    repo,
+                    all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
                 Some(AssayCommands::Callees {
                     function,
@@ -1221,14 +1734,86 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
-                Some(AssayCommands::Derive { json }) => commands::assay::AssayOptions {
+                Some(AssayCommands::Clones {
+                    min_lines,
+                    limit,
+                    json,
+                }) => commands::assay::AssayOptions {
+                    query_type: commands::assay::QueryType::Clones,
+                    pattern: None,
+                    limit,
+                    json,
+                    repo,
+                    all_repos,
+                    fuzzy: false,
+                    min_lines,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
+                },
+                Some(AssayCommands::Query {
+                    lang,
+                    pattern,
+                    limit,
+                    json,
+                }) => commands::assay::AssayOptions {
+                    query_type: commands::assay::QueryType::Query,
+                    pattern: Some(pattern),
+                    limit,
+                    json,
+                    repo,
+                    all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: Some(lang),
+                    resolve: false,
+                    since: None,
+                },
+                Some(AssayCommands::Authored {
+                    author,
+                    limit,
+                    json,
+                }) => commands::assay::AssayOptions {
+                    query_type: commands::assay::QueryType::Authored,
+                    pattern: Some(author),
+                    limit,
+                    json,
+                    repo,
+                    all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
+                },
+                Some(AssayCommands::Derive { json, since }) => commands::assay::AssayOptions {
                     query_type: commands::assay::QueryType::Derive,
                     pattern: None,
                     limit: 0,
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since,
                 },
                 Some(AssayCommands::DeriveMoments { json }) => commands::assay::AssayOptions {
                     query_type: commands::assay::QueryType::DeriveMoments,
@@ -1237,9 +1822,36 @@ fn main() -> Result<()> {
                     json,
                     repo,
                     all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
+                },
+                Some(AssayCommands::Summary { json }) => commands::assay::AssayOptions {
+                    query_type: commands::assay::QueryType::Summary,
+                    pattern: None,
+                    limit: 0,
+                    json,
+                    repo,
+                    all_repos,
+                    fuzzy: false,
+                    min_lines: 0,
+                    unsafe_only: false,
+                    const_only: false,
+                    lang: None,
+                    resolve: false,
+                    since: None,
                 },
             };
-            commands::assay::execute(options)?;
+            match output {
+                Some(path) => {
+                    commands::assay::execute_to_file(&options, std::path::Path::new(&path))?
+                }
+                None => commands::assay::execute(options)?,
+            }
         }
     }
 
@@ -3,6 +3,7 @@
 //! JSON-RPC 2.0 over stdio. No external SDK - blocking I/O, minimal dependencies.
 
 mod protocol;
+mod resources;
 mod server;
 
 pub use server::run_mcp_server;
@@ -0,0 +1,185 @@
+//! MCP resources - knowledge-base files a client can browse
+//!
+//! "Do X": Let clients enumerate and fetch layer markdown without shelling out.
+//!
+//! URIs:
+//! - `patina://belief/<id>`   → layer/surface/epistemic/beliefs/<id>.md
+//! - `patina://pattern/<name>` → layer/core/<name>.md, else layer/surface/<name>.md
+//! - `patina://session/<id>`  → layer/sessions/<id>.md (most recent only)
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::commands::scrape::beliefs::BELIEFS_DIR;
+
+const CORE_DIR: &str = "layer/core";
+const SURFACE_DIR: &str = "layer/surface";
+const SESSIONS_DIR: &str = "layer/sessions";
+
+/// How many sessions `resources/list` surfaces (newest first)
+const RECENT_SESSIONS: usize = 20;
+
+/// One entry in a `resources/list` result
+#[derive(Debug, Serialize)]
+pub struct Resource {
+    pub uri: String,
+    pub name: String,
+    #[serde(rename = "mimeType")]
+    pub mime_type: &'static str,
+}
+
+impl Resource {
+    fn new(kind: &str, name: String) -> Self {
+        Self {
+            uri: format!("patina://{}/{}", kind, name),
+            name,
+            mime_type: "text/markdown",
+        }
+    }
+}
+
+/// Markdown file stems in a directory, sorted
+fn markdown_stems(dir: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut stems: Vec<String> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|p| p.file_stem().map(|s| s.to_string_lossy().to_string()))
+        .collect();
+    stems.sort();
+    stems
+}
+
+/// List beliefs, patterns, and recent sessions under `project_root`
+pub fn list_resources(project_root: &Path) -> Vec<Resource> {
+    let mut resources: Vec<Resource> = markdown_stems(&project_root.join(BELIEFS_DIR))
+        .into_iter()
+        .map(|id| Resource::new("belief", id))
+        .collect();
+
+    let mut patterns = markdown_stems(&project_root.join(CORE_DIR));
+    for name in markdown_stems(&project_root.join(SURFACE_DIR)) {
+        if !patterns.contains(&name) {
+            patterns.push(name);
+        }
+    }
+    resources.extend(patterns.into_iter().map(|n| Resource::new("pattern", n)));
+
+    // Session ids start with a timestamp, so reverse order is newest first
+    let mut sessions = markdown_stems(&project_root.join(SESSIONS_DIR));
+    sessions.reverse();
+    sessions.truncate(RECENT_SESSIONS);
+    resources.extend(sessions.into_iter().map(|id| Resource::new("session", id)));
+
+    resources
+}
+
+/// Candidate files for a resource URI (first existing one wins)
+fn resolve(project_root: &Path, uri: &str) -> Result<Vec<PathBuf>> {
+    let rest = uri
+        .strip_prefix("patina://")
+        .with_context(|| format!("Not a patina resource URI: {}", uri))?;
+    let (kind, name) = rest
+        .split_once('/')
+        .with_context(|| format!("Malformed resource URI: {}", uri))?;
+
+    // Names are file stems; anything path-like could escape the layer
+    if name.is_empty() || name.contains(['/', '\\']) || name.starts_with('.') {
+        anyhow::bail!("Invalid resource name in URI: {}", uri);
+    }
+    let file = format!("{}.md", name);
+
+    let dirs: &[&str] = match kind {
+        "belief" => &[BELIEFS_DIR],
+        "pattern" => &[CORE_DIR, SURFACE_DIR],
+        "session" => &[SESSIONS_DIR],
+        _ => anyhow::bail!("Unknown resource kind '{}' in URI: {}", kind, uri),
+    };
+    Ok(dirs
+        .iter()
+        .map(|dir| project_root.join(dir).join(&file))
+        .collect())
+}
+
+/// Read a resource's markdown content
+pub fn read_resource(project_root: &Path, uri: &str) -> Result<String> {
+    let path = resolve(project_root, uri)?
+        .into_iter()
+        .find(|p| p.is_file())
+        .with_context(|| format!("Resource not found: {}", uri))?;
+    fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn seeded_layer() -> TempDir {
+        let temp = TempDir::new().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(BELIEFS_DIR)).unwrap();
+        fs::create_dir_all(root.join(CORE_DIR)).unwrap();
+        fs::create_dir_all(root.join(SESSIONS_DIR)).unwrap();
+        fs::write(
+            root.join(BELIEFS_DIR).join("sync-first.md"),
+            "# sync-first\n\nPrefer synchronous code.\n",
+        )
+        .unwrap();
+        fs::write(root.join(BELIEFS_DIR).join("notes.txt"), "ignored").unwrap();
+        fs::write(root.join(CORE_DIR).join("unix-philosophy.md"), "# Unix\n").unwrap();
+        fs::write(
+            root.join(SESSIONS_DIR).join("20260101-120000.md"),
+            "# Old\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join(SESSIONS_DIR).join("20260201-120000.md"),
+            "# New\n",
+        )
+        .unwrap();
+        temp
+    }
+
+    #[test]
+    fn test_list_resources_includes_belief_uris() {
+        let temp = seeded_layer();
+        let uris: Vec<String> = list_resources(temp.path())
+            .into_iter()
+            .map(|r| r.uri)
+            .collect();
+
+        assert_eq!(
+            uris,
+            vec![
+                "patina://belief/sync-first",
+                "patina://pattern/unix-philosophy",
+                "patina://session/20260201-120000",
+                "patina://session/20260101-120000",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_resource_returns_file_body() -> Result<()> {
+        let temp = seeded_layer();
+
+        let body = read_resource(temp.path(), "patina://belief/sync-first")?;
+        assert_eq!(body, "# sync-first\n\nPrefer synchronous code.\n");
+        assert_eq!(
+            read_resource(temp.path(), "patina://pattern/unix-philosophy")?,
+            "# Unix\n"
+        );
+
+        assert!(read_resource(temp.path(), "patina://belief/missing").is_err());
+        assert!(read_resource(temp.path(), "patina://belief/../../secrets").is_err());
+        assert!(read_resource(temp.path(), "patina://topic/x").is_err());
+
+        Ok(())
+    }
+}
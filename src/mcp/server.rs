@@ -4,9 +4,11 @@ use anyhow::Result;
 use std::io::{BufRead, BufReader, Write};
 
 use super::protocol::{Request, Response};
+use super::resources;
 use crate::commands::assay::{AssayOptions, QueryType};
 use crate::commands::context::get_project_context;
 use crate::commands::scry::internal::enrichment::find_belief_impact;
+use crate::commands::scry::internal::error::json_rpc_code;
 use crate::commands::scry::ScryResult;
 use crate::retrieval::{snippet, FusedResult, QueryEngine, QueryOptions};
 
@@ -117,6 +119,8 @@ fn dispatch(req: &Request, engine: &QueryEngine) -> Response {
         "initialized" => Response::success(req.id.clone(), serde_json::json!({})),
         "tools/list" => handle_list_tools(req),
         "tools/call" => handle_tool_call(req, engine),
+        "resources/list" => handle_list_resources(req),
+        "resources/read" => handle_read_resource(req),
         _ => Response::error(req.id.clone(), -32601, "Method not found"),
     }
 }
@@ -127,7 +131,8 @@ fn handle_initialize(req: &Request, engine: &QueryEngine) -> Response {
         serde_json::json!({
             "protocolVersion": "2024-11-05",
             "capabilities": {
-                "tools": {}
+                "tools": {},
+                "resources": {}
             },
             "serverInfo": {
                 "name": "patina",
@@ -138,6 +143,31 @@ fn handle_initialize(req: &Request, engine: &QueryEngine) -> Response {
     )
 }
 
+fn handle_list_resources(req: &Request) -> Response {
+    let project_root = std::env::current_dir().unwrap_or_default();
+    Response::success(
+        req.id.clone(),
+        serde_json::json!({ "resources": resources::list_resources(&project_root) }),
+    )
+}
+
+fn handle_read_resource(req: &Request) -> Response {
+    let Some(uri) = req.params.get("uri").and_then(|v| v.as_str()) else {
+        return Response::error(req.id.clone(), -32602, "Missing 'uri' parameter");
+    };
+    let project_root = std::env::current_dir().unwrap_or_default();
+
+    match resources::read_resource(&project_root, uri) {
+        Ok(text) => Response::success(
+            req.id.clone(),
+            serde_json::json!({
+                "contents": [{ "uri": uri, "mimeType": "text/markdown", "text": text }]
+            }),
+        ),
+        Err(e) => Response::error(req.id.clone(), -32002, &e.to_string()),
+    }
+}
+
 fn handle_list_tools(req: &Request) -> Response {
     Response::success(
         req.id.clone(),
@@ -246,13 +276,13 @@ fn handle_list_tools(req: &Request) -> Response {
                 },
                 {
                     "name": "assay",
-                    "description": "Query codebase structure - modules, imports, functions, call graph. Use for exact structural questions like 'list all modules', 'what imports X', 'show largest files'. For semantic similarity, use scry instead. Use 'derive' to compute/view structural signals (usage, activity, centrality).",
+                    "description": "Query codebase structure - modules, imports, functions, call graph. Use for exact structural questions like 'list all modules', 'what imports X', 'show largest files'. For semantic similarity, use scry instead. Use 'derive' to compute/view structural signals (usage, activity, centrality). Use 'clones' to find structurally duplicated functions. Use 'authored' with an author name as pattern to list files a contributor modified. Use 'summary' for a one-screen overview (totals, hotspots, dormancy, import cycles, coverage).",
                     "inputSchema": {
                         "type": "object",
                         "properties": {
                             "query_type": {
                                 "type": "string",
-                                "enum": ["inventory", "imports", "importers", "functions", "callers", "callees", "derive"],
+                                "enum": ["inventory", "imports", "importers", "functions", "methods", "callers", "callees", "clones", "authored", "derive", "summary"],
                                 "default": "inventory",
                                 "description": "Type of structural query"
                             },
@@ -265,6 +295,11 @@ fn handle_list_tools(req: &Request) -> Response {
                                 "default": 50,
                                 "description": "Maximum results to return"
                             },
+                            "min_lines": {
+                                "type": "integer",
+                                "default": 5,
+                                "description": "Shortest function considered by 'clones'"
+                            },
                             "repo": {
                                 "type": "string",
                                 "description": "Query a specific registered repo by name (from registry)"
@@ -276,6 +311,74 @@ fn handle_list_tools(req: &Request) -> Response {
                             }
                         }
                     }
+                },
+                {
+                    "name": "assay_callers",
+                    "description": "Find call sites of a function - who calls X. Returns caller, callee, file, and call_type for each edge in the call graph.",
+                    "inputSchema": {
+                        "type": "object",
+                        "properties": {
+                            "function": {
+                                "type": "string",
+                                "description": "Function name pattern (substring match on the callee)"
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "default": 100,
+                                "description": "Maximum call sites to return"
+                            },
+                            "repo": {
+                                "type": "string",
+                                "description": "Query a specific registered repo by name (from registry)"
+                            }
+                        },
+                        "required": ["function"]
+                    }
+                },
+                {
+                    "name": "assay_callees",
+                    "description": "Find what a function calls - what does X call. Returns caller, callee, file, and call_type for each edge in the call graph.",
+                    "inputSchema": {
+                        "type": "object",
+                        "properties": {
+                            "function": {
+                                "type": "string",
+                                "description": "Function name pattern (substring match on the caller)"
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "default": 100,
+                                "description": "Maximum call sites to return"
+                            },
+                            "repo": {
+                                "type": "string",
+                                "description": "Query a specific registered repo by name (from registry)"
+                            }
+                        },
+                        "required": ["function"]
+                    }
+                },
+                {
+                    "name": "assay_inventory",
+                    "description": "List modules with line, function, and import counts, largest first.",
+                    "inputSchema": {
+                        "type": "object",
+                        "properties": {
+                            "pattern": {
+                                "type": "string",
+                                "description": "SQL LIKE path pattern (e.g., 'src/mcp/%')"
+                            },
+                            "limit": {
+                                "type": "integer",
+                                "default": 50,
+                                "description": "Maximum modules to return"
+                            },
+                            "repo": {
+                                "type": "string",
+                                "description": "Query a specific registered repo by name (from registry)"
+                            }
+                        }
+                    }
                 }
             ]
         }),
@@ -376,7 +479,7 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                         ..Default::default()
                     };
 
-                    match crate::commands::scry::scry_belief_fn(belief_id, &options) {
+                    match crate::commands::scry::search(None, &options) {
                         Ok(results) => {
                             let mut text = format!(
                                 "Belief grounding for '{}' ({} results):\n\n",
@@ -400,7 +503,9 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                                 }),
                             )
                         }
-                        Err(e) => Response::error(req.id.clone(), -32603, &e.to_string()),
+                        Err(e) => {
+                            Response::error(req.id.clone(), json_rpc_code(&e), &e.to_string())
+                        }
                     }
                 }
                 "use" => {
@@ -487,6 +592,7 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                         repo,
                         all_repos,
                         include_issues,
+                        ..Default::default()
                     };
 
                     match engine.query_with_options(&full_query, limit, &options) {
@@ -549,6 +655,7 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                         repo,
                         all_repos,
                         include_issues,
+                        ..Default::default()
                     };
 
                     match engine.query_with_options(&full_query, limit, &options) {
@@ -602,21 +709,31 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                 .get("all_repos")
                 .and_then(|v| v.as_bool())
                 .unwrap_or(false);
+            let min_lines = args.get("min_lines").and_then(|v| v.as_u64()).unwrap_or(5) as usize;
 
             let query_type = match query_type_str {
                 "imports" => QueryType::Imports,
                 "importers" => QueryType::Importers,
                 "functions" => QueryType::Functions,
+                "methods" => QueryType::Methods,
                 "callers" => QueryType::Callers,
                 "callees" => QueryType::Callees,
+                "clones" => QueryType::Clones,
+                "authored" => QueryType::Authored,
                 "derive" => QueryType::Derive,
+                "summary" => QueryType::Summary,
                 _ => QueryType::Inventory,
             };
 
             // For pattern-required queries, validate pattern is provided
             if matches!(
                 query_type,
-                QueryType::Imports | QueryType::Importers | QueryType::Callers | QueryType::Callees
+                QueryType::Imports
+                    | QueryType::Importers
+                    | QueryType::Methods
+                    | QueryType::Callers
+                    | QueryType::Callees
+                    | QueryType::Authored
             ) && pattern.is_none()
             {
                 return Response::error(
@@ -636,6 +753,13 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                 json: true, // Always use JSON for MCP
                 repo,
                 all_repos,
+                fuzzy: false,
+                min_lines,
+                unsafe_only: false,
+                const_only: false,
+                lang: None,
+                resolve: false,
+                since: None,
             };
 
             match execute_assay(&options) {
@@ -648,14 +772,79 @@ fn handle_tool_call(req: &Request, engine: &QueryEngine) -> Response {
                 Err(e) => Response::error(req.id.clone(), -32603, &e.to_string()),
             }
         }
+        "assay_callers" | "assay_callees" | "assay_inventory" => {
+            let query_type = match name {
+                "assay_callers" => QueryType::Callers,
+                "assay_callees" => QueryType::Callees,
+                _ => QueryType::Inventory,
+            };
+            if !matches!(query_type, QueryType::Inventory)
+                && args.get("function").and_then(|v| v.as_str()).is_none()
+            {
+                return Response::error(
+                    req.id.clone(),
+                    -32602,
+                    &format!("The '{}' tool requires a 'function' parameter", name),
+                );
+            }
+
+            let result =
+                open_assay_db(&args).and_then(|conn| handle_assay_tool(query_type, &args, &conn));
+            match result {
+                Ok(text) => Response::success(
+                    req.id.clone(),
+                    serde_json::json!({
+                        "content": [{ "type": "text", "text": text }]
+                    }),
+                ),
+                Err(e) => Response::error(req.id.clone(), -32603, &e.to_string()),
+            }
+        }
         _ => Response::error(req.id.clone(), -32602, &format!("Unknown tool: {}", name)),
     }
 }
 
+/// Open the database for an assay tool: the `repo` argument's, else the project's
+fn open_assay_db(args: &serde_json::Value) -> Result<rusqlite::Connection> {
+    let db_path = match args.get("repo").and_then(|v| v.as_str()) {
+        Some(name) => crate::commands::repo::get_db_path(name)?,
+        None => crate::commands::assay::DB_PATH.to_string(),
+    };
+    open_existing_db(&db_path)
+}
+
+/// Open a scraped database, failing instead of creating an empty one
+fn open_existing_db(db_path: &str) -> Result<rusqlite::Connection> {
+    crate::commands::exit::require_db(std::path::Path::new(db_path))?;
+    Ok(rusqlite::Connection::open(db_path)?)
+}
+
+/// Run a dedicated assay tool (callers/callees/inventory) and return its JSON
+fn handle_assay_tool(
+    query_type: QueryType,
+    args: &serde_json::Value,
+    conn: &rusqlite::Connection,
+) -> Result<String> {
+    let pattern_arg = match query_type {
+        QueryType::Inventory => "pattern",
+        _ => "function",
+    };
+    let options = AssayOptions {
+        query_type,
+        pattern: args
+            .get(pattern_arg)
+            .and_then(|v| v.as_str())
+            .map(String::from),
+        limit: args.get("limit").and_then(|v| v.as_u64()).unwrap_or(0) as usize,
+        json: true,
+        ..Default::default()
+    };
+    let result = crate::commands::assay::query_json(conn, &options)?;
+    Ok(serde_json::to_string_pretty(&result)?)
+}
+
 /// Execute assay query and return JSON result
 fn execute_assay(options: &AssayOptions) -> Result<String> {
-    use rusqlite::Connection;
-
     const DB_PATH: &str = ".patina/local/data/patina.db";
 
     // Handle all_repos mode
@@ -669,7 +858,7 @@ fn execute_assay(options: &AssayOptions) -> Result<String> {
         None => DB_PATH.to_string(),
     };
 
-    let conn = Connection::open(&db_path)?;
+    let conn = open_existing_db(&db_path)?;
 
     match options.query_type {
         QueryType::Inventory => {
@@ -957,6 +1146,13 @@ fn execute_assay(options: &AssayOptions) -> Result<String> {
             });
             Ok(serde_json::to_string_pretty(&result)?)
         }
+        QueryType::Methods
+        | QueryType::Clones
+        | QueryType::Query
+        | QueryType::Authored
+        | QueryType::Summary => Ok(serde_json::to_string_pretty(
+            &crate::commands::assay::query_json(&conn, options)?,
+        )?),
         QueryType::DeriveMoments => {
             // DeriveMoments not yet supported in MCP - use CLI instead
             Ok(serde_json::to_string_pretty(&serde_json::json!({
@@ -1159,6 +1355,8 @@ fn annotate_impact(results: &[FusedResult], mut text: String) -> String {
                 event_type,
                 source_id: r.doc_id.clone(),
                 timestamp: r.metadata.timestamp.clone().unwrap_or_default(),
+                explain: None,
+                cochange_count: None,
             }
         })
         .collect();
@@ -1757,3 +1955,56 @@ fn format_detail_content(event_type: &str, raw_json: &str) -> String {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn seeded_db() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE call_graph (caller TEXT, callee TEXT, file TEXT, call_type TEXT);
+             INSERT INTO call_graph VALUES ('main', 'run_mcp_server', 'src/main.rs', 'direct');
+             INSERT INTO call_graph VALUES ('run_mcp_server', 'dispatch', 'src/mcp/server.rs', 'direct');
+             INSERT INTO call_graph VALUES ('dispatch', 'handle_tool_call', 'src/mcp/server.rs', 'direct');",
+        )
+        .unwrap();
+        conn
+    }
+
+    #[test]
+    fn test_assay_callers_tool_returns_call_sites() -> Result<()> {
+        let conn = seeded_db();
+        let args = serde_json::json!({ "function": "dispatch" });
+
+        let text = handle_assay_tool(QueryType::Callers, &args, &conn)?;
+        let calls: serde_json::Value = serde_json::from_str(&text)?;
+
+        assert_eq!(
+            calls,
+            serde_json::json!([{
+                "caller": "run_mcp_server",
+                "callee": "dispatch",
+                "file": "src/mcp/server.rs",
+                "call_type": "direct"
+            }])
+        );
+
+        let text = handle_assay_tool(QueryType::Callees, &args, &conn)?;
+        let calls: serde_json::Value = serde_json::from_str(&text)?;
+        assert_eq!(calls[0]["callee"], "handle_tool_call");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assay_db_missing_is_not_created() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("patina.db");
+
+        let err = open_existing_db(db_path.to_str().unwrap()).unwrap_err();
+        assert!(err.to_string().contains("patina scrape"));
+        assert!(!db_path.exists());
+    }
+}
@@ -51,7 +51,8 @@ fn migrate_persona_cache() -> bool {
         .join("default")
         .join("materialized");
 
-    let new_path = paths::persona::cache_dir();
+    // Only `default` predates named personas
+    let new_path = paths::persona::cache_dir_for(paths::persona::DEFAULT);
 
     if !old_path.exists() {
         return false;
@@ -43,7 +43,7 @@ pub use download::{download_and_verify, download_file, sha256_file};
 pub use internal::{LockedModel, ModelLock};
 
 use crate::paths;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 
 /// Check if a model is available in the mother cache.
@@ -117,6 +117,63 @@ pub fn resolve_model_path(name: &str) -> Result<PathBuf> {
     )
 }
 
+/// Environment variable that forbids network model pulls when set
+pub const OFFLINE_ENV: &str = "PATINA_OFFLINE";
+
+/// Whether a missing model may be downloaded on demand
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PullPolicy {
+    /// Pull missing models instead of failing (`--auto-pull` or config)
+    pub auto_pull: bool,
+    /// Never touch the network, even with `auto_pull`
+    pub offline: bool,
+}
+
+impl PullPolicy {
+    /// True when `PATINA_OFFLINE` is set to anything but empty or `0`
+    pub fn offline_from_env() -> bool {
+        std::env::var(OFFLINE_ENV).is_ok_and(|v| !v.is_empty() && v != "0")
+    }
+}
+
+/// Resolve a model before loading it, pulling it first when the policy allows.
+///
+/// Commands that embed call this up front so a missing cache ends in an
+/// instruction (or a download) instead of an ONNX session error halfway through.
+pub fn ensure_model(name: &str, policy: PullPolicy) -> Result<PathBuf> {
+    ensure_model_with(name, policy, resolve_model_path, add_model)
+}
+
+/// `ensure_model` with injectable resolution and download (tests mock the network)
+fn ensure_model_with(
+    name: &str,
+    policy: PullPolicy,
+    resolve: impl Fn(&str) -> Result<PathBuf>,
+    pull: impl FnOnce(&str) -> Result<()>,
+) -> Result<PathBuf> {
+    if let Ok(path) = resolve(name) {
+        return Ok(path);
+    }
+    if !policy.auto_pull {
+        anyhow::bail!(
+            "Embedding model '{}' is not downloaded.\n  Run `patina model add {}` first, or pass --auto-pull (or set `auto_pull = true` under [embeddings]) to download it now.",
+            name,
+            name
+        );
+    }
+    if policy.offline {
+        anyhow::bail!(
+            "Embedding model '{}' is not downloaded and offline mode is on.\n  Run `patina model add {}` once a network is available.",
+            name,
+            name
+        );
+    }
+
+    println!("Embedding model '{}' not cached, pulling...", name);
+    pull(name)?;
+    resolve(name).with_context(|| format!("Pulled model '{}' is incomplete", name))
+}
+
 /// Get model status: where it's available and provenance info.
 #[derive(Debug)]
 pub struct ModelStatus {
@@ -231,6 +288,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_ensure_model_missing_is_actionable() {
+        let err = ensure_model("nonexistent-model-xyz", PullPolicy::default()).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("not downloaded"), "{}", message);
+        assert!(message.contains("patina model add nonexistent-model-xyz"));
+        assert!(message.contains("--auto-pull"));
+    }
+
+    #[test]
+    fn test_auto_pull_invokes_pull_then_resolves() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let model_dir = temp.path().join("tiny-model");
+        let resolve = |_: &str| {
+            if has_valid_model_files(&model_dir) {
+                Ok(model_dir.clone())
+            } else {
+                anyhow::bail!("not found")
+            }
+        };
+        let policy = PullPolicy {
+            auto_pull: true,
+            offline: false,
+        };
+
+        // Stand-in for the network: writes what `add_model` would download
+        let mut pulled = Vec::new();
+        let path = ensure_model_with("tiny-model", policy, resolve, |name| {
+            pulled.push(name.to_string());
+            std::fs::create_dir_all(&model_dir)?;
+            std::fs::write(model_dir.join("tokenizer.json"), "{}")?;
+            std::fs::write(model_dir.join("model_quantized.onnx"), [0u8; 4])?;
+            Ok(())
+        })
+        .unwrap();
+
+        assert_eq!(pulled, vec!["tiny-model"]);
+        assert_eq!(path, model_dir);
+
+        // Once cached, no further pull happens
+        let path = ensure_model_with("tiny-model", policy, resolve, |_| {
+            panic!("model is already cached")
+        })
+        .unwrap();
+        assert_eq!(path, model_dir);
+    }
+
+    #[test]
+    fn test_offline_blocks_auto_pull() {
+        let policy = PullPolicy {
+            auto_pull: true,
+            offline: true,
+        };
+        let err = ensure_model_with(
+            "tiny-model",
+            policy,
+            |_| anyhow::bail!("not found"),
+            |_| panic!("offline mode must not pull"),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("offline mode is on"));
+    }
+
     #[test]
     #[ignore] // Run with: cargo test test_add_model_e2e -- --ignored
     fn test_add_model_e2e() {
@@ -6,7 +6,7 @@
 
 use anyhow::{Context, Result};
 use reqwest::blocking::Client as HttpClient;
-use serde::{Deserialize, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::io::{Read, Write};
 use std::time::Duration;
 
@@ -49,12 +49,17 @@ impl Client {
     }
 
     /// Health check - returns Ok if mother is reachable
+    ///
+    /// A reachable mother with no indices answers 503; that still parses into
+    /// `Ok` with `status != "ok"` (see `HealthResponse::is_ready`).
     pub fn health(&self) -> Result<HealthResponse> {
         // Try UDS first for local mother
         if self.try_uds {
-            if let Some(body) = uds_get("/health") {
-                return serde_json::from_slice(&body)
-                    .context("Failed to parse health response from UDS");
+            if let Some((status, body)) = uds_get("/health") {
+                if is_health_status(status) {
+                    return serde_json::from_slice(&body)
+                        .context("Failed to parse health response from UDS");
+                }
             }
         }
 
@@ -68,7 +73,7 @@ impl Client {
             .send()
             .with_context(|| format!("Failed to connect to mother at {}", self.base_url))?;
 
-        if !response.status().is_success() {
+        if !is_health_status(response.status().as_u16()) {
             anyhow::bail!("Mother returned status: {}", response.status());
         }
 
@@ -79,34 +84,51 @@ impl Client {
 
     /// Execute a scry query against the mother
     pub fn scry(&self, request: ScryRequest) -> Result<ScryResponse> {
+        self.post_json("/api/scry", &request)
+    }
+
+    /// Execute several scry queries in one round trip
+    ///
+    /// Responses are aligned with `request.queries` by index.
+    pub fn scry_batch(&self, request: ScryBatchRequest) -> Result<Vec<ScryResponse>> {
+        self.post_json("/api/scry/batch", &request)
+    }
+
+    /// Execute a structural assay query against the mother's database
+    pub fn assay(&self, request: AssayRequest) -> Result<AssayResponse> {
+        self.post_json("/api/assay", &request)
+    }
+
+    /// POST a JSON body to the mother: UDS first, TCP with auth as fallback
+    fn post_json<T: DeserializeOwned>(&self, path: &str, request: &impl Serialize) -> Result<T> {
         // Try UDS first for local mother
         if self.try_uds {
-            let json_body = serde_json::to_vec(&request)?;
-            if let Some(body) = uds_post("/api/scry", &json_body) {
+            let json_body = serde_json::to_vec(request)?;
+            if let Some(body) = uds_post(path, &json_body) {
                 return serde_json::from_slice(&body)
-                    .context("Failed to parse scry response from UDS");
+                    .with_context(|| format!("Failed to parse {} response from UDS", path));
             }
         }
 
         // TCP fallback with auth
-        let url = format!("{}/api/scry", self.base_url);
-        let mut req = self.http.post(&url).json(&request);
+        let url = format!("{}{}", self.base_url, path);
+        let mut req = self.http.post(&url).json(request);
         if let Some(ref token) = self.token {
             req = req.header("Authorization", format!("Bearer {}", token));
         }
         let response = req
             .send()
-            .with_context(|| format!("Failed to send scry request to {}", self.base_url))?;
+            .with_context(|| format!("Failed to send {} request to {}", path, self.base_url))?;
 
         if !response.status().is_success() {
             let status = response.status();
             let body = response.text().unwrap_or_default();
-            anyhow::bail!("Mother scry failed ({}): {}", status, body);
+            anyhow::bail!("Mother {} failed ({}): {}", path, status, body);
         }
 
         response
-            .json::<ScryResponse>()
-            .with_context(|| "Failed to parse scry response")
+            .json::<T>()
+            .with_context(|| format!("Failed to parse {} response", path))
     }
 }
 
@@ -114,8 +136,8 @@ impl Client {
 // Small HTTP-over-UDS client — same pattern as secrets/session.rs.
 // No reqwest needed for local path. File permissions are auth.
 
-/// Send a GET request over UDS and return the response body.
-fn uds_get(path: &str) -> Option<Vec<u8>> {
+/// Send a GET request over UDS and return the status and response body.
+fn uds_get(path: &str) -> Option<(u16, Vec<u8>)> {
     let sock_path = paths::serve::socket_path();
     let mut stream = std::os::unix::net::UnixStream::connect(&sock_path).ok()?;
     stream.set_read_timeout(Some(Duration::from_secs(5))).ok()?;
@@ -126,7 +148,7 @@ fn uds_get(path: &str) -> Option<Vec<u8>> {
     let mut response_buf = Vec::new();
     stream.read_to_end(&mut response_buf).ok()?;
 
-    parse_http_body(&response_buf)
+    parse_http_response(&response_buf)
 }
 
 /// Send a POST request with JSON body over UDS and return the response body.
@@ -153,12 +175,17 @@ fn uds_post(path: &str, json_body: &[u8]) -> Option<Vec<u8>> {
 
 /// Extract HTTP response body (everything after \r\n\r\n) if status is 2xx.
 fn parse_http_body(response: &[u8]) -> Option<Vec<u8>> {
+    match parse_http_response(response)? {
+        (status, body) if (200..300).contains(&status) => Some(body),
+        _ => None,
+    }
+}
+
+/// Split an HTTP response into status code and body, whatever the status.
+fn parse_http_response(response: &[u8]) -> Option<(u16, Vec<u8>)> {
     let status_end = response.iter().position(|&b| b == b'\r')?;
     let first_line = std::str::from_utf8(&response[..status_end]).ok()?;
     let status: u16 = first_line.split_whitespace().nth(1)?.parse().ok()?;
-    if !(200..300).contains(&status) {
-        return None;
-    }
 
     let separator = b"\r\n\r\n";
     let body_start = response
@@ -166,7 +193,12 @@ fn parse_http_body(response: &[u8]) -> Option<Vec<u8>> {
         .position(|w| w == separator)
         .map(|p| p + 4)?;
 
-    Some(response[body_start..].to_vec())
+    Some((status, response[body_start..].to_vec()))
+}
+
+/// /health answers 200 when ready and 503 when up but without indices.
+fn is_health_status(status: u16) -> bool {
+    status == 200 || status == 503
 }
 
 // === Token + localhost detection ===
@@ -196,6 +228,19 @@ pub struct HealthResponse {
     pub status: String,
     pub version: String,
     pub uptime_secs: u64,
+    /// Embedding model served (empty for daemons predating readiness info)
+    #[serde(default)]
+    pub model: String,
+    /// Dimension indices present on the mother's disk
+    #[serde(default)]
+    pub indices: Vec<String>,
+}
+
+impl HealthResponse {
+    /// True if the mother has at least one index to answer queries from
+    pub fn is_ready(&self) -> bool {
+        self.status == "ok"
+    }
 }
 
 /// Scry request to mother
@@ -231,6 +276,32 @@ impl Default for ScryRequest {
     }
 }
 
+/// Batch of scry requests sent in a single call
+#[derive(Debug, Default, Serialize)]
+pub struct ScryBatchRequest {
+    pub queries: Vec<ScryRequest>,
+}
+
+/// Assay request to mother (inventory, imports, functions)
+#[derive(Debug, Serialize)]
+pub struct AssayRequest {
+    pub query_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    pub limit: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repo: Option<String>,
+}
+
+/// Assay response from mother
+///
+/// `result` has the same shape as local `patina assay --json` output.
+#[derive(Debug, Deserialize)]
+pub struct AssayResponse {
+    pub query_type: String,
+    pub result: serde_json::Value,
+}
+
 /// Scry response from mother
 #[derive(Debug, Deserialize)]
 pub struct ScryResponse {
@@ -307,6 +378,25 @@ mod tests {
         assert!(parse_http_body(response).is_none());
     }
 
+    #[test]
+    fn test_health_response_readiness() {
+        let ready: HealthResponse = serde_json::from_str(
+            r#"{"status":"ok","version":"0.11.0","uptime_secs":3,"model":"e5-base-v2","indices":["semantic"]}"#,
+        )
+        .unwrap();
+        assert!(ready.is_ready());
+        assert_eq!(ready.indices, vec!["semantic"]);
+
+        let response = b"HTTP/1.1 503 Service Unavailable\r\n\r\n{\"status\":\"no_indices\"}";
+        let (status, _) = parse_http_response(response).unwrap();
+        assert!(is_health_status(status));
+
+        // Older daemons omit model/indices
+        let legacy: HealthResponse =
+            serde_json::from_str(r#"{"status":"ok","version":"0.10.0","uptime_secs":1}"#).unwrap();
+        assert!(legacy.model.is_empty());
+    }
+
     #[test]
     fn test_parse_http_body_empty() {
         assert!(parse_http_body(b"").is_none());
@@ -324,4 +414,25 @@ mod tests {
         assert!(json.contains("test query"));
         assert!(json.contains("\"limit\":5"));
     }
+
+    #[test]
+    fn test_scry_batch_request_serialization() {
+        let request = ScryBatchRequest {
+            queries: vec![
+                ScryRequest {
+                    query: "first".to_string(),
+                    ..Default::default()
+                },
+                ScryRequest {
+                    query: "second".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+
+        let json: serde_json::Value = serde_json::to_value(&request).unwrap();
+        let queries = json["queries"].as_array().unwrap();
+        assert_eq!(queries.len(), 2);
+        assert_eq!(queries[1]["query"], "second");
+    }
 }
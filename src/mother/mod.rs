@@ -37,7 +37,10 @@ pub use graph::{
 };
 
 // Client exports
-pub use internal::{Client, ScryRequest, ScryResponse, ScryResultJson};
+pub use internal::{
+    AssayRequest, AssayResponse, Client, HealthResponse, ScryBatchRequest, ScryRequest,
+    ScryResponse, ScryResultJson,
+};
 
 /// Default port for mother daemon
 pub const DEFAULT_PORT: u16 = 50051;
@@ -71,6 +74,20 @@ pub fn connect() -> Option<Client> {
     get_address().map(Client::new)
 }
 
+/// Run a structural assay query on the mother
+/// Returns Err if mother is not configured or unreachable
+pub fn assay(request: AssayRequest) -> Result<AssayResponse> {
+    let client = connect().ok_or_else(|| anyhow::anyhow!("PATINA_MOTHER not set"))?;
+    client.assay(request)
+}
+
+/// Fetch the mother's health: version, model, and which indices it serves
+/// Returns Err if mother is not configured or unreachable
+pub fn health() -> Result<HealthResponse> {
+    let client = connect().ok_or_else(|| anyhow::anyhow!("PATINA_MOTHER not set"))?;
+    client.health()
+}
+
 /// Check if the mother is reachable (health check)
 pub fn is_available() -> bool {
     if let Some(client) = connect() {
@@ -86,3 +103,10 @@ pub fn scry(request: ScryRequest) -> Result<ScryResponse> {
     let client = connect().ok_or_else(|| anyhow::anyhow!("PATINA_MOTHER not set"))?;
     client.scry(request)
 }
+
+/// Query the mother with several scry requests in one round trip
+/// Responses are aligned with the request order
+pub fn scry_batch(queries: Vec<ScryRequest>) -> Result<Vec<ScryResponse>> {
+    let client = connect().ok_or_else(|| anyhow::anyhow!("PATINA_MOTHER not set"))?;
+    client.scry_batch(ScryBatchRequest { queries })
+}
@@ -18,13 +18,13 @@
 //! ├── config.toml              # Global config
 //! ├── registry.yaml            # Project/repo registry
 //! ├── adapters/                # LLM adapter templates
-//! ├── personas/default/events/ # Source (valuable)
+//! ├── personas/<name>/events/  # Source (valuable; `default` unless selected)
 //! ├── run/                     # Runtime (socket, pid, token)
 //! │   ├── serve.sock           # Unix domain socket
 //! │   └── serve.token          # Bearer token file (TCP only)
 //! └── cache/                   # Derived (rebuildable)
 //!     ├── repos/               # Cloned reference repos
-//!     └── personas/default/    # Materialized indices
+//!     └── personas/<name>/     # Materialized indices
 //! ```
 //!
 //! # Project-Level Paths (project/.patina/)
@@ -76,17 +76,55 @@ pub fn adapters_dir() -> PathBuf {
 }
 
 /// Persona paths (cross-project user knowledge)
+///
+/// Each named persona is a separate knowledge base. Paths take the persona
+/// name explicitly; `active()` resolves the one `PATINA_PERSONA` selects.
 pub mod persona {
     use super::*;
 
-    /// Source events (valuable): `~/.patina/personas/default/events/`
-    pub fn events_dir() -> PathBuf {
-        patina_home().join("personas/default/events")
+    /// Persona used when none is selected
+    pub const DEFAULT: &str = "default";
+
+    /// Environment variable naming the active persona
+    pub const ENV_VAR: &str = "PATINA_PERSONA";
+
+    /// Active persona: `$PATINA_PERSONA` when set, else `default`
+    ///
+    /// An invalid name is an error, never a quiet fallback to `default`'s data.
+    pub fn active() -> anyhow::Result<String> {
+        match std::env::var(ENV_VAR) {
+            Ok(name) if !name.is_empty() => check_name(&name).map(str::to_string),
+            _ => Ok(DEFAULT.to_string()),
+        }
     }
 
-    /// Materialized cache (rebuildable): `~/.patina/cache/personas/default/`
-    pub fn cache_dir() -> PathBuf {
-        patina_cache().join("personas/default")
+    /// `name` itself when it is a valid persona name
+    pub fn check_name(name: &str) -> anyhow::Result<&str> {
+        if !is_valid_name(name) {
+            anyhow::bail!(
+                "Invalid persona name '{}' (use letters, digits, '-' or '_')",
+                name
+            );
+        }
+        Ok(name)
+    }
+
+    /// Persona names are one path component: ASCII letters, digits, `-`, `_`
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    /// Source events of a named persona: `~/.patina/personas/<name>/events/`
+    pub fn events_dir_for(name: &str) -> PathBuf {
+        patina_home().join("personas").join(name).join("events")
+    }
+
+    /// Materialized cache of a named persona: `~/.patina/cache/personas/<name>/`
+    pub fn cache_dir_for(name: &str) -> PathBuf {
+        patina_cache().join("personas").join(name)
     }
 }
 
@@ -293,6 +331,31 @@ pub mod project {
     pub fn backups_dir(root: &Path) -> PathBuf {
         root.join(".patina/local/backups")
     }
+
+    /// Raw forge API responses: `.patina/cache/forge/{owner}/{repo}/`
+    pub fn forge_cache_dir(root: &Path, owner: &str, repo: &str) -> PathBuf {
+        root.join(".patina/cache/forge").join(owner).join(repo)
+    }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Total size in bytes of the files under `path` (0 if it isn't a directory)
+pub fn dir_size(path: &Path) -> std::io::Result<u64> {
+    let mut total = 0;
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                total += dir_size(&path)?;
+            } else {
+                total += std::fs::metadata(&path)?.len();
+            }
+        }
+    }
+    Ok(total)
 }
 
 #[cfg(test)]
@@ -314,13 +377,25 @@ mod tests {
 
     #[test]
     fn test_persona_paths() {
-        let events = persona::events_dir();
-        let cache = persona::cache_dir();
+        let events = persona::events_dir_for(persona::DEFAULT);
+        let cache = persona::cache_dir_for(persona::DEFAULT);
 
         assert!(events.to_string_lossy().contains("personas/default/events"));
         assert!(cache.to_string_lossy().contains("cache/personas/default"));
     }
 
+    #[test]
+    fn test_named_persona_paths() {
+        assert!(persona::events_dir_for("work").ends_with("personas/work/events"));
+        assert!(persona::cache_dir_for("work").ends_with("cache/personas/work"));
+
+        assert!(persona::is_valid_name("oss-2026_b"));
+        assert!(!persona::is_valid_name(""));
+        assert!(!persona::is_valid_name("../default"));
+        assert!(!persona::is_valid_name("work/notes"));
+        assert!(persona::check_name("../default").is_err());
+    }
+
     #[test]
     fn test_repos_cache() {
         let repos = repos::cache_dir();
@@ -366,6 +441,18 @@ mod tests {
         assert!(pid.to_string_lossy().ends_with("run/mother.pid"));
     }
 
+    #[test]
+    fn test_dir_size_counts_nested_files() -> std::io::Result<()> {
+        let temp = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(temp.path().join("a/b"))?;
+        std::fs::write(temp.path().join("top.bin"), [0u8; 10])?;
+        std::fs::write(temp.path().join("a/b/deep.bin"), [0u8; 5])?;
+
+        assert_eq!(dir_size(temp.path())?, 15);
+        assert_eq!(dir_size(&temp.path().join("missing"))?, 0);
+        Ok(())
+    }
+
     #[test]
     fn test_project_paths() {
         let root = Path::new("/tmp/test-project");
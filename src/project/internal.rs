@@ -33,6 +33,10 @@ pub struct ProjectConfig {
     pub search: SearchSection,
     #[serde(default)]
     pub retrieval: RetrievalSection,
+    #[serde(default)]
+    pub scrape: ScrapeSection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scry: Option<ScrySection>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub environment: Option<EnvironmentSection>,
 }
@@ -241,6 +245,8 @@ impl Default for RetrievalSection {
 /// - **scry** (0.0): Cast a wide net, let user filter results
 /// - **query semantic** (0.35): Balance relevance vs recall for exploration
 /// - **belief validate** (0.50): Only strong evidence for validation claims
+/// - **belief grounding** (0.85, 20 neighbors): Links a belief to code,
+///   commits, and sessions during scrape; lower it if beliefs stay floating
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchSection {
     /// Default threshold for scry command (broad search, low filter)
@@ -254,6 +260,18 @@ pub struct SearchSection {
     /// Default threshold for belief validation (strict evidence)
     #[serde(default = "default_belief_threshold")]
     pub belief_threshold: f32,
+
+    /// Minimum similarity for a neighbor to ground a belief
+    #[serde(default = "default_grounding_min_score")]
+    pub grounding_min_score: f32,
+
+    /// Nearest neighbors examined per belief when computing grounding
+    #[serde(default = "default_grounding_limit")]
+    pub grounding_limit: usize,
+
+    /// Extra words keyword search ignores, merged with the built-in English list
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop_words: Vec<String>,
 }
 
 fn default_scry_threshold() -> f32 {
@@ -265,6 +283,12 @@ fn default_semantic_threshold() -> f32 {
 fn default_belief_threshold() -> f32 {
     0.50
 }
+fn default_grounding_min_score() -> f32 {
+    0.85
+}
+fn default_grounding_limit() -> usize {
+    20
+}
 
 impl Default for SearchSection {
     fn default() -> Self {
@@ -272,6 +296,61 @@ impl Default for SearchSection {
             scry_threshold: default_scry_threshold(),
             semantic_threshold: default_semantic_threshold(),
             belief_threshold: default_belief_threshold(),
+            grounding_min_score: default_grounding_min_score(),
+            grounding_limit: default_grounding_limit(),
+            stop_words: Vec::new(),
+        }
+    }
+}
+
+/// Scry defaults - team-wide replacements for the built-in flag defaults
+///
+/// Only keys that are set apply; an explicit CLI flag still wins. The
+/// embedding model stays under `[embeddings]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScrySection {
+    /// Results per query (built-in: 10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<usize>,
+
+    /// Minimum similarity score (built-in: 0.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_score: Option<f32>,
+
+    /// Fuse persona results (built-in: true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub include_persona: Option<bool>,
+}
+
+/// Scrape configuration - size limits for the code scraper
+///
+/// Giant generated files (parsers, bindings) dominate scrape time and
+/// embedding cost without adding much signal. Files over either limit are
+/// skipped; `0` disables a limit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScrapeSection {
+    /// Skip source files with more lines than this (default: 50000)
+    #[serde(default = "default_max_file_lines")]
+    pub max_file_lines: usize,
+
+    /// Skip source files larger than this many bytes (default: 5 MB)
+    #[serde(default = "default_max_file_bytes")]
+    pub max_file_bytes: u64,
+}
+
+fn default_max_file_lines() -> usize {
+    50_000
+}
+
+fn default_max_file_bytes() -> u64 {
+    5 * 1024 * 1024
+}
+
+impl Default for ScrapeSection {
+    fn default() -> Self {
+        Self {
+            max_file_lines: default_max_file_lines(),
+            max_file_bytes: default_max_file_bytes(),
         }
     }
 }
@@ -35,7 +35,7 @@ use std::path::{Path, PathBuf};
 // Re-export config types
 pub use internal::{
     AdaptersSection, CiSection, DevSection, EmbeddingsSection, EnvironmentSection, ProjectConfig,
-    ProjectSection, RetrievalSection, SearchSection, UpstreamSection,
+    ProjectSection, RetrievalSection, ScrapeSection, ScrySection, SearchSection, UpstreamSection,
 };
 
 /// Check if a directory is a patina project (has .patina/)
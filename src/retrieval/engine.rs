@@ -6,12 +6,12 @@
 use anyhow::Result;
 use rayon::prelude::*;
 use rusqlite::Connection;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use super::fusion::{rrf_fuse, rrf_fuse_weighted, FusedResult, StructuralAnnotations};
-use super::intent::{detect_intent, IntentWeights};
-use super::oracle::Oracle;
+use super::intent::{detect_intent, IntentWeights, QueryIntent};
+use super::oracle::{Oracle, PhaseTimings};
 use super::oracles::{BeliefOracle, LexicalOracle, PersonaOracle, SemanticOracle, TemporalOracle};
 
 /// Retrieval configuration for QueryEngine
@@ -51,12 +51,20 @@ pub struct QueryOptions {
     pub all_repos: bool,
     /// Include GitHub issues in search results
     pub include_issues: bool,
+    /// Per-oracle multipliers applied on top of intent weights (unlisted = 1.0)
+    pub oracle_weights: Vec<(String, f32)>,
+    /// RRF smoothing constant for this query (None = `RetrievalConfig::rrf_k`)
+    ///
+    /// Lower values let top-ranked results dominate the fused list.
+    pub rrf_k: Option<usize>,
 }
 
 /// Query engine that coordinates parallel oracle retrieval
 pub struct QueryEngine {
     oracles: Vec<Box<dyn Oracle>>,
     config: RetrievalConfig,
+    /// Project the local oracles read
+    root: PathBuf,
 }
 
 impl QueryEngine {
@@ -67,16 +75,45 @@ impl QueryEngine {
 
     /// Create engine with custom retrieval config
     pub fn with_config(config: RetrievalConfig) -> Self {
-        // Oracles for retrieval - structural signals available via `assay` tool directly
-        let oracles: Vec<Box<dyn Oracle>> = vec![
-            Box::new(SemanticOracle::new()),
-            Box::new(LexicalOracle::new()),
-            Box::new(TemporalOracle::new()),
-            Box::new(PersonaOracle::new()),
-            Box::new(BeliefOracle::new()),
-        ];
+        Self::in_project(Path::new("."), config)
+    }
+
+    /// Create engine over the project at `root` (persona stays cross-project)
+    pub fn in_project(root: &Path, config: RetrievalConfig) -> Self {
+        Self {
+            oracles: Self::create_oracles(root, false),
+            config,
+            root: root.to_path_buf(),
+        }
+    }
+
+    /// Create engine over a fixed oracle set (tests)
+    #[cfg(test)]
+    pub fn with_oracles(oracles: Vec<Box<dyn Oracle>>, config: RetrievalConfig) -> Self {
+        Self {
+            oracles,
+            config,
+            root: PathBuf::from("."),
+        }
+    }
 
-        Self { oracles, config }
+    /// Restrict fusion to the named oracles (ablation from the CLI)
+    ///
+    /// Names must be available oracles, so a typo fails instead of silently
+    /// returning an empty fusion.
+    pub fn restrict_to(&mut self, names: &[String]) -> Result<()> {
+        let available = self.available_oracles();
+        for name in names {
+            if !available.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+                anyhow::bail!(
+                    "Unknown or unavailable oracle '{}'. Available: {}",
+                    name,
+                    available.join(", ")
+                );
+            }
+        }
+        self.config.oracle_filter = Some(names.to_vec());
+        Ok(())
     }
 
     /// Query all available oracles in parallel, fuse with RRF
@@ -84,7 +121,26 @@ impl QueryEngine {
     /// This is the simple single-project query. For multi-repo queries,
     /// use `query_with_options`.
     pub fn query(&self, query: &str, limit: usize) -> Result<Vec<FusedResult>> {
-        self.query_local(query, limit)
+        let weights = self.fusion_weights(query, &QueryOptions::default());
+        self.query_local(query, limit, &weights, self.config.rrf_k)
+    }
+
+    /// RRF constant for a query: the per-query override, else the engine config
+    pub fn rrf_k(&self, options: &QueryOptions) -> usize {
+        options.rrf_k.unwrap_or(self.config.rrf_k)
+    }
+
+    /// Effective per-oracle RRF weights for a query
+    ///
+    /// Local queries start from the detected intent's weights; repo queries
+    /// start uniform. Explicit `oracle_weights` then scale either.
+    pub fn fusion_weights(&self, query: &str, options: &QueryOptions) -> IntentWeights {
+        let intent = if options.repo.is_some() || options.all_repos {
+            QueryIntent::General
+        } else {
+            detect_intent(query)
+        };
+        IntentWeights::for_intent(intent).scaled(&options.oracle_weights)
     }
 
     /// Query with federation options (repo, all_repos, include_issues)
@@ -113,30 +169,34 @@ impl QueryEngine {
         self.query_local_with_options(query, limit, options)
     }
 
-    /// Create oracles configured with the given options
-    fn create_oracles(include_issues: bool) -> Vec<Box<dyn Oracle>> {
+    /// Create oracles over the project at `root` configured with the given options
+    ///
+    /// Structural signals stay out of fusion; they're available via `assay`.
+    fn create_oracles(root: &Path, include_issues: bool) -> Vec<Box<dyn Oracle>> {
         vec![
-            Box::new(SemanticOracle::new()),
-            Box::new(LexicalOracle::with_options(include_issues)),
-            Box::new(TemporalOracle::new()),
+            Box::new(SemanticOracle::in_project(root)),
+            Box::new(LexicalOracle::in_project(root, include_issues)),
+            Box::new(TemporalOracle::in_project(root)),
             Box::new(PersonaOracle::new()),
-            Box::new(BeliefOracle::new()),
+            Box::new(BeliefOracle::in_project(root)),
         ]
     }
 
     /// Query local project only (current directory) - uses default oracles
-    fn query_local(&self, query: &str, limit: usize) -> Result<Vec<FusedResult>> {
+    fn query_local(
+        &self,
+        query: &str,
+        limit: usize,
+        weights: &IntentWeights,
+        rrf_k: usize,
+    ) -> Result<Vec<FusedResult>> {
         let start = Instant::now();
 
-        // Detect intent from query for weighted fusion
-        let intent = detect_intent(query);
-        let weights = IntentWeights::for_intent(intent);
-
         // Log intent detection if PATINA_LOG is set
         if std::env::var("PATINA_LOG").is_ok() {
             eprintln!(
                 "[DEBUG retrieval::engine] detected intent: {:?} for query: \"{}\"",
-                intent,
+                detect_intent(query),
                 &query[..query.len().min(50)]
             );
         }
@@ -145,13 +205,7 @@ impl QueryEngine {
         let fetch_limit = limit * self.config.fetch_multiplier;
 
         // Query available oracles in parallel (optionally filtered)
-        let oracle_results: Vec<_> = self
-            .oracles
-            .par_iter()
-            .filter(|o| o.is_available())
-            .filter(|o| self.matches_filter(o.name()))
-            .filter_map(|oracle| oracle.query(query, fetch_limit).ok())
-            .collect();
+        let oracle_results = self.query_active(&self.oracles, query, fetch_limit)?;
 
         let oracle_elapsed = start.elapsed();
 
@@ -161,8 +215,7 @@ impl QueryEngine {
         }
 
         // Fuse with RRF using intent-aware weights
-        let mut results =
-            rrf_fuse_weighted(oracle_results, self.config.rrf_k, limit, Some(&weights));
+        let mut results = rrf_fuse_weighted(oracle_results, rrf_k, limit, Some(weights));
 
         // Populate structural annotations from module_signals
         populate_annotations(&mut results);
@@ -187,24 +240,17 @@ impl QueryEngine {
         limit: usize,
         options: &QueryOptions,
     ) -> Result<Vec<FusedResult>> {
+        let weights = self.fusion_weights(query, options);
+
         // If include_issues, create oracles with that config
         // Otherwise use default oracles for efficiency
         if options.include_issues {
             let start = Instant::now();
 
-            // Detect intent from query for weighted fusion
-            let intent = detect_intent(query);
-            let weights = IntentWeights::for_intent(intent);
-
-            let oracles = Self::create_oracles(true);
+            let oracles = Self::create_oracles(&self.root, true);
             let fetch_limit = limit * self.config.fetch_multiplier;
 
-            let oracle_results: Vec<_> = oracles
-                .par_iter()
-                .filter(|o| o.is_available())
-                .filter(|o| self.matches_filter(o.name()))
-                .filter_map(|oracle| oracle.query(query, fetch_limit).ok())
-                .collect();
+            let oracle_results = self.query_active(&oracles, query, fetch_limit)?;
 
             let oracle_elapsed = start.elapsed();
 
@@ -214,7 +260,7 @@ impl QueryEngine {
             }
 
             let mut results =
-                rrf_fuse_weighted(oracle_results, self.config.rrf_k, limit, Some(&weights));
+                rrf_fuse_weighted(oracle_results, self.rrf_k(options), limit, Some(&weights));
             populate_annotations(&mut results);
 
             if std::env::var("PATINA_LOG").is_ok() {
@@ -228,7 +274,7 @@ impl QueryEngine {
 
             Ok(results)
         } else {
-            self.query_local(query, limit)
+            self.query_local(query, limit, &weights, self.rrf_k(options))
         }
     }
 
@@ -260,7 +306,8 @@ impl QueryEngine {
             limit,
             repo_path,
             Some(repo_name),
-            options.include_issues,
+            options,
+            &self.fusion_weights(query, options),
         )
     }
 
@@ -310,7 +357,7 @@ impl QueryEngine {
         }
 
         // 3. RRF fuse all results together
-        Ok(rrf_fuse(all_results, self.config.rrf_k, limit))
+        Ok(rrf_fuse(all_results, self.rrf_k(options), limit))
     }
 
     /// Query in a specific directory context
@@ -320,16 +367,17 @@ impl QueryEngine {
         limit: usize,
         context_path: &Path,
         repo_name: Option<&str>,
-        include_issues: bool,
+        options: &QueryOptions,
+        weights: &IntentWeights,
     ) -> Result<Vec<FusedResult>> {
         let results = self.collect_oracle_results_in_context(
             query,
             limit,
             context_path,
             repo_name.unwrap_or("unknown"),
-            include_issues,
+            options.include_issues,
         )?;
-        let fused = rrf_fuse(results, self.config.rrf_k, limit);
+        let fused = rrf_fuse_weighted(results, self.rrf_k(options), limit, Some(weights));
         // Note: annotations for external repos would need context switch
         // For now, skip annotations for repo queries
         Ok(fused)
@@ -342,17 +390,38 @@ impl QueryEngine {
         limit: usize,
         include_issues: bool,
     ) -> Result<Vec<Vec<super::oracle::OracleResult>>> {
-        let oracles = Self::create_oracles(include_issues);
+        let oracles = Self::create_oracles(&self.root, include_issues);
         let fetch_limit = limit * self.config.fetch_multiplier;
 
-        let results: Vec<_> = oracles
+        self.query_active(&oracles, query, fetch_limit)
+    }
+
+    /// Query every available oracle that passes the filter, in parallel
+    ///
+    /// A failing oracle is left out of fusion unless they all fail; then the
+    /// first error is returned, so a typed `ScryError` (missing index, failed
+    /// embedding) reaches the caller instead of an empty result.
+    fn query_active(
+        &self,
+        oracles: &[Box<dyn Oracle>],
+        query: &str,
+        fetch_limit: usize,
+    ) -> Result<Vec<Vec<super::oracle::OracleResult>>> {
+        let (answered, failed): (Vec<_>, Vec<_>) = oracles
             .par_iter()
             .filter(|o| o.is_available())
             .filter(|o| self.matches_filter(o.name()))
-            .filter_map(|oracle| oracle.query(query, fetch_limit).ok())
-            .collect();
-
-        Ok(results)
+            .map(|oracle| oracle.query(query, fetch_limit))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .partition(Result::is_ok);
+
+        if answered.is_empty() {
+            if let Some(Err(e)) = failed.into_iter().next() {
+                return Err(e);
+            }
+        }
+        Ok(answered.into_iter().filter_map(Result::ok).collect())
     }
 
     /// Collect raw oracle results in a different directory context
@@ -417,6 +486,22 @@ impl QueryEngine {
             .map(|o| o.name())
             .collect()
     }
+
+    /// Available oracles that pass the filter (what a query will fuse)
+    pub fn active_oracles(&self) -> Vec<&'static str> {
+        self.available_oracles()
+            .into_iter()
+            .filter(|name| self.matches_filter(name))
+            .collect()
+    }
+
+    /// Phase timings each vector oracle recorded for its last query
+    pub fn oracle_timings(&self) -> Vec<(&'static str, PhaseTimings)> {
+        self.oracles
+            .iter()
+            .filter_map(|o| o.timings().map(|t| (o.name(), t)))
+            .collect()
+    }
 }
 
 impl Default for QueryEngine {
@@ -549,3 +634,122 @@ fn extract_file_path(doc_id: &str) -> String {
         doc_id.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::oracle::{OracleMetadata, OracleResult};
+    use super::*;
+
+    /// Oracle returning fixed doc ids
+    struct StubOracle {
+        name: &'static str,
+        docs: &'static [&'static str],
+    }
+
+    impl Oracle for StubOracle {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn query(&self, _query: &str, limit: usize) -> Result<Vec<OracleResult>> {
+            Ok(self
+                .docs
+                .iter()
+                .take(limit)
+                .map(|doc| OracleResult {
+                    doc_id: doc.to_string(),
+                    content: doc.to_string(),
+                    source: self.name,
+                    score: 1.0,
+                    score_type: "stub",
+                    metadata: OracleMetadata::default(),
+                })
+                .collect())
+        }
+
+        fn is_available(&self) -> bool {
+            true
+        }
+    }
+
+    fn stub_engine() -> QueryEngine {
+        QueryEngine::with_oracles(
+            vec![
+                Box::new(StubOracle {
+                    name: "semantic",
+                    docs: &["shared", "semantic_only"],
+                }),
+                Box::new(StubOracle {
+                    name: "lexical",
+                    docs: &["shared", "lexical_only"],
+                }),
+            ],
+            RetrievalConfig::default(),
+        )
+    }
+
+    #[test]
+    fn test_restrict_to_single_oracle() -> Result<()> {
+        let mut engine = stub_engine();
+        let all = engine.query("anything", 10)?;
+        assert!(all.iter().any(|r| r.sources.contains(&"semantic")));
+
+        engine.restrict_to(&["lexical".to_string()])?;
+        assert_eq!(engine.active_oracles(), vec!["lexical"]);
+
+        let results = engine.query("anything", 10)?;
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.sources == vec!["lexical"]));
+        assert!(results.iter().all(|r| r.doc_id != "semantic_only"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restrict_to_rejects_unknown_oracle() {
+        let mut engine = stub_engine();
+        let err = engine
+            .restrict_to(&["lexcial".to_string()])
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("lexcial"));
+        assert!(err.contains("semantic, lexical"));
+        assert!(engine.config.oracle_filter.is_none());
+    }
+
+    #[test]
+    fn test_broken_semantic_index_is_typed_unless_another_oracle_answers() -> Result<()> {
+        use crate::commands::scry::internal::error::{http_status, ScryError};
+
+        let temp = tempfile::tempdir()?;
+        std::fs::write(temp.path().join("semantic.usearch"), b"not an index")?;
+        std::fs::write(temp.path().join("patina.db"), b"")?;
+        let semantic = || SemanticOracle::at(temp.path().join("patina.db"), temp.path());
+
+        let alone =
+            QueryEngine::with_oracles(vec![Box::new(semantic())], RetrievalConfig::default());
+        let err = alone.query("anything", 10).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ScryError>(),
+            Some(ScryError::IndexMissing { dimension, .. }) if dimension == "semantic"
+        ));
+        assert_eq!(http_status(&err), 503);
+
+        // One failing oracle doesn't sink the fusion
+        let mixed = QueryEngine::with_oracles(
+            vec![
+                Box::new(semantic()),
+                Box::new(StubOracle {
+                    name: "lexical",
+                    docs: &["lexical_only"],
+                }),
+            ],
+            RetrievalConfig::default(),
+        );
+        let results = mixed.query("anything", 10)?;
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].doc_id, "lexical_only");
+
+        Ok(())
+    }
+}
@@ -147,6 +147,7 @@ pub fn rrf_fuse_weighted(
 
 #[cfg(test)]
 mod tests {
+    use super::super::intent::QueryIntent;
     use super::*;
 
     fn make_result(doc_id: &str, source: &'static str) -> OracleResult {
@@ -210,4 +211,51 @@ mod tests {
 
         assert_eq!(fused.len(), 2);
     }
+
+    #[test]
+    fn test_rrf_k_changes_fused_order() {
+        // doc_top leads one oracle; doc_steady is 5th in two others
+        let lists = || {
+            let ranked = |source: &'static str, ids: &[&str]| -> Vec<OracleResult> {
+                ids.iter().map(|id| make_result(id, source)).collect()
+            };
+            vec![
+                ranked("semantic", &["doc_top", "s1", "s2"]),
+                ranked("lexical", &["l0", "l1", "l2", "l3", "doc_steady"]),
+                ranked("temporal", &["t0", "t1", "t2", "t3", "doc_steady"]),
+            ]
+        };
+        let position =
+            |fused: &[FusedResult], id: &str| fused.iter().position(|r| r.doc_id == id).unwrap();
+
+        // Smooth fusion rewards agreement across oracles: 2/65 > 1/61
+        let smooth = rrf_fuse(lists(), 60, 20);
+        assert!(position(&smooth, "doc_steady") < position(&smooth, "doc_top"));
+
+        // Sharp fusion lets a single top rank dominate: 1/2 > 2/6
+        let sharp = rrf_fuse(lists(), 1, 20);
+        assert!(position(&sharp, "doc_top") < position(&sharp, "doc_steady"));
+    }
+
+    #[test]
+    fn test_oracle_weight_breaks_tie() {
+        // Same rank in different oracles: equal RRF scores
+        let lists = || {
+            vec![
+                vec![make_result("temporal_only", "temporal")],
+                vec![make_result("lexical_only", "lexical")],
+            ]
+        };
+
+        let uniform = IntentWeights::for_intent(QueryIntent::General);
+        let fused = rrf_fuse_weighted(lists(), 60, 10, Some(&uniform));
+        assert_eq!(fused[0].fused_score, fused[1].fused_score);
+
+        let boosted = uniform.scaled(&[("lexical".to_string(), 2.0)]);
+        assert_eq!(boosted.weight_for("temporal"), 1.0);
+        let fused = rrf_fuse_weighted(lists(), 60, 10, Some(&boosted));
+        assert_eq!(fused[0].doc_id, "lexical_only");
+        assert_eq!(fused[1].doc_id, "temporal_only");
+        assert!(fused[0].fused_score > fused[1].fused_score);
+    }
 }
@@ -3,6 +3,11 @@
 //! Detects user intent from query text and provides intent-specific
 //! oracle weights for RRF fusion.
 
+use anyhow::{Context, Result};
+
+/// Oracle names that accept an explicit weight
+const ORACLE_NAMES: [&str; 5] = ["semantic", "lexical", "temporal", "persona", "belief"];
+
 /// Query intent categories
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum QueryIntent {
@@ -74,6 +79,34 @@ impl IntentWeights {
         }
     }
 
+    /// Scale by explicit per-oracle multipliers (`scry --oracle-weight`)
+    ///
+    /// Oracles without an override keep their intent weight (multiplier 1.0).
+    pub fn scaled(mut self, overrides: &[(String, f32)]) -> Self {
+        for (name, factor) in overrides {
+            match name.to_lowercase().as_str() {
+                "semantic" => self.semantic *= factor,
+                "lexical" => self.lexical *= factor,
+                "temporal" => self.temporal *= factor,
+                "persona" => self.persona *= factor,
+                "belief" => self.belief *= factor,
+                _ => {}
+            }
+        }
+        self
+    }
+
+    /// Weights by oracle name, in display order
+    pub fn entries(&self) -> [(&'static str, f32); 5] {
+        [
+            ("semantic", self.semantic),
+            ("lexical", self.lexical),
+            ("temporal", self.temporal),
+            ("persona", self.persona),
+            ("belief", self.belief),
+        ]
+    }
+
     /// Get weight for a specific oracle by name
     pub fn weight_for(&self, oracle_name: &str) -> f32 {
         match oracle_name.to_lowercase().as_str() {
@@ -87,6 +120,29 @@ impl IntentWeights {
     }
 }
 
+/// Parse an `--oracle-weight` spec like `lexical=2.0`
+pub fn parse_oracle_weight(spec: &str) -> Result<(String, f32)> {
+    let (name, weight) = spec
+        .split_once('=')
+        .with_context(|| format!("Oracle weight must be name=weight: '{}'", spec))?;
+    let name = name.trim().to_lowercase();
+    if !ORACLE_NAMES.contains(&name.as_str()) {
+        anyhow::bail!(
+            "Unknown oracle '{}' (expected one of: {})",
+            name,
+            ORACLE_NAMES.join(", ")
+        );
+    }
+    let weight: f32 = weight
+        .trim()
+        .parse()
+        .with_context(|| format!("Invalid weight in '{}'", spec))?;
+    if !weight.is_finite() || weight < 0.0 {
+        anyhow::bail!("Oracle weight must be a non-negative number: '{}'", spec);
+    }
+    Ok((name, weight))
+}
+
 /// Detect intent from query text
 ///
 /// Uses simple keyword matching. The LLM can also provide explicit intent
@@ -20,7 +20,8 @@ pub mod snippet;
 
 pub use engine::{QueryEngine, QueryOptions, RetrievalConfig};
 pub use fusion::FusedResult;
-pub use oracle::Oracle;
+pub use intent::parse_oracle_weight;
+pub use oracle::{Oracle, PhaseTimings};
 pub use oracles::BeliefOracle;
 pub use snippet::snippet;
 
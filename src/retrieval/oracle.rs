@@ -4,6 +4,7 @@
 //! Each oracle queries one knowledge dimension and returns ranked results.
 
 use anyhow::Result;
+use std::time::Duration;
 
 /// Result from a single oracle query
 #[derive(Debug, Clone)]
@@ -32,6 +33,32 @@ pub struct OracleMetadata {
     pub matches: Option<Vec<String>>,
 }
 
+/// Wall time of each vector-search phase (`scry --timing`)
+///
+/// One-off loads (model, projection weights, index) count toward the query
+/// that triggered them.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PhaseTimings {
+    pub embed: Duration,
+    pub projection: Duration,
+    pub index_load: Duration,
+    pub search: Duration,
+}
+
+impl PhaseTimings {
+    /// One-line breakdown in milliseconds
+    pub fn report(&self) -> String {
+        let ms = |d: Duration| d.as_secs_f64() * 1000.0;
+        format!(
+            "embed {:.1}ms, projection {:.1}ms, index load {:.1}ms, search {:.1}ms",
+            ms(self.embed),
+            ms(self.projection),
+            ms(self.index_load),
+            ms(self.search)
+        )
+    }
+}
+
 /// Oracle interface - each retrieval dimension implements this
 ///
 /// This is a strategy pattern (not adapter pattern) because oracles
@@ -45,4 +72,9 @@ pub trait Oracle: Send + Sync {
 
     /// Whether this oracle is available (index exists, etc.)
     fn is_available(&self) -> bool;
+
+    /// Phase timings of the last query (vector oracles only)
+    fn timings(&self) -> Option<PhaseTimings> {
+        None
+    }
 }
@@ -9,15 +9,15 @@ use rusqlite::Connection;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
-use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+use usearch::Index;
 
+use crate::commands::oxidize::index_meta;
 use crate::commands::oxidize::trainer::Projection;
+use crate::commands::scry::internal::search::embedding_model_in;
 use crate::retrieval::oracle::{Oracle, OracleMetadata, OracleResult};
+use patina::embeddings::id_offsets::{classify_key, source_id, KeyKind};
 use patina::embeddings::{create_embedder, EmbeddingEngine};
 
-const BELIEF_ID_OFFSET: i64 = 4_000_000_000;
-const FORGE_ID_OFFSET: i64 = 5_000_000_000;
-
 const VECTOR_WEIGHT: f32 = 0.7;
 const TEXT_WEIGHT: f32 = 0.3;
 
@@ -38,17 +38,19 @@ pub struct BeliefOracle {
 
 impl BeliefOracle {
     pub fn new() -> Self {
-        let model = patina::project::load(Path::new("."))
-            .ok()
-            .map(|c| c.embeddings.model)
-            .unwrap_or_else(|| "e5-base-v2".to_string());
+        Self::in_project(Path::new("."))
+    }
 
-        let embeddings_dir = format!(".patina/local/data/embeddings/{}/projections", model);
+    /// Oracle over the project at `root`, using the model its config names
+    pub fn in_project(root: &Path) -> Self {
+        let model = embedding_model_in(root);
+        let data_dir = root.join(".patina/local/data");
+        let embeddings_dir = data_dir.join(format!("embeddings/{}/projections", model));
 
         Self {
-            db_path: PathBuf::from(".patina/local/data/patina.db"),
-            index_path: PathBuf::from(format!("{}/semantic.usearch", embeddings_dir)),
-            projection_path: PathBuf::from(format!("{}/semantic.safetensors", embeddings_dir)),
+            db_path: data_dir.join("patina.db"),
+            index_path: embeddings_dir.join("semantic.usearch"),
+            projection_path: embeddings_dir.join("semantic.safetensors"),
             cache: OnceLock::new(),
         }
     }
@@ -66,12 +68,7 @@ impl BeliefOracle {
             None
         };
 
-        let index_options = IndexOptions {
-            dimensions: 256,
-            metric: MetricKind::Cos,
-            quantization: ScalarKind::F32,
-            ..Default::default()
-        };
+        let index_options = index_meta::load_options(&self.index_path);
 
         let index =
             Index::new(&index_options).map_err(|e| format!("Failed to create index: {}", e))?;
@@ -118,6 +115,7 @@ impl BeliefOracle {
         // Over-fetch aggressively: ~47 beliefs in index of thousands
         let over_fetch = (limit * 50).min(cache.index_size / 2).max(limit);
 
+        index_meta::ensure_dimensions(&cache.index, &projected)?;
         let matches = cache
             .index
             .search(&projected, over_fetch)
@@ -129,8 +127,8 @@ impl BeliefOracle {
         for i in 0..matches.keys.len() {
             let key = matches.keys[i] as i64;
 
-            // Filter to belief range: [BELIEF_ID_OFFSET, FORGE_ID_OFFSET)
-            if !(BELIEF_ID_OFFSET..FORGE_ID_OFFSET).contains(&key) {
+            // Filter to the belief range
+            if classify_key(key) != KeyKind::Belief {
                 continue;
             }
 
@@ -139,7 +137,7 @@ impl BeliefOracle {
                 continue;
             }
 
-            let rowid = key - BELIEF_ID_OFFSET;
+            let rowid = source_id(key);
             if let Ok(hit) = enrich_belief(&conn, rowid, score) {
                 hits.push(hit);
             }
@@ -5,24 +5,27 @@
 
 use anyhow::Result;
 use rusqlite::Connection;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::commands::scry::{scry_lexical, ScryOptions};
 use crate::retrieval::oracle::{Oracle, OracleMetadata, OracleResult};
 
 pub struct LexicalOracle {
+    root: PathBuf,
     db_path: PathBuf,
     include_issues: bool,
 }
 
 impl LexicalOracle {
-    pub fn new() -> Self {
-        Self::with_options(false)
+    pub fn with_options(include_issues: bool) -> Self {
+        Self::in_project(Path::new("."), include_issues)
     }
 
-    pub fn with_options(include_issues: bool) -> Self {
+    /// Oracle over the project at `root`
+    pub fn in_project(root: &Path, include_issues: bool) -> Self {
         Self {
-            db_path: PathBuf::from(".patina/local/data/patina.db"),
+            root: root.to_path_buf(),
+            db_path: root.join(".patina/local/data/patina.db"),
             include_issues,
         }
     }
@@ -105,6 +108,7 @@ impl Oracle for LexicalOracle {
             limit,
             include_persona: false,
             include_issues: self.include_issues,
+            project_root: self.root.clone(),
             ..Default::default()
         };
 
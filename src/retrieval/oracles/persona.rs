@@ -20,15 +20,25 @@ struct PersonaCache {
 }
 
 pub struct PersonaOracle {
+    /// False when `PATINA_PERSONA` names no valid persona
+    enabled: bool,
     db_path: PathBuf,
     index_path: PathBuf,
     cache: OnceLock<Result<PersonaCache, String>>,
 }
 
 impl PersonaOracle {
+    /// Oracle over the active persona (`PATINA_PERSONA`, else `default`)
+    ///
+    /// An invalid persona name disables the oracle with a warning instead of
+    /// silently searching `default`.
     pub fn new() -> Self {
-        let cache_dir = persona_paths::cache_dir();
+        let (enabled, cache_dir) = match crate::commands::persona::active_for_search() {
+            Some(name) => (true, persona_paths::cache_dir_for(&name)),
+            None => (false, PathBuf::new()),
+        };
         Self {
+            enabled,
             db_path: cache_dir.join("persona.db"),
             index_path: cache_dir.join("persona.usearch"),
             cache: OnceLock::new(),
@@ -78,7 +88,7 @@ impl Oracle for PersonaOracle {
 
     fn query(&self, query: &str, limit: usize) -> Result<Vec<OracleResult>> {
         // Return empty if no index exists
-        if !self.index_path.exists() {
+        if !self.enabled || !self.index_path.exists() {
             return Ok(Vec::new());
         }
 
@@ -153,6 +163,6 @@ impl Oracle for PersonaOracle {
     }
 
     fn is_available(&self) -> bool {
-        self.db_path.exists() && self.index_path.exists()
+        self.enabled && self.db_path.exists() && self.index_path.exists()
     }
 }
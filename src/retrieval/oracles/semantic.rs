@@ -7,11 +7,15 @@ use anyhow::{Context, Result};
 use rusqlite::Connection;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, OnceLock};
-use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
+use std::time::Instant;
+use usearch::Index;
 
+use crate::commands::oxidize::index_meta;
 use crate::commands::oxidize::trainer::Projection;
 use crate::commands::scry::internal::enrichment::{enrich_results, SearchResults};
-use crate::retrieval::oracle::{Oracle, OracleMetadata, OracleResult};
+use crate::commands::scry::internal::error::ScryError;
+use crate::commands::scry::internal::search::embedding_model_in;
+use crate::retrieval::oracle::{Oracle, OracleMetadata, OracleResult, PhaseTimings};
 use patina::embeddings::{create_embedder, EmbeddingEngine};
 
 /// Cached resources for semantic search (loaded once, reused)
@@ -19,6 +23,17 @@ struct SemanticCache {
     embedder: Mutex<Box<dyn EmbeddingEngine>>,
     projection: Option<Projection>,
     index: Index,
+    /// Load times, charged to (and taken by) the first query
+    load_timings: Mutex<Option<PhaseTimings>>,
+}
+
+/// Why the cache couldn't be built, kept as text so every query can report it
+enum CacheError {
+    /// The index file couldn't be loaded
+    Index,
+    /// The embedding model couldn't be created
+    Embedder(String),
+    Other(String),
 }
 
 pub struct SemanticOracle {
@@ -26,72 +41,97 @@ pub struct SemanticOracle {
     index_path: PathBuf,
     projection_path: PathBuf,
     /// Lazy-initialized cache - loads on first query
-    cache: OnceLock<Result<SemanticCache, String>>,
+    cache: OnceLock<Result<SemanticCache, CacheError>>,
+    /// Phases of the last query
+    last_timings: Mutex<Option<PhaseTimings>>,
 }
 
 impl SemanticOracle {
     pub fn new() -> Self {
-        // Read model from project config
-        let model = patina::project::load(Path::new("."))
-            .ok()
-            .map(|c| c.embeddings.model)
-            .unwrap_or_else(|| "e5-base-v2".to_string());
+        Self::in_project(Path::new("."))
+    }
 
-        let embeddings_dir = format!(".patina/local/data/embeddings/{}/projections", model);
+    /// Oracle over the project at `root`, using the model its config names
+    pub fn in_project(root: &Path) -> Self {
+        let model = embedding_model_in(root);
+        let data_dir = root.join(".patina/local/data");
+        Self::at(
+            data_dir.join("patina.db"),
+            &data_dir.join(format!("embeddings/{}/projections", model)),
+        )
+    }
 
+    /// Oracle over `db_path` and the semantic index in `embeddings_dir`
+    pub fn at(db_path: PathBuf, embeddings_dir: &Path) -> Self {
         Self {
-            db_path: PathBuf::from(".patina/local/data/patina.db"),
-            index_path: PathBuf::from(format!("{}/semantic.usearch", embeddings_dir)),
-            projection_path: PathBuf::from(format!("{}/semantic.safetensors", embeddings_dir)),
+            db_path,
+            index_path: embeddings_dir.join("semantic.usearch"),
+            projection_path: embeddings_dir.join("semantic.safetensors"),
             cache: OnceLock::new(),
+            last_timings: Mutex::new(None),
         }
     }
 
-    /// Initialize cache (embedder, projection, index) - called once
-    fn init_cache(&self) -> Result<SemanticCache, String> {
-        // Create embedder
-        let embedder =
-            create_embedder().map_err(|e| format!("Failed to create embedder: {}", e))?;
+    /// Initialize cache (index, projection, embedder) - called once
+    ///
+    /// The index loads first: it's the cheap check, and a broken index should
+    /// be reported as such rather than after a model load.
+    fn init_cache(&self) -> Result<SemanticCache, CacheError> {
+        let mut timings = PhaseTimings::default();
+
+        // Load index
+        let started = Instant::now();
+        let index_options = index_meta::load_options(&self.index_path);
+
+        let index = Index::new(&index_options)
+            .map_err(|e| CacheError::Other(format!("Failed to create index: {}", e)))?;
+
+        index
+            .load(self.index_path.to_str().unwrap_or(""))
+            .map_err(|_| CacheError::Index)?;
+        timings.index_load = started.elapsed();
 
         // Load projection (optional)
+        let started = Instant::now();
         let projection = if self.projection_path.exists() {
             Some(
                 Projection::load_safetensors(&self.projection_path)
-                    .map_err(|e| format!("Failed to load projection: {}", e))?,
+                    .map_err(|e| CacheError::Other(format!("Failed to load projection: {}", e)))?,
             )
         } else {
             None
         };
+        timings.projection = started.elapsed();
 
-        // Load index
-        let index_options = IndexOptions {
-            dimensions: 256,
-            metric: MetricKind::Cos,
-            quantization: ScalarKind::F32,
-            ..Default::default()
-        };
-
-        let index =
-            Index::new(&index_options).map_err(|e| format!("Failed to create index: {}", e))?;
-
-        index
-            .load(self.index_path.to_str().unwrap_or(""))
-            .map_err(|e| format!("Failed to load index: {}", e))?;
+        // Create embedder
+        let started = Instant::now();
+        let embedder = create_embedder()
+            .map_err(|e| CacheError::Embedder(format!("Failed to create embedder: {}", e)))?;
+        timings.embed = started.elapsed();
 
         Ok(SemanticCache {
             embedder: Mutex::new(embedder),
             projection,
             index,
+            load_timings: Mutex::new(Some(timings)),
         })
     }
 
-    /// Get or initialize cache
+    /// Get or initialize cache (index and embedder failures as `ScryError`)
     fn get_cache(&self) -> Result<&SemanticCache> {
         let cache_result = self.cache.get_or_init(|| self.init_cache());
 
         match cache_result {
             Ok(cache) => Ok(cache),
-            Err(msg) => Err(anyhow::anyhow!("{}", msg)),
+            Err(CacheError::Index) => Err(ScryError::IndexMissing {
+                dimension: "semantic".to_string(),
+                path: self.index_path.display().to_string(),
+            }
+            .into()),
+            Err(CacheError::Embedder(msg)) => {
+                Err(ScryError::EmbedFailed(anyhow::anyhow!("{}", msg)).into())
+            }
+            Err(CacheError::Other(msg)) => Err(anyhow::anyhow!("{}", msg)),
         }
     }
 }
@@ -103,27 +143,46 @@ impl Oracle for SemanticOracle {
 
     fn query(&self, query: &str, limit: usize) -> Result<Vec<OracleResult>> {
         let cache = self.get_cache()?;
+        let mut timings = cache
+            .load_timings
+            .lock()
+            .ok()
+            .and_then(|mut t| t.take())
+            .unwrap_or_default();
 
         // Embed query (needs mutable access to embedder)
+        let started = Instant::now();
         let query_embedding = {
             let mut embedder = cache
                 .embedder
                 .lock()
                 .map_err(|e| anyhow::anyhow!("Embedder lock poisoned: {}", e))?;
-            embedder.embed_query(query)?
+            embedder
+                .embed_query(query)
+                .map_err(ScryError::EmbedFailed)?
         };
 
+        timings.embed += started.elapsed();
+
         // Project embedding if projection exists
+        let started = Instant::now();
         let projected = match &cache.projection {
             Some(proj) => proj.forward(&query_embedding),
             None => query_embedding,
         };
+        timings.projection += started.elapsed();
 
         // Search index
+        index_meta::ensure_dimensions(&cache.index, &projected)?;
+        let started = Instant::now();
         let matches = cache
             .index
             .search(&projected, limit)
             .with_context(|| "Vector search failed")?;
+        timings.search = started.elapsed();
+        if let Ok(mut last) = self.last_timings.lock() {
+            *last = Some(timings);
+        }
 
         // Convert to SearchResults for enrichment
         let results = SearchResults {
@@ -164,4 +223,8 @@ impl Oracle for SemanticOracle {
     fn is_available(&self) -> bool {
         self.index_path.exists() && self.db_path.exists()
     }
+
+    fn timings(&self) -> Option<PhaseTimings> {
+        self.last_timings.lock().ok().and_then(|t| *t)
+    }
 }
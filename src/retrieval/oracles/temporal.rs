@@ -7,7 +7,7 @@
 use anyhow::Result;
 use rusqlite::Connection;
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::retrieval::oracle::{Oracle, OracleMetadata, OracleResult};
 
@@ -17,8 +17,13 @@ pub struct TemporalOracle {
 
 impl TemporalOracle {
     pub fn new() -> Self {
+        Self::in_project(Path::new("."))
+    }
+
+    /// Oracle over the project at `root`
+    pub fn in_project(root: &Path) -> Self {
         Self {
-            db_path: PathBuf::from(".patina/local/data/patina.db"),
+            db_path: root.join(".patina/local/data/patina.db"),
         }
     }
 
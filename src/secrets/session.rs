@@ -103,9 +103,12 @@ fn serve_token() -> Option<String> {
 // === Public API ===
 
 /// Check if the serve daemon is running (UDS first, then TCP).
+///
+/// Probes `/version`, not `/health`: health answers 503 while the daemon
+/// has no indices, but the secrets cache works regardless.
 pub fn is_serve_running() -> bool {
     // Try UDS
-    if uds_get("/health").is_some() {
+    if uds_get("/version").is_some() {
         return true;
     }
 
@@ -118,7 +121,7 @@ pub fn is_serve_running() -> bool {
         Err(_) => return false,
     };
 
-    let mut req = client.get(format!("{}/health", serve_url()));
+    let mut req = client.get(format!("{}/version", serve_url()));
     if let Some(token) = serve_token() {
         req = req.header("Authorization", format!("Bearer {}", token));
     }